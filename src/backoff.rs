@@ -0,0 +1,347 @@
+//! Policies which decide how long to wait before a failed request is sent again
+//!
+//! A `BackoffPolicy` only calculates the time to wait. Limits like the maximum
+//! number of attempts or the maximum time spent waiting, as well as which
+//! errors are retried, are added using `Backoff`, which is passed to
+//! `Tenable::request_with_policy` and `Tenable::request_with_policy_async`.
+//!
+//! Rate limited requests are always retried, as the server did not process
+//! them. Other transient errors (see `Error::is_retryable`) are only retried if
+//! enabled for the kind of request, so that requests which are not idempotent,
+//! like `POST`, are not replayed by accident.
+
+use crate::Error;
+use http::{header::RETRY_AFTER, HeaderMap};
+use std::{
+    convert::TryFrom,
+    fmt,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// Calculates the time to wait before a request is sent again
+pub trait BackoffPolicy {
+    /// Returns the time to wait before the next attempt or `None` if the
+    /// request should not be retried anymore.
+    ///
+    /// # Arguments
+    ///
+    /// * `attempt`: Number of failed attempts so far, starting at 1
+    /// * `retry_after`: Time to wait as requested by the server using the `Retry-After` header
+    fn backoff(&mut self, attempt: u32, retry_after: Option<Duration>) -> Option<Duration>;
+}
+
+impl<P: BackoffPolicy + ?Sized> BackoffPolicy for &mut P {
+    #[inline]
+    fn backoff(&mut self, attempt: u32, retry_after: Option<Duration>) -> Option<Duration> {
+        (**self).backoff(attempt, retry_after)
+    }
+}
+
+/// Waits `step` longer after every attempt: 100ms, 200ms, 300ms... by default
+#[derive(Clone, Debug, PartialEq, Copy)]
+pub struct Linear {
+    /// Time added to the wait time after every attempt
+    pub step: Duration,
+}
+
+impl Default for Linear {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            step: Duration::from_millis(100),
+        }
+    }
+}
+
+impl BackoffPolicy for Linear {
+    #[inline]
+    fn backoff(&mut self, attempt: u32, _retry_after: Option<Duration>) -> Option<Duration> {
+        self.step.checked_mul(attempt)
+    }
+}
+
+/// Doubles the wait time after every attempt, up to `max`. With `jitter`
+/// enabled, a random time between zero and the calculated wait time is used
+/// instead, which spreads out clients that were rate limited at the same time.
+#[derive(Clone, Debug, PartialEq, Copy)]
+pub struct Exponential {
+    /// Time to wait after the first attempt
+    pub initial: Duration,
+    /// Upper bound for a single wait time
+    pub max: Duration,
+    /// Whether to randomize the wait time
+    pub jitter: bool,
+    state: u64,
+}
+
+impl Exponential {
+    /// Creates a new exponential policy
+    ///
+    /// # Arguments
+    ///
+    /// * `initial`: Time to wait after the first attempt
+    /// * `max`: Upper bound for a single wait time
+    /// * `jitter`: Whether to randomize the wait time
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use tenable::backoff::Exponential;
+    /// let policy = Exponential::new(Duration::from_millis(500), Duration::from_secs(60), true);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn new(initial: Duration, max: Duration, jitter: bool) -> Self {
+        let seed = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |time| u64::from(time.subsec_nanos()));
+        Self {
+            initial,
+            max,
+            jitter,
+            // xorshift must not be seeded with zero
+            state: seed | 1,
+        }
+    }
+
+    /// xorshift64 which is good enough to spread out retries
+    fn random(&mut self) -> u64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        self.state
+    }
+}
+
+impl Default for Exponential {
+    #[inline]
+    fn default() -> Self {
+        Self::new(Duration::from_millis(100), Duration::from_secs(60), true)
+    }
+}
+
+impl BackoffPolicy for Exponential {
+    #[inline]
+    fn backoff(&mut self, attempt: u32, _retry_after: Option<Duration>) -> Option<Duration> {
+        let factor = 2_u32
+            .checked_pow(attempt.saturating_sub(1))
+            .unwrap_or(u32::MAX);
+        let wait = self
+            .initial
+            .checked_mul(factor)
+            .map_or(self.max, |wait| wait.min(self.max));
+        if !self.jitter {
+            return Some(wait);
+        }
+        let millis = u64::try_from(wait.as_millis()).unwrap_or(u64::MAX);
+        Some(Duration::from_millis(
+            self.random() % millis.saturating_add(1),
+        ))
+    }
+}
+
+/// Waits as long as the server requested using the `Retry-After` header and
+/// falls back to another policy if the header is missing
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RetryAfter<P> {
+    /// Policy used when the server did not send a `Retry-After` header
+    pub fallback: P,
+}
+
+impl<P: BackoffPolicy> BackoffPolicy for RetryAfter<P> {
+    #[inline]
+    fn backoff(&mut self, attempt: u32, retry_after: Option<Duration>) -> Option<Duration> {
+        match retry_after {
+            Some(wait) => Some(wait),
+            None => self.fallback.backoff(attempt, retry_after),
+        }
+    }
+}
+
+/// A `BackoffPolicy` together with the limits after which a request is given
+/// up and the requests which are retried on transient errors
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Backoff<P> {
+    /// Policy which calculates the time to wait
+    pub policy: P,
+    /// Maximum number of attempts, including the first one
+    pub max_attempts: Option<u32>,
+    /// Maximum time to spend waiting in total
+    pub max_elapsed: Option<Duration>,
+    /// Whether idempotent requests like `GET`, `PUT` or `DELETE` are retried on transient errors
+    pub retry_idempotent: bool,
+    /// Whether non-idempotent requests like `POST` are retried on transient errors
+    pub retry_non_idempotent: bool,
+}
+
+impl<P: BackoffPolicy> Backoff<P> {
+    /// Creates a backoff without any limits, which only retries rate limited requests
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use tenable::backoff::{Backoff, Exponential, RetryAfter};
+    /// let backoff = Backoff::new(RetryAfter { fallback: Exponential::default() })
+    ///     .max_attempts(5)
+    ///     .max_elapsed(Duration::from_secs(300));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn new(policy: P) -> Self {
+        Self {
+            policy,
+            max_attempts: None,
+            max_elapsed: None,
+            retry_idempotent: false,
+            retry_non_idempotent: false,
+        }
+    }
+
+    /// Gives up after the given number of attempts, including the first one
+    #[must_use]
+    #[inline]
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = Some(max_attempts);
+        self
+    }
+
+    /// Gives up once the time spent waiting would exceed the given duration
+    #[must_use]
+    #[inline]
+    pub fn max_elapsed(mut self, max_elapsed: Duration) -> Self {
+        self.max_elapsed = Some(max_elapsed);
+        self
+    }
+
+    /// Enables or disables retrying idempotent requests like `GET`, `PUT` or
+    /// `DELETE` on transient errors
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tenable::backoff::{Backoff, Exponential};
+    /// let backoff = Backoff::new(Exponential::default())
+    ///     .retry_idempotent(true)
+    ///     .max_attempts(5);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn retry_idempotent(mut self, retry: bool) -> Self {
+        self.retry_idempotent = retry;
+        self
+    }
+
+    /// Enables or disables retrying non-idempotent requests like `POST` on
+    /// transient errors. Only enable this if replaying the request is safe.
+    #[must_use]
+    #[inline]
+    pub fn retry_non_idempotent(mut self, retry: bool) -> Self {
+        self.retry_non_idempotent = retry;
+        self
+    }
+
+    /// Whether a request failing with the given error should be sent again
+    pub(crate) fn should_retry<RE: fmt::Debug>(&self, err: &Error<RE>, idempotent: bool) -> bool {
+        match err {
+            Error::RateLimitReached => true,
+            err if idempotent => self.retry_idempotent && err.is_retryable(),
+            err => self.retry_non_idempotent && err.is_retryable(),
+        }
+    }
+
+    /// Returns the time to wait after the given failed attempt or the reason
+    /// to give up. `elapsed` is the time spent waiting so far and is updated
+    /// accordingly.
+    pub(crate) fn next<RE: fmt::Debug>(
+        &mut self,
+        attempt: u32,
+        elapsed: &mut Duration,
+        retry_after: Option<Duration>,
+    ) -> Result<Duration, Error<RE>> {
+        if self.max_attempts.map_or(false, |max| attempt >= max) {
+            return Err(Error::MaximumAttemptsReached);
+        }
+        let wait = self
+            .policy
+            .backoff(attempt, retry_after)
+            .ok_or(Error::MaximumWaitTimeReached)?;
+        let total = elapsed
+            .checked_add(wait)
+            .ok_or(Error::MaximumWaitTimeReached)?;
+        if self.max_elapsed.map_or(false, |max| total > max) {
+            return Err(Error::MaximumWaitTimeReached);
+        }
+        *elapsed = total;
+        Ok(wait)
+    }
+}
+
+/// Reads the `Retry-After` header, which either contains the number of seconds
+/// to wait or the date after which the request may be sent again.
+#[must_use]
+#[inline]
+pub fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+    if let Ok(seconds) = value.parse() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = parse_http_date(value)?;
+    Some(
+        date.duration_since(SystemTime::now())
+            .unwrap_or_else(|_| Duration::from_secs(0)),
+    )
+}
+
+/// Parses an IMF-fixdate like `Sun, 06 Nov 1994 08:49:37 GMT`
+fn parse_http_date(value: &str) -> Option<SystemTime> {
+    let mut parts = value.split_whitespace().skip(1);
+    let day: u64 = parts.next()?.parse().ok()?;
+    let month = match parts.next()? {
+        "Jan" => 1,
+        "Feb" => 2,
+        "Mar" => 3,
+        "Apr" => 4,
+        "May" => 5,
+        "Jun" => 6,
+        "Jul" => 7,
+        "Aug" => 8,
+        "Sep" => 9,
+        "Oct" => 10,
+        "Nov" => 11,
+        "Dec" => 12,
+        _ => return None,
+    };
+    let year: u64 = parts.next()?.parse().ok()?;
+    let mut time = parts.next()?.split(':');
+    let hours: u64 = time.next()?.parse().ok()?;
+    let minutes: u64 = time.next()?.parse().ok()?;
+    let seconds: u64 = time.next()?.parse().ok()?;
+    // IMF-fixdate has a four digit year, which also keeps the arithmetic below from overflowing
+    if year < 1970
+        || year > 9999
+        || day == 0
+        || day > 31
+        || hours > 23
+        || minutes > 59
+        || seconds > 60
+    {
+        return None;
+    }
+
+    // days since the unix epoch, see http://howardhinnant.github.io/date_algorithms.html
+    let (year, month) = if month <= 2 {
+        (year - 1, month + 9)
+    } else {
+        (year, month - 3)
+    };
+    let era = year / 400;
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * month + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = (era * 146_097 + day_of_era).checked_sub(719_468)?;
+
+    let secs = days * 86_400 + hours * 3_600 + minutes * 60 + seconds;
+    UNIX_EPOCH.checked_add(Duration::from_secs(secs))
+}
@@ -0,0 +1,363 @@
+use crate::{
+    backoff::{Backoff, BackoffPolicy},
+    export::{ExportChunks, ExportRequest},
+    transport::{AsyncTransport, Transport},
+    Error, HttpRequest, Response, Tenable,
+};
+use futures_util::stream::Stream;
+use http::Request;
+use std::{borrow::Cow, fmt, future::Future, time::Duration};
+
+/// Tenable Client which owns the transport used to send requests
+///
+/// The functions of `Tenable` expect the function sending the request on
+/// every call. `TenableClient` instead holds a `Transport` or `AsyncTransport`,
+/// like one of the transports in the `transport` module, which keeps state
+/// like connection pools between requests.
+#[derive(Clone, Debug)]
+pub struct TenableClient<'a, T> {
+    /// Inner tenable Client used to create requests
+    pub tenable: Tenable<'a>,
+    /// Transport used to send requests
+    pub transport: T,
+}
+
+impl<'a, T> TenableClient<'a, T> {
+    /// Creates a new `TenableClient` which sends requests using the given transport
+    ///
+    /// # Arguments
+    ///
+    /// * `tenable`: Tenable Client holding the credentials
+    /// * `transport`: Transport used to send requests
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tenable::{transport::ReqwestBlocking, Tenable, TenableClient};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let client = TenableClient::new(tenable, ReqwestBlocking::default());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn new(tenable: Tenable<'a>, transport: T) -> Self {
+        Self { tenable, transport }
+    }
+}
+
+impl<T> TenableClient<'_, T> {
+    /// Executes a synchronous http request. See `Tenable::request`.
+    ///
+    /// # Errors
+    ///
+    /// Fails in the following cases:
+    ///
+    /// * Unable to create a valid Request
+    /// * Server responded with error code
+    /// * Unable to deserialize the server response
+    /// * Errors returned by the transport
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use tenable::{requests::AssetReq, transport::ReqwestBlocking, Tenable, TenableClient};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let client = TenableClient::new(tenable, ReqwestBlocking::default());
+    /// let req = client.tenable.assets();
+    /// let _assets = client.send(req).expect("Unable to list all assets");
+    /// ```
+    #[inline]
+    #[allow(single_use_lifetimes)]
+    pub fn send<'a, O, R, CR, RE>(&self, request: CR) -> Result<O, Error<RE>>
+    where
+        CR: Into<Cow<'a, R>>,
+        R: 'a + HttpRequest<RE, Output = O>,
+        RE: fmt::Debug,
+        T: Transport<RE>,
+    {
+        Tenable::request(request, |req| self.transport.send(req))
+    }
+
+    /// Executes a synchronous http request and backs off according to the
+    /// given `Backoff`. See `Tenable::request_with_policy`.
+    ///
+    /// # Errors
+    ///
+    /// Fails in the following cases:
+    ///
+    /// * Unable to create a valid Request
+    /// * Server responded with error code
+    /// * Unable to deserialize the server response
+    /// * Errors returned by the transport
+    /// * The maximum number of attempts or the maximum wait time was reached
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::thread::sleep;
+    /// use tenable::{
+    ///     backoff::{Backoff, Exponential, RetryAfter},
+    ///     requests::AssetReq,
+    ///     transport::ReqwestBlocking,
+    ///     Tenable, TenableClient,
+    /// };
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let client = TenableClient::new(tenable, ReqwestBlocking::default());
+    /// let req = client.tenable.assets();
+    /// let backoff = Backoff::new(RetryAfter { fallback: Exponential::default() }).max_attempts(5);
+    /// let _assets = client.send_with_policy(req, sleep, backoff)
+    ///     .expect("Unable to list all assets");
+    /// ```
+    #[inline]
+    #[allow(single_use_lifetimes)]
+    pub fn send_with_policy<'a, O, R, CR, RE, BF, P>(
+        &self,
+        request: CR,
+        backoff_fun: BF,
+        backoff: Backoff<P>,
+    ) -> Result<O, Error<RE>>
+    where
+        CR: Into<Cow<'a, R>>,
+        R: 'a + HttpRequest<RE, Output = O>,
+        RE: fmt::Debug,
+        T: Transport<RE>,
+        BF: Fn(Duration),
+        P: BackoffPolicy,
+    {
+        Tenable::request_with_policy(
+            request,
+            |req| self.transport.send(req),
+            backoff_fun,
+            backoff,
+        )
+    }
+
+    /// Runs an export job to completion. See `Tenable::export`.
+    ///
+    /// # Errors
+    ///
+    /// The iterator yields an error and ends in the following cases:
+    ///
+    /// * Any request fails as described in `Tenable::request_with_backoff`
+    /// * The export job was cancelled or failed
+    /// * The export job or one of its chunks is no longer available
+    ///
+    /// If the job is still running at that point, it is cancelled using the
+    /// matching `*_export_cancel` request before the error is yielded.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::{thread::sleep, time::Duration};
+    /// use tenable::{
+    ///     requests::AssetReq, transport::ReqwestBlocking, types::AssetsExportDef, Tenable,
+    ///     TenableClient,
+    /// };
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let client = TenableClient::new(tenable, ReqwestBlocking::default());
+    /// let req = client.tenable.assets_export(AssetsExportDef {
+    ///     chunk_size: 1000,
+    ///     ..AssetsExportDef::default()
+    /// });
+    /// for chunk in client.export(req, sleep, Duration::from_secs(5)) {
+    ///     let _assets = chunk.expect("Unable to export assets");
+    /// }
+    /// ```
+    #[inline]
+    pub fn export<'a, R, RE, BF>(
+        &'a self,
+        request: R,
+        backoff_fun: BF,
+        poll_interval: Duration,
+    ) -> ExportChunks<'a, R, RE, impl Fn(Request<Vec<u8>>) -> Result<Response, Error<RE>> + 'a, BF>
+    where
+        R: ExportRequest<'a, RE>,
+        RE: fmt::Debug,
+        T: Transport<RE>,
+        BF: Fn(Duration),
+    {
+        Tenable::export(
+            request,
+            move |req| self.transport.send(req),
+            backoff_fun,
+            poll_interval,
+        )
+    }
+
+    /// Executes an asynchronous http request. See `Tenable::request_async`.
+    ///
+    /// # Errors
+    ///
+    /// Fails in the following cases:
+    ///
+    /// * Unable to create a valid Request
+    /// * Server responded with error code
+    /// * Unable to deserialize the server response
+    /// * Errors returned by the transport
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio::runtime::Runtime;
+    /// use tenable::{requests::AssetReq, transport::ReqwestAsync, types::Assets, Tenable, TenableClient};
+    /// # let mut rt = Runtime::new().expect("Unable to create runtime");
+    /// # rt.block_on(async {
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let client = TenableClient::new(tenable, ReqwestAsync::default());
+    /// let req = client.tenable.assets();
+    /// let _assets: Assets = client.send_async(req).await.expect("Unable to list all assets");
+    /// # })
+    /// ```
+    #[inline]
+    #[allow(single_use_lifetimes)]
+    pub async fn send_async<'a, O, R, CR, RE>(&self, request: CR) -> Result<O, Error<RE>>
+    where
+        CR: Into<Cow<'a, R>>,
+        R: 'a + HttpRequest<RE, Output = O>,
+        RE: fmt::Debug,
+        T: AsyncTransport<RE>,
+    {
+        Tenable::request_async(request, |req| self.transport.send(req)).await
+    }
+
+    /// Executes an asynchronous http request and backs off according to the
+    /// given `Backoff`. See `Tenable::request_with_policy_async`.
+    ///
+    /// # Errors
+    ///
+    /// Fails in the following cases:
+    ///
+    /// * Unable to create a valid Request
+    /// * Server responded with error code
+    /// * Unable to deserialize the server response
+    /// * Errors returned by the transport
+    /// * The maximum number of attempts or the maximum wait time was reached
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio::runtime::Runtime;
+    /// use tenable::{
+    ///     backoff::{Backoff, Exponential},
+    ///     requests::AssetReq,
+    ///     transport::ReqwestAsync,
+    ///     types::Assets,
+    ///     Tenable, TenableClient,
+    /// };
+    /// use tokio::time::delay_for;
+    /// # let mut rt = Runtime::new().expect("Unable to create runtime");
+    /// # rt.block_on(async {
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let client = TenableClient::new(tenable, ReqwestAsync::default());
+    /// let req = client.tenable.assets();
+    /// let backoff = Backoff::new(Exponential::default()).retry_idempotent(true);
+    /// let _assets: Assets = client.send_with_policy_async(req, delay_for, backoff).await
+    ///     .expect("Unable to list all assets");
+    /// # })
+    /// ```
+    #[inline]
+    #[allow(single_use_lifetimes)]
+    pub async fn send_with_policy_async<'a, O, R, CR, RE, BF, FutBF, P>(
+        &self,
+        request: CR,
+        backoff_fun: BF,
+        backoff: Backoff<P>,
+    ) -> Result<O, Error<RE>>
+    where
+        CR: Into<Cow<'a, R>>,
+        R: 'a + HttpRequest<RE, Output = O>,
+        RE: fmt::Debug,
+        T: AsyncTransport<RE>,
+        BF: Fn(Duration) -> FutBF,
+        FutBF: Future<Output = ()>,
+        P: BackoffPolicy,
+    {
+        Tenable::request_with_policy_async(
+            request,
+            |req| self.transport.send(req),
+            backoff_fun,
+            backoff,
+        )
+        .await
+    }
+
+    /// Runs an export job to completion asynchronously. See `Tenable::export_async`.
+    ///
+    /// # Errors
+    ///
+    /// The stream yields an error and ends in the following cases:
+    ///
+    /// * Any request fails as described in `Tenable::request_with_backoff_async`
+    /// * The export job was cancelled or failed
+    /// * The export job or one of its chunks is no longer available
+    ///
+    /// If the job is still running at that point, it is cancelled using the
+    /// matching `*_export_cancel` request before the error is yielded.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio::runtime::Runtime;
+    /// use futures_util::stream::StreamExt;
+    /// use std::time::Duration;
+    /// use tenable::{
+    ///     requests::VulnReq, transport::ReqwestAsync, types::VulnsExportDef, Tenable,
+    ///     TenableClient,
+    /// };
+    /// use tokio::time::delay_for;
+    /// # let mut rt = Runtime::new().expect("Unable to create runtime");
+    /// # rt.block_on(async {
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let client = TenableClient::new(tenable, ReqwestAsync::default());
+    /// let req = client.tenable.vulns_export(VulnsExportDef::default());
+    /// let chunks = client.export_async(req, delay_for, Duration::from_secs(5));
+    /// futures_util::pin_mut!(chunks);
+    /// while let Some(chunk) = chunks.next().await {
+    ///     let _findings = chunk.expect("Unable to export vulnerabilities");
+    /// }
+    /// # })
+    /// ```
+    #[inline]
+    pub fn export_async<'a, R, RE, BF, FutBF>(
+        &'a self,
+        request: R,
+        backoff_fun: BF,
+        poll_interval: Duration,
+    ) -> impl Stream<Item = Result<Vec<R::Item>, Error<RE>>> + 'a
+    where
+        R: ExportRequest<'a, RE>,
+        R::Item: 'a,
+        RE: 'a + fmt::Debug,
+        T: AsyncTransport<RE>,
+        T::Future: 'a,
+        BF: 'a + Fn(Duration) -> FutBF,
+        FutBF: 'a + Future<Output = ()>,
+    {
+        Tenable::export_async(
+            request,
+            move |req| self.transport.send(req),
+            backoff_fun,
+            poll_interval,
+        )
+    }
+}
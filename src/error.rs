@@ -1,6 +1,12 @@
+use crate::{types::ExportState, Response};
+use bytes::Bytes;
 use http::{header::InvalidHeaderValue, status::StatusCode};
+use serde::Deserialize;
 use std::{error, fmt};
 
+/// Header which contains the id Tenable.io assigned to a request
+const REQUEST_UUID: &str = "X-Request-Uuid";
+
 /// Possible error types occuring in this library
 #[derive(Debug)]
 pub enum Error<RE: fmt::Debug> {
@@ -8,18 +14,179 @@ pub enum Error<RE: fmt::Debug> {
     InvalidAuth(InvalidHeaderValue),
     /// Unable to build Http Request.
     Http(http::Error),
+    /// The name of a file to upload contains control characters like line breaks.
+    InvalidFileName(String),
     /// User is not allowed to perform this operation.
     InsufficientPermission,
     /// Rate Limit reached. Try again later.
     RateLimitReached,
     /// The Backoff function reached a number to high to represent while waiting
+    /// or exceeded the maximum time to wait in total
     MaximumWaitTimeReached,
-    /// API returned unexpected status code.
+    /// The maximum number of attempts was reached without a successful response
+    MaximumAttemptsReached,
+    /// The request was malformed, for example because of an invalid filter.
+    BadRequest {
+        /// Message returned by the API
+        message: Option<String>,
+        /// Id of the request as returned in the `X-Request-Uuid` header
+        request_id: Option<String>,
+    },
+    /// AccessKey or SecretKey is unknown, expired or was revoked.
+    Unauthorized {
+        /// Message returned by the API
+        message: Option<String>,
+        /// Id of the request as returned in the `X-Request-Uuid` header
+        request_id: Option<String>,
+    },
+    /// The request conflicts with the current state of the resource.
+    Conflict {
+        /// Message returned by the API
+        message: Option<String>,
+        /// Id of the request as returned in the `X-Request-Uuid` header
+        request_id: Option<String>,
+    },
+    /// API returned unexpected status code. Custom `HttpRequest` implementations
+    /// may return this, the requests of this crate use `from_response` instead.
     UnexpectedStatusCode(StatusCode),
+    /// API returned an unexpected status code.
+    Api {
+        /// Status code of the response
+        status: StatusCode,
+        /// Message returned by the API
+        message: Option<String>,
+        /// Id of the request as returned in the `X-Request-Uuid` header
+        request_id: Option<String>,
+        /// Raw body of the response
+        body: Bytes,
+    },
     /// Error in inner request client.
     Request(RE),
+    /// Error in inner request client which may succeed when retried, like a
+    /// connection reset or a timeout. Functions sending requests return this
+    /// instead of `Request` to flag an error as safe to retry.
+    TransientRequest(RE),
     /// Unable to transform response to concret type.
     Deserialization(serde_json::Error),
+    /// The export job does not exist or has expired.
+    ExportNotFound,
+    /// The export job was cancelled or failed on the server.
+    ExportFailed(ExportState),
+}
+
+/// Body Tenable.io returns on errors
+#[derive(Deserialize)]
+struct ApiError {
+    error: Option<String>,
+    message: Option<String>,
+}
+
+impl<RE: fmt::Debug> Error<RE> {
+    /// Creates the error for a response with an unsuccessful status code.
+    /// The message is taken from the JSON body returned by the API, if any.
+    /// Useful when implementing `HttpRequest` for custom requests.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bytes::Bytes;
+    /// use http::{HeaderMap, StatusCode};
+    /// use std::convert::Infallible;
+    /// use tenable::{Error, Response};
+    /// let res = Response {
+    ///     status: StatusCode::BAD_REQUEST,
+    ///     headers: HeaderMap::new(),
+    ///     body: Bytes::from_static(br#"{"statusCode":400,"error":"Bad Request","message":"Invalid filter"}"#),
+    /// };
+    /// match Error::<Infallible>::from_response(res) {
+    ///     Error::BadRequest { message, .. } => assert_eq!(message.as_deref(), Some("Invalid filter")),
+    ///     err => panic!("unexpected error: {}", err),
+    /// }
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn from_response(res: Response) -> Self {
+        let request_id = res
+            .headers
+            .get(REQUEST_UUID)
+            .and_then(|value| value.to_str().ok())
+            .map(ToOwned::to_owned);
+        let message = serde_json::from_slice::<ApiError>(&res.body)
+            .ok()
+            .and_then(|err| err.message.or(err.error));
+        match res.status {
+            StatusCode::BAD_REQUEST => Self::BadRequest {
+                message,
+                request_id,
+            },
+            StatusCode::UNAUTHORIZED => Self::Unauthorized {
+                message,
+                request_id,
+            },
+            StatusCode::FORBIDDEN => Self::InsufficientPermission,
+            StatusCode::CONFLICT => Self::Conflict {
+                message,
+                request_id,
+            },
+            StatusCode::TOO_MANY_REQUESTS => Self::RateLimitReached,
+            status => Self::Api {
+                status,
+                message,
+                request_id,
+                body: res.body,
+            },
+        }
+    }
+
+    /// Whether the error is transient, so that sending the same request again
+    /// may succeed. This is the case for rate limits, for `500`, `502`, `503`
+    /// and `504` responses, whether returned as `Api` or as
+    /// `UnexpectedStatusCode`, and for `TransientRequest` errors.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bytes::Bytes;
+    /// use http::StatusCode;
+    /// use std::convert::Infallible;
+    /// use tenable::Error;
+    /// let err = Error::<Infallible>::Api {
+    ///     status: StatusCode::BAD_GATEWAY,
+    ///     message: None,
+    ///     request_id: None,
+    ///     body: Bytes::new(),
+    /// };
+    /// assert!(err.is_retryable());
+    /// assert!(Error::<Infallible>::UnexpectedStatusCode(StatusCode::SERVICE_UNAVAILABLE).is_retryable());
+    /// assert!(!Error::<Infallible>::InsufficientPermission.is_retryable());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::RateLimitReached | Self::TransientRequest(_) => true,
+            Self::Api { status, .. } | Self::UnexpectedStatusCode(status) => matches!(
+                *status,
+                StatusCode::INTERNAL_SERVER_ERROR
+                    | StatusCode::BAD_GATEWAY
+                    | StatusCode::SERVICE_UNAVAILABLE
+                    | StatusCode::GATEWAY_TIMEOUT
+            ),
+            Self::InvalidAuth(_)
+            | Self::Http(_)
+            | Self::InvalidFileName(_)
+            | Self::InsufficientPermission
+            | Self::BadRequest { .. }
+            | Self::Unauthorized { .. }
+            | Self::Conflict { .. }
+            | Self::MaximumWaitTimeReached
+            | Self::MaximumAttemptsReached
+            | Self::Request(_)
+            | Self::Deserialization(_)
+            | Self::ExportNotFound
+            | Self::ExportFailed(_) => false,
+        }
+    }
 }
 
 impl<RE: 'static + fmt::Debug + error::Error> error::Error for Error<RE> {
@@ -28,12 +195,20 @@ impl<RE: 'static + fmt::Debug + error::Error> error::Error for Error<RE> {
         match self {
             Self::InvalidAuth(err) => Some(err),
             Self::Http(err) => Some(err),
-            Self::Request(err) => Some(err),
+            Self::Request(err) | Self::TransientRequest(err) => Some(err),
             Self::Deserialization(err) => Some(err),
-            Self::InsufficientPermission
+            Self::InvalidFileName(_)
+            | Self::InsufficientPermission
             | Self::RateLimitReached
             | Self::MaximumWaitTimeReached
-            | Self::UnexpectedStatusCode(_) => None,
+            | Self::MaximumAttemptsReached
+            | Self::BadRequest { .. }
+            | Self::Unauthorized { .. }
+            | Self::Conflict { .. }
+            | Self::UnexpectedStatusCode(_)
+            | Self::Api { .. }
+            | Self::ExportNotFound
+            | Self::ExportFailed(_) => None,
         }
     }
 }
@@ -46,6 +221,9 @@ impl<RE: fmt::Debug> fmt::Display for Error<RE> {
                 write!(f, "AccessKey or SecretKey contains invalid characters.")
             }
             Self::Http(_) => write!(f, "Unable to build Http Request."),
+            Self::InvalidFileName(name) => {
+                write!(f, "The file name {:?} contains control characters.", name)
+            }
             Self::InsufficientPermission => {
                 write!(f, "User is not allowed to perform this operation.")
             }
@@ -54,15 +232,53 @@ impl<RE: fmt::Debug> fmt::Display for Error<RE> {
                 f,
                 "The Backoff function reached a number to high to represent while waiting."
             ),
+            Self::MaximumAttemptsReached => write!(
+                f,
+                "The maximum number of attempts was reached without a successful response."
+            ),
+            Self::BadRequest { message, .. } => {
+                write!(f, "The request was malformed")?;
+                write_message(f, message.as_deref())
+            }
+            Self::Unauthorized { message, .. } => {
+                write!(f, "AccessKey or SecretKey is unknown or expired")?;
+                write_message(f, message.as_deref())
+            }
+            Self::Conflict { message, .. } => {
+                write!(
+                    f,
+                    "The request conflicts with the current state of the resource"
+                )?;
+                write_message(f, message.as_deref())
+            }
             Self::UnexpectedStatusCode(code) => {
                 write!(f, "API returned unexpected status code: {}.", code)
             }
+            Self::Api {
+                status, message, ..
+            } => {
+                write!(f, "API returned unexpected status code: {}", status)?;
+                write_message(f, message.as_deref())
+            }
             Self::Request(_) => write!(f, "Error in inner request client."),
+            Self::TransientRequest(_) => write!(f, "Transient error in inner request client."),
             Self::Deserialization(_) => write!(f, "Unable to transform response to concret type."),
+            Self::ExportNotFound => write!(f, "The export job does not exist or has expired."),
+            Self::ExportFailed(state) => {
+                write!(f, "The export job ended with state {:?}.", state)
+            }
         }
     }
 }
 
+/// Appends the message returned by the API, if any, and ends the sentence
+fn write_message(f: &mut fmt::Formatter<'_>, message: Option<&str>) -> fmt::Result {
+    match message {
+        Some(message) => write!(f, ": {}.", message),
+        None => write!(f, "."),
+    }
+}
+
 impl<RE: fmt::Debug> From<InvalidHeaderValue> for Error<RE> {
     #[inline]
     fn from(err: InvalidHeaderValue) -> Self {
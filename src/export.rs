@@ -0,0 +1,438 @@
+//! Drivers which run an export job to completion
+//!
+//! Exports are started using one of the `*_export` requests and processed
+//! asynchronously by Tenable.io. The drivers in this module poll the status of
+//! the job and hand out every chunk as soon as it becomes available. Use
+//! `Tenable::export` or `Tenable::export_async` to create them.
+
+use crate::{
+    types::{
+        AssetExport, AssetsExportCancelReq, AssetsExportChunkReq, AssetsExportReq,
+        AssetsExportStatusReq, ExportState, ExportStatus, ExportUuid, Finding,
+        VulnsExportCancelReq, VulnsExportChunkReq, VulnsExportReq, VulnsExportStatusReq,
+    },
+    Error, HttpRequest, Response, Tenable,
+};
+use futures_util::stream::{self, Stream};
+use http::Request;
+use std::{
+    borrow::Cow,
+    collections::{BTreeSet, VecDeque},
+    fmt,
+    future::Future,
+    marker::PhantomData,
+    time::Duration,
+};
+
+/// Request which starts an export job and knows how to follow it up
+pub trait ExportRequest<'a, RE: fmt::Debug>:
+    'a + HttpRequest<RE, Output = ExportUuid> + Into<Cow<'a, Self>>
+{
+    /// Type of a single exported element
+    type Item;
+    /// Request which fetches the status of the export job
+    type Status: 'a + HttpRequest<RE, Output = Option<ExportStatus>> + Into<Cow<'a, Self::Status>>;
+    /// Request which downloads a single chunk of the export job
+    type Chunk: 'a + HttpRequest<RE, Output = Option<Vec<Self::Item>>> + Into<Cow<'a, Self::Chunk>>;
+    /// Request which cancels the export job
+    type Cancel: 'a + HttpRequest<RE, Output = Option<ExportStatus>> + Into<Cow<'a, Self::Cancel>>;
+
+    /// Creates the request which fetches the status of the given export job
+    fn status_request(&self, export_uuid: String) -> Self::Status;
+
+    /// Creates the request which downloads the given chunk of the export job
+    fn chunk_request(&self, export_uuid: String, chunk_id: u64) -> Self::Chunk;
+
+    /// Creates the request which cancels the given export job
+    fn cancel_request(&self, export_uuid: String) -> Self::Cancel;
+}
+
+impl<'a, RE: fmt::Debug> ExportRequest<'a, RE> for AssetsExportReq<'a> {
+    type Item = AssetExport;
+    type Status = AssetsExportStatusReq<'a>;
+    type Chunk = AssetsExportChunkReq<'a>;
+    type Cancel = AssetsExportCancelReq<'a>;
+
+    #[inline]
+    fn status_request(&self, export_uuid: String) -> Self::Status {
+        AssetsExportStatusReq {
+            tenable: self.tenable,
+            export_uuid: export_uuid.into(),
+        }
+    }
+
+    #[inline]
+    fn chunk_request(&self, export_uuid: String, chunk_id: u64) -> Self::Chunk {
+        AssetsExportChunkReq {
+            tenable: self.tenable,
+            export_uuid: export_uuid.into(),
+            chunk_id,
+        }
+    }
+
+    #[inline]
+    fn cancel_request(&self, export_uuid: String) -> Self::Cancel {
+        AssetsExportCancelReq {
+            tenable: self.tenable,
+            export_uuid: export_uuid.into(),
+        }
+    }
+}
+
+impl<'a, RE: fmt::Debug> ExportRequest<'a, RE> for VulnsExportReq<'a> {
+    type Item = Finding;
+    type Status = VulnsExportStatusReq<'a>;
+    type Chunk = VulnsExportChunkReq<'a>;
+    type Cancel = VulnsExportCancelReq<'a>;
+
+    #[inline]
+    fn status_request(&self, export_uuid: String) -> Self::Status {
+        VulnsExportStatusReq {
+            tenable: self.tenable,
+            export_uuid: export_uuid.into(),
+        }
+    }
+
+    #[inline]
+    fn chunk_request(&self, export_uuid: String, chunk_id: u64) -> Self::Chunk {
+        VulnsExportChunkReq {
+            tenable: self.tenable,
+            export_uuid: export_uuid.into(),
+            chunk_id,
+        }
+    }
+
+    #[inline]
+    fn cancel_request(&self, export_uuid: String) -> Self::Cancel {
+        VulnsExportCancelReq {
+            tenable: self.tenable,
+            export_uuid: export_uuid.into(),
+        }
+    }
+}
+
+/// Next action the driver has to take
+enum Step {
+    Start,
+    Status(String),
+    Chunk(String, u64),
+    Done,
+}
+
+/// Bookkeeping shared by the sync and async driver
+#[derive(Clone, Debug, Default)]
+struct Progress {
+    export_uuid: Option<String>,
+    pending: VecDeque<u64>,
+    seen: BTreeSet<u64>,
+    finished: bool,
+    done: bool,
+    /// Export job which is still running after an error and has to be cancelled
+    cancel: Option<String>,
+}
+
+impl Progress {
+    fn step(&mut self) -> Step {
+        if self.done {
+            return Step::Done;
+        }
+        let export_uuid = match self.export_uuid {
+            Some(ref export_uuid) => export_uuid.clone(),
+            None => return Step::Start,
+        };
+        match self.pending.pop_front() {
+            Some(chunk_id) => Step::Chunk(export_uuid, chunk_id),
+            None if self.finished => {
+                self.done = true;
+                Step::Done
+            }
+            None => Step::Status(export_uuid),
+        }
+    }
+
+    fn check<T, RE: fmt::Debug>(&mut self, res: Result<T, Error<RE>>) -> Result<T, Error<RE>> {
+        if res.is_err() {
+            self.done = true;
+            if !self.finished {
+                self.cancel = self.export_uuid.clone();
+            }
+        }
+        res
+    }
+
+    fn started<RE: fmt::Debug>(
+        &mut self,
+        res: Result<ExportUuid, Error<RE>>,
+    ) -> Result<(), Error<RE>> {
+        let export = self.check(res)?;
+        self.export_uuid = Some(export.export_uuid);
+        Ok(())
+    }
+
+    /// Returns whether the driver has to wait before polling the status again
+    fn status<RE: fmt::Debug>(
+        &mut self,
+        res: Result<Option<ExportStatus>, Error<RE>>,
+    ) -> Result<bool, Error<RE>> {
+        let status = match res {
+            Ok(Some(status)) => status,
+            Ok(None) => {
+                // the job is gone, so there is nothing left to cancel
+                self.finished = true;
+                return self.check(Err(Error::ExportNotFound));
+            }
+            Err(err) => return self.check(Err(err)),
+        };
+        for chunk_id in status.chunks_available.unwrap_or_default() {
+            if self.seen.insert(chunk_id) {
+                self.pending.push_back(chunk_id);
+            }
+        }
+        match status.status {
+            ExportState::Finished => {
+                self.finished = true;
+                Ok(false)
+            }
+            ExportState::Queued | ExportState::Processing => Ok(self.pending.is_empty()),
+            state @ ExportState::Cancelled | state @ ExportState::Error => {
+                self.finished = true;
+                self.check(Err(Error::ExportFailed(state)))
+            }
+        }
+    }
+
+    fn chunk<T, RE: fmt::Debug>(
+        &mut self,
+        res: Result<Option<Vec<T>>, Error<RE>>,
+    ) -> Result<Vec<T>, Error<RE>> {
+        let res = res.and_then(|chunk| chunk.ok_or(Error::ExportNotFound));
+        self.check(res)
+    }
+}
+
+/// Iterator over the chunks of an export job. Created by `Tenable::export`.
+///
+/// Every request is sent using `Tenable::request_with_backoff`. The iterator
+/// ends once the job is finished and all chunks have been downloaded, or after
+/// the first error. If the job is still running when an error occurs, it is
+/// cancelled before the error is returned, so it does not block further
+/// exports. Dropping the iterator early leaves the job running, use
+/// `export_uuid` with the matching `*_export_cancel` request to stop it.
+pub struct ExportChunks<'a, R, RE, F, BF> {
+    request: R,
+    fun: F,
+    backoff_fun: BF,
+    poll_interval: Duration,
+    progress: Progress,
+    _marker: PhantomData<(&'a (), fn() -> RE)>,
+}
+
+impl<R, RE, F, BF> ExportChunks<'_, R, RE, F, BF> {
+    pub(crate) fn new(request: R, fun: F, backoff_fun: BF, poll_interval: Duration) -> Self {
+        Self {
+            request,
+            fun,
+            backoff_fun,
+            poll_interval,
+            progress: Progress::default(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// UUID of the export job, once it has been started
+    #[inline]
+    pub fn export_uuid(&self) -> Option<&str> {
+        self.progress.export_uuid.as_deref()
+    }
+}
+
+impl<R: fmt::Debug, RE, F, BF> fmt::Debug for ExportChunks<'_, R, RE, F, BF> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExportChunks")
+            .field("request", &self.request)
+            .field("poll_interval", &self.poll_interval)
+            .field("progress", &self.progress)
+            .finish()
+    }
+}
+
+impl<'a, R, RE, F, BF> Iterator for ExportChunks<'a, R, RE, F, BF>
+where
+    R: ExportRequest<'a, RE>,
+    RE: fmt::Debug,
+    F: Fn(Request<Vec<u8>>) -> Result<Response, Error<RE>>,
+    BF: Fn(Duration),
+{
+    type Item = Result<Vec<R::Item>, Error<RE>>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.advance();
+        if let Some(export_uuid) = self.progress.cancel.take() {
+            // best effort, the error which caused the cancellation is more relevant
+            let _res = Tenable::request_with_backoff(
+                self.request.cancel_request(export_uuid),
+                &self.fun,
+                &self.backoff_fun,
+            );
+        }
+        item
+    }
+}
+
+impl<'a, R, RE, F, BF> ExportChunks<'a, R, RE, F, BF>
+where
+    R: ExportRequest<'a, RE>,
+    RE: fmt::Debug,
+    F: Fn(Request<Vec<u8>>) -> Result<Response, Error<RE>>,
+    BF: Fn(Duration),
+{
+    fn advance(&mut self) -> Option<Result<Vec<R::Item>, Error<RE>>> {
+        loop {
+            match self.progress.step() {
+                Step::Done => return None,
+                Step::Start => {
+                    let res = Tenable::request_with_backoff(
+                        self.request.clone(),
+                        &self.fun,
+                        &self.backoff_fun,
+                    );
+                    if let Err(err) = self.progress.started(res) {
+                        return Some(Err(err));
+                    }
+                }
+                Step::Status(export_uuid) => {
+                    let res = Tenable::request_with_backoff(
+                        self.request.status_request(export_uuid),
+                        &self.fun,
+                        &self.backoff_fun,
+                    );
+                    match self.progress.status(res) {
+                        Ok(true) => (self.backoff_fun)(self.poll_interval),
+                        Ok(false) => {}
+                        Err(err) => return Some(Err(err)),
+                    }
+                }
+                Step::Chunk(export_uuid, chunk_id) => {
+                    let res = Tenable::request_with_backoff(
+                        self.request.chunk_request(export_uuid, chunk_id),
+                        &self.fun,
+                        &self.backoff_fun,
+                    );
+                    return Some(self.progress.chunk(res));
+                }
+            }
+        }
+    }
+}
+
+/// State of the async driver which is threaded through the stream
+struct Driver<R, F, BF> {
+    request: R,
+    fun: F,
+    backoff_fun: BF,
+    poll_interval: Duration,
+    progress: Progress,
+}
+
+impl<'a, R, F, BF> Driver<R, F, BF> {
+    async fn next<RE, Fut, FutBF>(&mut self) -> Option<Result<Vec<R::Item>, Error<RE>>>
+    where
+        R: ExportRequest<'a, RE>,
+        RE: fmt::Debug,
+        F: Fn(Request<Vec<u8>>) -> Fut,
+        Fut: Future<Output = Result<Response, Error<RE>>>,
+        BF: Fn(Duration) -> FutBF,
+        FutBF: Future<Output = ()>,
+    {
+        let item = self.advance().await;
+        if let Some(export_uuid) = self.progress.cancel.take() {
+            // best effort, the error which caused the cancellation is more relevant
+            let _res = Tenable::request_with_backoff_async(
+                self.request.cancel_request(export_uuid),
+                &self.fun,
+                &self.backoff_fun,
+            )
+            .await;
+        }
+        item
+    }
+
+    async fn advance<RE, Fut, FutBF>(&mut self) -> Option<Result<Vec<R::Item>, Error<RE>>>
+    where
+        R: ExportRequest<'a, RE>,
+        RE: fmt::Debug,
+        F: Fn(Request<Vec<u8>>) -> Fut,
+        Fut: Future<Output = Result<Response, Error<RE>>>,
+        BF: Fn(Duration) -> FutBF,
+        FutBF: Future<Output = ()>,
+    {
+        loop {
+            match self.progress.step() {
+                Step::Done => return None,
+                Step::Start => {
+                    let res = Tenable::request_with_backoff_async(
+                        self.request.clone(),
+                        &self.fun,
+                        &self.backoff_fun,
+                    )
+                    .await;
+                    if let Err(err) = self.progress.started(res) {
+                        return Some(Err(err));
+                    }
+                }
+                Step::Status(export_uuid) => {
+                    let res = Tenable::request_with_backoff_async(
+                        self.request.status_request(export_uuid),
+                        &self.fun,
+                        &self.backoff_fun,
+                    )
+                    .await;
+                    match self.progress.status(res) {
+                        Ok(true) => (self.backoff_fun)(self.poll_interval).await,
+                        Ok(false) => {}
+                        Err(err) => return Some(Err(err)),
+                    }
+                }
+                Step::Chunk(export_uuid, chunk_id) => {
+                    let res = Tenable::request_with_backoff_async(
+                        self.request.chunk_request(export_uuid, chunk_id),
+                        &self.fun,
+                        &self.backoff_fun,
+                    )
+                    .await;
+                    return Some(self.progress.chunk(res));
+                }
+            }
+        }
+    }
+}
+
+pub(crate) fn export_stream<'a, R, RE, F, Fut, BF, FutBF>(
+    request: R,
+    fun: F,
+    backoff_fun: BF,
+    poll_interval: Duration,
+) -> impl Stream<Item = Result<Vec<R::Item>, Error<RE>>> + 'a
+where
+    R: ExportRequest<'a, RE>,
+    R::Item: 'a,
+    RE: 'a + fmt::Debug,
+    F: 'a + Fn(Request<Vec<u8>>) -> Fut,
+    Fut: 'a + Future<Output = Result<Response, Error<RE>>>,
+    BF: 'a + Fn(Duration) -> FutBF,
+    FutBF: 'a + Future<Output = ()>,
+{
+    let driver = Driver {
+        request,
+        fun,
+        backoff_fun,
+        poll_interval,
+        progress: Progress::default(),
+    };
+    stream::unfold(driver, |mut driver| async move {
+        let item = driver.next().await?;
+        Some((item, driver))
+    })
+}
@@ -8,12 +8,81 @@
 //!
 //! # Usage
 //!
-//! Add this crate as a dependency to your `Cargo.toml`. Afterwards you can use it like this to execute api calls like fetching all assets:
+//! Add this crate as a dependency to your `Cargo.toml` and enable the feature of the http client you would like to use:
+//!
+//! ```toml
+//! [dependencies]
+//! tenable = { version = "0.1", features = ["reqwest-blocking"] }
+//! ```
+//!
+//! Afterwards you can use it like this to execute api calls like fetching all assets:
 //!
 //! ## Sync
 //!
 //! ```rust,no_run
-//! use std::convert::Infallible;
+//! use tenable::{requests::AssetReq, transport::ReqwestBlocking, Tenable};
+//!
+//! let tenable = Tenable::new(
+//!     "0000000000000000000000000000000000000000000000000000000000000000",
+//!     "0000000000000000000000000000000000000000000000000000000000000000",
+//! );
+//! // Create the transport once, as it holds the connection pool
+//! let transport = ReqwestBlocking::default();
+//! let req = tenable.assets();
+//! let _assets = Tenable::request(req, |req| transport.send(req))
+//!     .expect("Unable to list all assets");
+//! ```
+//!
+//! # Async
+//!
+//! ```rust,no_run
+//! # use tokio::runtime::Runtime;
+//! use tenable::{requests::AssetReq, transport::ReqwestAsync, types::Assets, Tenable};
+//!
+//! # let mut rt = Runtime::new().expect("Unable to create runtime");
+//! # rt.block_on(async {
+//! let tenable = Tenable::new(
+//!     "0000000000000000000000000000000000000000000000000000000000000000",
+//!     "0000000000000000000000000000000000000000000000000000000000000000",
+//! );
+//! let transport = ReqwestAsync::default();
+//! let req = tenable.assets();
+//! let _assets: Assets = Tenable::request_async(req, |req| transport.send(req)).await
+//!     .expect("Unable to list all assets");
+//! # })
+//! ```
+//!
+//! The following features provide ready-made transports:
+//!
+//! | Feature            | Transport         |
+//! |--------------------|-------------------|
+//! | `reqwest-blocking` | `ReqwestBlocking` |
+//! | `reqwest-async`    | `ReqwestAsync`    |
+//! | `ureq`             | `Ureq`            |
+//! | `hyper`            | `Hyper`           |
+//! | `isahc`            | `Isahc`           |
+//!
+//! # Client
+//!
+//! `TenableClient` owns the transport, so that it does not need to be passed on every call:
+//!
+//! ```rust,no_run
+//! use tenable::{requests::AssetReq, transport::ReqwestBlocking, Tenable, TenableClient};
+//!
+//! let tenable = Tenable::new(
+//!     "0000000000000000000000000000000000000000000000000000000000000000",
+//!     "0000000000000000000000000000000000000000000000000000000000000000",
+//! );
+//! let client = TenableClient::new(tenable, ReqwestBlocking::default());
+//! let req = client.tenable.assets();
+//! let _assets = client.send(req).expect("Unable to list all assets");
+//! ```
+//!
+//! # Other clients
+//!
+//! Any other http client can be used by passing a function which sends the request and returns the `Response`. Such functions, as well as custom types implementing `Transport` or `AsyncTransport`, can also be used with `TenableClient`:
+//!
+//! ```rust,no_run
 //! use reqwest::blocking::Client;
 //! use tenable::{requests::AssetReq, Error, Response, Tenable};
 //! use http::Request;
@@ -28,6 +97,7 @@
 //!         .map_err(Error::Request)?;
 //!     Ok(Response {
 //!         status: res.status(),
+//!         headers: res.headers().clone(),
 //!         body: res.bytes().map_err(Error::Request)?,
 //!     })
 //! }
@@ -40,40 +110,44 @@
 //! let _assets = Tenable::request(req, request).expect("Unable to list all assets");
 //! ```
 //!
-//! # Async
+//! # Testing
 //!
-//! ```rust,no_run
-//! # use tokio::runtime::Runtime;
-//! use std::convert::Infallible;
-//! use reqwest::Client;
-//! use tenable::{requests::AssetReq, types::Assets, Error, Response, Tenable};
-//! use http::Request;
+//! The `mock` feature provides an offline stand-in for Tenable.io in the `mock` module. `MockTransport` answers requests in-process using canned fixtures, while `MockServer` serves them using http on a local port. Both inject faults like `403 Forbidden` or `429 Too Many Requests` on demand:
+//!
+//! ```rust
+//! # #[cfg(feature = "mock")]
+//! # {
+//! use tenable::{
+//!     mock::{Fault, MockTransport},
+//!     requests::AssetReq,
+//!     Error, Tenable, TenableClient,
+//! };
 //!
-//! pub async fn request_async(req: Request<Vec<u8>>) -> Result<Response, Error<reqwest::Error>> {
-//!    let (req, body) = req.into_parts();
-//!    let res = Client::new()
-//!        .request(req.method, &req.uri.to_string())
-//!        .headers(req.headers)
-//!        .body(body)
-//!        .send()
-//!        .await
-//!        .map_err(Error::Request)?;
-//!    Ok(Response {
-//!        status: res.status(),
-//!        body: res.bytes().await.map_err(Error::Request)?,
-//!    })
+//! let client = TenableClient::new(Tenable::new("", ""), MockTransport::new());
+//! let _ = client.transport.inject(Fault::Forbidden, 1);
+//! match client.send(client.tenable.assets()) {
+//!     Err(Error::InsufficientPermission) => {}
+//!     res => panic!("Unexpected response: {:?}", res),
 //! }
+//! # }
+//! ```
 //!
-//! # let mut rt = Runtime::new().expect("Unable to create runtime");
-//! # rt.block_on(async {
-//! let tenable = Tenable::new(
-//!     "0000000000000000000000000000000000000000000000000000000000000000",
-//!     "0000000000000000000000000000000000000000000000000000000000000000",
-//! );
-//! let req = tenable.assets();
-//! let _assets: Assets = Tenable::request_async(req, request_async).await
-//!     .expect("Unable to list all assets");
-//! # })
+//! # Scan files
+//!
+//! The `nessus-xml` feature provides a streaming parser for the `.nessus` files returned by `scan_export` in the `nessus` module. `NessusReader` yields one `ReportHost` at a time, whose fields are named and shaped like the ones of `Asset`, so that offline scan results can be joined with data from the API:
+//!
+//! ```rust,no_run
+//! # #[cfg(feature = "nessus-xml")]
+//! # {
+//! use std::{fs::File, io::BufReader};
+//! use tenable::nessus::NessusReader;
+//!
+//! let file = File::open("scan.nessus").expect("Unable to open file");
+//! for host in NessusReader::new(BufReader::new(file)) {
+//!     let host = host.expect("Unable to parse host");
+//!     println!("{:?}: {} findings", host.fqdn, host.items.len());
+//! }
+//! # }
 //! ```
 //!
 //! # Extending
@@ -125,8 +199,8 @@
 //!             // Otherwise, check whether the server returned one of the known errors
 //!             StatusCode::FORBIDDEN => Err(Error::InsufficientPermission),
 //!             StatusCode::TOO_MANY_REQUESTS => Err(Error::RateLimitReached),
-//!             // Every other error may be collected in catch all type
-//!             code => Err(Error::UnexpectedStatusCode(code)),
+//!             // Every other error is parsed from the body returned by the API
+//!             _ => Err(Error::from_response(res)),
 //!         }
 //!     }
 //! }
@@ -216,18 +290,31 @@
     clippy::used_underscore_binding
 )]
 
+pub mod backoff;
+mod client;
 mod error;
+pub mod export;
+#[cfg(feature = "mock")]
+pub mod mock;
+#[cfg(feature = "nessus-xml")]
+pub mod nessus;
 pub mod requests;
+pub mod transport;
 pub mod types;
 
+pub use client::TenableClient;
 pub use error::Error;
 
+use backoff::{Backoff, BackoffPolicy, Linear};
 use bytes::Bytes;
-use http::{status::StatusCode, Request};
+use export::{ExportChunks, ExportRequest};
+use futures_util::stream::Stream;
+use http::{status::StatusCode, HeaderMap, Request};
 use std::{borrow::Cow, fmt, future::Future, time::Duration};
+use types::{Secret, UserKeys, UserKeysReq};
 
 /// Tenable Client which allows requests against the tenable API
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 pub struct Tenable<'a> {
     /// Authentication string
     pub auth: String,
@@ -235,6 +322,17 @@ pub struct Tenable<'a> {
     pub uri: Cow<'a, str>,
 }
 
+/// Redacts `auth`, as it contains the secret key
+impl fmt::Debug for Tenable<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Tenable")
+            .field("auth", &Secret(&self.auth))
+            .field("uri", &self.uri)
+            .finish()
+    }
+}
+
 impl Tenable<'_> {
     /// Creates a new `Tenable` client with the given credentials
     ///
@@ -261,6 +359,119 @@ impl Tenable<'_> {
         }
     }
 
+    /// Generates new API keys for the given user and returns a client using them
+    ///
+    /// The previous keys of the user stop working immediately, so replace the
+    /// current client with the returned one when rotating its own keys. The
+    /// `uri` of the current client is kept. Returns `None` if the user does not exist.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id`: ID of the user whose keys are rotated
+    /// * `fun`: Function which implements sending synchronous requests.
+    ///
+    /// # Errors
+    ///
+    /// Fails in the following cases:
+    ///
+    /// * Unable to create a valid Request
+    /// * Server responded with error code
+    /// * Unable to deserialize the server response
+    /// * Custom Errors returned by the function given as `fun` parameter
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let tenable = tenable.rotate_keys(1, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to rotate keys")
+    /// .expect("User not found");
+    /// ```
+    #[inline]
+    pub fn rotate_keys<RE, F>(&self, user_id: u64, fun: F) -> Result<Option<Self>, Error<RE>>
+    where
+        RE: fmt::Debug,
+        F: FnOnce(Request<Vec<u8>>) -> Result<Response, Error<RE>>,
+    {
+        let req = UserKeysReq {
+            tenable: self,
+            user_id,
+        };
+        let keys = Self::request(req, fun)?;
+        Ok(keys.map(|keys| self.with_keys(&keys)))
+    }
+
+    /// Generates new API keys for the given user and returns a client using them.
+    /// See `rotate_keys`.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id`: ID of the user whose keys are rotated
+    /// * `fun`: Function which implements sending asynchronous requests.
+    ///
+    /// # Errors
+    ///
+    /// Fails in the following cases:
+    ///
+    /// * Unable to create a valid Request
+    /// * Server responded with error code
+    /// * Unable to deserialize the server response
+    /// * Custom Errors returned by the function given as `fun` parameter
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio::runtime::Runtime;
+    /// use http::Request;
+    /// use std::convert::Infallible;
+    /// use tenable::{Error, Response, Tenable};
+    /// async fn request(_req: Request<Vec<u8>>) -> Result::<Response, Error<Infallible>> { Ok(todo!("Define a method to send http requests")) }
+    ///
+    /// # let mut rt = Runtime::new().expect("Unable to create runtime");
+    /// # rt.block_on(async {
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let tenable = tenable.rotate_keys_async(1, request).await
+    ///     .expect("Unable to rotate keys")
+    ///     .expect("User not found");
+    /// # })
+    /// ```
+    #[inline]
+    pub async fn rotate_keys_async<RE, F, Fut>(
+        &self,
+        user_id: u64,
+        fun: F,
+    ) -> Result<Option<Self>, Error<RE>>
+    where
+        RE: fmt::Debug,
+        F: FnOnce(Request<Vec<u8>>) -> Fut,
+        Fut: Future<Output = Result<Response, Error<RE>>>,
+    {
+        let req = UserKeysReq {
+            tenable: self,
+            user_id,
+        };
+        let keys = Self::request_async(req, fun).await?;
+        Ok(keys.map(|keys| self.with_keys(&keys)))
+    }
+
+    /// Creates a client with the given keys which sends requests to the same uri
+    fn with_keys(&self, keys: &UserKeys) -> Self {
+        Self {
+            uri: self.uri.clone(),
+            ..Self::new(&keys.access_key, &keys.secret_key.0)
+        }
+    }
+
     /// Executes a synchronous http request using the given function
     ///
     /// # Arguments
@@ -352,19 +563,84 @@ impl Tenable<'_> {
         F: Fn(Request<Vec<u8>>) -> Result<Response, Error<RE>>,
         BF: Fn(Duration) -> (),
     {
-        let mut wait = Duration::from_millis(100);
+        Self::request_with_policy(request, fun, backoff_fun, Backoff::new(Linear::default()))
+    }
+
+    /// Executes a synchronous http request using the given function.
+    /// Automatically backs off according to the given `Backoff` when a Rate
+    /// Limit is hit or, if enabled in `backoff`, when a transient error like a
+    /// `503 Service Unavailable` occurs. The time to wait is calculated by the
+    /// policy, which receives the `Retry-After` header sent by the server.
+    ///
+    /// # Arguments
+    ///
+    /// * `request`: Request to send. Use one of the functions in the `requests` module to create a request
+    /// * `fun`: Function which implements sending synchronous requests.
+    /// * `backoff_fun`: Function which waits for the given Duration
+    /// * `backoff`: Policy and limits used to calculate the time to wait
+    ///
+    /// # Errors
+    ///
+    /// Fails in the following cases:
+    ///
+    /// * Unable to create a valid Request
+    /// * Server responded with error code
+    /// * Unable to deserialize the server response
+    /// * Custom Errors returned by the function given as `fun` parameter
+    /// * The maximum number of attempts or the maximum wait time was reached
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::{convert::Infallible, thread::sleep};
+    /// use tenable::{
+    ///     backoff::{Backoff, Exponential, RetryAfter},
+    ///     requests::AssetReq,
+    ///     Error, Response, Tenable,
+    /// };
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.assets();
+    /// let backoff = Backoff::new(RetryAfter { fallback: Exponential::default() }).max_attempts(5);
+    /// let _assets = Tenable::request_with_policy(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// }, sleep, backoff).expect("Unable to list all assets");
+    /// ```
+    #[inline]
+    #[allow(single_use_lifetimes)]
+    pub fn request_with_policy<'a, O, R, CR, RE, F, BF, P>(
+        request: CR,
+        fun: F,
+        backoff_fun: BF,
+        mut backoff: Backoff<P>,
+    ) -> Result<O, Error<RE>>
+    where
+        CR: Into<Cow<'a, R>>,
+        R: 'a + HttpRequest<RE, Output = O>,
+        RE: fmt::Debug,
+        F: Fn(Request<Vec<u8>>) -> Result<Response, Error<RE>>,
+        BF: Fn(Duration),
+        P: BackoffPolicy,
+    {
+        let mut attempt = 0_u32;
+        let mut elapsed = Duration::from_secs(0);
         let request = request.into();
         loop {
             let req = request.to_request()?;
-            let res = fun(req)?;
-            #[allow(clippy::wildcard_enum_match_arm)]
-            match request.from_response(res) {
-                Err(Error::RateLimitReached) => {
-                    backoff_fun(wait);
-                    match wait.checked_add(Duration::from_millis(100)) {
-                        Some(new_wait) => wait = new_wait,
-                        None => return Err(Error::MaximumWaitTimeReached),
-                    }
+            let idempotent = req.method().is_idempotent();
+            let (res, retry_after) = match fun(req) {
+                Ok(res) => {
+                    let retry_after = backoff::retry_after(&res.headers);
+                    (request.from_response(res), retry_after)
+                }
+                Err(err) => (Err(err), None),
+            };
+            match res {
+                Err(err) if backoff.should_retry(&err, idempotent) => {
+                    attempt = attempt.saturating_add(1);
+                    backoff_fun(backoff.next(attempt, &mut elapsed, retry_after)?);
                 }
                 other => return other,
             }
@@ -525,24 +801,229 @@ impl Tenable<'_> {
         BF: Fn(Duration) -> FutBF,
         FutBF: Future<Output = ()>,
     {
-        let mut wait = Duration::from_millis(100);
+        Self::request_with_policy_async(request, fun, backoff_fun, Backoff::new(Linear::default()))
+            .await
+    }
+
+    /// Executes an asynchronous http request using the given function.
+    /// Automatically backs off according to the given `Backoff` when a Rate
+    /// Limit is hit or, if enabled in `backoff`, when a transient error like a
+    /// `503 Service Unavailable` occurs. The time to wait is calculated by the
+    /// policy, which receives the `Retry-After` header sent by the server.
+    ///
+    /// # Arguments
+    ///
+    /// * `request`: Request to send. Use one of the functions in the `requests` module to create a request
+    /// * `fun`: Function which implements sending asynchronous requests.
+    /// * `backoff_fun`: Function which waits for the given Duration
+    /// * `backoff`: Policy and limits used to calculate the time to wait
+    ///
+    /// # Errors
+    ///
+    /// Fails in the following cases:
+    ///
+    /// * Unable to create a valid Request
+    /// * Server responded with error code
+    /// * Unable to deserialize the server response
+    /// * Custom Errors returned by the function given as `fun` parameter
+    /// * The maximum number of attempts or the maximum wait time was reached
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio::runtime::Runtime;
+    /// use tokio::time::delay_for;
+    /// use http::Request;
+    /// use std::{convert::Infallible, time::Duration};
+    /// use tenable::{
+    ///     backoff::{Backoff, Linear, RetryAfter},
+    ///     requests::AssetReq,
+    ///     Error, Response, Tenable,
+    /// };
+    /// async fn request(_req: Request<Vec<u8>>) -> Result::<Response, Error<Infallible>> { Ok(todo!("Define a method to send http requests")) }
+    ///
+    /// # let mut rt = Runtime::new().expect("Unable to create runtime");
+    /// # rt.block_on(async {
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.assets();
+    /// let backoff = Backoff::new(RetryAfter { fallback: Linear::default() })
+    ///     .max_elapsed(Duration::from_secs(60));
+    /// let _assets = Tenable::request_with_policy_async(req, request, delay_for, backoff).await
+    ///     .expect("Unable to list all assets");
+    /// # })
+    /// ```
+    #[inline]
+    #[allow(single_use_lifetimes, unused_lifetimes)]
+    pub async fn request_with_policy_async<'a, O, R, CR, RE, F, Fut, BF, FutBF, P>(
+        request: CR,
+        fun: F,
+        backoff_fun: BF,
+        mut backoff: Backoff<P>,
+    ) -> Result<O, Error<RE>>
+    where
+        CR: Into<Cow<'a, R>>,
+        R: 'a + HttpRequest<RE, Output = O>,
+        RE: fmt::Debug,
+        F: Fn(Request<Vec<u8>>) -> Fut,
+        Fut: Future<Output = Result<Response, Error<RE>>>,
+        BF: Fn(Duration) -> FutBF,
+        FutBF: Future<Output = ()>,
+        P: BackoffPolicy,
+    {
+        let mut attempt = 0_u32;
+        let mut elapsed = Duration::from_secs(0);
         let request = request.into();
         loop {
             let req = request.to_request()?;
-            let res = fun(req).await?;
-            #[allow(clippy::wildcard_enum_match_arm)]
-            match request.from_response(res) {
-                Err(Error::RateLimitReached) => {
-                    backoff_fun(wait).await;
-                    match wait.checked_add(Duration::from_millis(100)) {
-                        Some(new_wait) => wait = new_wait,
-                        None => return Err(Error::MaximumWaitTimeReached),
-                    }
+            let idempotent = req.method().is_idempotent();
+            let (res, retry_after) = match fun(req).await {
+                Ok(res) => {
+                    let retry_after = backoff::retry_after(&res.headers);
+                    (request.from_response(res), retry_after)
+                }
+                Err(err) => (Err(err), None),
+            };
+            match res {
+                Err(err) if backoff.should_retry(&err, idempotent) => {
+                    attempt = attempt.saturating_add(1);
+                    backoff_fun(backoff.next(attempt, &mut elapsed, retry_after)?).await;
                 }
                 other => return other,
             }
         }
     }
+
+    /// Runs an export job to completion using the given functions.
+    ///
+    /// Starts the export, polls its status every `poll_interval` and yields
+    /// every chunk as soon as it is available, even while the job is still
+    /// processing. Each request is sent using `request_with_backoff`.
+    ///
+    /// # Arguments
+    ///
+    /// * `request`: Export to run. Use one of the `*_export` functions in the `requests` module to create it
+    /// * `fun`: Function which implements sending synchronous requests.
+    /// * `backoff_fun`: Function which waits for the given Duration
+    /// * `poll_interval`: Time to wait between two status requests while no chunk is available
+    ///
+    /// # Errors
+    ///
+    /// The iterator yields an error and ends in the following cases:
+    ///
+    /// * Any request fails as described in `request_with_backoff`
+    /// * The export job was cancelled or failed
+    /// * The export job or one of its chunks is no longer available
+    ///
+    /// If the job is still running at that point, it is cancelled using the
+    /// matching `*_export_cancel` request before the error is yielded.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::{convert::Infallible, thread::sleep, time::Duration};
+    /// use tenable::{requests::AssetReq, types::AssetsExportDef, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.assets_export(AssetsExportDef {
+    ///     chunk_size: 1000,
+    ///     ..AssetsExportDef::default()
+    /// });
+    /// let chunks = Tenable::export(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// }, sleep, Duration::from_secs(5));
+    /// for chunk in chunks {
+    ///     let _assets = chunk.expect("Unable to export assets");
+    /// }
+    /// ```
+    #[inline]
+    pub fn export<'a, R, RE, F, BF>(
+        request: R,
+        fun: F,
+        backoff_fun: BF,
+        poll_interval: Duration,
+    ) -> ExportChunks<'a, R, RE, F, BF>
+    where
+        R: ExportRequest<'a, RE>,
+        RE: fmt::Debug,
+        F: Fn(Request<Vec<u8>>) -> Result<Response, Error<RE>>,
+        BF: Fn(Duration),
+    {
+        ExportChunks::new(request, fun, backoff_fun, poll_interval)
+    }
+
+    /// Runs an export job to completion using the given asynchronous functions.
+    ///
+    /// Starts the export, polls its status every `poll_interval` and yields
+    /// every chunk as soon as it is available, even while the job is still
+    /// processing. Each request is sent using `request_with_backoff_async`.
+    ///
+    /// # Arguments
+    ///
+    /// * `request`: Export to run. Use one of the `*_export` functions in the `requests` module to create it
+    /// * `fun`: Function which implements sending asynchronous requests.
+    /// * `backoff_fun`: Function which waits for the given Duration
+    /// * `poll_interval`: Time to wait between two status requests while no chunk is available
+    ///
+    /// # Errors
+    ///
+    /// The stream yields an error and ends in the following cases:
+    ///
+    /// * Any request fails as described in `request_with_backoff_async`
+    /// * The export job was cancelled or failed
+    /// * The export job or one of its chunks is no longer available
+    ///
+    /// If the job is still running at that point, it is cancelled using the
+    /// matching `*_export_cancel` request before the error is yielded.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio::runtime::Runtime;
+    /// use futures_util::stream::StreamExt;
+    /// use http::Request;
+    /// use std::{convert::Infallible, time::Duration};
+    /// use tenable::{requests::VulnReq, types::VulnsExportDef, Error, Response, Tenable};
+    /// use tokio::time::delay_for;
+    /// async fn request(_req: Request<Vec<u8>>) -> Result::<Response, Error<Infallible>> { Ok(todo!("Define a method to send http requests")) }
+    ///
+    /// # let mut rt = Runtime::new().expect("Unable to create runtime");
+    /// # rt.block_on(async {
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.vulns_export(VulnsExportDef::default());
+    /// let chunks = Tenable::export_async(req, request, delay_for, Duration::from_secs(5));
+    /// futures_util::pin_mut!(chunks);
+    /// while let Some(chunk) = chunks.next().await {
+    ///     let _findings = chunk.expect("Unable to export vulnerabilities");
+    /// }
+    /// # })
+    /// ```
+    #[inline]
+    #[allow(single_use_lifetimes)]
+    pub fn export_async<'a, R, RE, F, Fut, BF, FutBF>(
+        request: R,
+        fun: F,
+        backoff_fun: BF,
+        poll_interval: Duration,
+    ) -> impl Stream<Item = Result<Vec<R::Item>, Error<RE>>> + 'a
+    where
+        R: ExportRequest<'a, RE>,
+        R::Item: 'a,
+        RE: 'a + fmt::Debug,
+        F: 'a + Fn(Request<Vec<u8>>) -> Fut,
+        Fut: 'a + Future<Output = Result<Response, Error<RE>>>,
+        BF: 'a + Fn(Duration) -> FutBF,
+        FutBF: 'a + Future<Output = ()>,
+    {
+        export::export_stream(request, fun, backoff_fun, poll_interval)
+    }
 }
 
 /// Server Response allowing further processing
@@ -550,10 +1031,24 @@ impl Tenable<'_> {
 pub struct Response {
     /// The `StatusCode` returned by the Server
     pub status: StatusCode,
+    /// The Headers returned by the Server, like `Retry-After` or `X-Request-Uuid`
+    pub headers: HeaderMap,
     /// The Server Body in bytes
     pub body: Bytes,
 }
 
+impl From<http::Response<Bytes>> for Response {
+    #[inline]
+    fn from(res: http::Response<Bytes>) -> Self {
+        let (parts, body) = res.into_parts();
+        Self {
+            status: parts.status,
+            headers: parts.headers,
+            body,
+        }
+    }
+}
+
 /// Generic Requests which provides information for further processing using
 /// the HTTP function given by the user
 pub trait HttpRequest<RE: fmt::Debug>: Clone {
@@ -566,7 +1061,9 @@ pub trait HttpRequest<RE: fmt::Debug>: Clone {
     /// Fails if it is not possible to create a valid Request
     fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>>;
 
-    /// Parses the Http Client Response to its concret Type.
+    /// Parses the Http Client Response to its concret Type. Besides status
+    /// and body, the headers sent by the server are available in
+    /// `res.headers`.
     ///
     /// # Errors
     ///
@@ -0,0 +1,875 @@
+//! Offline stand-in for Tenable.io, enabled using the `mock` feature
+//!
+//! `MockTransport` answers requests in-process using canned fixtures for every
+//! endpoint in the `requests` module. `MockServer` serves the same responses
+//! using http on a local port, which allows testing the transports in the
+//! `transport` module or any other http client. Both record the requests they
+//! receive and return faults like `403 Forbidden` or `429 Too Many Requests`
+//! on demand, so that code using this crate can be tested without network
+//! access.
+//!
+//! # Example
+//!
+//! ```
+//! use tenable::{
+//!     mock::{Fault, MockTransport},
+//!     requests::AssetReq,
+//!     Error, Tenable, TenableClient,
+//! };
+//! let client = TenableClient::new(Tenable::new("", ""), MockTransport::new());
+//! let req = client.tenable.assets();
+//! let _assets = client.send(&req).expect("Unable to list all assets");
+//!
+//! let _ = client.transport.inject(Fault::Forbidden, 1);
+//! match client.send(&req) {
+//!     Err(Error::InsufficientPermission) => {}
+//!     res => panic!("Unexpected response: {:?}", res),
+//! }
+//! assert_eq!(client.transport.requests().len(), 2);
+//! ```
+
+mod server;
+
+pub use server::MockServer;
+
+use crate::{
+    transport::{AsyncTransport, Transport},
+    Error, Response,
+};
+use bytes::Bytes;
+use futures_util::future::{self, Ready};
+use http::{
+    header::{HeaderValue, CONTENT_TYPE, RETRY_AFTER},
+    HeaderMap, Method, Request, StatusCode, Uri,
+};
+use std::{
+    collections::VecDeque,
+    convert::Infallible,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+};
+
+/// Fault returned instead of the fixture
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Fault {
+    /// `401 Unauthorized`, as returned for unknown or expired keys
+    Unauthorized,
+    /// `403 Forbidden`, as returned if the user lacks permission
+    Forbidden,
+    /// `404 Not Found`
+    NotFound,
+    /// `429 Too Many Requests` with the given `Retry-After` in seconds
+    RateLimited(Option<u64>),
+    /// A server error like `500 Internal Server Error` or `503 Service Unavailable`
+    ServerError(StatusCode),
+}
+
+impl Fault {
+    fn status(self) -> StatusCode {
+        match self {
+            Self::Unauthorized => StatusCode::UNAUTHORIZED,
+            Self::Forbidden => StatusCode::FORBIDDEN,
+            Self::NotFound => StatusCode::NOT_FOUND,
+            Self::RateLimited(_) => StatusCode::TOO_MANY_REQUESTS,
+            Self::ServerError(status) => status,
+        }
+    }
+}
+
+/// Request received by the mock
+#[derive(Clone, Debug)]
+pub struct MockRequest {
+    /// Method of the request
+    pub method: Method,
+    /// Uri of the request
+    pub uri: Uri,
+    /// Headers of the request
+    pub headers: HeaderMap,
+    /// Body of the request
+    pub body: Vec<u8>,
+}
+
+impl From<Request<Vec<u8>>> for MockRequest {
+    #[inline]
+    fn from(req: Request<Vec<u8>>) -> Self {
+        let (parts, body) = req.into_parts();
+        Self {
+            method: parts.method,
+            uri: parts.uri,
+            headers: parts.headers,
+            body,
+        }
+    }
+}
+
+/// Response served for all requests matching `method` and `path`
+#[derive(Clone, Debug)]
+struct Route {
+    method: Method,
+    path: String,
+    status: StatusCode,
+    body: Bytes,
+    content_type: &'static str,
+}
+
+impl Route {
+    fn new(method: Method, path: &str, status: StatusCode, body: Bytes) -> Self {
+        Self {
+            method,
+            path: path.into(),
+            status,
+            body,
+            content_type: "application/json",
+        }
+    }
+
+    /// Segments in curly braces, like `{asset_uuid}`, match any value
+    fn matches(&self, method: &Method, path: &str) -> bool {
+        let mut expected = self.path.trim_matches('/').split('/');
+        let mut actual = path.trim_matches('/').split('/');
+        if self.method != method {
+            return false;
+        }
+        loop {
+            match (expected.next(), actual.next()) {
+                (None, None) => return true,
+                (Some(expected), Some(actual)) => {
+                    if !(expected == actual || expected.starts_with('{') && expected.ends_with('}'))
+                    {
+                        return false;
+                    }
+                }
+                _ => return false,
+            }
+        }
+    }
+}
+
+/// Canned responses for every endpoint in the `requests` module
+fn fixtures() -> Vec<Route> {
+    let fixture = |method, path, body: &'static str| {
+        Route::new(
+            method,
+            path,
+            StatusCode::OK,
+            Bytes::from_static(body.as_bytes()),
+        )
+    };
+    let file = |method, path, body| Route {
+        content_type: "application/octet-stream",
+        ..fixture(method, path, body)
+    };
+    vec![
+        fixture(
+            Method::POST,
+            "/api/v2/assets/bulk-jobs/acr",
+            include_str!("fixtures/acr_update.json"),
+        ),
+        fixture(
+            Method::POST,
+            "/api/v2/assets/bulk-jobs/move-to-network",
+            include_str!("fixtures/assets_move.json"),
+        ),
+        fixture(
+            Method::POST,
+            "/api/v2/assets/bulk-jobs/delete",
+            include_str!("fixtures/assets_delete.json"),
+        ),
+        fixture(
+            Method::GET,
+            "/api/v2/assets/bulk-jobs/{job_uuid}",
+            include_str!("fixtures/asset_bulk_job.json"),
+        ),
+        fixture(Method::GET, "/assets", include_str!("fixtures/assets.json")),
+        fixture(
+            Method::GET,
+            "/assets/{asset_uuid}",
+            include_str!("fixtures/asset.json"),
+        ),
+        fixture(
+            Method::POST,
+            "/assets/export",
+            include_str!("fixtures/export.json"),
+        ),
+        fixture(
+            Method::GET,
+            "/assets/export/{export_uuid}/status",
+            include_str!("fixtures/export_status.json"),
+        ),
+        fixture(
+            Method::POST,
+            "/assets/export/{export_uuid}/cancel",
+            include_str!("fixtures/export_cancel.json"),
+        ),
+        fixture(
+            Method::GET,
+            "/assets/export/{export_uuid}/chunks/{chunk_id}",
+            include_str!("fixtures/assets_export_chunk.json"),
+        ),
+        fixture(Method::GET, "/groups", include_str!("fixtures/groups.json")),
+        fixture(Method::POST, "/groups", include_str!("fixtures/group.json")),
+        fixture(
+            Method::PUT,
+            "/groups/{group_id}",
+            include_str!("fixtures/group.json"),
+        ),
+        fixture(Method::DELETE, "/groups/{group_id}", ""),
+        fixture(
+            Method::GET,
+            "/groups/{group_id}/users",
+            include_str!("fixtures/users.json"),
+        ),
+        fixture(Method::POST, "/groups/{group_id}/users/{user_id}", ""),
+        fixture(Method::DELETE, "/groups/{group_id}/users/{user_id}", ""),
+        fixture(
+            Method::POST,
+            "/import/assets",
+            include_str!("fixtures/asset_import.json"),
+        ),
+        fixture(
+            Method::GET,
+            "/import/assets",
+            include_str!("fixtures/asset_import_jobs.json"),
+        ),
+        fixture(
+            Method::GET,
+            "/import/asset-jobs",
+            include_str!("fixtures/asset_import_jobs.json"),
+        ),
+        fixture(
+            Method::GET,
+            "/import/asset-jobs/{job_uuid}",
+            include_str!("fixtures/asset_import_job.json"),
+        ),
+        fixture(
+            Method::GET,
+            "/networks",
+            include_str!("fixtures/networks.json"),
+        ),
+        fixture(
+            Method::POST,
+            "/networks",
+            include_str!("fixtures/network.json"),
+        ),
+        fixture(
+            Method::GET,
+            "/networks/{network_id}",
+            include_str!("fixtures/network.json"),
+        ),
+        fixture(
+            Method::PUT,
+            "/networks/{network_id}",
+            include_str!("fixtures/network.json"),
+        ),
+        fixture(Method::DELETE, "/networks/{network_id}", ""),
+        fixture(
+            Method::GET,
+            "/networks/{network_id}/counts/assets-not-seen-in/{days}",
+            include_str!("fixtures/network_assets_not_seen.json"),
+        ),
+        fixture(
+            Method::POST,
+            "/networks/{network_id}/scanners/{scanner_uuid}",
+            "",
+        ),
+        fixture(
+            Method::GET,
+            "/networks/{network_id}/scanners",
+            include_str!("fixtures/network_scanners.json"),
+        ),
+        fixture(
+            Method::GET,
+            "/networks/{network_id}/assignable-scanners",
+            include_str!("fixtures/network_scanners.json"),
+        ),
+        fixture(
+            Method::GET,
+            "/credentials",
+            include_str!("fixtures/credentials.json"),
+        ),
+        fixture(
+            Method::POST,
+            "/credentials",
+            include_str!("fixtures/credential_created.json"),
+        ),
+        fixture(
+            Method::GET,
+            "/credentials/{credential_uuid}",
+            include_str!("fixtures/credential.json"),
+        ),
+        fixture(Method::PUT, "/credentials/{credential_uuid}", ""),
+        fixture(Method::DELETE, "/credentials/{credential_uuid}", ""),
+        fixture(
+            Method::GET,
+            "/credentials/types",
+            include_str!("fixtures/credential_types.json"),
+        ),
+        fixture(
+            Method::POST,
+            "/credentials/files",
+            include_str!("fixtures/credential_file.json"),
+        ),
+        fixture(
+            Method::POST,
+            "/file/upload",
+            include_str!("fixtures/file_upload.json"),
+        ),
+        fixture(
+            Method::GET,
+            "/editor/policy/{policy_id}",
+            include_str!("fixtures/editor_template.json"),
+        ),
+        fixture(
+            Method::GET,
+            "/editor/{editor_type}/templates",
+            include_str!("fixtures/editor_templates.json"),
+        ),
+        fixture(
+            Method::GET,
+            "/editor/{editor_type}/templates/{template_uuid}",
+            include_str!("fixtures/editor_template.json"),
+        ),
+        fixture(
+            Method::GET,
+            "/policies",
+            include_str!("fixtures/policies.json"),
+        ),
+        fixture(
+            Method::POST,
+            "/policies",
+            include_str!("fixtures/policy_created.json"),
+        ),
+        fixture(
+            Method::GET,
+            "/policies/{policy_id}",
+            include_str!("fixtures/policy.json"),
+        ),
+        fixture(Method::PUT, "/policies/{policy_id}", ""),
+        fixture(Method::DELETE, "/policies/{policy_id}", ""),
+        fixture(
+            Method::POST,
+            "/policies/{policy_id}/copy",
+            include_str!("fixtures/policy_copy.json"),
+        ),
+        fixture(
+            Method::POST,
+            "/policies/import",
+            include_str!("fixtures/policy_import.json"),
+        ),
+        file(
+            Method::GET,
+            "/policies/{policy_id}/export",
+            include_str!("fixtures/policy_export.nessus"),
+        ),
+        fixture(Method::GET, "/scans", include_str!("fixtures/scans.json")),
+        fixture(
+            Method::POST,
+            "/scans",
+            include_str!("fixtures/scan_create.json"),
+        ),
+        fixture(
+            Method::GET,
+            "/scans/{scan_id}",
+            include_str!("fixtures/scan.json"),
+        ),
+        fixture(
+            Method::PUT,
+            "/scans/{scan_id}",
+            include_str!("fixtures/scan_config.json"),
+        ),
+        fixture(Method::DELETE, "/scans/{scan_id}", ""),
+        fixture(
+            Method::POST,
+            "/scans/{scan_id}/launch",
+            include_str!("fixtures/scan_launch.json"),
+        ),
+        fixture(Method::POST, "/scans/{scan_id}/pause", ""),
+        fixture(Method::POST, "/scans/{scan_id}/resume", ""),
+        fixture(Method::POST, "/scans/{scan_id}/stop", ""),
+        fixture(
+            Method::POST,
+            "/scans/{scan_id}/export",
+            include_str!("fixtures/scan_export.json"),
+        ),
+        fixture(
+            Method::GET,
+            "/scans/{scan_id}/export/{file_id}/status",
+            include_str!("fixtures/scan_export_status.json"),
+        ),
+        file(
+            Method::GET,
+            "/scans/{scan_id}/export/{file_id}/download",
+            include_str!("fixtures/scan_export.nessus"),
+        ),
+        fixture(
+            Method::GET,
+            "/scans/{scan_id}/history",
+            include_str!("fixtures/scan_history.json"),
+        ),
+        fixture(
+            Method::GET,
+            "/scans/{scan_id}/history/{history_uuid}",
+            include_str!("fixtures/scan_history_run.json"),
+        ),
+        fixture(
+            Method::GET,
+            "/scans/{scan_id}/hosts/{host_id}",
+            include_str!("fixtures/scan_host.json"),
+        ),
+        fixture(
+            Method::GET,
+            "/scans/{scan_id}/hosts/{host_id}/plugins/{plugin_id}",
+            include_str!("fixtures/scan_plugin_output.json"),
+        ),
+        fixture(
+            Method::GET,
+            "/scans/{scan_id}/latest-status",
+            include_str!("fixtures/scan_latest_status.json"),
+        ),
+        fixture(
+            Method::POST,
+            "/tags/assets/assignments",
+            include_str!("fixtures/tag_assignments.json"),
+        ),
+        fixture(
+            Method::GET,
+            "/scanners",
+            include_str!("fixtures/scanners.json"),
+        ),
+        fixture(
+            Method::GET,
+            "/scanners/{scanner_id}",
+            include_str!("fixtures/scanner.json"),
+        ),
+        fixture(Method::PUT, "/scanners/{scanner_id}", ""),
+        fixture(Method::PUT, "/scanners/{scanner_id}/link", ""),
+        fixture(Method::DELETE, "/scanners/{scanner_id}", ""),
+        fixture(
+            Method::GET,
+            "/scanners/{scanner_id}/scans",
+            include_str!("fixtures/scanner_scans.json"),
+        ),
+        fixture(
+            Method::GET,
+            "/scanners/{scanner_id}/aws-targets",
+            include_str!("fixtures/scanner_aws_targets.json"),
+        ),
+        fixture(
+            Method::GET,
+            "/scanner-groups",
+            include_str!("fixtures/scanner_groups.json"),
+        ),
+        fixture(
+            Method::POST,
+            "/scanner-groups",
+            include_str!("fixtures/scanner_group.json"),
+        ),
+        fixture(
+            Method::GET,
+            "/scanner-groups/{group_id}",
+            include_str!("fixtures/scanner_group.json"),
+        ),
+        fixture(Method::PUT, "/scanner-groups/{group_id}", ""),
+        fixture(Method::DELETE, "/scanner-groups/{group_id}", ""),
+        fixture(
+            Method::GET,
+            "/scanner-groups/{group_id}/scanners",
+            include_str!("fixtures/scanners.json"),
+        ),
+        fixture(
+            Method::POST,
+            "/scanner-groups/{group_id}/scanners/{scanner_id}",
+            "",
+        ),
+        fixture(
+            Method::DELETE,
+            "/scanner-groups/{group_id}/scanners/{scanner_id}",
+            "",
+        ),
+        fixture(
+            Method::GET,
+            "/scanners/{scanner_id}/agents",
+            include_str!("fixtures/agents.json"),
+        ),
+        fixture(
+            Method::DELETE,
+            "/scanners/{scanner_id}/agents/{agent_id}",
+            "",
+        ),
+        fixture(
+            Method::POST,
+            "/scanners/{scanner_id}/agents/_bulk/unlink",
+            include_str!("fixtures/agent_bulk_task.json"),
+        ),
+        fixture(
+            Method::GET,
+            "/scanners/{scanner_id}/agents/_bulk/{task_uuid}",
+            include_str!("fixtures/agent_bulk_task.json"),
+        ),
+        fixture(
+            Method::GET,
+            "/scanners/{scanner_id}/agent-groups",
+            include_str!("fixtures/agent_groups.json"),
+        ),
+        fixture(
+            Method::POST,
+            "/scanners/{scanner_id}/agent-groups",
+            include_str!("fixtures/agent_group.json"),
+        ),
+        fixture(
+            Method::GET,
+            "/scanners/{scanner_id}/agent-groups/{group_id}",
+            include_str!("fixtures/agent_group.json"),
+        ),
+        fixture(
+            Method::PUT,
+            "/scanners/{scanner_id}/agent-groups/{group_id}",
+            include_str!("fixtures/agent_group.json"),
+        ),
+        fixture(
+            Method::DELETE,
+            "/scanners/{scanner_id}/agent-groups/{group_id}",
+            "",
+        ),
+        fixture(
+            Method::PUT,
+            "/scanners/{scanner_id}/agent-groups/{group_id}/agents/{agent_id}",
+            "",
+        ),
+        fixture(
+            Method::DELETE,
+            "/scanners/{scanner_id}/agent-groups/{group_id}/agents/{agent_id}",
+            "",
+        ),
+        fixture(
+            Method::GET,
+            "/scanners/{scanner_id}/agent-groups/{group_id}/agents/_bulk/{task_uuid}",
+            include_str!("fixtures/agent_bulk_task.json"),
+        ),
+        fixture(
+            Method::GET,
+            "/tags/categories",
+            include_str!("fixtures/tag_categories.json"),
+        ),
+        fixture(
+            Method::POST,
+            "/tags/categories",
+            include_str!("fixtures/tag_category.json"),
+        ),
+        fixture(
+            Method::GET,
+            "/tags/categories/{category_uuid}",
+            include_str!("fixtures/tag_category.json"),
+        ),
+        fixture(
+            Method::PUT,
+            "/tags/categories/{category_uuid}",
+            include_str!("fixtures/tag_category.json"),
+        ),
+        fixture(Method::DELETE, "/tags/categories/{category_uuid}", ""),
+        fixture(
+            Method::GET,
+            "/tags/values",
+            include_str!("fixtures/tag_values.json"),
+        ),
+        fixture(
+            Method::POST,
+            "/tags/values",
+            include_str!("fixtures/tag_value.json"),
+        ),
+        fixture(
+            Method::GET,
+            "/tags/values/{value_uuid}",
+            include_str!("fixtures/tag_value.json"),
+        ),
+        fixture(
+            Method::PUT,
+            "/tags/values/{value_uuid}",
+            include_str!("fixtures/tag_value.json"),
+        ),
+        fixture(Method::DELETE, "/tags/values/{value_uuid}", ""),
+        fixture(Method::GET, "/users", include_str!("fixtures/users.json")),
+        fixture(Method::POST, "/users", include_str!("fixtures/user.json")),
+        fixture(
+            Method::GET,
+            "/users/{user_id}",
+            include_str!("fixtures/user.json"),
+        ),
+        fixture(
+            Method::PUT,
+            "/users/{user_id}",
+            include_str!("fixtures/user.json"),
+        ),
+        fixture(Method::DELETE, "/users/{user_id}", ""),
+        fixture(
+            Method::PUT,
+            "/users/{user_id}/enabled",
+            include_str!("fixtures/user.json"),
+        ),
+        fixture(
+            Method::PUT,
+            "/users/{user_id}/keys",
+            include_str!("fixtures/user_keys.json"),
+        ),
+        fixture(
+            Method::GET,
+            "/users/{user_id}/authorizations",
+            include_str!("fixtures/user_authorizations.json"),
+        ),
+        fixture(
+            Method::GET,
+            "/v2/access-groups",
+            include_str!("fixtures/access_groups.json"),
+        ),
+        fixture(
+            Method::POST,
+            "/v2/access-groups",
+            include_str!("fixtures/access_group.json"),
+        ),
+        fixture(
+            Method::GET,
+            "/v2/access-groups/{access_group_id}",
+            include_str!("fixtures/access_group.json"),
+        ),
+        fixture(
+            Method::PUT,
+            "/v2/access-groups/{access_group_id}",
+            include_str!("fixtures/access_group.json"),
+        ),
+        fixture(Method::DELETE, "/v2/access-groups/{access_group_id}", ""),
+        fixture(
+            Method::GET,
+            "/v2/access-groups/filters",
+            include_str!("fixtures/access_group_filters.json"),
+        ),
+        fixture(
+            Method::GET,
+            "/v2/access-groups/rules/filters",
+            include_str!("fixtures/access_group_rule_filters.json"),
+        ),
+        fixture(
+            Method::POST,
+            "/vulns/export",
+            include_str!("fixtures/export.json"),
+        ),
+        fixture(
+            Method::GET,
+            "/vulns/export/{export_uuid}/status",
+            include_str!("fixtures/export_status.json"),
+        ),
+        fixture(
+            Method::POST,
+            "/vulns/export/{export_uuid}/cancel",
+            include_str!("fixtures/export_cancel.json"),
+        ),
+        fixture(
+            Method::GET,
+            "/vulns/export/{export_uuid}/chunks/{chunk_id}",
+            include_str!("fixtures/vulns_export_chunk.json"),
+        ),
+    ]
+}
+
+#[derive(Debug, Default)]
+struct State {
+    routes: Vec<Route>,
+    faults: VecDeque<Fault>,
+    requests: Vec<MockRequest>,
+}
+
+/// Transport which answers requests in-process using canned fixtures. Cloning
+/// the transport is cheap and shares the fixtures, faults and recorded
+/// requests.
+#[derive(Clone, Debug)]
+pub struct MockTransport {
+    state: Arc<Mutex<State>>,
+}
+
+impl MockTransport {
+    /// Creates a new transport serving the fixtures for every endpoint
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tenable::mock::MockTransport;
+    /// let transport = MockTransport::new();
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn new() -> Self {
+        let state = State {
+            routes: fixtures(),
+            ..State::default()
+        };
+        Self {
+            state: Arc::new(Mutex::new(state)),
+        }
+    }
+
+    fn state(&self) -> MutexGuard<'_, State> {
+        // the state stays consistent even if a thread panicked while holding the lock
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Serves the given response for all requests matching `method` and
+    /// `path`. Segments in curly braces, like `/assets/{asset_uuid}`, match
+    /// any value. Routes added later take precedence over earlier ones and the
+    /// fixtures.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use http::{Method, StatusCode};
+    /// use tenable::mock::MockTransport;
+    /// let transport = MockTransport::new();
+    /// let _ = transport
+    ///     .route(Method::GET, "/assets", StatusCode::OK, r#"{"assets":[],"total":0}"#)
+    ///     .route(Method::GET, "/assets/unknown", StatusCode::NOT_FOUND, "");
+    /// ```
+    #[inline]
+    pub fn route<B: Into<Bytes>>(
+        &self,
+        method: Method,
+        path: &str,
+        status: StatusCode,
+        body: B,
+    ) -> &Self {
+        self.state()
+            .routes
+            .push(Route::new(method, path, status, body.into()));
+        self
+    }
+
+    /// Answers the next `times` requests with the given fault instead of the fixture
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use http::StatusCode;
+    /// use tenable::mock::{Fault, MockTransport};
+    /// let transport = MockTransport::new();
+    /// let _ = transport
+    ///     .inject(Fault::RateLimited(Some(1)), 2)
+    ///     .inject(Fault::ServerError(StatusCode::SERVICE_UNAVAILABLE), 1);
+    /// ```
+    #[inline]
+    pub fn inject(&self, fault: Fault, times: u32) -> &Self {
+        let mut state = self.state();
+        for _ in 0..times {
+            state.faults.push_back(fault);
+        }
+        self
+    }
+
+    /// Returns all requests received so far
+    #[must_use]
+    #[inline]
+    pub fn requests(&self) -> Vec<MockRequest> {
+        self.state().requests.clone()
+    }
+
+    /// Answers the given request using the injected faults or the fixtures
+    #[must_use]
+    #[inline]
+    pub fn respond(&self, req: MockRequest) -> Response {
+        let mut state = self.state();
+        let id = state.requests.len();
+        let authenticated = req.headers.contains_key("X-ApiKeys");
+        let route = state
+            .routes
+            .iter()
+            .rev()
+            .find(|route| route.matches(&req.method, req.uri.path()))
+            .cloned();
+        let fault = state.faults.pop_front();
+        state.requests.push(req);
+        drop(state);
+
+        let mut headers = HeaderMap::new();
+        let _ = headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        if let Ok(request_id) = HeaderValue::from_str(&format!("mock-{}", id)) {
+            let _ = headers.insert("X-Request-Uuid", request_id);
+        }
+        let fault = match (fault, route) {
+            (Some(fault), _) => fault,
+            (None, _) if !authenticated => Fault::Unauthorized,
+            (None, Some(route)) => {
+                let _ = headers.insert(CONTENT_TYPE, HeaderValue::from_static(route.content_type));
+                return Response {
+                    status: route.status,
+                    headers,
+                    body: route.body,
+                };
+            }
+            (None, None) => Fault::NotFound,
+        };
+        if let Fault::RateLimited(Some(retry_after)) = fault {
+            let _ = headers.insert(RETRY_AFTER, HeaderValue::from(retry_after));
+        }
+        let status = fault.status();
+        let body = format!(
+            r#"{{"statusCode":{},"error":"{}","message":"Injected by the mock"}}"#,
+            status.as_u16(),
+            status.canonical_reason().unwrap_or_default()
+        );
+        Response {
+            status,
+            headers,
+            body: Bytes::from(body),
+        }
+    }
+
+    /// Answers the request. Never fails, but returns a `Result` to be usable
+    /// wherever a function sending requests is expected.
+    ///
+    /// # Errors
+    ///
+    /// Never fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tenable::{mock::MockTransport, requests::AssetReq, Tenable};
+    /// let tenable = Tenable::new("", "");
+    /// let transport = MockTransport::new();
+    /// let req = tenable.assets();
+    /// let _assets = Tenable::request(req, |req| transport.send(req))
+    ///     .expect("Unable to list all assets");
+    /// ```
+    #[inline]
+    pub fn send(&self, req: Request<Vec<u8>>) -> Result<Response, Error<Infallible>> {
+        Ok(self.respond(req.into()))
+    }
+
+    /// Answers the request asynchronously. See `send`.
+    ///
+    /// # Errors
+    ///
+    /// Never fails.
+    #[inline]
+    pub fn send_async(&self, req: Request<Vec<u8>>) -> Ready<Result<Response, Error<Infallible>>> {
+        future::ready(self.send(req))
+    }
+}
+
+impl Default for MockTransport {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Transport<Infallible> for MockTransport {
+    #[inline]
+    fn send(&self, req: Request<Vec<u8>>) -> Result<Response, Error<Infallible>> {
+        Self::send(self, req)
+    }
+}
+
+impl AsyncTransport<Infallible> for MockTransport {
+    type Future = Ready<Result<Response, Error<Infallible>>>;
+
+    #[inline]
+    fn send(&self, req: Request<Vec<u8>>) -> Self::Future {
+        self.send_async(req)
+    }
+}
@@ -0,0 +1,196 @@
+use crate::mock::{MockRequest, MockTransport};
+use http::{
+    header::{HeaderName, HeaderValue, CONTENT_LENGTH},
+    HeaderMap, Method, Uri,
+};
+use std::{
+    io::{self, BufRead, BufReader, Read, Write},
+    net::{SocketAddr, TcpListener, TcpStream},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    thread::{self, JoinHandle},
+    time::Duration,
+};
+
+/// Local http server answering requests using a `MockTransport`
+///
+/// The server listens on a random port of `127.0.0.1` and stops once it is
+/// dropped. Every connection is closed after a single request.
+#[derive(Debug)]
+pub struct MockServer {
+    transport: MockTransport,
+    addr: SocketAddr,
+    shutdown: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl MockServer {
+    /// Starts a new server answering requests using the given transport
+    ///
+    /// # Errors
+    ///
+    /// Fails if no local port could be bound.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tenable::{
+    ///     mock::{MockServer, MockTransport},
+    ///     Tenable,
+    /// };
+    /// let server = MockServer::start(MockTransport::new()).expect("Unable to start server");
+    /// let tenable = Tenable {
+    ///     uri: server.uri().into(),
+    ///     ..Tenable::new("", "")
+    /// };
+    /// ```
+    #[inline]
+    pub fn start(transport: MockTransport) -> io::Result<Self> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let addr = listener.local_addr()?;
+        let shutdown = Arc::new(AtomicBool::new(false));
+        let handle = {
+            let transport = transport.clone();
+            let shutdown = Arc::clone(&shutdown);
+            thread::spawn(move || {
+                for stream in listener.incoming() {
+                    if shutdown.load(Ordering::SeqCst) {
+                        break;
+                    }
+                    if let Ok(stream) = stream {
+                        let transport = transport.clone();
+                        let _ = thread::spawn(move || {
+                            // the client sees a closed connection on failure
+                            let _ = serve(&transport, stream);
+                        });
+                    }
+                }
+            })
+        };
+        Ok(Self {
+            transport,
+            addr,
+            shutdown,
+            handle: Some(handle),
+        })
+    }
+
+    /// Uri to send requests against, like `http://127.0.0.1:12345`
+    #[must_use]
+    #[inline]
+    pub fn uri(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+
+    /// Transport answering the requests, used to add routes, inject faults
+    /// and inspect the received requests
+    #[must_use]
+    #[inline]
+    pub fn transport(&self) -> &MockTransport {
+        &self.transport
+    }
+}
+
+impl Drop for MockServer {
+    #[inline]
+    fn drop(&mut self) {
+        self.shutdown.store(true, Ordering::SeqCst);
+        // wakes up the listener, which is blocked waiting for a connection
+        let _ = TcpStream::connect(self.addr);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+/// Reads a single request from the stream and writes the response
+fn serve(transport: &MockTransport, stream: TcpStream) -> io::Result<()> {
+    stream.set_read_timeout(Some(Duration::from_secs(10)))?;
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let req = read_request(&mut reader)?;
+    let res = transport.respond(req);
+
+    let mut writer = stream;
+    write!(
+        writer,
+        "HTTP/1.1 {} {}\r\n",
+        res.status.as_u16(),
+        res.status.canonical_reason().unwrap_or_default()
+    )?;
+    for (name, value) in &res.headers {
+        write!(writer, "{}: ", name)?;
+        writer.write_all(value.as_bytes())?;
+        writer.write_all(b"\r\n")?;
+    }
+    write!(
+        writer,
+        "{}: {}\r\nConnection: close\r\n\r\n",
+        CONTENT_LENGTH,
+        res.body.len()
+    )?;
+    writer.write_all(&res.body)?;
+    writer.flush()
+}
+
+fn invalid<E: Into<Box<dyn std::error::Error + Send + Sync>>>(err: E) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, err)
+}
+
+/// Parses a HTTP/1.1 request with an optional `Content-Length` body
+fn read_request<R: BufRead>(reader: &mut R) -> io::Result<MockRequest> {
+    let mut line = String::new();
+    let _ = reader.read_line(&mut line)?;
+    let mut parts = line.split_whitespace();
+    let method: Method = parts
+        .next()
+        .ok_or_else(|| invalid("missing method"))?
+        .parse()
+        .map_err(invalid)?;
+    let uri: Uri = parts
+        .next()
+        .ok_or_else(|| invalid("missing uri"))?
+        .parse()
+        .map_err(invalid)?;
+
+    let mut headers = HeaderMap::new();
+    loop {
+        line.clear();
+        let _ = reader.read_line(&mut line)?;
+        let header = line.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        let mut header = header.splitn(2, ':');
+        let name = header.next().unwrap_or_default().trim();
+        let value = header
+            .next()
+            .ok_or_else(|| invalid("invalid header"))?
+            .trim();
+        let name = HeaderName::from_bytes(name.as_bytes()).map_err(invalid)?;
+        let value = HeaderValue::from_str(value).map_err(invalid)?;
+        let _ = headers.append(name, value);
+    }
+
+    let length = headers
+        .get(CONTENT_LENGTH)
+        .and_then(|length| length.to_str().ok())
+        .and_then(|length| length.parse::<u64>().ok())
+        .unwrap_or(0);
+    // the length is sent by the client, so only allocate what actually arrives
+    let mut body = Vec::new();
+    let _ = reader.by_ref().take(length).read_to_end(&mut body)?;
+    if body.len() as u64 != length {
+        return Err(io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            "body shorter than Content-Length",
+        ));
+    }
+    Ok(MockRequest {
+        method,
+        uri,
+        headers,
+        body,
+    })
+}
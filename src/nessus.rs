@@ -0,0 +1,473 @@
+//! Streaming parser for the `.nessus` v2 XML format, enabled using the
+//! `nessus-xml` feature
+//!
+//! `.nessus` files are returned by `scan_export` using `ScanExportFormat::Nessus`
+//! and contain the full results of a scan run. `NessusReader` reads them host by
+//! host, so that large files do not need to be kept in memory. The fields of
+//! `ReportHost` are named and shaped like the ones of `Asset` and `AssetByUuid`,
+//! which allows joining offline scan files with data returned by the API.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use std::{fs::File, io::BufReader};
+//! use tenable::nessus::NessusReader;
+//!
+//! let file = File::open("scan.nessus").expect("Unable to open file");
+//! for host in NessusReader::new(BufReader::new(file)) {
+//!     let host = host.expect("Unable to parse host");
+//!     for item in host.items.iter().filter(|item| item.plugin_id == 51192) {
+//!         println!("{:?}: {:?}", host.ipv4, item.plugin_output);
+//!     }
+//! }
+//! ```
+
+use crate::types::Severity;
+use quick_xml::{
+    events::{BytesStart, Event},
+    Reader,
+};
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, error, fmt, io::BufRead, mem, str::FromStr};
+
+/// Errors which occur while parsing a `.nessus` file
+#[derive(Debug)]
+pub enum ParseError {
+    /// The file is not well-formed XML or could not be read.
+    Xml(quick_xml::Error),
+    /// An attribute or element contains a value of the wrong type.
+    InvalidValue {
+        /// Name of the attribute or element
+        name: String,
+        /// Value which could not be parsed
+        value: String,
+    },
+}
+
+impl error::Error for ParseError {
+    #[inline]
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::Xml(err) => Some(err),
+            Self::InvalidValue { .. } => None,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Xml(_) => write!(f, "Unable to read the nessus file."),
+            Self::InvalidValue { name, value } => {
+                write!(f, "Invalid value for {}: {}.", name, value)
+            }
+        }
+    }
+}
+
+impl From<quick_xml::Error> for ParseError {
+    #[inline]
+    fn from(err: quick_xml::Error) -> Self {
+        Self::Xml(err)
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+/// Host of a scan together with all findings reported for it
+pub struct ReportHost {
+    /// The name of the host as given in the targets of the scan.
+    #[serde(rename = "name")]
+    pub name: String,
+    /// The UUID of the asset, if the file was exported from Tenable.io.
+    #[serde(rename = "uuid", skip_serializing_if = "Option::is_none")]
+    pub uuid: Option<String>,
+    /// The hostnames of the host.
+    #[serde(rename = "hostname", skip_serializing_if = "Option::is_none")]
+    pub hostname: Option<Vec<String>>,
+    /// The IPv4 addresses of the host.
+    #[serde(rename = "ipv4", skip_serializing_if = "Option::is_none")]
+    pub ipv4: Option<Vec<String>>,
+    /// The IPv6 addresses of the host.
+    #[serde(rename = "ipv6", skip_serializing_if = "Option::is_none")]
+    pub ipv6: Option<Vec<String>>,
+    /// The fully-qualified domain names of the host.
+    #[serde(rename = "fqdn", skip_serializing_if = "Option::is_none")]
+    pub fqdn: Option<Vec<String>>,
+    /// The NetBIOS names of the host.
+    #[serde(rename = "netbios_name", skip_serializing_if = "Option::is_none")]
+    pub netbios_name: Option<Vec<String>>,
+    /// The operating systems the scan detected on the host.
+    #[serde(rename = "operating_system", skip_serializing_if = "Option::is_none")]
+    pub operating_system: Option<Vec<String>>,
+    /// The MAC addresses of the host.
+    #[serde(rename = "mac_address", skip_serializing_if = "Option::is_none")]
+    pub mac_address: Option<Vec<String>>,
+    /// The Unix timestamp when the scan of the host started.
+    #[serde(rename = "host_start", skip_serializing_if = "Option::is_none")]
+    pub host_start: Option<u64>,
+    /// The Unix timestamp when the scan of the host ended.
+    #[serde(rename = "host_end", skip_serializing_if = "Option::is_none")]
+    pub host_end: Option<u64>,
+    /// All properties of the host, keyed by their name like `host-ip` or `os`.
+    #[serde(rename = "properties")]
+    pub properties: BTreeMap<String, String>,
+    /// The findings reported for the host.
+    #[serde(rename = "items")]
+    pub items: Vec<ReportItem>,
+}
+
+impl ReportHost {
+    /// Fills the typed fields using the host properties
+    fn apply_properties(&mut self) -> Result<(), ParseError> {
+        for (name, value) in &self.properties {
+            match name.as_str() {
+                "host-uuid" => self.uuid = Some(value.clone()),
+                "hostname" => self.hostname = Some(values(value)),
+                "host-ip" => {
+                    let (ipv6, ipv4): (Vec<_>, Vec<_>) =
+                        values(value).into_iter().partition(|ip| ip.contains(':'));
+                    self.ipv4 = Some(ipv4).filter(|ips| !ips.is_empty());
+                    self.ipv6 = Some(ipv6).filter(|ips| !ips.is_empty());
+                }
+                "host-fqdn" => self.fqdn = Some(values(value)),
+                "netbios-name" => self.netbios_name = Some(values(value)),
+                "operating-system" => self.operating_system = Some(values(value)),
+                "mac-address" => self.mac_address = Some(values(value)),
+                "HOST_START_TIMESTAMP" => self.host_start = Some(parse(name, value)?),
+                "HOST_END_TIMESTAMP" => self.host_end = Some(parse(name, value)?),
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Finding reported by a plugin for a host
+pub struct ReportItem {
+    /// The port the finding was reported for, `0` if it does not relate to a port.
+    #[serde(rename = "port")]
+    pub port: u16,
+    /// The protocol of the port, like `tcp` or `udp`.
+    #[serde(rename = "protocol")]
+    pub protocol: String,
+    /// The name of the service running on the port, like `www`.
+    #[serde(rename = "svc_name")]
+    pub svc_name: String,
+    /// The severity of the finding.
+    #[serde(rename = "severity")]
+    pub severity: Severity,
+    /// The ID of the plugin which reported the finding.
+    #[serde(rename = "plugin_id")]
+    pub plugin_id: u64,
+    /// The name of the plugin which reported the finding.
+    #[serde(rename = "plugin_name")]
+    pub plugin_name: String,
+    /// The family of the plugin which reported the finding.
+    #[serde(rename = "plugin_family")]
+    pub plugin_family: String,
+    /// A brief summary of the finding.
+    #[serde(rename = "synopsis", skip_serializing_if = "Option::is_none")]
+    pub synopsis: Option<String>,
+    /// The full description of the finding.
+    #[serde(rename = "description", skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The recommended way to fix the finding.
+    #[serde(rename = "solution", skip_serializing_if = "Option::is_none")]
+    pub solution: Option<String>,
+    /// The output of the plugin for this host.
+    #[serde(rename = "plugin_output", skip_serializing_if = "Option::is_none")]
+    pub plugin_output: Option<String>,
+    /// The risk factor of the finding, like `None`, `Low` or `Critical`.
+    #[serde(rename = "risk_factor", skip_serializing_if = "Option::is_none")]
+    pub risk_factor: Option<String>,
+    /// The CVSSv2 base score of the finding.
+    #[serde(rename = "cvss_base_score", skip_serializing_if = "Option::is_none")]
+    pub cvss_base_score: Option<f64>,
+    /// The CVSSv3 base score of the finding.
+    #[serde(rename = "cvss3_base_score", skip_serializing_if = "Option::is_none")]
+    pub cvss3_base_score: Option<f64>,
+    /// The CVEs associated with the finding.
+    #[serde(rename = "cve")]
+    pub cve: Vec<String>,
+    /// Links to further information about the finding.
+    #[serde(rename = "see_also", skip_serializing_if = "Option::is_none")]
+    pub see_also: Option<String>,
+    /// All other elements of the finding, keyed by their name like `plugin_type`.
+    #[serde(rename = "properties")]
+    pub properties: BTreeMap<String, String>,
+}
+
+impl ReportItem {
+    /// Creates the finding using the attributes of the `ReportItem` element
+    fn new(attributes: &BTreeMap<String, String>) -> Result<Self, ParseError> {
+        let attribute = |name: &str| attributes.get(name).cloned().unwrap_or_default();
+        let severity = match attribute("severity").as_str() {
+            "0" => Severity::Info,
+            "1" => Severity::Low,
+            "2" => Severity::Medium,
+            "3" => Severity::High,
+            "4" => Severity::Critical,
+            value => {
+                return Err(ParseError::InvalidValue {
+                    name: "severity".into(),
+                    value: value.into(),
+                })
+            }
+        };
+        Ok(Self {
+            port: parse("port", &attribute("port"))?,
+            protocol: attribute("protocol"),
+            svc_name: attribute("svc_name"),
+            severity,
+            plugin_id: parse("pluginID", &attribute("pluginID"))?,
+            plugin_name: attribute("pluginName"),
+            plugin_family: attribute("pluginFamily"),
+            synopsis: None,
+            description: None,
+            solution: None,
+            plugin_output: None,
+            risk_factor: None,
+            cvss_base_score: None,
+            cvss3_base_score: None,
+            cve: Vec::new(),
+            see_also: None,
+            properties: BTreeMap::new(),
+        })
+    }
+
+    /// Stores the text of a child element in the matching field
+    fn set(&mut self, name: String, value: String) -> Result<(), ParseError> {
+        match name.as_str() {
+            "synopsis" => self.synopsis = Some(value),
+            "description" => self.description = Some(value),
+            "solution" => self.solution = Some(value),
+            "plugin_output" => self.plugin_output = Some(value),
+            "risk_factor" => self.risk_factor = Some(value),
+            "cvss_base_score" => self.cvss_base_score = Some(parse(&name, &value)?),
+            "cvss3_base_score" => self.cvss3_base_score = Some(parse(&name, &value)?),
+            "cve" => self.cve.push(value),
+            "see_also" => self.see_also = Some(value),
+            _ => {
+                let _ = self.properties.insert(name, value);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Splits a property which holds one value per line, like `mac-address`
+fn values(value: &str) -> Vec<String> {
+    value
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+fn parse<T: FromStr>(name: &str, value: &str) -> Result<T, ParseError> {
+    value.trim().parse().map_err(|_| ParseError::InvalidValue {
+        name: name.into(),
+        value: value.into(),
+    })
+}
+
+/// Iterator over the hosts of a `.nessus` file. Hosts are parsed one at a
+/// time while reading the file. The iterator ends after the last host or the
+/// first error.
+pub struct NessusReader<R: BufRead> {
+    reader: Reader<R>,
+    buf: Vec<u8>,
+    report_name: Option<String>,
+    host: Option<ReportHost>,
+    item: Option<ReportItem>,
+    done: bool,
+}
+
+impl<R: BufRead> NessusReader<R> {
+    /// Creates a parser reading from the given source
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tenable::nessus::NessusReader;
+    /// let xml = r#"<NessusClientData_v2><Report name="Deployment">
+    ///     <ReportHost name="192.0.2.10"><HostProperties>
+    ///         <tag name="host-ip">192.0.2.10</tag>
+    ///     </HostProperties></ReportHost>
+    /// </Report></NessusClientData_v2>"#;
+    /// let mut reader = NessusReader::new(xml.as_bytes());
+    /// let host = reader.next().expect("No host found").expect("Unable to parse host");
+    /// assert_eq!(host.ipv4, Some(vec!["192.0.2.10".to_owned()]));
+    /// assert_eq!(reader.report_name(), Some("Deployment"));
+    /// ```
+    #[inline]
+    pub fn new(source: R) -> Self {
+        Self {
+            reader: Reader::from_reader(source),
+            buf: Vec::new(),
+            report_name: None,
+            host: None,
+            item: None,
+            done: false,
+        }
+    }
+
+    /// Name of the report, once the `Report` element has been read
+    #[must_use]
+    #[inline]
+    pub fn report_name(&self) -> Option<&str> {
+        self.report_name.as_deref()
+    }
+
+    /// Collects the attributes of an element
+    fn attributes(&self, event: &BytesStart<'_>) -> Result<BTreeMap<String, String>, ParseError> {
+        let mut attributes = BTreeMap::new();
+        for attribute in event.attributes() {
+            let attribute = attribute?;
+            let key = self.reader.decode(attribute.key)?.to_owned();
+            let value = attribute.unescape_and_decode_value(&self.reader)?;
+            let _ = attributes.insert(key, value);
+        }
+        Ok(attributes)
+    }
+
+    /// Reads the text of an element, including CDATA sections, up to its end
+    fn text(&mut self, end: &[u8]) -> Result<String, ParseError> {
+        let mut text = String::new();
+        let mut buf = Vec::new();
+        loop {
+            match self.reader.read_event(&mut buf)? {
+                Event::Text(event) | Event::CData(event) => {
+                    text.push_str(&event.unescape_and_decode(&self.reader)?);
+                }
+                Event::End(ref event) if event.name() == end => return Ok(text),
+                Event::Eof => {
+                    let end = String::from_utf8_lossy(end).into_owned();
+                    return Err(quick_xml::Error::UnexpectedEof(end).into());
+                }
+                _ => {}
+            }
+            buf.clear();
+        }
+    }
+
+    /// Handles the start of an element. Empty elements have no text.
+    fn start(&mut self, event: &BytesStart<'_>, empty: bool) -> Result<(), ParseError> {
+        match event.name() {
+            b"Report" => self.report_name = self.attributes(event)?.remove("name"),
+            b"ReportHost" => {
+                self.host = Some(ReportHost {
+                    name: self.attributes(event)?.remove("name").unwrap_or_default(),
+                    ..ReportHost::default()
+                });
+            }
+            b"ReportItem" => self.item = Some(ReportItem::new(&self.attributes(event)?)?),
+            b"tag" if self.host.is_some() => {
+                let name = self.attributes(event)?.remove("name").unwrap_or_default();
+                let value = if empty {
+                    String::new()
+                } else {
+                    self.text(b"tag")?
+                };
+                if let Some(ref mut host) = self.host {
+                    let _ = host.properties.insert(name, value);
+                }
+            }
+            name if self.item.is_some() => {
+                let name = self.reader.decode(name)?.to_owned();
+                let value = if empty {
+                    String::new()
+                } else {
+                    self.text(event.name())?
+                };
+                if let Some(ref mut item) = self.item {
+                    item.set(name, value)?;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Handles the end of an element and returns the host once it is complete
+    fn end(&mut self, name: &[u8]) -> Result<Option<ReportHost>, ParseError> {
+        match name {
+            b"ReportItem" => {
+                if let (Some(host), Some(item)) = (self.host.as_mut(), self.item.take()) {
+                    host.items.push(item);
+                }
+            }
+            b"ReportHost" => {
+                if let Some(mut host) = self.host.take() {
+                    host.apply_properties()?;
+                    return Ok(Some(host));
+                }
+            }
+            _ => {}
+        }
+        Ok(None)
+    }
+
+    /// Reads until the end of the next `ReportHost` element
+    fn next_host(&mut self) -> Result<Option<ReportHost>, ParseError> {
+        loop {
+            let mut buf = mem::take(&mut self.buf);
+            buf.clear();
+            let event = self.reader.read_event(&mut buf)?.into_owned();
+            self.buf = buf;
+            let host = match event {
+                Event::Start(ref event) => {
+                    self.start(event, false)?;
+                    None
+                }
+                Event::Empty(ref event) => {
+                    self.start(event, true)?;
+                    self.end(event.name())?
+                }
+                Event::End(ref event) => self.end(event.name())?,
+                Event::Eof => return Ok(None),
+                _ => None,
+            };
+            if host.is_some() {
+                return Ok(host);
+            }
+        }
+    }
+}
+
+impl<R: BufRead> fmt::Debug for NessusReader<R> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NessusReader")
+            .field("report_name", &self.report_name)
+            .field("host", &self.host)
+            .field("item", &self.item)
+            .field("done", &self.done)
+            .finish()
+    }
+}
+
+impl<R: BufRead> Iterator for NessusReader<R> {
+    type Item = Result<ReportHost, ParseError>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        match self.next_host() {
+            Ok(Some(host)) => Some(Ok(host)),
+            Ok(None) => {
+                self.done = true;
+                None
+            }
+            Err(err) => {
+                self.done = true;
+                Some(Err(err))
+            }
+        }
+    }
+}
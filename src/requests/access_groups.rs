@@ -0,0 +1,291 @@
+use crate::{
+    types::{
+        AccessGroupByIdReq, AccessGroupCreateReq, AccessGroupDef, AccessGroupDeleteReq,
+        AccessGroupFiltersReq, AccessGroupRuleFiltersReq, AccessGroupUpdateReq, AccessGroupsReq,
+    },
+    Tenable,
+};
+use std::borrow::Cow;
+
+/// Provides methods for the `AccessGroup` Type.
+pub trait AccessGroupReq {
+    /// Lists the access groups. Set `limit`, `offset` or `sort` on the
+    /// returned request to page through the access groups.
+    ///
+    /// # Permission
+    ///
+    /// Requires ADMINISTRATOR [64] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::AccessGroupReq, types::AccessGroups, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.access_groups();
+    /// let _access_groups: AccessGroups = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to list access groups");
+    /// ```
+    fn access_groups(&self) -> AccessGroupsReq<'_>;
+
+    /// Creates an access group. Tenable.io assigns assets matching the
+    /// `rules` asynchronously, see `status` of the returned access group.
+    ///
+    /// # Permission
+    ///
+    /// Requires ADMINISTRATOR [64] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::AccessGroupReq, types::{AccessGroup, AccessGroupDef, AccessGroupPermission, AccessGroupsPrincipals, AccessGroupsRules}, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.access_group_create(AccessGroupDef {
+    ///     name: "Web servers".into(),
+    ///     rules: Some(vec![AccessGroupsRules {
+    ///         _type: Some("fqdn".into()),
+    ///         operator: Some("ends".into()),
+    ///         terms: Some(vec![".web.example.com".into()]),
+    ///     }]),
+    ///     principals: Some(vec![AccessGroupsPrincipals {
+    ///         _type: Some("group".into()),
+    ///         principal_name: Some("Web team".into()),
+    ///         permissions: Some(vec![AccessGroupPermission::CanView]),
+    ///         ..AccessGroupsPrincipals::default()
+    ///     }]),
+    ///     ..AccessGroupDef::default()
+    /// });
+    /// let _access_group: AccessGroup = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to create access group");
+    /// ```
+    fn access_group_create<'a, D: Into<Cow<'a, AccessGroupDef>>>(
+        &'a self,
+        access_group_def: D,
+    ) -> AccessGroupCreateReq<'a>;
+
+    /// Returns the specified access group including its rules and principals.
+    ///
+    /// # Permission
+    ///
+    /// Requires ADMINISTRATOR [64] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::AccessGroupReq, types::AccessGroup, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.access_group_by_id("00000000-0000-0000-0000-000000000000");
+    /// let _access_group: AccessGroup = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to fetch access group")
+    /// .expect("Access group not found");
+    /// ```
+    fn access_group_by_id<'a, I: Into<Cow<'a, str>>>(
+        &'a self,
+        access_group_id: I,
+    ) -> AccessGroupByIdReq<'a>;
+
+    /// Updates the specified access group. The rules and principals of the
+    /// access group are replaced by the ones of the definition.
+    ///
+    /// # Permission
+    ///
+    /// Requires ADMINISTRATOR [64] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::AccessGroupReq, types::{AccessGroup, AccessGroupDef, AccessGroupPermission, AccessGroupsPrincipals, AccessGroupsRules}, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.access_group_update(
+    ///     "00000000-0000-0000-0000-000000000000",
+    ///     AccessGroupDef {
+    ///         name: "Web servers".into(),
+    ///         rules: Some(vec![AccessGroupsRules {
+    ///             _type: Some("fqdn".into()),
+    ///             operator: Some("ends".into()),
+    ///             terms: Some(vec![".web.example.com".into()]),
+    ///         }]),
+    ///         principals: Some(vec![AccessGroupsPrincipals {
+    ///             _type: Some("group".into()),
+    ///             principal_name: Some("Web team".into()),
+    ///             permissions: Some(vec![AccessGroupPermission::CanView]),
+    ///             ..AccessGroupsPrincipals::default()
+    ///         }]),
+    ///         ..AccessGroupDef::default()
+    ///     },
+    /// );
+    /// let _access_group: AccessGroup = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to update access group")
+    /// .expect("Access group not found");
+    /// ```
+    fn access_group_update<'a, I: Into<Cow<'a, str>>, D: Into<Cow<'a, AccessGroupDef>>>(
+        &'a self,
+        access_group_id: I,
+        access_group_def: D,
+    ) -> AccessGroupUpdateReq<'a>;
+
+    /// Deletes the specified access group.
+    ///
+    /// # Permission
+    ///
+    /// Requires ADMINISTRATOR [64] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::AccessGroupReq, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.access_group_delete("00000000-0000-0000-0000-000000000000");
+    /// Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to delete access group")
+    /// .expect("Access group not found");
+    /// ```
+    fn access_group_delete<'a, I: Into<Cow<'a, str>>>(
+        &'a self,
+        access_group_id: I,
+    ) -> AccessGroupDeleteReq<'a>;
+
+    /// Lists the filters which can be used to narrow down the list of access groups.
+    ///
+    /// # Permission
+    ///
+    /// Requires ADMINISTRATOR [64] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::AccessGroupReq, types::AccessGroupFilters, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.access_group_filters();
+    /// let _filters: AccessGroupFilters = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to list access group filters");
+    /// ```
+    fn access_group_filters(&self) -> AccessGroupFiltersReq<'_>;
+
+    /// Lists the rule types and operators which can be used in the `rules` of
+    /// an access group.
+    ///
+    /// # Permission
+    ///
+    /// Requires ADMINISTRATOR [64] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::AccessGroupReq, types::AccessGroupRuleFilters, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.access_group_rule_filters();
+    /// let _filters: AccessGroupRuleFilters = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to list access group rule filters");
+    /// ```
+    fn access_group_rule_filters(&self) -> AccessGroupRuleFiltersReq<'_>;
+}
+
+impl AccessGroupReq for Tenable<'_> {
+    #[inline]
+    fn access_groups(&self) -> AccessGroupsReq<'_> {
+        AccessGroupsReq {
+            tenable: self,
+            limit: None,
+            offset: None,
+            sort: None,
+        }
+    }
+
+    #[inline]
+    fn access_group_create<'a, D: Into<Cow<'a, AccessGroupDef>>>(
+        &'a self,
+        access_group_def: D,
+    ) -> AccessGroupCreateReq<'a> {
+        AccessGroupCreateReq {
+            tenable: self,
+            access_group_def: access_group_def.into(),
+        }
+    }
+
+    #[inline]
+    fn access_group_by_id<'a, I: Into<Cow<'a, str>>>(
+        &'a self,
+        access_group_id: I,
+    ) -> AccessGroupByIdReq<'a> {
+        AccessGroupByIdReq {
+            tenable: self,
+            access_group_id: access_group_id.into(),
+        }
+    }
+
+    #[inline]
+    fn access_group_update<'a, I: Into<Cow<'a, str>>, D: Into<Cow<'a, AccessGroupDef>>>(
+        &'a self,
+        access_group_id: I,
+        access_group_def: D,
+    ) -> AccessGroupUpdateReq<'a> {
+        AccessGroupUpdateReq {
+            tenable: self,
+            access_group_id: access_group_id.into(),
+            access_group_def: access_group_def.into(),
+        }
+    }
+
+    #[inline]
+    fn access_group_delete<'a, I: Into<Cow<'a, str>>>(
+        &'a self,
+        access_group_id: I,
+    ) -> AccessGroupDeleteReq<'a> {
+        AccessGroupDeleteReq {
+            tenable: self,
+            access_group_id: access_group_id.into(),
+        }
+    }
+
+    #[inline]
+    fn access_group_filters(&self) -> AccessGroupFiltersReq<'_> {
+        AccessGroupFiltersReq { tenable: self }
+    }
+
+    #[inline]
+    fn access_group_rule_filters(&self) -> AccessGroupRuleFiltersReq<'_> {
+        AccessGroupRuleFiltersReq { tenable: self }
+    }
+}
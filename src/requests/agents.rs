@@ -0,0 +1,521 @@
+use crate::{
+    types::{
+        AgentBulkDef, AgentBulkStatusReq, AgentGroupAddAgentReq, AgentGroupBulkStatusReq,
+        AgentGroupByIdReq, AgentGroupCreateReq, AgentGroupDef, AgentGroupDeleteReq,
+        AgentGroupRemoveAgentReq, AgentGroupUpdateReq, AgentGroupsReq, AgentUnlinkReq, AgentsReq,
+        AgentsUnlinkReq,
+    },
+    Tenable,
+};
+use std::borrow::Cow;
+
+/// Provides methods for the `Agent` Type.
+pub trait AgentReq {
+    /// Lists the agents linked to the specified scanner. Set `filters`,
+    /// `wildcard`, `limit`, `offset` or `sort` on the returned request to narrow
+    /// down the agents. Use `1` as scanner ID for agents linked to Tenable.io.
+    ///
+    /// # Permission
+    ///
+    /// Requires STANDARD [32] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::AgentReq, types::{Agents, AgentsReq, QueryFilter}, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = AgentsReq {
+    ///     filters: Some(vec![QueryFilter {
+    ///         field: "status".into(),
+    ///         operator: "eq".into(),
+    ///         value: "off".into(),
+    ///     }]),
+    ///     ..tenable.agents(1)
+    /// };
+    /// let _agents: Agents = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to list agents")
+    /// .expect("Scanner not found");
+    /// ```
+    fn agents(&self, scanner_id: u64) -> AgentsReq<'_>;
+
+    /// Unlinks the specified agent. The agent stops sending scan results.
+    ///
+    /// # Permission
+    ///
+    /// Requires ADMINISTRATOR [64] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::AgentReq, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.agent_unlink(1, 42);
+    /// Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to unlink agent")
+    /// .expect("Agent not found");
+    /// ```
+    fn agent_unlink(&self, scanner_id: u64, agent_id: u64) -> AgentUnlinkReq<'_>;
+
+    /// Unlinks multiple agents at once. Tenable.io processes the operation
+    /// asynchronously. Use `agent_bulk_status` to follow it up.
+    ///
+    /// # Permission
+    ///
+    /// Requires ADMINISTRATOR [64] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::AgentReq, types::{AgentBulkCriteria, AgentBulkDef, AgentBulkTask, QueryFilter}, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// // unlink agents which have not connected within the last 30 days
+    /// let req = tenable.agents_unlink(
+    ///     1,
+    ///     AgentBulkDef {
+    ///         criteria: Some(AgentBulkCriteria {
+    ///             filters: Some(vec![QueryFilter {
+    ///                 field: "last_connect".into(),
+    ///                 operator: "lt".into(),
+    ///                 value: "1590969600".into(),
+    ///             }]),
+    ///             ..AgentBulkCriteria::default()
+    ///         }),
+    ///         ..AgentBulkDef::default()
+    ///     },
+    /// );
+    /// let _task: AgentBulkTask = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to unlink agents")
+    /// .expect("Scanner not found");
+    /// ```
+    fn agents_unlink<'a, D: Into<Cow<'a, AgentBulkDef>>>(
+        &'a self,
+        scanner_id: u64,
+        agent_bulk_def: D,
+    ) -> AgentsUnlinkReq<'a>;
+
+    /// Returns the progress of a bulk operation created by `agents_unlink`.
+    /// Poll it until `status.is_finished()` to wait for the operation to complete.
+    ///
+    /// # Permission
+    ///
+    /// Requires ADMINISTRATOR [64] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::AgentReq, types::AgentBulkTask, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.agent_bulk_status(1, "00000000-0000-0000-0000-000000000000");
+    /// let _task: AgentBulkTask = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to fetch bulk operation")
+    /// .expect("Bulk operation not found");
+    /// ```
+    fn agent_bulk_status<'a, I: Into<Cow<'a, str>>>(
+        &'a self,
+        scanner_id: u64,
+        task_uuid: I,
+    ) -> AgentBulkStatusReq<'a>;
+
+    /// Lists the agent groups of the specified scanner.
+    ///
+    /// # Permission
+    ///
+    /// Requires STANDARD [32] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::AgentReq, types::AgentGroups, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.agent_groups(1);
+    /// let _groups: AgentGroups = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to list agent groups")
+    /// .expect("Scanner not found");
+    /// ```
+    fn agent_groups(&self, scanner_id: u64) -> AgentGroupsReq<'_>;
+
+    /// Creates an agent group.
+    ///
+    /// # Permission
+    ///
+    /// Requires ADMINISTRATOR [64] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::AgentReq, types::{AgentGroup, AgentGroupDef}, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.agent_group_create(
+    ///     1,
+    ///     AgentGroupDef {
+    ///         name: "Workstations".into(),
+    ///     },
+    /// );
+    /// let _group: AgentGroup = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to create agent group")
+    /// .expect("Scanner not found");
+    /// ```
+    fn agent_group_create<'a, D: Into<Cow<'a, AgentGroupDef>>>(
+        &'a self,
+        scanner_id: u64,
+        agent_group_def: D,
+    ) -> AgentGroupCreateReq<'a>;
+
+    /// Returns the specified agent group including its agents.
+    ///
+    /// # Permission
+    ///
+    /// Requires STANDARD [32] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::AgentReq, types::AgentGroup, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.agent_group_by_id(1, 7);
+    /// let _group: AgentGroup = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to fetch agent group")
+    /// .expect("Agent group not found");
+    /// ```
+    fn agent_group_by_id(&self, scanner_id: u64, group_id: u64) -> AgentGroupByIdReq<'_>;
+
+    /// Renames the specified agent group.
+    ///
+    /// # Permission
+    ///
+    /// Requires ADMINISTRATOR [64] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::AgentReq, types::{AgentGroup, AgentGroupDef}, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.agent_group_update(
+    ///     1,
+    ///     7,
+    ///     AgentGroupDef {
+    ///         name: "Laptops".into(),
+    ///     },
+    /// );
+    /// let _group: AgentGroup = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to update agent group")
+    /// .expect("Agent group not found");
+    /// ```
+    fn agent_group_update<'a, D: Into<Cow<'a, AgentGroupDef>>>(
+        &'a self,
+        scanner_id: u64,
+        group_id: u64,
+        agent_group_def: D,
+    ) -> AgentGroupUpdateReq<'a>;
+
+    /// Deletes the specified agent group. The agents stay linked.
+    ///
+    /// # Permission
+    ///
+    /// Requires ADMINISTRATOR [64] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::AgentReq, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.agent_group_delete(1, 7);
+    /// Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to delete agent group")
+    /// .expect("Agent group not found");
+    /// ```
+    fn agent_group_delete(&self, scanner_id: u64, group_id: u64) -> AgentGroupDeleteReq<'_>;
+
+    /// Adds the specified agent to the specified agent group.
+    ///
+    /// # Permission
+    ///
+    /// Requires ADMINISTRATOR [64] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::AgentReq, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.agent_group_add_agent(1, 7, 42);
+    /// Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to add agent")
+    /// .expect("Agent or agent group not found");
+    /// ```
+    fn agent_group_add_agent(
+        &self,
+        scanner_id: u64,
+        group_id: u64,
+        agent_id: u64,
+    ) -> AgentGroupAddAgentReq<'_>;
+
+    /// Removes the specified agent from the specified agent group.
+    ///
+    /// # Permission
+    ///
+    /// Requires ADMINISTRATOR [64] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::AgentReq, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.agent_group_remove_agent(1, 7, 42);
+    /// Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to remove agent")
+    /// .expect("Agent or agent group not found");
+    /// ```
+    fn agent_group_remove_agent(
+        &self,
+        scanner_id: u64,
+        group_id: u64,
+        agent_id: u64,
+    ) -> AgentGroupRemoveAgentReq<'_>;
+
+    /// Returns the progress of a bulk operation on the agents of the specified
+    /// agent group. Poll it until `status.is_finished()` to wait for the operation
+    /// to complete.
+    ///
+    /// # Permission
+    ///
+    /// Requires ADMINISTRATOR [64] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::AgentReq, types::AgentBulkTask, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.agent_group_bulk_status(1, 7, "00000000-0000-0000-0000-000000000000");
+    /// let _task: AgentBulkTask = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to fetch bulk operation")
+    /// .expect("Bulk operation not found");
+    /// ```
+    fn agent_group_bulk_status<'a, I: Into<Cow<'a, str>>>(
+        &'a self,
+        scanner_id: u64,
+        group_id: u64,
+        task_uuid: I,
+    ) -> AgentGroupBulkStatusReq<'a>;
+}
+
+impl AgentReq for Tenable<'_> {
+    #[inline]
+    fn agents(&self, scanner_id: u64) -> AgentsReq<'_> {
+        AgentsReq {
+            tenable: self,
+            scanner_id,
+            filters: None,
+            filter_type: None,
+            wildcard: None,
+            limit: None,
+            offset: None,
+            sort: None,
+        }
+    }
+
+    #[inline]
+    fn agent_unlink(&self, scanner_id: u64, agent_id: u64) -> AgentUnlinkReq<'_> {
+        AgentUnlinkReq {
+            tenable: self,
+            scanner_id,
+            agent_id,
+        }
+    }
+
+    #[inline]
+    fn agents_unlink<'a, D: Into<Cow<'a, AgentBulkDef>>>(
+        &'a self,
+        scanner_id: u64,
+        agent_bulk_def: D,
+    ) -> AgentsUnlinkReq<'a> {
+        AgentsUnlinkReq {
+            tenable: self,
+            scanner_id,
+            agent_bulk_def: agent_bulk_def.into(),
+        }
+    }
+
+    #[inline]
+    fn agent_bulk_status<'a, I: Into<Cow<'a, str>>>(
+        &'a self,
+        scanner_id: u64,
+        task_uuid: I,
+    ) -> AgentBulkStatusReq<'a> {
+        AgentBulkStatusReq {
+            tenable: self,
+            scanner_id,
+            task_uuid: task_uuid.into(),
+        }
+    }
+
+    #[inline]
+    fn agent_groups(&self, scanner_id: u64) -> AgentGroupsReq<'_> {
+        AgentGroupsReq {
+            tenable: self,
+            scanner_id,
+        }
+    }
+
+    #[inline]
+    fn agent_group_create<'a, D: Into<Cow<'a, AgentGroupDef>>>(
+        &'a self,
+        scanner_id: u64,
+        agent_group_def: D,
+    ) -> AgentGroupCreateReq<'a> {
+        AgentGroupCreateReq {
+            tenable: self,
+            scanner_id,
+            agent_group_def: agent_group_def.into(),
+        }
+    }
+
+    #[inline]
+    fn agent_group_by_id(&self, scanner_id: u64, group_id: u64) -> AgentGroupByIdReq<'_> {
+        AgentGroupByIdReq {
+            tenable: self,
+            scanner_id,
+            group_id,
+        }
+    }
+
+    #[inline]
+    fn agent_group_update<'a, D: Into<Cow<'a, AgentGroupDef>>>(
+        &'a self,
+        scanner_id: u64,
+        group_id: u64,
+        agent_group_def: D,
+    ) -> AgentGroupUpdateReq<'a> {
+        AgentGroupUpdateReq {
+            tenable: self,
+            scanner_id,
+            group_id,
+            agent_group_def: agent_group_def.into(),
+        }
+    }
+
+    #[inline]
+    fn agent_group_delete(&self, scanner_id: u64, group_id: u64) -> AgentGroupDeleteReq<'_> {
+        AgentGroupDeleteReq {
+            tenable: self,
+            scanner_id,
+            group_id,
+        }
+    }
+
+    #[inline]
+    fn agent_group_add_agent(
+        &self,
+        scanner_id: u64,
+        group_id: u64,
+        agent_id: u64,
+    ) -> AgentGroupAddAgentReq<'_> {
+        AgentGroupAddAgentReq {
+            tenable: self,
+            scanner_id,
+            group_id,
+            agent_id,
+        }
+    }
+
+    #[inline]
+    fn agent_group_remove_agent(
+        &self,
+        scanner_id: u64,
+        group_id: u64,
+        agent_id: u64,
+    ) -> AgentGroupRemoveAgentReq<'_> {
+        AgentGroupRemoveAgentReq {
+            tenable: self,
+            scanner_id,
+            group_id,
+            agent_id,
+        }
+    }
+
+    #[inline]
+    fn agent_group_bulk_status<'a, I: Into<Cow<'a, str>>>(
+        &'a self,
+        scanner_id: u64,
+        group_id: u64,
+        task_uuid: I,
+    ) -> AgentGroupBulkStatusReq<'a> {
+        AgentGroupBulkStatusReq {
+            tenable: self,
+            scanner_id,
+            group_id,
+            task_uuid: task_uuid.into(),
+        }
+    }
+}
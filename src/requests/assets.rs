@@ -1,5 +1,10 @@
 use crate::{
-    types::{Acr, AcrUpdate, AssetByUuidReq, AssetsMove, AssetsMoveDef, AssetsReq},
+    types::{
+        Acr, AcrUpdate, AssetBulkJobReq, AssetByUuidReq, AssetImportJobReq, AssetImportJobsReq,
+        AssetImportReq, AssetImportsReq, AssetsDeleteDef, AssetsDeleteReq, AssetsExportCancelReq,
+        AssetsExportChunkReq, AssetsExportDef, AssetsExportReq, AssetsExportStatusReq, AssetsMove,
+        AssetsMoveDef, AssetsReq, ImportAsset,
+    },
     Tenable,
 };
 use std::borrow::Cow;
@@ -62,6 +67,8 @@ pub trait AssetReq {
     /// see Lumin metrics in the Tenable.io Vulnerability Management User Guide.
     ///
     /// You must have a Lumin license to update the ACR for assets in your organization.
+    /// This request creates an asynchronous job in Tenable.io. Use `asset_bulk_job`
+    /// to follow it up.
     ///
     /// # Permission
     ///
@@ -71,7 +78,7 @@ pub trait AssetReq {
     ///
     /// ```no_run
     /// use std::convert::Infallible;
-    /// use tenable::{requests::AssetReq, types::Acr, Error, Response, Tenable};
+    /// use tenable::{requests::AssetReq, types::{Acr, UpdatedAcrs}, Error, Response, Tenable};
     /// let tenable = Tenable::new(
     ///     "0000000000000000000000000000000000000000000000000000000000000000",
     ///     "0000000000000000000000000000000000000000000000000000000000000000",
@@ -81,7 +88,7 @@ pub trait AssetReq {
     ///     asset: Vec::new(),
     ///     ..Acr::default()
     /// }]);
-    /// Tenable::request(req, |_| {
+    /// let _updated: UpdatedAcrs = Tenable::request(req, |_| {
     ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
     /// })
     /// .expect("Unable to update acr");
@@ -94,6 +101,7 @@ pub trait AssetReq {
     /// creates an asynchronous job in Tenable.io.
     ///
     /// For information about the assets move workflow and payload examples, see Bulk Asset Operations.
+    /// The UUIDs of the networks can be looked up using `NetworkReq::networks`.
     ///     
     /// Requires ADMINISTRATOR [64] user permissions.
     ///
@@ -121,6 +129,299 @@ pub trait AssetReq {
         &'a self,
         assets_move_def: I,
     ) -> AssetsMove<'a>;
+
+    /// Deletes all assets matching the query. This request creates an
+    /// asynchronous job in Tenable.io. Use `asset_bulk_job` to follow it up.
+    ///
+    /// # Permission
+    ///
+    /// Requires ADMINISTRATOR [64] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::AssetReq, Error, Response, Tenable, types::{AssetFilter, AssetQuery, AssetsDeleteDef, DeletedAssets}};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.assets_delete(AssetsDeleteDef {
+    ///     query: AssetQuery::And(vec![AssetFilter {
+    ///         field: "host.hostname".into(),
+    ///         operator: "match".into(),
+    ///         value: "decommissioned".into(),
+    ///     }]),
+    ///     hard_delete: None,
+    /// });
+    /// let _deleted: DeletedAssets = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to delete assets");
+    /// ```
+    fn assets_delete<'a, I: Into<Cow<'a, AssetsDeleteDef>>>(
+        &'a self,
+        assets_delete_def: I,
+    ) -> AssetsDeleteReq<'a>;
+
+    /// Returns the progress of a job created by `acr_update`, `assets_move` or
+    /// `assets_delete`. Poll it until `status.is_finished()` to wait for the
+    /// job to complete.
+    ///
+    /// # Permission
+    ///
+    /// Requires ADMINISTRATOR [64] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::{convert::Infallible, thread::sleep, time::Duration};
+    /// use tenable::{requests::AssetReq, Error, Response, Tenable, types::AssetBulkJobState};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.asset_bulk_job("00000000-0000-0000-0000-000000000000");
+    /// loop {
+    ///     let job = Tenable::request(&req, |_| {
+    ///         Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    ///     })
+    ///     .expect("Unable to fetch job")
+    ///     .expect("Job not found");
+    ///     if job.status.is_finished() {
+    ///         assert_eq!(job.status, AssetBulkJobState::Completed);
+    ///         break;
+    ///     }
+    ///     sleep(Duration::from_secs(5));
+    /// }
+    /// ```
+    fn asset_bulk_job<'a, I: Into<Cow<'a, str>>>(&'a self, job_uuid: I) -> AssetBulkJobReq<'a>;
+
+    /// Imports assets from a third-party source. Tenable.io accepts at most
+    /// `ASSET_IMPORT_BATCH_SIZE` assets and `ASSET_IMPORT_BATCH_BYTES` bytes
+    /// per request, so the assets are split into one request per batch. Each
+    /// request creates an asynchronous import job in Tenable.io. Use
+    /// `asset_import_job` to follow it up.
+    ///
+    /// # Permission
+    ///
+    /// Requires ADMINISTRATOR [64] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::AssetReq, Error, Response, Tenable, types::{AssetImportJobUuid, ImportAsset}};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let assets = vec![ImportAsset {
+    ///     fqdn: Some(vec!["web01.example.com".into()]),
+    ///     ipv4: Some(vec!["192.168.0.10".into()]),
+    ///     ..ImportAsset::default()
+    /// }];
+    /// for req in tenable.asset_import("discovery", assets) {
+    ///     let _job: AssetImportJobUuid = Tenable::request(req, |_| {
+    ///         Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    ///     })
+    ///     .expect("Unable to import assets");
+    /// }
+    /// ```
+    fn asset_import<'a, S: Into<Cow<'a, str>>, I: Into<Cow<'a, [ImportAsset]>>>(
+        &'a self,
+        source: S,
+        assets: I,
+    ) -> Vec<AssetImportReq<'a>>;
+
+    /// Lists the asset import jobs of the last seven days.
+    ///
+    /// # Permission
+    ///
+    /// Requires ADMINISTRATOR [64] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::AssetReq, Error, Response, Tenable, types::AssetImportJobs};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.asset_import_jobs();
+    /// let _jobs: AssetImportJobs = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to list import jobs");
+    /// ```
+    fn asset_import_jobs(&self) -> AssetImportJobsReq<'_>;
+
+    /// Returns the specified asset import job.
+    ///
+    /// # Permission
+    ///
+    /// Requires ADMINISTRATOR [64] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::AssetReq, Error, Response, Tenable, types::AssetImportJob};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.asset_import_job("00000000-0000-0000-0000-000000000000");
+    /// let _job: AssetImportJob = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to fetch import job")
+    /// .expect("Import job not found");
+    /// ```
+    fn asset_import_job<'a, I: Into<Cow<'a, str>>>(&'a self, job_uuid: I) -> AssetImportJobReq<'a>;
+
+    /// Lists the asset imports of the container using the `/import/assets`
+    /// endpoint. The response has the same layout as `asset_import_jobs`.
+    ///
+    /// # Permission
+    ///
+    /// Requires ADMINISTRATOR [64] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::AssetReq, Error, Response, Tenable, types::AssetImportJobs};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.asset_imports();
+    /// let _imports: AssetImportJobs = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to list imports");
+    /// ```
+    fn asset_imports(&self) -> AssetImportsReq<'_>;
+
+    /// Exports all assets that match the request criteria. The export is
+    /// processed asynchronously by Tenable.io and split into chunks. Use
+    /// `assets_export_status` to find out which chunks are available and
+    /// `assets_export_chunk` to download them.
+    ///
+    /// # Permission
+    ///
+    /// Requires ADMINISTRATOR [64] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::AssetReq, Error, Response, Tenable, types::{AssetsExportDef, ExportUuid}};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.assets_export(AssetsExportDef {
+    ///     chunk_size: 1000,
+    ///     ..AssetsExportDef::default()
+    /// });
+    /// let _export: ExportUuid = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to export assets");
+    /// ```
+    fn assets_export<'a, I: Into<Cow<'a, AssetsExportDef>>>(
+        &'a self,
+        assets_export_def: I,
+    ) -> AssetsExportReq<'a>;
+
+    /// Returns the status of an assets export request. Tenable.io processes the
+    /// chunks in parallel so chunks may not complete in order, and the list of
+    /// available chunks is only complete once the status is `FINISHED`.
+    ///
+    /// # Permission
+    ///
+    /// Requires ADMINISTRATOR [64] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::AssetReq, Error, Response, Tenable, types::ExportStatus};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.assets_export_status("00000000-0000-0000-0000-000000000000");
+    /// let _status: ExportStatus = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to fetch export status")
+    /// .expect("Export not found");
+    /// ```
+    fn assets_export_status<'a, I: Into<Cow<'a, str>>>(
+        &'a self,
+        export_uuid: I,
+    ) -> AssetsExportStatusReq<'a>;
+
+    /// Downloads a chunk of assets from an assets export. Chunks are
+    /// available for download for up to 24 hours after they have been created.
+    ///
+    /// # Permission
+    ///
+    /// Requires ADMINISTRATOR [64] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::AssetReq, Error, Response, Tenable, types::AssetExport};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.assets_export_chunk("00000000-0000-0000-0000-000000000000", 1);
+    /// let _assets: Vec<AssetExport> = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to download chunk")
+    /// .expect("Chunk not found");
+    /// ```
+    fn assets_export_chunk<'a, I: Into<Cow<'a, str>>>(
+        &'a self,
+        export_uuid: I,
+        chunk_id: u64,
+    ) -> AssetsExportChunkReq<'a>;
+
+    /// Cancels the specified assets export. Chunks which have already been
+    /// processed remain available for download.
+    ///
+    /// # Permission
+    ///
+    /// Requires ADMINISTRATOR [64] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::AssetReq, Error, Response, Tenable, types::ExportStatus};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.assets_export_cancel("00000000-0000-0000-0000-000000000000");
+    /// let _status: ExportStatus = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to cancel export")
+    /// .expect("Export not found");
+    /// ```
+    fn assets_export_cancel<'a, I: Into<Cow<'a, str>>>(
+        &'a self,
+        export_uuid: I,
+    ) -> AssetsExportCancelReq<'a>;
 }
 
 impl AssetReq for Tenable<'_> {
@@ -155,4 +456,96 @@ impl AssetReq for Tenable<'_> {
             assets_move_def: assets_move_def.into(),
         }
     }
+
+    #[inline]
+    fn assets_delete<'a, I: Into<Cow<'a, AssetsDeleteDef>>>(
+        &'a self,
+        assets_delete_def: I,
+    ) -> AssetsDeleteReq<'a> {
+        AssetsDeleteReq {
+            tenable: self,
+            assets_delete_def: assets_delete_def.into(),
+        }
+    }
+
+    #[inline]
+    fn asset_bulk_job<'a, I: Into<Cow<'a, str>>>(&'a self, job_uuid: I) -> AssetBulkJobReq<'a> {
+        AssetBulkJobReq {
+            tenable: self,
+            job_uuid: job_uuid.into(),
+        }
+    }
+
+    #[inline]
+    fn asset_import<'a, S: Into<Cow<'a, str>>, I: Into<Cow<'a, [ImportAsset]>>>(
+        &'a self,
+        source: S,
+        assets: I,
+    ) -> Vec<AssetImportReq<'a>> {
+        AssetImportReq::batches(self, source.into(), assets.into())
+    }
+
+    #[inline]
+    fn asset_import_jobs(&self) -> AssetImportJobsReq<'_> {
+        AssetImportJobsReq { tenable: self }
+    }
+
+    #[inline]
+    fn asset_import_job<'a, I: Into<Cow<'a, str>>>(&'a self, job_uuid: I) -> AssetImportJobReq<'a> {
+        AssetImportJobReq {
+            tenable: self,
+            job_uuid: job_uuid.into(),
+        }
+    }
+
+    #[inline]
+    fn asset_imports(&self) -> AssetImportsReq<'_> {
+        AssetImportsReq { tenable: self }
+    }
+
+    #[inline]
+    fn assets_export<'a, I: Into<Cow<'a, AssetsExportDef>>>(
+        &'a self,
+        assets_export_def: I,
+    ) -> AssetsExportReq<'a> {
+        AssetsExportReq {
+            tenable: self,
+            assets_export_def: assets_export_def.into(),
+        }
+    }
+
+    #[inline]
+    fn assets_export_status<'a, I: Into<Cow<'a, str>>>(
+        &'a self,
+        export_uuid: I,
+    ) -> AssetsExportStatusReq<'a> {
+        AssetsExportStatusReq {
+            tenable: self,
+            export_uuid: export_uuid.into(),
+        }
+    }
+
+    #[inline]
+    fn assets_export_chunk<'a, I: Into<Cow<'a, str>>>(
+        &'a self,
+        export_uuid: I,
+        chunk_id: u64,
+    ) -> AssetsExportChunkReq<'a> {
+        AssetsExportChunkReq {
+            tenable: self,
+            export_uuid: export_uuid.into(),
+            chunk_id,
+        }
+    }
+
+    #[inline]
+    fn assets_export_cancel<'a, I: Into<Cow<'a, str>>>(
+        &'a self,
+        export_uuid: I,
+    ) -> AssetsExportCancelReq<'a> {
+        AssetsExportCancelReq {
+            tenable: self,
+            export_uuid: export_uuid.into(),
+        }
+    }
 }
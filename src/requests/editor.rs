@@ -0,0 +1,120 @@
+use crate::{
+    types::{EditorPolicyByIdReq, EditorTemplateByUuidReq, EditorTemplatesReq, EditorType},
+    Tenable,
+};
+use std::borrow::Cow;
+
+/// Provides methods for the `Editor` Type.
+pub trait EditorReq {
+    /// Lists the Tenable-provided templates of scans or policies.
+    ///
+    /// # Permission
+    ///
+    /// Requires BASIC [16] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::EditorReq, types::{EditorTemplates, EditorType}, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.editor_templates(EditorType::Scan);
+    /// let _templates: EditorTemplates = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to list templates");
+    /// ```
+    fn editor_templates(&self, editor_type: EditorType) -> EditorTemplatesReq<'_>;
+
+    /// Returns the editor form of the specified template, including all settings
+    /// with their default values.
+    ///
+    /// # Permission
+    ///
+    /// Requires BASIC [16] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::EditorReq, types::{EditorDetails, EditorType}, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.editor_template_by_uuid(
+    ///     EditorType::Policy,
+    ///     "731a8e52-3ea6-a291-ec0a-d2ff0619c19d7bd788d6be818b65",
+    /// );
+    /// let template: EditorDetails = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to fetch template")
+    /// .expect("Template not found");
+    /// let _defaults = template.default_settings();
+    /// ```
+    fn editor_template_by_uuid<'a, T: Into<Cow<'a, str>>>(
+        &'a self,
+        editor_type: EditorType,
+        template_uuid: T,
+    ) -> EditorTemplateByUuidReq<'a>;
+
+    /// Returns the editor form of the specified policy. The default value of each
+    /// input is the value currently configured by the policy.
+    ///
+    /// # Permission
+    ///
+    /// Requires STANDARD [32] user permissions and CAN VIEW [16] policy permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::EditorReq, types::EditorDetails, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.editor_policy_by_id(1);
+    /// let _policy: EditorDetails = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to fetch policy")
+    /// .expect("Policy not found");
+    /// ```
+    fn editor_policy_by_id(&self, policy_id: u64) -> EditorPolicyByIdReq<'_>;
+}
+
+impl EditorReq for Tenable<'_> {
+    #[inline]
+    fn editor_templates(&self, editor_type: EditorType) -> EditorTemplatesReq<'_> {
+        EditorTemplatesReq {
+            tenable: self,
+            editor_type,
+        }
+    }
+
+    #[inline]
+    fn editor_template_by_uuid<'a, T: Into<Cow<'a, str>>>(
+        &'a self,
+        editor_type: EditorType,
+        template_uuid: T,
+    ) -> EditorTemplateByUuidReq<'a> {
+        EditorTemplateByUuidReq {
+            tenable: self,
+            editor_type,
+            template_uuid: template_uuid.into(),
+        }
+    }
+
+    #[inline]
+    fn editor_policy_by_id(&self, policy_id: u64) -> EditorPolicyByIdReq<'_> {
+        EditorPolicyByIdReq {
+            tenable: self,
+            policy_id,
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use crate::{
+    types::{FileUploadReq, Secret},
+    Tenable,
+};
+use std::borrow::Cow;
+
+/// Provides methods for uploading files.
+pub trait FileReq {
+    /// Uploads a file, for example a `.nessus` policy to import with
+    /// `PolicyReq::policy_import`. Returns the name of the file on the server.
+    ///
+    /// # Permission
+    ///
+    /// Requires BASIC [16] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::FileReq, types::UploadedFile, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let content = std::fs::read("policy.nessus").expect("Unable to read policy");
+    /// let req = tenable.file_upload("policy.nessus", content);
+    /// let _file: UploadedFile = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to upload file");
+    /// ```
+    fn file_upload<'a, N: Into<Cow<'a, str>>, C: Into<Cow<'a, [u8]>>>(
+        &'a self,
+        file_name: N,
+        content: C,
+    ) -> FileUploadReq<'a>;
+}
+
+impl FileReq for Tenable<'_> {
+    #[inline]
+    fn file_upload<'a, N: Into<Cow<'a, str>>, C: Into<Cow<'a, [u8]>>>(
+        &'a self,
+        file_name: N,
+        content: C,
+    ) -> FileUploadReq<'a> {
+        FileUploadReq {
+            tenable: self,
+            file_name: file_name.into(),
+            content: Secret(content.into()),
+        }
+    }
+}
@@ -0,0 +1,250 @@
+use crate::{
+    types::{
+        GroupAddUserReq, GroupCreateReq, GroupDef, GroupDeleteReq, GroupRemoveUserReq,
+        GroupUpdateReq, GroupUsersReq, GroupsReq,
+    },
+    Tenable,
+};
+use std::borrow::Cow;
+
+/// Provides methods for the `Group` Type.
+pub trait GroupReq {
+    /// Lists the user groups of the container.
+    ///
+    /// # Permission
+    ///
+    /// Requires BASIC [16] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::GroupReq, types::Groups, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.groups();
+    /// let _groups: Groups = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to list groups");
+    /// ```
+    fn groups(&self) -> GroupsReq<'_>;
+
+    /// Creates a user group.
+    ///
+    /// # Permission
+    ///
+    /// Requires ADMINISTRATOR [64] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::GroupReq, types::{Group, GroupDef}, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.group_create(GroupDef {
+    ///     name: "Web team".into(),
+    /// });
+    /// let _group: Group = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to create group");
+    /// ```
+    fn group_create<'a, D: Into<Cow<'a, GroupDef>>>(&'a self, group_def: D) -> GroupCreateReq<'a>;
+
+    /// Renames the specified user group.
+    ///
+    /// # Permission
+    ///
+    /// Requires ADMINISTRATOR [64] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::GroupReq, types::{Group, GroupDef}, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.group_update(
+    ///     1,
+    ///     GroupDef {
+    ///         name: "Web team".into(),
+    ///     },
+    /// );
+    /// let _group: Group = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to update group")
+    /// .expect("Group not found");
+    /// ```
+    fn group_update<'a, D: Into<Cow<'a, GroupDef>>>(
+        &'a self,
+        group_id: u64,
+        group_def: D,
+    ) -> GroupUpdateReq<'a>;
+
+    /// Deletes the specified user group. Its users are not deleted.
+    ///
+    /// # Permission
+    ///
+    /// Requires ADMINISTRATOR [64] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::GroupReq, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.group_delete(1);
+    /// Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to delete group")
+    /// .expect("Group not found");
+    /// ```
+    fn group_delete(&self, group_id: u64) -> GroupDeleteReq<'_>;
+
+    /// Lists the users of the specified user group.
+    ///
+    /// # Permission
+    ///
+    /// Requires ADMINISTRATOR [64] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::GroupReq, types::Users, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.group_users(1);
+    /// let _users: Users = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to list users")
+    /// .expect("Group not found");
+    /// ```
+    fn group_users(&self, group_id: u64) -> GroupUsersReq<'_>;
+
+    /// Adds the specified user to the specified user group.
+    ///
+    /// # Permission
+    ///
+    /// Requires ADMINISTRATOR [64] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::GroupReq, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.group_add_user(1, 2);
+    /// Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to add user")
+    /// .expect("User or group not found");
+    /// ```
+    fn group_add_user(&self, group_id: u64, user_id: u64) -> GroupAddUserReq<'_>;
+
+    /// Removes the specified user from the specified user group.
+    ///
+    /// # Permission
+    ///
+    /// Requires ADMINISTRATOR [64] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::GroupReq, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.group_remove_user(1, 2);
+    /// Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to remove user")
+    /// .expect("User or group not found");
+    /// ```
+    fn group_remove_user(&self, group_id: u64, user_id: u64) -> GroupRemoveUserReq<'_>;
+}
+
+impl GroupReq for Tenable<'_> {
+    #[inline]
+    fn groups(&self) -> GroupsReq<'_> {
+        GroupsReq { tenable: self }
+    }
+
+    #[inline]
+    fn group_create<'a, D: Into<Cow<'a, GroupDef>>>(&'a self, group_def: D) -> GroupCreateReq<'a> {
+        GroupCreateReq {
+            tenable: self,
+            group_def: group_def.into(),
+        }
+    }
+
+    #[inline]
+    fn group_update<'a, D: Into<Cow<'a, GroupDef>>>(
+        &'a self,
+        group_id: u64,
+        group_def: D,
+    ) -> GroupUpdateReq<'a> {
+        GroupUpdateReq {
+            tenable: self,
+            group_id,
+            group_def: group_def.into(),
+        }
+    }
+
+    #[inline]
+    fn group_delete(&self, group_id: u64) -> GroupDeleteReq<'_> {
+        GroupDeleteReq {
+            tenable: self,
+            group_id,
+        }
+    }
+
+    #[inline]
+    fn group_users(&self, group_id: u64) -> GroupUsersReq<'_> {
+        GroupUsersReq {
+            tenable: self,
+            group_id,
+        }
+    }
+
+    #[inline]
+    fn group_add_user(&self, group_id: u64, user_id: u64) -> GroupAddUserReq<'_> {
+        GroupAddUserReq {
+            tenable: self,
+            group_id,
+            user_id,
+        }
+    }
+
+    #[inline]
+    fn group_remove_user(&self, group_id: u64, user_id: u64) -> GroupRemoveUserReq<'_> {
+        GroupRemoveUserReq {
+            tenable: self,
+            group_id,
+            user_id,
+        }
+    }
+}
@@ -1,5 +1,31 @@
 //! Tenable API Endpoints grouped by Type
 
+mod access_groups;
+mod agents;
 mod assets;
+mod credentials;
+mod editor;
+mod files;
+mod groups;
+mod networks;
+mod policies;
+mod scanners;
+mod scans;
+mod tags;
+mod users;
+mod vulns;
 
+pub use access_groups::*;
+pub use agents::*;
 pub use assets::*;
+pub use credentials::*;
+pub use editor::*;
+pub use files::*;
+pub use groups::*;
+pub use networks::*;
+pub use policies::*;
+pub use scanners::*;
+pub use scans::*;
+pub use tags::*;
+pub use users::*;
+pub use vulns::*;
@@ -0,0 +1,376 @@
+use crate::{
+    types::{
+        NetworkAssetsNotSeenReq, NetworkAssignableScannersReq, NetworkByIdReq, NetworkCreateReq,
+        NetworkDef, NetworkDeleteReq, NetworkScannerAssignReq, NetworkScannersReq,
+        NetworkUpdateReq, NetworksReq,
+    },
+    Tenable,
+};
+use std::borrow::Cow;
+
+/// Provides methods for the `Network` Type.
+pub trait NetworkReq {
+    /// Lists the networks. Set `filters`, `limit`, `offset`, `sort` or
+    /// `include_deleted` on the returned request to narrow down the networks.
+    ///
+    /// # Permission
+    ///
+    /// Requires ADMINISTRATOR [64] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::NetworkReq, types::{Networks, NetworksReq, QueryFilter}, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = NetworksReq {
+    ///     filters: Some(vec![QueryFilter {
+    ///         field: "name".into(),
+    ///         operator: "eq".into(),
+    ///         value: "Data center".into(),
+    ///     }]),
+    ///     ..tenable.networks()
+    /// };
+    /// let _networks: Networks = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to list networks");
+    /// ```
+    fn networks(&self) -> NetworksReq<'_>;
+
+    /// Creates a network.
+    ///
+    /// # Permission
+    ///
+    /// Requires ADMINISTRATOR [64] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::NetworkReq, types::{Network, NetworkDef}, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.network_create(NetworkDef {
+    ///     name: "Data center".into(),
+    ///     assets_ttl_days: Some(90),
+    ///     ..NetworkDef::default()
+    /// });
+    /// let _network: Network = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to create network");
+    /// ```
+    fn network_create<'a, D: Into<Cow<'a, NetworkDef>>>(
+        &'a self,
+        network_def: D,
+    ) -> NetworkCreateReq<'a>;
+
+    /// Returns the specified network.
+    ///
+    /// # Permission
+    ///
+    /// Requires ADMINISTRATOR [64] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::NetworkReq, types::Network, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.network_by_id("00000000-0000-0000-0000-000000000000");
+    /// let _network: Network = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to fetch network")
+    /// .expect("Network not found");
+    /// ```
+    fn network_by_id<'a, I: Into<Cow<'a, str>>>(&'a self, network_id: I) -> NetworkByIdReq<'a>;
+
+    /// Updates the specified network. The `Default` network cannot be updated.
+    ///
+    /// # Permission
+    ///
+    /// Requires ADMINISTRATOR [64] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::NetworkReq, types::{Network, NetworkDef}, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.network_update(
+    ///     "00000000-0000-0000-0000-000000000000",
+    ///     NetworkDef {
+    ///         name: "Data center".into(),
+    ///         description: Some("Assets in the data center".into()),
+    ///         ..NetworkDef::default()
+    ///     },
+    /// );
+    /// let _network: Network = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to update network")
+    /// .expect("Network not found");
+    /// ```
+    fn network_update<'a, I: Into<Cow<'a, str>>, D: Into<Cow<'a, NetworkDef>>>(
+        &'a self,
+        network_id: I,
+        network_def: D,
+    ) -> NetworkUpdateReq<'a>;
+
+    /// Deletes the specified network. Its scanners and assets are moved to
+    /// the `Default` network.
+    ///
+    /// # Permission
+    ///
+    /// Requires ADMINISTRATOR [64] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::NetworkReq, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.network_delete("00000000-0000-0000-0000-000000000000");
+    /// Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to delete network")
+    /// .expect("Network not found");
+    /// ```
+    fn network_delete<'a, I: Into<Cow<'a, str>>>(&'a self, network_id: I) -> NetworkDeleteReq<'a>;
+
+    /// Returns the number of assets of the specified network which have not
+    /// been seen in the given number of days.
+    ///
+    /// # Permission
+    ///
+    /// Requires ADMINISTRATOR [64] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::NetworkReq, types::NetworkAssetsNotSeen, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.network_assets_not_seen("00000000-0000-0000-0000-000000000000", 30);
+    /// let _count: NetworkAssetsNotSeen = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to count assets")
+    /// .expect("Network not found");
+    /// ```
+    fn network_assets_not_seen<'a, I: Into<Cow<'a, str>>>(
+        &'a self,
+        network_id: I,
+        days: u32,
+    ) -> NetworkAssetsNotSeenReq<'a>;
+
+    /// Assigns a scanner or scanner group to the specified network. Assets
+    /// found by the scanner are added to the network.
+    ///
+    /// # Permission
+    ///
+    /// Requires ADMINISTRATOR [64] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::NetworkReq, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.network_scanner_assign(
+    ///     "00000000-0000-0000-0000-000000000000",
+    ///     "00000000-0000-0000-0000-000000000000",
+    /// );
+    /// Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to assign scanner")
+    /// .expect("Network or scanner not found");
+    /// ```
+    fn network_scanner_assign<'a, I: Into<Cow<'a, str>>, S: Into<Cow<'a, str>>>(
+        &'a self,
+        network_id: I,
+        scanner_uuid: S,
+    ) -> NetworkScannerAssignReq<'a>;
+
+    /// Lists the scanners and scanner groups assigned to the specified network.
+    ///
+    /// # Permission
+    ///
+    /// Requires ADMINISTRATOR [64] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::NetworkReq, types::NetworkScanners, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.network_scanners("00000000-0000-0000-0000-000000000000");
+    /// let _scanners: NetworkScanners = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to list scanners")
+    /// .expect("Network not found");
+    /// ```
+    fn network_scanners<'a, I: Into<Cow<'a, str>>>(
+        &'a self,
+        network_id: I,
+    ) -> NetworkScannersReq<'a>;
+
+    /// Lists the scanners and scanner groups which can be assigned to the
+    /// specified network.
+    ///
+    /// # Permission
+    ///
+    /// Requires ADMINISTRATOR [64] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::NetworkReq, types::NetworkScanners, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.network_assignable_scanners("00000000-0000-0000-0000-000000000000");
+    /// let _scanners: NetworkScanners = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to list scanners")
+    /// .expect("Network not found");
+    /// ```
+    fn network_assignable_scanners<'a, I: Into<Cow<'a, str>>>(
+        &'a self,
+        network_id: I,
+    ) -> NetworkAssignableScannersReq<'a>;
+}
+
+impl NetworkReq for Tenable<'_> {
+    #[inline]
+    fn networks(&self) -> NetworksReq<'_> {
+        NetworksReq {
+            tenable: self,
+            filters: None,
+            filter_type: None,
+            limit: None,
+            offset: None,
+            sort: None,
+            include_deleted: None,
+        }
+    }
+
+    #[inline]
+    fn network_create<'a, D: Into<Cow<'a, NetworkDef>>>(
+        &'a self,
+        network_def: D,
+    ) -> NetworkCreateReq<'a> {
+        NetworkCreateReq {
+            tenable: self,
+            network_def: network_def.into(),
+        }
+    }
+
+    #[inline]
+    fn network_by_id<'a, I: Into<Cow<'a, str>>>(&'a self, network_id: I) -> NetworkByIdReq<'a> {
+        NetworkByIdReq {
+            tenable: self,
+            network_id: network_id.into(),
+        }
+    }
+
+    #[inline]
+    fn network_update<'a, I: Into<Cow<'a, str>>, D: Into<Cow<'a, NetworkDef>>>(
+        &'a self,
+        network_id: I,
+        network_def: D,
+    ) -> NetworkUpdateReq<'a> {
+        NetworkUpdateReq {
+            tenable: self,
+            network_id: network_id.into(),
+            network_def: network_def.into(),
+        }
+    }
+
+    #[inline]
+    fn network_delete<'a, I: Into<Cow<'a, str>>>(&'a self, network_id: I) -> NetworkDeleteReq<'a> {
+        NetworkDeleteReq {
+            tenable: self,
+            network_id: network_id.into(),
+        }
+    }
+
+    #[inline]
+    fn network_assets_not_seen<'a, I: Into<Cow<'a, str>>>(
+        &'a self,
+        network_id: I,
+        days: u32,
+    ) -> NetworkAssetsNotSeenReq<'a> {
+        NetworkAssetsNotSeenReq {
+            tenable: self,
+            network_id: network_id.into(),
+            days,
+        }
+    }
+
+    #[inline]
+    fn network_scanner_assign<'a, I: Into<Cow<'a, str>>, S: Into<Cow<'a, str>>>(
+        &'a self,
+        network_id: I,
+        scanner_uuid: S,
+    ) -> NetworkScannerAssignReq<'a> {
+        NetworkScannerAssignReq {
+            tenable: self,
+            network_id: network_id.into(),
+            scanner_uuid: scanner_uuid.into(),
+        }
+    }
+
+    #[inline]
+    fn network_scanners<'a, I: Into<Cow<'a, str>>>(
+        &'a self,
+        network_id: I,
+    ) -> NetworkScannersReq<'a> {
+        NetworkScannersReq {
+            tenable: self,
+            network_id: network_id.into(),
+        }
+    }
+
+    #[inline]
+    fn network_assignable_scanners<'a, I: Into<Cow<'a, str>>>(
+        &'a self,
+        network_id: I,
+    ) -> NetworkAssignableScannersReq<'a> {
+        NetworkAssignableScannersReq {
+            tenable: self,
+            network_id: network_id.into(),
+        }
+    }
+}
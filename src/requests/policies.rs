@@ -0,0 +1,304 @@
+use crate::{
+    types::{
+        PoliciesReq, PolicyByIdReq, PolicyCopyReq, PolicyCreateReq, PolicyDef, PolicyDeleteReq,
+        PolicyExportReq, PolicyImportReq, PolicyUpdateReq,
+    },
+    Tenable,
+};
+use std::borrow::Cow;
+
+/// Provides methods for the `Policy` Type.
+pub trait PolicyReq {
+    /// Lists the policies the current user can view.
+    ///
+    /// # Permission
+    ///
+    /// Requires STANDARD [32] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::PolicyReq, types::Policies, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.policies();
+    /// let _policies: Policies = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to list policies");
+    /// ```
+    fn policies(&self) -> PoliciesReq<'_>;
+
+    /// Creates a policy based on a template. Settings which are not specified use the
+    /// default of the template as returned by `EditorReq::editor_template_by_uuid`.
+    ///
+    /// # Permission
+    ///
+    /// Requires STANDARD [32] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::PolicyReq, types::{EditorValue, PolicyCreated, PolicyDef}, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.policy_create(PolicyDef {
+    ///     uuid: "731a8e52-3ea6-a291-ec0a-d2ff0619c19d7bd788d6be818b65".into(),
+    ///     settings: vec![("name".into(), EditorValue::Text("Web servers".into()))]
+    ///         .into_iter()
+    ///         .collect(),
+    /// });
+    /// let _policy: PolicyCreated = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to create policy");
+    /// ```
+    fn policy_create<'a, D: Into<Cow<'a, PolicyDef>>>(
+        &'a self,
+        policy_def: D,
+    ) -> PolicyCreateReq<'a>;
+
+    /// Returns the template and settings of the specified policy.
+    ///
+    /// # Permission
+    ///
+    /// Requires STANDARD [32] user permissions and CAN VIEW [16] policy permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::PolicyReq, types::PolicyDetails, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.policy_by_id(1);
+    /// let _policy: PolicyDetails = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to fetch policy")
+    /// .expect("Policy not found");
+    /// ```
+    fn policy_by_id(&self, policy_id: u64) -> PolicyByIdReq<'_>;
+
+    /// Changes the settings of the specified policy.
+    ///
+    /// # Permission
+    ///
+    /// Requires STANDARD [32] user permissions and CAN EDIT [64] policy permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::PolicyReq, types::{EditorValue, PolicyDef}, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.policy_update(
+    ///     1,
+    ///     PolicyDef {
+    ///         uuid: "731a8e52-3ea6-a291-ec0a-d2ff0619c19d7bd788d6be818b65".into(),
+    ///         settings: vec![("name".into(), EditorValue::Text("Web servers".into()))]
+    ///             .into_iter()
+    ///             .collect(),
+    ///     },
+    /// );
+    /// Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to update policy")
+    /// .expect("Policy not found");
+    /// ```
+    fn policy_update<'a, D: Into<Cow<'a, PolicyDef>>>(
+        &'a self,
+        policy_id: u64,
+        policy_def: D,
+    ) -> PolicyUpdateReq<'a>;
+
+    /// Deletes the specified policy.
+    ///
+    /// # Permission
+    ///
+    /// Requires STANDARD [32] user permissions and CAN EDIT [64] policy permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::PolicyReq, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.policy_delete(1);
+    /// Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to delete policy")
+    /// .expect("Policy not found");
+    /// ```
+    fn policy_delete(&self, policy_id: u64) -> PolicyDeleteReq<'_>;
+
+    /// Copies the specified policy. The copy is owned by the current user.
+    ///
+    /// # Permission
+    ///
+    /// Requires STANDARD [32] user permissions and CAN VIEW [16] policy permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::PolicyReq, types::PolicyCopy, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.policy_copy(1);
+    /// let _copy: PolicyCopy = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to copy policy")
+    /// .expect("Policy not found");
+    /// ```
+    fn policy_copy(&self, policy_id: u64) -> PolicyCopyReq<'_>;
+
+    /// Imports a policy from a `.nessus` file which has been uploaded with
+    /// `FileReq::file_upload` before.
+    ///
+    /// # Permission
+    ///
+    /// Requires STANDARD [32] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{
+    ///     requests::{FileReq, PolicyReq},
+    ///     types::Policy,
+    ///     Error, Response, Tenable,
+    /// };
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let content = std::fs::read("policy.nessus").expect("Unable to read policy");
+    /// let req = tenable.file_upload("policy.nessus", content);
+    /// let file = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to upload policy");
+    /// let req = tenable.policy_import(file.fileuploaded);
+    /// let _policy: Policy = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to import policy");
+    /// ```
+    fn policy_import<'a, F: Into<Cow<'a, str>>>(&'a self, file: F) -> PolicyImportReq<'a>;
+
+    /// Exports the specified policy as `.nessus` file. The file is returned as is,
+    /// without parsing it.
+    ///
+    /// # Permission
+    ///
+    /// Requires STANDARD [32] user permissions and CAN VIEW [16] policy permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::PolicyReq, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.policy_export(1);
+    /// let _policy = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to export policy")
+    /// .expect("Policy not found");
+    /// ```
+    fn policy_export(&self, policy_id: u64) -> PolicyExportReq<'_>;
+}
+
+impl PolicyReq for Tenable<'_> {
+    #[inline]
+    fn policies(&self) -> PoliciesReq<'_> {
+        PoliciesReq { tenable: self }
+    }
+
+    #[inline]
+    fn policy_create<'a, D: Into<Cow<'a, PolicyDef>>>(
+        &'a self,
+        policy_def: D,
+    ) -> PolicyCreateReq<'a> {
+        PolicyCreateReq {
+            tenable: self,
+            policy_def: policy_def.into(),
+        }
+    }
+
+    #[inline]
+    fn policy_by_id(&self, policy_id: u64) -> PolicyByIdReq<'_> {
+        PolicyByIdReq {
+            tenable: self,
+            policy_id,
+        }
+    }
+
+    #[inline]
+    fn policy_update<'a, D: Into<Cow<'a, PolicyDef>>>(
+        &'a self,
+        policy_id: u64,
+        policy_def: D,
+    ) -> PolicyUpdateReq<'a> {
+        PolicyUpdateReq {
+            tenable: self,
+            policy_id,
+            policy_def: policy_def.into(),
+        }
+    }
+
+    #[inline]
+    fn policy_delete(&self, policy_id: u64) -> PolicyDeleteReq<'_> {
+        PolicyDeleteReq {
+            tenable: self,
+            policy_id,
+        }
+    }
+
+    #[inline]
+    fn policy_copy(&self, policy_id: u64) -> PolicyCopyReq<'_> {
+        PolicyCopyReq {
+            tenable: self,
+            policy_id,
+        }
+    }
+
+    #[inline]
+    fn policy_import<'a, F: Into<Cow<'a, str>>>(&'a self, file: F) -> PolicyImportReq<'a> {
+        PolicyImportReq {
+            tenable: self,
+            file: file.into(),
+        }
+    }
+
+    #[inline]
+    fn policy_export(&self, policy_id: u64) -> PolicyExportReq<'_> {
+        PolicyExportReq {
+            tenable: self,
+            policy_id,
+        }
+    }
+}
@@ -0,0 +1,548 @@
+use crate::{
+    types::{
+        ScannerAwsTargetsReq, ScannerByIdReq, ScannerDeleteReq, ScannerGroupAddScannerReq,
+        ScannerGroupByIdReq, ScannerGroupCreateReq, ScannerGroupDef, ScannerGroupDeleteReq,
+        ScannerGroupRemoveScannerReq, ScannerGroupScannersReq, ScannerGroupUpdateReq,
+        ScannerGroupsReq, ScannerLinkReq, ScannerScansReq, ScannerUpdateDef, ScannerUpdateReq,
+        ScannersReq,
+    },
+    Tenable,
+};
+use std::borrow::Cow;
+
+/// Provides methods for the `Scanner` Type.
+pub trait ScannerReq {
+    /// Lists the scanners of the container, including Tenable-managed cloud
+    /// scanners and linked on-prem scanners.
+    ///
+    /// # Permission
+    ///
+    /// Requires STANDARD [32] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::ScannerReq, types::Scanners, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.scanners();
+    /// let _scanners: Scanners = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to list scanners");
+    /// ```
+    fn scanners(&self) -> ScannersReq<'_>;
+
+    /// Returns the specified scanner.
+    ///
+    /// # Permission
+    ///
+    /// Requires STANDARD [32] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::ScannerReq, types::Scanner, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.scanner_by_id(1);
+    /// let _scanner: Scanner = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to fetch scanner")
+    /// .expect("Scanner not found");
+    /// ```
+    fn scanner_by_id(&self, scanner_id: u64) -> ScannerByIdReq<'_>;
+
+    /// Requests a plugin or software update of the specified scanner or changes
+    /// its registration code.
+    ///
+    /// # Permission
+    ///
+    /// Requires ADMINISTRATOR [64] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::ScannerReq, types::ScannerUpdateDef, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.scanner_update(
+    ///     1,
+    ///     ScannerUpdateDef {
+    ///         force_plugin_update: Some(true),
+    ///         ..ScannerUpdateDef::default()
+    ///     },
+    /// );
+    /// Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to update scanner")
+    /// .expect("Scanner not found");
+    /// ```
+    fn scanner_update<'a, D: Into<Cow<'a, ScannerUpdateDef>>>(
+        &'a self,
+        scanner_id: u64,
+        scanner_update_def: D,
+    ) -> ScannerUpdateReq<'a>;
+
+    /// Links or unlinks the specified scanner. Unlinked scanners stay registered
+    /// but do not receive scans until they are linked again.
+    ///
+    /// # Permission
+    ///
+    /// Requires ADMINISTRATOR [64] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::ScannerReq, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.scanner_link(1, true);
+    /// Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to link scanner")
+    /// .expect("Scanner not found");
+    /// ```
+    fn scanner_link(&self, scanner_id: u64, link: bool) -> ScannerLinkReq<'_>;
+
+    /// Removes the specified scanner from the container.
+    ///
+    /// # Permission
+    ///
+    /// Requires ADMINISTRATOR [64] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::ScannerReq, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.scanner_delete(1);
+    /// Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to delete scanner")
+    /// .expect("Scanner not found");
+    /// ```
+    fn scanner_delete(&self, scanner_id: u64) -> ScannerDeleteReq<'_>;
+
+    /// Lists the scans currently running on the specified scanner.
+    ///
+    /// # Permission
+    ///
+    /// Requires STANDARD [32] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::ScannerReq, types::ScannerScans, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.scanner_scans(1);
+    /// let _scans: ScannerScans = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to list scans")
+    /// .expect("Scanner not found");
+    /// ```
+    fn scanner_scans(&self, scanner_id: u64) -> ScannerScansReq<'_>;
+
+    /// Lists the AWS instances the specified AWS scanner can scan.
+    ///
+    /// # Permission
+    ///
+    /// Requires STANDARD [32] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::ScannerReq, types::ScannerAwsTargets, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.scanner_aws_targets(1);
+    /// let _targets: ScannerAwsTargets = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to list AWS targets")
+    /// .expect("Scanner not found");
+    /// ```
+    fn scanner_aws_targets(&self, scanner_id: u64) -> ScannerAwsTargetsReq<'_>;
+
+    /// Lists the scanner groups of the container.
+    ///
+    /// # Permission
+    ///
+    /// Requires STANDARD [32] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::ScannerReq, types::ScannerGroups, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.scanner_groups();
+    /// let _groups: ScannerGroups = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to list scanner groups");
+    /// ```
+    fn scanner_groups(&self) -> ScannerGroupsReq<'_>;
+
+    /// Creates a scanner group.
+    ///
+    /// # Permission
+    ///
+    /// Requires ADMINISTRATOR [64] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::ScannerReq, types::{ScannerGroup, ScannerGroupDef}, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.scanner_group_create(ScannerGroupDef {
+    ///     name: "Data center".into(),
+    ///     ..ScannerGroupDef::default()
+    /// });
+    /// let _group: ScannerGroup = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to create scanner group");
+    /// ```
+    fn scanner_group_create<'a, D: Into<Cow<'a, ScannerGroupDef>>>(
+        &'a self,
+        scanner_group_def: D,
+    ) -> ScannerGroupCreateReq<'a>;
+
+    /// Returns the specified scanner group.
+    ///
+    /// # Permission
+    ///
+    /// Requires STANDARD [32] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::ScannerReq, types::ScannerGroup, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.scanner_group_by_id(7);
+    /// let _group: ScannerGroup = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to fetch scanner group")
+    /// .expect("Scanner group not found");
+    /// ```
+    fn scanner_group_by_id(&self, group_id: u64) -> ScannerGroupByIdReq<'_>;
+
+    /// Renames the specified scanner group.
+    ///
+    /// # Permission
+    ///
+    /// Requires ADMINISTRATOR [64] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::ScannerReq, types::ScannerGroupDef, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.scanner_group_update(
+    ///     7,
+    ///     ScannerGroupDef {
+    ///         name: "Data center".into(),
+    ///         ..ScannerGroupDef::default()
+    ///     },
+    /// );
+    /// Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to update scanner group")
+    /// .expect("Scanner group not found");
+    /// ```
+    fn scanner_group_update<'a, D: Into<Cow<'a, ScannerGroupDef>>>(
+        &'a self,
+        group_id: u64,
+        scanner_group_def: D,
+    ) -> ScannerGroupUpdateReq<'a>;
+
+    /// Deletes the specified scanner group. Its scanners stay linked.
+    ///
+    /// # Permission
+    ///
+    /// Requires ADMINISTRATOR [64] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::ScannerReq, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.scanner_group_delete(7);
+    /// Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to delete scanner group")
+    /// .expect("Scanner group not found");
+    /// ```
+    fn scanner_group_delete(&self, group_id: u64) -> ScannerGroupDeleteReq<'_>;
+
+    /// Lists the scanners of the specified scanner group.
+    ///
+    /// # Permission
+    ///
+    /// Requires STANDARD [32] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::ScannerReq, types::Scanners, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.scanner_group_scanners(7);
+    /// let _scanners: Scanners = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to list scanners")
+    /// .expect("Scanner group not found");
+    /// ```
+    fn scanner_group_scanners(&self, group_id: u64) -> ScannerGroupScannersReq<'_>;
+
+    /// Adds the specified scanner to the specified scanner group.
+    ///
+    /// # Permission
+    ///
+    /// Requires ADMINISTRATOR [64] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::ScannerReq, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.scanner_group_add_scanner(7, 1);
+    /// Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to add scanner")
+    /// .expect("Scanner or scanner group not found");
+    /// ```
+    fn scanner_group_add_scanner(
+        &self,
+        group_id: u64,
+        scanner_id: u64,
+    ) -> ScannerGroupAddScannerReq<'_>;
+
+    /// Removes the specified scanner from the specified scanner group.
+    ///
+    /// # Permission
+    ///
+    /// Requires ADMINISTRATOR [64] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::ScannerReq, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.scanner_group_remove_scanner(7, 1);
+    /// Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to remove scanner")
+    /// .expect("Scanner or scanner group not found");
+    /// ```
+    fn scanner_group_remove_scanner(
+        &self,
+        group_id: u64,
+        scanner_id: u64,
+    ) -> ScannerGroupRemoveScannerReq<'_>;
+}
+
+impl ScannerReq for Tenable<'_> {
+    #[inline]
+    fn scanners(&self) -> ScannersReq<'_> {
+        ScannersReq { tenable: self }
+    }
+
+    #[inline]
+    fn scanner_by_id(&self, scanner_id: u64) -> ScannerByIdReq<'_> {
+        ScannerByIdReq {
+            tenable: self,
+            scanner_id,
+        }
+    }
+
+    #[inline]
+    fn scanner_update<'a, D: Into<Cow<'a, ScannerUpdateDef>>>(
+        &'a self,
+        scanner_id: u64,
+        scanner_update_def: D,
+    ) -> ScannerUpdateReq<'a> {
+        ScannerUpdateReq {
+            tenable: self,
+            scanner_id,
+            scanner_update_def: scanner_update_def.into(),
+        }
+    }
+
+    #[inline]
+    fn scanner_link(&self, scanner_id: u64, link: bool) -> ScannerLinkReq<'_> {
+        ScannerLinkReq {
+            tenable: self,
+            scanner_id,
+            link,
+        }
+    }
+
+    #[inline]
+    fn scanner_delete(&self, scanner_id: u64) -> ScannerDeleteReq<'_> {
+        ScannerDeleteReq {
+            tenable: self,
+            scanner_id,
+        }
+    }
+
+    #[inline]
+    fn scanner_scans(&self, scanner_id: u64) -> ScannerScansReq<'_> {
+        ScannerScansReq {
+            tenable: self,
+            scanner_id,
+        }
+    }
+
+    #[inline]
+    fn scanner_aws_targets(&self, scanner_id: u64) -> ScannerAwsTargetsReq<'_> {
+        ScannerAwsTargetsReq {
+            tenable: self,
+            scanner_id,
+        }
+    }
+
+    #[inline]
+    fn scanner_groups(&self) -> ScannerGroupsReq<'_> {
+        ScannerGroupsReq { tenable: self }
+    }
+
+    #[inline]
+    fn scanner_group_create<'a, D: Into<Cow<'a, ScannerGroupDef>>>(
+        &'a self,
+        scanner_group_def: D,
+    ) -> ScannerGroupCreateReq<'a> {
+        ScannerGroupCreateReq {
+            tenable: self,
+            scanner_group_def: scanner_group_def.into(),
+        }
+    }
+
+    #[inline]
+    fn scanner_group_by_id(&self, group_id: u64) -> ScannerGroupByIdReq<'_> {
+        ScannerGroupByIdReq {
+            tenable: self,
+            group_id,
+        }
+    }
+
+    #[inline]
+    fn scanner_group_update<'a, D: Into<Cow<'a, ScannerGroupDef>>>(
+        &'a self,
+        group_id: u64,
+        scanner_group_def: D,
+    ) -> ScannerGroupUpdateReq<'a> {
+        ScannerGroupUpdateReq {
+            tenable: self,
+            group_id,
+            scanner_group_def: scanner_group_def.into(),
+        }
+    }
+
+    #[inline]
+    fn scanner_group_delete(&self, group_id: u64) -> ScannerGroupDeleteReq<'_> {
+        ScannerGroupDeleteReq {
+            tenable: self,
+            group_id,
+        }
+    }
+
+    #[inline]
+    fn scanner_group_scanners(&self, group_id: u64) -> ScannerGroupScannersReq<'_> {
+        ScannerGroupScannersReq {
+            tenable: self,
+            group_id,
+        }
+    }
+
+    #[inline]
+    fn scanner_group_add_scanner(
+        &self,
+        group_id: u64,
+        scanner_id: u64,
+    ) -> ScannerGroupAddScannerReq<'_> {
+        ScannerGroupAddScannerReq {
+            tenable: self,
+            group_id,
+            scanner_id,
+        }
+    }
+
+    #[inline]
+    fn scanner_group_remove_scanner(
+        &self,
+        group_id: u64,
+        scanner_id: u64,
+    ) -> ScannerGroupRemoveScannerReq<'_> {
+        ScannerGroupRemoveScannerReq {
+            tenable: self,
+            group_id,
+            scanner_id,
+        }
+    }
+}
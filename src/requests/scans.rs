@@ -0,0 +1,680 @@
+use crate::{
+    types::{
+        ScanByIdReq, ScanCreateReq, ScanDef, ScanDeleteReq, ScanExportDef, ScanExportDownloadReq,
+        ScanExportReq, ScanExportStatusReq, ScanHistoryByIdReq, ScanHistoryReq, ScanHostDetailsReq,
+        ScanLatestStatusReq, ScanLaunchReq, ScanPauseReq, ScanPluginOutputReq, ScanResumeReq,
+        ScanStopReq, ScanUpdateReq, ScansReq,
+    },
+    Tenable,
+};
+use std::borrow::Cow;
+
+/// Provides methods for the `Scan` Type.
+pub trait ScanReq {
+    /// Lists the scans and folders the current user has access to. Set
+    /// `folder_id` or `last_modification_date` on the returned request to only
+    /// list the scans of a folder or the scans changed since the given time.
+    ///
+    /// # Permission
+    ///
+    /// Requires BASIC [16] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::ScanReq, types::Scans, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.scans();
+    /// let _scans: Scans = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to list scans");
+    /// ```
+    fn scans(&self) -> ScansReq<'_>;
+
+    /// Creates a scan based on a template. The scan is not launched unless its
+    /// settings schedule it, use `scan_launch` to start it right away.
+    ///
+    /// # Permission
+    ///
+    /// Requires STANDARD [32] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::ScanReq, types::{ScanCreated, ScanDef, ScanSettings}, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.scan_create(ScanDef {
+    ///     uuid: "00000000-0000-0000-0000-000000000000".into(),
+    ///     settings: ScanSettings {
+    ///         name: "Deployment".into(),
+    ///         text_targets: Some("192.0.2.0/24".into()),
+    ///         ..ScanSettings::default()
+    ///     },
+    /// });
+    /// let _scan: ScanCreated = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to create scan");
+    /// ```
+    fn scan_create<'a, I: Into<Cow<'a, ScanDef>>>(&'a self, scan_def: I) -> ScanCreateReq<'a>;
+
+    /// Returns the results of the latest run of the specified scan.
+    ///
+    /// # Permission
+    ///
+    /// Requires BASIC [16] user permissions and CAN VIEW [16] scan permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::ScanReq, types::ScanDetails, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.scan_by_id("1");
+    /// let _scan: ScanDetails = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to fetch scan")
+    /// .expect("Scan not found");
+    /// ```
+    fn scan_by_id<'a, I: Into<Cow<'a, str>>>(&'a self, scan_id: I) -> ScanByIdReq<'a>;
+
+    /// Replaces the settings of the specified scan. Settings which are omitted
+    /// are reset to the defaults of the template.
+    ///
+    /// # Permission
+    ///
+    /// Requires STANDARD [32] user permissions and CAN CONFIGURE [64] scan permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::ScanReq, types::{ScanConfig, ScanDef, ScanSettings}, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.scan_update(
+    ///     "1",
+    ///     ScanDef {
+    ///         uuid: "00000000-0000-0000-0000-000000000000".into(),
+    ///         settings: ScanSettings {
+    ///             name: "Deployment".into(),
+    ///             ..ScanSettings::default()
+    ///         },
+    ///     },
+    /// );
+    /// let _scan: ScanConfig = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to update scan")
+    /// .expect("Scan not found");
+    /// ```
+    fn scan_update<'a, I: Into<Cow<'a, str>>, D: Into<Cow<'a, ScanDef>>>(
+        &'a self,
+        scan_id: I,
+        scan_def: D,
+    ) -> ScanUpdateReq<'a>;
+
+    /// Deletes the specified scan together with all its results. Running scans
+    /// have to be stopped before they can be deleted.
+    ///
+    /// # Permission
+    ///
+    /// Requires STANDARD [32] user permissions and CAN CONFIGURE [64] scan permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::ScanReq, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.scan_delete("1");
+    /// Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to delete scan")
+    /// .expect("Scan not found");
+    /// ```
+    fn scan_delete<'a, I: Into<Cow<'a, str>>>(&'a self, scan_id: I) -> ScanDeleteReq<'a>;
+
+    /// Launches the specified scan. Set `alt_targets` on the returned request
+    /// to scan other targets than the configured ones for this run only. Fails
+    /// with `Error::Conflict` if the scan is already running.
+    ///
+    /// # Permission
+    ///
+    /// Requires STANDARD [32] user permissions and CAN EXECUTE [32] scan permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::ScanReq, types::ScanLaunched, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.scan_launch("1");
+    /// let _launched: ScanLaunched = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to launch scan")
+    /// .expect("Scan not found");
+    /// ```
+    fn scan_launch<'a, I: Into<Cow<'a, str>>>(&'a self, scan_id: I) -> ScanLaunchReq<'a>;
+
+    /// Pauses the specified running scan.
+    ///
+    /// # Permission
+    ///
+    /// Requires STANDARD [32] user permissions and CAN EXECUTE [32] scan permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::ScanReq, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.scan_pause("1");
+    /// Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to pause scan")
+    /// .expect("Scan not found");
+    /// ```
+    fn scan_pause<'a, I: Into<Cow<'a, str>>>(&'a self, scan_id: I) -> ScanPauseReq<'a>;
+
+    /// Resumes the specified paused scan.
+    ///
+    /// # Permission
+    ///
+    /// Requires STANDARD [32] user permissions and CAN EXECUTE [32] scan permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::ScanReq, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.scan_resume("1");
+    /// Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to resume scan")
+    /// .expect("Scan not found");
+    /// ```
+    fn scan_resume<'a, I: Into<Cow<'a, str>>>(&'a self, scan_id: I) -> ScanResumeReq<'a>;
+
+    /// Stops the specified running scan. Results gathered so far are kept.
+    ///
+    /// # Permission
+    ///
+    /// Requires STANDARD [32] user permissions and CAN EXECUTE [32] scan permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::ScanReq, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.scan_stop("1");
+    /// Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to stop scan")
+    /// .expect("Scan not found");
+    /// ```
+    fn scan_stop<'a, I: Into<Cow<'a, str>>>(&'a self, scan_id: I) -> ScanStopReq<'a>;
+
+    /// Returns the status of the latest run of the specified scan. Poll it after
+    /// `scan_launch` to find out when the results are available.
+    ///
+    /// # Permission
+    ///
+    /// Requires BASIC [16] user permissions and CAN VIEW [16] scan permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::ScanReq, types::ScanLatestStatus, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.scan_latest_status("1");
+    /// let _status: ScanLatestStatus = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to fetch scan status")
+    /// .expect("Scan not found");
+    /// ```
+    fn scan_latest_status<'a, I: Into<Cow<'a, str>>>(
+        &'a self,
+        scan_id: I,
+    ) -> ScanLatestStatusReq<'a>;
+
+    /// Exports the results of the latest run of the specified scan. Set
+    /// `history_id` or `history_uuid` on the returned request to export an
+    /// earlier run instead. The file is generated asynchronously, use
+    /// `scan_export_status` to find out when it is ready for download.
+    ///
+    /// # Permission
+    ///
+    /// Requires BASIC [16] user permissions and CAN VIEW [16] scan permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::ScanReq, types::{ScanExportChapter, ScanExportDef, ScanExportFile, ScanExportFormat}, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.scan_export(
+    ///     "1",
+    ///     ScanExportDef {
+    ///         format: ScanExportFormat::Pdf,
+    ///         chapters: Some(vec![ScanExportChapter::VulnHostsSummary]),
+    ///     },
+    /// );
+    /// let _file: ScanExportFile = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to export scan")
+    /// .expect("Scan not found");
+    /// ```
+    fn scan_export<'a, I: Into<Cow<'a, str>>, D: Into<Cow<'a, ScanExportDef>>>(
+        &'a self,
+        scan_id: I,
+        scan_export_def: D,
+    ) -> ScanExportReq<'a>;
+
+    /// Returns the status of a file created by `scan_export`.
+    ///
+    /// # Permission
+    ///
+    /// Requires BASIC [16] user permissions and CAN VIEW [16] scan permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::ScanReq, types::ScanExportStatus, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.scan_export_status("1", "1036548617");
+    /// let _status: ScanExportStatus = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to fetch export status")
+    /// .expect("Export not found");
+    /// ```
+    fn scan_export_status<'a, I: Into<Cow<'a, str>>, F: Into<Cow<'a, str>>>(
+        &'a self,
+        scan_id: I,
+        file_id: F,
+    ) -> ScanExportStatusReq<'a>;
+
+    /// Downloads a file created by `scan_export` once its status is `ready`.
+    /// The file is returned as is, without parsing it.
+    ///
+    /// # Permission
+    ///
+    /// Requires BASIC [16] user permissions and CAN VIEW [16] scan permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::ScanReq, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.scan_export_download("1", "1036548617");
+    /// let report = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to download export")
+    /// .expect("Export not found");
+    /// std::fs::write("report.pdf", report).expect("Unable to store report");
+    /// ```
+    fn scan_export_download<'a, I: Into<Cow<'a, str>>, F: Into<Cow<'a, str>>>(
+        &'a self,
+        scan_id: I,
+        file_id: F,
+    ) -> ScanExportDownloadReq<'a>;
+
+    /// Lists the runs of the specified scan, most recent first. Set `limit`
+    /// and `offset` on the returned request to page through long histories.
+    ///
+    /// # Permission
+    ///
+    /// Requires BASIC [16] user permissions and CAN VIEW [16] scan permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::ScanReq, types::ScanHistory, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.scan_history("1");
+    /// let _history: ScanHistory = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to list scan history")
+    /// .expect("Scan not found");
+    /// ```
+    fn scan_history<'a, I: Into<Cow<'a, str>>>(&'a self, scan_id: I) -> ScanHistoryReq<'a>;
+
+    /// Returns the details of a single run of the specified scan.
+    ///
+    /// # Permission
+    ///
+    /// Requires BASIC [16] user permissions and CAN VIEW [16] scan permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::ScanReq, types::ScanRunDetails, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.scan_history_by_id("1", "00000000-0000-0000-0000-000000000000");
+    /// let _run: ScanRunDetails = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to fetch scan run")
+    /// .expect("Scan run not found");
+    /// ```
+    fn scan_history_by_id<'a, I: Into<Cow<'a, str>>, H: Into<Cow<'a, str>>>(
+        &'a self,
+        scan_id: I,
+        history_uuid: H,
+    ) -> ScanHistoryByIdReq<'a>;
+
+    /// Returns the results of the latest run of the specified scan for a
+    /// single host. Set `history_id` or `history_uuid` on the returned request
+    /// to fetch the results of an earlier run instead.
+    ///
+    /// # Permission
+    ///
+    /// Requires BASIC [16] user permissions and CAN VIEW [16] scan permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::ScanReq, types::ScanHostDetails, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.scan_host_details("1", 2);
+    /// let _host: ScanHostDetails = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to fetch host details")
+    /// .expect("Host not found");
+    /// ```
+    fn scan_host_details<'a, I: Into<Cow<'a, str>>>(
+        &'a self,
+        scan_id: I,
+        host_id: u64,
+    ) -> ScanHostDetailsReq<'a>;
+
+    /// Returns the output a plugin reported for a single host during the
+    /// latest run of the specified scan. Set `history_id` or `history_uuid` on
+    /// the returned request to fetch the output of an earlier run instead.
+    ///
+    /// # Permission
+    ///
+    /// Requires BASIC [16] user permissions and CAN VIEW [16] scan permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::ScanReq, types::ScanPluginOutput, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.scan_plugin_output("1", 2, 51192);
+    /// let _output: ScanPluginOutput = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to fetch plugin output")
+    /// .expect("Plugin output not found");
+    /// ```
+    fn scan_plugin_output<'a, I: Into<Cow<'a, str>>>(
+        &'a self,
+        scan_id: I,
+        host_id: u64,
+        plugin_id: u64,
+    ) -> ScanPluginOutputReq<'a>;
+}
+
+impl ScanReq for Tenable<'_> {
+    #[inline]
+    fn scans(&self) -> ScansReq<'_> {
+        ScansReq {
+            tenable: self,
+            folder_id: None,
+            last_modification_date: None,
+        }
+    }
+
+    #[inline]
+    fn scan_create<'a, I: Into<Cow<'a, ScanDef>>>(&'a self, scan_def: I) -> ScanCreateReq<'a> {
+        ScanCreateReq {
+            tenable: self,
+            scan_def: scan_def.into(),
+        }
+    }
+
+    #[inline]
+    fn scan_by_id<'a, I: Into<Cow<'a, str>>>(&'a self, scan_id: I) -> ScanByIdReq<'a> {
+        ScanByIdReq {
+            tenable: self,
+            scan_id: scan_id.into(),
+        }
+    }
+
+    #[inline]
+    fn scan_update<'a, I: Into<Cow<'a, str>>, D: Into<Cow<'a, ScanDef>>>(
+        &'a self,
+        scan_id: I,
+        scan_def: D,
+    ) -> ScanUpdateReq<'a> {
+        ScanUpdateReq {
+            tenable: self,
+            scan_id: scan_id.into(),
+            scan_def: scan_def.into(),
+        }
+    }
+
+    #[inline]
+    fn scan_delete<'a, I: Into<Cow<'a, str>>>(&'a self, scan_id: I) -> ScanDeleteReq<'a> {
+        ScanDeleteReq {
+            tenable: self,
+            scan_id: scan_id.into(),
+        }
+    }
+
+    #[inline]
+    fn scan_launch<'a, I: Into<Cow<'a, str>>>(&'a self, scan_id: I) -> ScanLaunchReq<'a> {
+        ScanLaunchReq {
+            tenable: self,
+            scan_id: scan_id.into(),
+            alt_targets: None,
+        }
+    }
+
+    #[inline]
+    fn scan_pause<'a, I: Into<Cow<'a, str>>>(&'a self, scan_id: I) -> ScanPauseReq<'a> {
+        ScanPauseReq {
+            tenable: self,
+            scan_id: scan_id.into(),
+        }
+    }
+
+    #[inline]
+    fn scan_resume<'a, I: Into<Cow<'a, str>>>(&'a self, scan_id: I) -> ScanResumeReq<'a> {
+        ScanResumeReq {
+            tenable: self,
+            scan_id: scan_id.into(),
+        }
+    }
+
+    #[inline]
+    fn scan_stop<'a, I: Into<Cow<'a, str>>>(&'a self, scan_id: I) -> ScanStopReq<'a> {
+        ScanStopReq {
+            tenable: self,
+            scan_id: scan_id.into(),
+        }
+    }
+
+    #[inline]
+    fn scan_latest_status<'a, I: Into<Cow<'a, str>>>(
+        &'a self,
+        scan_id: I,
+    ) -> ScanLatestStatusReq<'a> {
+        ScanLatestStatusReq {
+            tenable: self,
+            scan_id: scan_id.into(),
+        }
+    }
+
+    #[inline]
+    fn scan_export<'a, I: Into<Cow<'a, str>>, D: Into<Cow<'a, ScanExportDef>>>(
+        &'a self,
+        scan_id: I,
+        scan_export_def: D,
+    ) -> ScanExportReq<'a> {
+        ScanExportReq {
+            tenable: self,
+            scan_id: scan_id.into(),
+            scan_export_def: scan_export_def.into(),
+            history_id: None,
+            history_uuid: None,
+        }
+    }
+
+    #[inline]
+    fn scan_export_status<'a, I: Into<Cow<'a, str>>, F: Into<Cow<'a, str>>>(
+        &'a self,
+        scan_id: I,
+        file_id: F,
+    ) -> ScanExportStatusReq<'a> {
+        ScanExportStatusReq {
+            tenable: self,
+            scan_id: scan_id.into(),
+            file_id: file_id.into(),
+        }
+    }
+
+    #[inline]
+    fn scan_export_download<'a, I: Into<Cow<'a, str>>, F: Into<Cow<'a, str>>>(
+        &'a self,
+        scan_id: I,
+        file_id: F,
+    ) -> ScanExportDownloadReq<'a> {
+        ScanExportDownloadReq {
+            tenable: self,
+            scan_id: scan_id.into(),
+            file_id: file_id.into(),
+        }
+    }
+
+    #[inline]
+    fn scan_history<'a, I: Into<Cow<'a, str>>>(&'a self, scan_id: I) -> ScanHistoryReq<'a> {
+        ScanHistoryReq {
+            tenable: self,
+            scan_id: scan_id.into(),
+            limit: None,
+            offset: None,
+            exclude_rollover: None,
+        }
+    }
+
+    #[inline]
+    fn scan_history_by_id<'a, I: Into<Cow<'a, str>>, H: Into<Cow<'a, str>>>(
+        &'a self,
+        scan_id: I,
+        history_uuid: H,
+    ) -> ScanHistoryByIdReq<'a> {
+        ScanHistoryByIdReq {
+            tenable: self,
+            scan_id: scan_id.into(),
+            history_uuid: history_uuid.into(),
+        }
+    }
+
+    #[inline]
+    fn scan_host_details<'a, I: Into<Cow<'a, str>>>(
+        &'a self,
+        scan_id: I,
+        host_id: u64,
+    ) -> ScanHostDetailsReq<'a> {
+        ScanHostDetailsReq {
+            tenable: self,
+            scan_id: scan_id.into(),
+            host_id,
+            history_id: None,
+            history_uuid: None,
+        }
+    }
+
+    #[inline]
+    fn scan_plugin_output<'a, I: Into<Cow<'a, str>>>(
+        &'a self,
+        scan_id: I,
+        host_id: u64,
+        plugin_id: u64,
+    ) -> ScanPluginOutputReq<'a> {
+        ScanPluginOutputReq {
+            tenable: self,
+            scan_id: scan_id.into(),
+            host_id,
+            plugin_id,
+            history_id: None,
+            history_uuid: None,
+        }
+    }
+}
@@ -0,0 +1,463 @@
+use crate::{
+    types::{
+        TagAssignmentsDef, TagAssignmentsReq, TagCategoriesReq, TagCategoryByUuidReq,
+        TagCategoryCreateReq, TagCategoryDef, TagCategoryDeleteReq, TagCategoryUpdateReq,
+        TagValueByUuidReq, TagValueCreateReq, TagValueDef, TagValueDeleteReq, TagValueUpdateReq,
+        TagValuesReq,
+    },
+    Tenable,
+};
+use std::borrow::Cow;
+
+/// Provides methods for the `Tag` Type.
+pub trait TagReq {
+    /// Lists the tag categories. Set `limit`, `offset` or `sort` on the
+    /// returned request to page through the categories.
+    ///
+    /// # Permission
+    ///
+    /// Requires BASIC [16] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::TagReq, types::TagCategories, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.tag_categories();
+    /// let _categories: TagCategories = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to list tag categories");
+    /// ```
+    fn tag_categories(&self) -> TagCategoriesReq<'_>;
+
+    /// Creates a tag category.
+    ///
+    /// # Permission
+    ///
+    /// Requires ADMINISTRATOR [64] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::TagReq, types::{TagCategory, TagCategoryDef}, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.tag_category_create(TagCategoryDef {
+    ///     name: "Owner".into(),
+    ///     description: Some("Team responsible for the asset".into()),
+    /// });
+    /// let _category: TagCategory = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to create tag category");
+    /// ```
+    fn tag_category_create<'a, D: Into<Cow<'a, TagCategoryDef>>>(
+        &'a self,
+        tag_category_def: D,
+    ) -> TagCategoryCreateReq<'a>;
+
+    /// Returns the specified tag category.
+    ///
+    /// # Permission
+    ///
+    /// Requires BASIC [16] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::TagReq, types::TagCategory, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.tag_category_by_uuid("00000000-0000-0000-0000-000000000000");
+    /// let _category: TagCategory = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to fetch tag category")
+    /// .expect("Tag category not found");
+    /// ```
+    fn tag_category_by_uuid<'a, I: Into<Cow<'a, str>>>(
+        &'a self,
+        category_uuid: I,
+    ) -> TagCategoryByUuidReq<'a>;
+
+    /// Renames or describes the specified tag category.
+    ///
+    /// # Permission
+    ///
+    /// Requires ADMINISTRATOR [64] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::TagReq, types::{TagCategory, TagCategoryDef}, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.tag_category_update(
+    ///     "00000000-0000-0000-0000-000000000000",
+    ///     TagCategoryDef {
+    ///         name: "Owner".into(),
+    ///         ..TagCategoryDef::default()
+    ///     },
+    /// );
+    /// let _category: TagCategory = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to update tag category")
+    /// .expect("Tag category not found");
+    /// ```
+    fn tag_category_update<'a, I: Into<Cow<'a, str>>, D: Into<Cow<'a, TagCategoryDef>>>(
+        &'a self,
+        category_uuid: I,
+        tag_category_def: D,
+    ) -> TagCategoryUpdateReq<'a>;
+
+    /// Deletes the specified tag category together with all its values. The
+    /// values are removed from all assets.
+    ///
+    /// # Permission
+    ///
+    /// Requires ADMINISTRATOR [64] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::TagReq, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.tag_category_delete("00000000-0000-0000-0000-000000000000");
+    /// Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to delete tag category")
+    /// .expect("Tag category not found");
+    /// ```
+    fn tag_category_delete<'a, I: Into<Cow<'a, str>>>(
+        &'a self,
+        category_uuid: I,
+    ) -> TagCategoryDeleteReq<'a>;
+
+    /// Lists the tag values of all categories. Set `limit`, `offset` or
+    /// `sort` on the returned request to page through the values.
+    ///
+    /// # Permission
+    ///
+    /// Requires BASIC [16] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::TagReq, types::TagValues, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.tag_values();
+    /// let _values: TagValues = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to list tag values");
+    /// ```
+    fn tag_values(&self) -> TagValuesReq<'_>;
+
+    /// Creates a tag value. Set `filters` to create a dynamic tag, which
+    /// Tenable.io assigns to all assets matching the rules.
+    ///
+    /// # Permission
+    ///
+    /// Requires ADMINISTRATOR [64] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::TagReq, types::{TagRule, TagRules, TagValue, TagValueDef, TagValueFilters}, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.tag_value_create(TagValueDef {
+    ///     category_name: Some("Owner".into()),
+    ///     value: "Web".into(),
+    ///     filters: Some(TagValueFilters {
+    ///         asset: TagRules::And(vec![TagRule {
+    ///             field: "fqdn".into(),
+    ///             operator: "ends".into(),
+    ///             value: ".web.example.com".into(),
+    ///         }]),
+    ///     }),
+    ///     ..TagValueDef::default()
+    /// });
+    /// let _value: TagValue = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to create tag value");
+    /// ```
+    fn tag_value_create<'a, D: Into<Cow<'a, TagValueDef>>>(
+        &'a self,
+        tag_value_def: D,
+    ) -> TagValueCreateReq<'a>;
+
+    /// Returns the specified tag value.
+    ///
+    /// # Permission
+    ///
+    /// Requires BASIC [16] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::TagReq, types::TagValue, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.tag_value_by_uuid("00000000-0000-0000-0000-000000000000");
+    /// let _value: TagValue = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to fetch tag value")
+    /// .expect("Tag value not found");
+    /// ```
+    fn tag_value_by_uuid<'a, I: Into<Cow<'a, str>>>(
+        &'a self,
+        value_uuid: I,
+    ) -> TagValueByUuidReq<'a>;
+
+    /// Updates the value, description or rules of the specified tag value.
+    /// The category of a value cannot be changed.
+    ///
+    /// # Permission
+    ///
+    /// Requires ADMINISTRATOR [64] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::TagReq, types::{TagValue, TagValueDef}, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.tag_value_update(
+    ///     "00000000-0000-0000-0000-000000000000",
+    ///     TagValueDef {
+    ///         value: "Web".into(),
+    ///         description: Some("Web servers".into()),
+    ///         ..TagValueDef::default()
+    ///     },
+    /// );
+    /// let _value: TagValue = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to update tag value")
+    /// .expect("Tag value not found");
+    /// ```
+    fn tag_value_update<'a, I: Into<Cow<'a, str>>, D: Into<Cow<'a, TagValueDef>>>(
+        &'a self,
+        value_uuid: I,
+        tag_value_def: D,
+    ) -> TagValueUpdateReq<'a>;
+
+    /// Deletes the specified tag value and removes it from all assets.
+    ///
+    /// # Permission
+    ///
+    /// Requires ADMINISTRATOR [64] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::TagReq, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.tag_value_delete("00000000-0000-0000-0000-000000000000");
+    /// Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to delete tag value")
+    /// .expect("Tag value not found");
+    /// ```
+    fn tag_value_delete<'a, I: Into<Cow<'a, str>>>(
+        &'a self,
+        value_uuid: I,
+    ) -> TagValueDeleteReq<'a>;
+
+    /// Adds tags to or removes tags from assets in bulk. Tenable.io applies
+    /// the change asynchronously and returns the UUID of the job doing so.
+    ///
+    /// # Permission
+    ///
+    /// Requires ADMINISTRATOR [64] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::TagReq, types::{TagAssignmentAction, TagAssignmentJob, TagAssignmentsDef}, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.tag_assignments(TagAssignmentsDef {
+    ///     action: TagAssignmentAction::Add,
+    ///     assets: vec!["00000000-0000-0000-0000-000000000000".into()],
+    ///     tags: vec!["00000000-0000-0000-0000-000000000000".into()],
+    /// });
+    /// let _job: TagAssignmentJob = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to assign tags");
+    /// ```
+    fn tag_assignments<'a, D: Into<Cow<'a, TagAssignmentsDef>>>(
+        &'a self,
+        tag_assignments_def: D,
+    ) -> TagAssignmentsReq<'a>;
+}
+
+impl TagReq for Tenable<'_> {
+    #[inline]
+    fn tag_categories(&self) -> TagCategoriesReq<'_> {
+        TagCategoriesReq {
+            tenable: self,
+            limit: None,
+            offset: None,
+            sort: None,
+        }
+    }
+
+    #[inline]
+    fn tag_category_create<'a, D: Into<Cow<'a, TagCategoryDef>>>(
+        &'a self,
+        tag_category_def: D,
+    ) -> TagCategoryCreateReq<'a> {
+        TagCategoryCreateReq {
+            tenable: self,
+            tag_category_def: tag_category_def.into(),
+        }
+    }
+
+    #[inline]
+    fn tag_category_by_uuid<'a, I: Into<Cow<'a, str>>>(
+        &'a self,
+        category_uuid: I,
+    ) -> TagCategoryByUuidReq<'a> {
+        TagCategoryByUuidReq {
+            tenable: self,
+            category_uuid: category_uuid.into(),
+        }
+    }
+
+    #[inline]
+    fn tag_category_update<'a, I: Into<Cow<'a, str>>, D: Into<Cow<'a, TagCategoryDef>>>(
+        &'a self,
+        category_uuid: I,
+        tag_category_def: D,
+    ) -> TagCategoryUpdateReq<'a> {
+        TagCategoryUpdateReq {
+            tenable: self,
+            category_uuid: category_uuid.into(),
+            tag_category_def: tag_category_def.into(),
+        }
+    }
+
+    #[inline]
+    fn tag_category_delete<'a, I: Into<Cow<'a, str>>>(
+        &'a self,
+        category_uuid: I,
+    ) -> TagCategoryDeleteReq<'a> {
+        TagCategoryDeleteReq {
+            tenable: self,
+            category_uuid: category_uuid.into(),
+        }
+    }
+
+    #[inline]
+    fn tag_values(&self) -> TagValuesReq<'_> {
+        TagValuesReq {
+            tenable: self,
+            limit: None,
+            offset: None,
+            sort: None,
+        }
+    }
+
+    #[inline]
+    fn tag_value_create<'a, D: Into<Cow<'a, TagValueDef>>>(
+        &'a self,
+        tag_value_def: D,
+    ) -> TagValueCreateReq<'a> {
+        TagValueCreateReq {
+            tenable: self,
+            tag_value_def: tag_value_def.into(),
+        }
+    }
+
+    #[inline]
+    fn tag_value_by_uuid<'a, I: Into<Cow<'a, str>>>(
+        &'a self,
+        value_uuid: I,
+    ) -> TagValueByUuidReq<'a> {
+        TagValueByUuidReq {
+            tenable: self,
+            value_uuid: value_uuid.into(),
+        }
+    }
+
+    #[inline]
+    fn tag_value_update<'a, I: Into<Cow<'a, str>>, D: Into<Cow<'a, TagValueDef>>>(
+        &'a self,
+        value_uuid: I,
+        tag_value_def: D,
+    ) -> TagValueUpdateReq<'a> {
+        TagValueUpdateReq {
+            tenable: self,
+            value_uuid: value_uuid.into(),
+            tag_value_def: tag_value_def.into(),
+        }
+    }
+
+    #[inline]
+    fn tag_value_delete<'a, I: Into<Cow<'a, str>>>(
+        &'a self,
+        value_uuid: I,
+    ) -> TagValueDeleteReq<'a> {
+        TagValueDeleteReq {
+            tenable: self,
+            value_uuid: value_uuid.into(),
+        }
+    }
+
+    #[inline]
+    fn tag_assignments<'a, D: Into<Cow<'a, TagAssignmentsDef>>>(
+        &'a self,
+        tag_assignments_def: D,
+    ) -> TagAssignmentsReq<'a> {
+        TagAssignmentsReq {
+            tenable: self,
+            tag_assignments_def: tag_assignments_def.into(),
+        }
+    }
+}
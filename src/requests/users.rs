@@ -0,0 +1,288 @@
+use crate::{
+    types::{
+        UserAuthorizationsReq, UserByIdReq, UserCreateReq, UserDef, UserDeleteReq, UserEnableReq,
+        UserKeysReq, UserUpdateDef, UserUpdateReq, UsersReq,
+    },
+    Tenable,
+};
+use std::borrow::Cow;
+
+/// Provides methods for the `User` Type.
+pub trait UserReq {
+    /// Lists the users of the container.
+    ///
+    /// # Permission
+    ///
+    /// Requires BASIC [16] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::UserReq, types::Users, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.users();
+    /// let _users: Users = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to list users");
+    /// ```
+    fn users(&self) -> UsersReq<'_>;
+
+    /// Creates a user.
+    ///
+    /// # Permission
+    ///
+    /// Requires ADMINISTRATOR [64] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::UserReq, types::{User, UserDef}, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.user_create(UserDef {
+    ///     username: "jane.doe@example.com".into(),
+    ///     password: "correct horse battery staple".into(),
+    ///     permissions: 32,
+    ///     name: Some("Jane Doe".into()),
+    ///     email: Some("jane.doe@example.com".into()),
+    ///     user_type: None,
+    /// });
+    /// let _user: User = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to create user");
+    /// ```
+    fn user_create<'a, D: Into<Cow<'a, UserDef>>>(&'a self, user_def: D) -> UserCreateReq<'a>;
+
+    /// Returns the specified user.
+    ///
+    /// # Permission
+    ///
+    /// Requires BASIC [16] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::UserReq, types::User, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.user_by_id(1);
+    /// let _user: User = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to fetch user")
+    /// .expect("User not found");
+    /// ```
+    fn user_by_id(&self, user_id: u64) -> UserByIdReq<'_>;
+
+    /// Changes the role, name, email address or state of the specified user.
+    ///
+    /// # Permission
+    ///
+    /// Requires ADMINISTRATOR [64] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::UserReq, types::{User, UserUpdateDef}, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.user_update(
+    ///     1,
+    ///     UserUpdateDef {
+    ///         permissions: Some(64),
+    ///         ..UserUpdateDef::default()
+    ///     },
+    /// );
+    /// let _user: User = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to update user")
+    /// .expect("User not found");
+    /// ```
+    fn user_update<'a, D: Into<Cow<'a, UserUpdateDef>>>(
+        &'a self,
+        user_id: u64,
+        user_update_def: D,
+    ) -> UserUpdateReq<'a>;
+
+    /// Deletes the specified user.
+    ///
+    /// # Permission
+    ///
+    /// Requires ADMINISTRATOR [64] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::UserReq, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.user_delete(1);
+    /// Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to delete user")
+    /// .expect("User not found");
+    /// ```
+    fn user_delete(&self, user_id: u64) -> UserDeleteReq<'_>;
+
+    /// Enables or disables the specified user. Disabled users cannot log in or use their API keys.
+    ///
+    /// # Permission
+    ///
+    /// Requires ADMINISTRATOR [64] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::UserReq, types::User, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.user_enable(1, false);
+    /// let _user: User = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to disable user")
+    /// .expect("User not found");
+    /// ```
+    fn user_enable(&self, user_id: u64, enabled: bool) -> UserEnableReq<'_>;
+
+    /// Generates new API keys for the specified user. The previous keys stop working
+    /// immediately. Use `Tenable::rotate_keys` to get a client using the new keys.
+    ///
+    /// # Permission
+    ///
+    /// Requires ADMINISTRATOR [64] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::UserReq, types::UserKeys, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.user_keys(1);
+    /// let _keys: UserKeys = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to generate keys")
+    /// .expect("User not found");
+    /// ```
+    fn user_keys(&self, user_id: u64) -> UserKeysReq<'_>;
+
+    /// Returns the ways the specified user is allowed to authenticate.
+    ///
+    /// # Permission
+    ///
+    /// Requires ADMINISTRATOR [64] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::UserReq, types::UserAuthorizations, Error, Response, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.user_authorizations(1);
+    /// let _authorizations: UserAuthorizations = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to fetch authorizations")
+    /// .expect("User not found");
+    /// ```
+    fn user_authorizations(&self, user_id: u64) -> UserAuthorizationsReq<'_>;
+}
+
+impl UserReq for Tenable<'_> {
+    #[inline]
+    fn users(&self) -> UsersReq<'_> {
+        UsersReq { tenable: self }
+    }
+
+    #[inline]
+    fn user_create<'a, D: Into<Cow<'a, UserDef>>>(&'a self, user_def: D) -> UserCreateReq<'a> {
+        UserCreateReq {
+            tenable: self,
+            user_def: user_def.into(),
+        }
+    }
+
+    #[inline]
+    fn user_by_id(&self, user_id: u64) -> UserByIdReq<'_> {
+        UserByIdReq {
+            tenable: self,
+            user_id,
+        }
+    }
+
+    #[inline]
+    fn user_update<'a, D: Into<Cow<'a, UserUpdateDef>>>(
+        &'a self,
+        user_id: u64,
+        user_update_def: D,
+    ) -> UserUpdateReq<'a> {
+        UserUpdateReq {
+            tenable: self,
+            user_id,
+            user_update_def: user_update_def.into(),
+        }
+    }
+
+    #[inline]
+    fn user_delete(&self, user_id: u64) -> UserDeleteReq<'_> {
+        UserDeleteReq {
+            tenable: self,
+            user_id,
+        }
+    }
+
+    #[inline]
+    fn user_enable(&self, user_id: u64, enabled: bool) -> UserEnableReq<'_> {
+        UserEnableReq {
+            tenable: self,
+            user_id,
+            enabled,
+        }
+    }
+
+    #[inline]
+    fn user_keys(&self, user_id: u64) -> UserKeysReq<'_> {
+        UserKeysReq {
+            tenable: self,
+            user_id,
+        }
+    }
+
+    #[inline]
+    fn user_authorizations(&self, user_id: u64) -> UserAuthorizationsReq<'_> {
+        UserAuthorizationsReq {
+            tenable: self,
+            user_id,
+        }
+    }
+}
@@ -0,0 +1,177 @@
+use crate::{
+    types::{
+        VulnsExportCancelReq, VulnsExportChunkReq, VulnsExportDef, VulnsExportReq,
+        VulnsExportStatusReq,
+    },
+    Tenable,
+};
+use std::borrow::Cow;
+
+/// Provides methods for the `Vulnerability` Type.
+pub trait VulnReq {
+    /// Exports vulnerabilities that match the request criteria. The export is
+    /// processed asynchronously by Tenable.io and split into chunks. Use
+    /// `vulns_export_status` to find out which chunks are available and
+    /// `vulns_export_chunk` to download them.
+    ///
+    /// # Permission
+    ///
+    /// Requires BASIC [16] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::VulnReq, Error, Response, Tenable, types::{ExportUuid, VulnsExportDef}};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.vulns_export(VulnsExportDef {
+    ///     num_assets: Some(500),
+    ///     ..VulnsExportDef::default()
+    /// });
+    /// let _export: ExportUuid = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to export vulnerabilities");
+    /// ```
+    fn vulns_export<'a, I: Into<Cow<'a, VulnsExportDef>>>(
+        &'a self,
+        vulns_export_def: I,
+    ) -> VulnsExportReq<'a>;
+
+    /// Returns the status of a vulnerabilities export request. Chunks may not
+    /// complete in order, and the list of available chunks is only complete
+    /// once the status is `FINISHED`.
+    ///
+    /// # Permission
+    ///
+    /// Requires BASIC [16] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::VulnReq, Error, Response, Tenable, types::ExportStatus};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.vulns_export_status("00000000-0000-0000-0000-000000000000");
+    /// let _status: ExportStatus = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to fetch export status")
+    /// .expect("Export not found");
+    /// ```
+    fn vulns_export_status<'a, I: Into<Cow<'a, str>>>(
+        &'a self,
+        export_uuid: I,
+    ) -> VulnsExportStatusReq<'a>;
+
+    /// Downloads a chunk of findings from a vulnerabilities export. Chunks are
+    /// available for download for up to 24 hours after they have been created.
+    ///
+    /// # Permission
+    ///
+    /// Requires BASIC [16] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::VulnReq, Error, Response, Tenable, types::Finding};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.vulns_export_chunk("00000000-0000-0000-0000-000000000000", 1);
+    /// let _findings: Vec<Finding> = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to download chunk")
+    /// .expect("Chunk not found");
+    /// ```
+    fn vulns_export_chunk<'a, I: Into<Cow<'a, str>>>(
+        &'a self,
+        export_uuid: I,
+        chunk_id: u64,
+    ) -> VulnsExportChunkReq<'a>;
+
+    /// Cancels the specified vulnerabilities export. Chunks which have already
+    /// been processed remain available for download.
+    ///
+    /// # Permission
+    ///
+    /// Requires BASIC [16] user permissions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::convert::Infallible;
+    /// use tenable::{requests::VulnReq, Error, Response, Tenable, types::ExportStatus};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let req = tenable.vulns_export_cancel("00000000-0000-0000-0000-000000000000");
+    /// let _status: ExportStatus = Tenable::request(req, |_| {
+    ///     Result::<Response, Error<Infallible>>::Ok(todo!("Define a method to send http requests"))
+    /// })
+    /// .expect("Unable to cancel export")
+    /// .expect("Export not found");
+    /// ```
+    fn vulns_export_cancel<'a, I: Into<Cow<'a, str>>>(
+        &'a self,
+        export_uuid: I,
+    ) -> VulnsExportCancelReq<'a>;
+}
+
+impl VulnReq for Tenable<'_> {
+    #[inline]
+    fn vulns_export<'a, I: Into<Cow<'a, VulnsExportDef>>>(
+        &'a self,
+        vulns_export_def: I,
+    ) -> VulnsExportReq<'a> {
+        VulnsExportReq {
+            tenable: self,
+            vulns_export_def: vulns_export_def.into(),
+        }
+    }
+
+    #[inline]
+    fn vulns_export_status<'a, I: Into<Cow<'a, str>>>(
+        &'a self,
+        export_uuid: I,
+    ) -> VulnsExportStatusReq<'a> {
+        VulnsExportStatusReq {
+            tenable: self,
+            export_uuid: export_uuid.into(),
+        }
+    }
+
+    #[inline]
+    fn vulns_export_chunk<'a, I: Into<Cow<'a, str>>>(
+        &'a self,
+        export_uuid: I,
+        chunk_id: u64,
+    ) -> VulnsExportChunkReq<'a> {
+        VulnsExportChunkReq {
+            tenable: self,
+            export_uuid: export_uuid.into(),
+            chunk_id,
+        }
+    }
+
+    #[inline]
+    fn vulns_export_cancel<'a, I: Into<Cow<'a, str>>>(
+        &'a self,
+        export_uuid: I,
+    ) -> VulnsExportCancelReq<'a> {
+        VulnsExportCancelReq {
+            tenable: self,
+            export_uuid: export_uuid.into(),
+        }
+    }
+}
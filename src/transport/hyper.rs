@@ -0,0 +1,109 @@
+use crate::{transport::AsyncTransport, Error, Response};
+use http::Request;
+use hyper::{client::connect::Connect, Body, Client};
+use std::{future::Future, pin::Pin};
+
+/// Sends asynchronous requests using `hyper`. Cloning the transport is cheap
+/// and shares the connection pool.
+///
+/// The connector is chosen by the caller, as Tenable.io is only reachable
+/// using https, which `hyper` does not support on its own.
+#[derive(Clone, Debug)]
+pub struct Hyper<C> {
+    /// Inner client which holds the connection pool
+    pub client: Client<C, Body>,
+}
+
+impl<C> Hyper<C> {
+    /// Creates a new transport using the given client
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use tokio::runtime::Runtime;
+    /// use hyper::Client;
+    /// use hyper_rustls::HttpsConnector;
+    /// use tenable::transport::Hyper;
+    /// # let mut rt = Runtime::new().expect("Unable to create runtime");
+    /// # rt.block_on(async {
+    /// let client = Client::builder().build(HttpsConnector::new());
+    /// let transport = Hyper::new(client);
+    /// # })
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn new(client: Client<C, Body>) -> Self {
+        Self { client }
+    }
+}
+
+impl<C: Connect + Clone + Send + Sync + 'static> Hyper<C> {
+    /// Sends the request and waits for the response
+    ///
+    /// # Errors
+    ///
+    /// Fails if the request could not be sent or the response could not be
+    /// read. Timeouts and connection errors are returned as
+    /// `Error::TransientRequest`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio::runtime::Runtime;
+    /// use hyper::Client;
+    /// use hyper_rustls::HttpsConnector;
+    /// use tenable::{requests::AssetReq, transport::Hyper, types::Assets, Tenable};
+    /// # let mut rt = Runtime::new().expect("Unable to create runtime");
+    /// # rt.block_on(async {
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let transport = Hyper::new(Client::builder().build(HttpsConnector::new()));
+    /// let req = tenable.assets();
+    /// let _assets: Assets = Tenable::request_async(req, |req| transport.send(req)).await
+    ///     .expect("Unable to list all assets");
+    /// # })
+    /// ```
+    #[inline]
+    pub async fn send(&self, req: Request<Vec<u8>>) -> Result<Response, Error<hyper::Error>> {
+        let (parts, body) = req.into_parts();
+        let res = self
+            .client
+            .request(Request::from_parts(parts, Body::from(body)))
+            .await
+            .map_err(hyper_error)?;
+        let (parts, body) = res.into_parts();
+        Ok(Response {
+            status: parts.status,
+            headers: parts.headers,
+            body: hyper::body::to_bytes(body).await.map_err(hyper_error)?,
+        })
+    }
+}
+
+impl<C> From<Client<C, Body>> for Hyper<C> {
+    #[inline]
+    fn from(client: Client<C, Body>) -> Self {
+        Self::new(client)
+    }
+}
+
+/// Flags errors of `hyper` which may succeed when retried
+fn hyper_error(err: hyper::Error) -> Error<hyper::Error> {
+    if err.is_connect() || err.is_timeout() || err.is_closed() {
+        Error::TransientRequest(err)
+    } else {
+        Error::Request(err)
+    }
+}
+
+impl<C: Connect + Clone + Send + Sync + 'static> AsyncTransport<hyper::Error> for Hyper<C> {
+    type Future = Pin<Box<dyn Future<Output = Result<Response, Error<hyper::Error>>> + Send>>;
+
+    #[inline]
+    fn send(&self, req: Request<Vec<u8>>) -> Self::Future {
+        let transport = self.clone();
+        Box::pin(async move { transport.send(req).await })
+    }
+}
@@ -0,0 +1,145 @@
+use crate::{
+    transport::{AsyncTransport, Transport},
+    Error, Response,
+};
+use bytes::Bytes;
+use futures_util::io::AsyncReadExt;
+use http::Request;
+use isahc::HttpClient;
+use std::{future::Future, io::Read, pin::Pin};
+
+/// Sends synchronous or asynchronous requests using `isahc`. Cloning the
+/// transport is cheap and shares the connection pool.
+#[derive(Clone, Debug)]
+pub struct Isahc {
+    /// Inner client which holds the connection pool
+    pub client: HttpClient,
+}
+
+impl Isahc {
+    /// Creates a new transport using the given client
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use isahc::HttpClient;
+    /// use tenable::transport::Isahc;
+    /// let client = HttpClient::new().expect("Unable to create client");
+    /// let transport = Isahc::new(client);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn new(client: HttpClient) -> Self {
+        Self { client }
+    }
+
+    /// Sends the request and waits for the response
+    ///
+    /// # Errors
+    ///
+    /// Fails if the request could not be sent or the response could not be
+    /// read. Timeouts and connection errors are returned as
+    /// `Error::TransientRequest`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use isahc::HttpClient;
+    /// use tenable::{requests::AssetReq, transport::Isahc, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let transport = Isahc::new(HttpClient::new().expect("Unable to create client"));
+    /// let req = tenable.assets();
+    /// let _assets = Tenable::request(req, |req| transport.send(req))
+    ///     .expect("Unable to list all assets");
+    /// ```
+    #[inline]
+    pub fn send(&self, req: Request<Vec<u8>>) -> Result<Response, Error<isahc::Error>> {
+        let res = self.client.send(req).map_err(isahc_error)?;
+        let (parts, mut body) = res.into_parts();
+        let mut bytes = Vec::new();
+        let _ = Read::read_to_end(&mut body, &mut bytes).map_err(|err| isahc_error(err.into()))?;
+        Ok(Response {
+            status: parts.status,
+            headers: parts.headers,
+            body: Bytes::from(bytes),
+        })
+    }
+
+    /// Sends the request and waits for the response asynchronously
+    ///
+    /// # Errors
+    ///
+    /// Fails if the request could not be sent or the response could not be
+    /// read. Timeouts and connection errors are returned as
+    /// `Error::TransientRequest`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio::runtime::Runtime;
+    /// use isahc::HttpClient;
+    /// use tenable::{requests::AssetReq, transport::Isahc, types::Assets, Tenable};
+    /// # let mut rt = Runtime::new().expect("Unable to create runtime");
+    /// # rt.block_on(async {
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let transport = Isahc::new(HttpClient::new().expect("Unable to create client"));
+    /// let req = tenable.assets();
+    /// let _assets: Assets = Tenable::request_async(req, |req| transport.send_async(req)).await
+    ///     .expect("Unable to list all assets");
+    /// # })
+    /// ```
+    #[inline]
+    pub async fn send_async(&self, req: Request<Vec<u8>>) -> Result<Response, Error<isahc::Error>> {
+        let res = self.client.send_async(req).await.map_err(isahc_error)?;
+        let (parts, mut body) = res.into_parts();
+        let mut bytes = Vec::new();
+        let _ = AsyncReadExt::read_to_end(&mut body, &mut bytes)
+            .await
+            .map_err(|err| isahc_error(err.into()))?;
+        Ok(Response {
+            status: parts.status,
+            headers: parts.headers,
+            body: Bytes::from(bytes),
+        })
+    }
+}
+
+impl From<HttpClient> for Isahc {
+    #[inline]
+    fn from(client: HttpClient) -> Self {
+        Self::new(client)
+    }
+}
+
+/// Flags errors of `isahc` which may succeed when retried
+fn isahc_error(err: isahc::Error) -> Error<isahc::Error> {
+    match err {
+        isahc::Error::ConnectFailed | isahc::Error::NoResponse | isahc::Error::Timeout => {
+            Error::TransientRequest(err)
+        }
+        err => Error::Request(err),
+    }
+}
+
+impl Transport<isahc::Error> for Isahc {
+    #[inline]
+    fn send(&self, req: Request<Vec<u8>>) -> Result<Response, Error<isahc::Error>> {
+        Self::send(self, req)
+    }
+}
+
+impl AsyncTransport<isahc::Error> for Isahc {
+    type Future = Pin<Box<dyn Future<Output = Result<Response, Error<isahc::Error>>> + Send>>;
+
+    #[inline]
+    fn send(&self, req: Request<Vec<u8>>) -> Self::Future {
+        let transport = self.clone();
+        Box::pin(async move { transport.send_async(req).await })
+    }
+}
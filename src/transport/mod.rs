@@ -0,0 +1,104 @@
+//! Traits for sending requests and ready-made transports for common http clients
+//!
+//! `Transport` and `AsyncTransport` are implemented for functions like the
+//! ones accepted by `Tenable::request`, as well as for the transports below,
+//! and are used by `TenableClient` to send requests.
+//!
+//! Every client is behind its own cargo feature, so that only the client
+//! actually used is compiled. The transports hold the client and with it the
+//! connection pool, so create them once and share them between requests.
+//!
+//! | Feature            | Transport         |
+//! |--------------------|-------------------|
+//! | `reqwest-blocking` | `ReqwestBlocking` |
+//! | `reqwest-async`    | `ReqwestAsync`    |
+//! | `ureq`             | `Ureq`            |
+//! | `hyper`            | `Hyper`           |
+//! | `isahc`            | `Isahc`           |
+//!
+//! Timeouts and connection errors are returned as `Error::TransientRequest`,
+//! so that they can be retried using `Tenable::request_with_policy`. Every
+//! other error of the client is returned as `Error::Request`.
+
+#[cfg(feature = "hyper")]
+mod hyper;
+#[cfg(feature = "isahc")]
+mod isahc;
+#[cfg(feature = "reqwest-async")]
+mod reqwest_async;
+#[cfg(feature = "reqwest-blocking")]
+mod reqwest_blocking;
+#[cfg(feature = "ureq")]
+mod ureq;
+
+#[cfg(feature = "hyper")]
+pub use self::hyper::*;
+#[cfg(feature = "isahc")]
+pub use self::isahc::*;
+#[cfg(feature = "ureq")]
+pub use self::ureq::*;
+#[cfg(feature = "reqwest-async")]
+pub use reqwest_async::*;
+#[cfg(feature = "reqwest-blocking")]
+pub use reqwest_blocking::*;
+
+use crate::{Error, Response};
+use http::Request;
+use std::{fmt, future::Future};
+
+/// Sends synchronous requests
+pub trait Transport<RE: fmt::Debug> {
+    /// Sends the request and waits for the response
+    ///
+    /// # Errors
+    ///
+    /// Fails if the request could not be sent or the response could not be
+    /// read. Errors which may succeed when retried should be returned as
+    /// `Error::TransientRequest`.
+    fn send(&self, req: Request<Vec<u8>>) -> Result<Response, Error<RE>>;
+}
+
+impl<RE, F> Transport<RE> for F
+where
+    RE: fmt::Debug,
+    F: Fn(Request<Vec<u8>>) -> Result<Response, Error<RE>>,
+{
+    #[inline]
+    fn send(&self, req: Request<Vec<u8>>) -> Result<Response, Error<RE>> {
+        self(req)
+    }
+}
+
+/// Sends asynchronous requests
+pub trait AsyncTransport<RE: fmt::Debug> {
+    /// Future which resolves to the response
+    type Future: Future<Output = Result<Response, Error<RE>>>;
+
+    /// Sends the request and returns a future resolving to the response. The
+    /// future may fail for the same reasons as `Transport::send`.
+    fn send(&self, req: Request<Vec<u8>>) -> Self::Future;
+}
+
+impl<RE, F, Fut> AsyncTransport<RE> for F
+where
+    RE: fmt::Debug,
+    F: Fn(Request<Vec<u8>>) -> Fut,
+    Fut: Future<Output = Result<Response, Error<RE>>>,
+{
+    type Future = Fut;
+
+    #[inline]
+    fn send(&self, req: Request<Vec<u8>>) -> Self::Future {
+        self(req)
+    }
+}
+
+/// Flags errors of `reqwest` which may succeed when retried
+#[cfg(any(feature = "reqwest-async", feature = "reqwest-blocking"))]
+fn reqwest_error(err: reqwest::Error) -> Error<reqwest::Error> {
+    if err.is_timeout() || err.is_connect() {
+        Error::TransientRequest(err)
+    } else {
+        Error::Request(err)
+    }
+}
@@ -0,0 +1,97 @@
+use crate::{
+    transport::{reqwest_error, AsyncTransport},
+    Error, Response,
+};
+use http::Request;
+use reqwest::Client;
+use std::{future::Future, pin::Pin};
+
+/// Sends asynchronous requests using `reqwest`. Cloning the transport is cheap
+/// and shares the connection pool.
+#[derive(Clone, Debug, Default)]
+pub struct ReqwestAsync {
+    /// Inner client which holds the connection pool
+    pub client: Client,
+}
+
+impl ReqwestAsync {
+    /// Creates a new transport using the given client
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use reqwest::Client;
+    /// use std::time::Duration;
+    /// use tenable::transport::ReqwestAsync;
+    /// let client = Client::builder()
+    ///     .timeout(Duration::from_secs(60))
+    ///     .build()
+    ///     .expect("Unable to create client");
+    /// let transport = ReqwestAsync::new(client);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn new(client: Client) -> Self {
+        Self { client }
+    }
+
+    /// Sends the request and waits for the response
+    ///
+    /// # Errors
+    ///
+    /// Fails if the request could not be sent or the response could not be
+    /// read. Timeouts and connection errors are returned as
+    /// `Error::TransientRequest`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use tokio::runtime::Runtime;
+    /// use tenable::{requests::AssetReq, transport::ReqwestAsync, types::Assets, Tenable};
+    /// # let mut rt = Runtime::new().expect("Unable to create runtime");
+    /// # rt.block_on(async {
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let transport = ReqwestAsync::default();
+    /// let req = tenable.assets();
+    /// let _assets: Assets = Tenable::request_async(req, |req| transport.send(req)).await
+    ///     .expect("Unable to list all assets");
+    /// # })
+    /// ```
+    #[inline]
+    pub async fn send(&self, req: Request<Vec<u8>>) -> Result<Response, Error<reqwest::Error>> {
+        let (req, body) = req.into_parts();
+        let res = self
+            .client
+            .request(req.method, &req.uri.to_string())
+            .headers(req.headers)
+            .body(body)
+            .send()
+            .await
+            .map_err(reqwest_error)?;
+        Ok(Response {
+            status: res.status(),
+            headers: res.headers().clone(),
+            body: res.bytes().await.map_err(reqwest_error)?,
+        })
+    }
+}
+
+impl From<Client> for ReqwestAsync {
+    #[inline]
+    fn from(client: Client) -> Self {
+        Self::new(client)
+    }
+}
+
+impl AsyncTransport<reqwest::Error> for ReqwestAsync {
+    type Future = Pin<Box<dyn Future<Output = Result<Response, Error<reqwest::Error>>> + Send>>;
+
+    #[inline]
+    fn send(&self, req: Request<Vec<u8>>) -> Self::Future {
+        let transport = self.clone();
+        Box::pin(async move { transport.send(req).await })
+    }
+}
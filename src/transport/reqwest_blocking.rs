@@ -0,0 +1,88 @@
+use crate::{
+    transport::{reqwest_error, Transport},
+    Error, Response,
+};
+use http::Request;
+use reqwest::blocking::Client;
+
+/// Sends synchronous requests using `reqwest`. Cloning the transport is cheap
+/// and shares the connection pool.
+#[derive(Clone, Debug, Default)]
+pub struct ReqwestBlocking {
+    /// Inner client which holds the connection pool
+    pub client: Client,
+}
+
+impl ReqwestBlocking {
+    /// Creates a new transport using the given client
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use reqwest::blocking::Client;
+    /// use std::time::Duration;
+    /// use tenable::transport::ReqwestBlocking;
+    /// let client = Client::builder()
+    ///     .timeout(Duration::from_secs(60))
+    ///     .build()
+    ///     .expect("Unable to create client");
+    /// let transport = ReqwestBlocking::new(client);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn new(client: Client) -> Self {
+        Self { client }
+    }
+
+    /// Sends the request and waits for the response
+    ///
+    /// # Errors
+    ///
+    /// Fails if the request could not be sent or the response could not be
+    /// read. Timeouts and connection errors are returned as
+    /// `Error::TransientRequest`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use tenable::{requests::AssetReq, transport::ReqwestBlocking, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let transport = ReqwestBlocking::default();
+    /// let req = tenable.assets();
+    /// let _assets = Tenable::request(req, |req| transport.send(req))
+    ///     .expect("Unable to list all assets");
+    /// ```
+    #[inline]
+    pub fn send(&self, req: Request<Vec<u8>>) -> Result<Response, Error<reqwest::Error>> {
+        let (req, body) = req.into_parts();
+        let res = self
+            .client
+            .request(req.method, &req.uri.to_string())
+            .headers(req.headers)
+            .body(body)
+            .send()
+            .map_err(reqwest_error)?;
+        Ok(Response {
+            status: res.status(),
+            headers: res.headers().clone(),
+            body: res.bytes().map_err(reqwest_error)?,
+        })
+    }
+}
+
+impl From<Client> for ReqwestBlocking {
+    #[inline]
+    fn from(client: Client) -> Self {
+        Self::new(client)
+    }
+}
+
+impl Transport<reqwest::Error> for ReqwestBlocking {
+    #[inline]
+    fn send(&self, req: Request<Vec<u8>>) -> Result<Response, Error<reqwest::Error>> {
+        Self::send(self, req)
+    }
+}
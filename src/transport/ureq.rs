@@ -0,0 +1,116 @@
+use crate::{transport::Transport, Error, Response};
+use bytes::Bytes;
+use http::{
+    header::{HeaderName, HeaderValue},
+    HeaderMap, Request, StatusCode,
+};
+use std::io::Read;
+use ureq::Agent;
+
+/// Sends synchronous requests using `ureq`. Cloning the transport is cheap and
+/// shares the connection pool.
+#[derive(Clone, Debug, Default)]
+pub struct Ureq {
+    /// Inner agent which holds the connection pool
+    pub agent: Agent,
+}
+
+impl Ureq {
+    /// Creates a new transport using the given agent
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tenable::transport::Ureq;
+    /// let transport = Ureq::new(ureq::agent());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn new(agent: Agent) -> Self {
+        Self { agent }
+    }
+
+    /// Sends the request and waits for the response
+    ///
+    /// # Errors
+    ///
+    /// Fails if the request could not be sent or the response could not be
+    /// read. Connection and io errors are returned as
+    /// `Error::TransientRequest`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use tenable::{requests::AssetReq, transport::Ureq, Tenable};
+    /// let tenable = Tenable::new(
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// let transport = Ureq::default();
+    /// let req = tenable.assets();
+    /// let _assets = Tenable::request(req, |req| transport.send(req))
+    ///     .expect("Unable to list all assets");
+    /// ```
+    #[inline]
+    pub fn send(&self, req: Request<Vec<u8>>) -> Result<Response, Error<ureq::Error>> {
+        let (req, body) = req.into_parts();
+        let mut request = self
+            .agent
+            .request(req.method.as_str(), &req.uri.to_string());
+        for (name, value) in &req.headers {
+            let value = value
+                .to_str()
+                .map_err(|_| Error::Request(ureq::Error::BadHeader))?;
+            let _ = request.set(name.as_str(), value);
+        }
+        let res = request.send_bytes(&body);
+        if res.synthetic() {
+            let err = res.into_synthetic_error().unwrap_or(ureq::Error::BadStatus);
+            return Err(ureq_error(err));
+        }
+        let status = StatusCode::from_u16(res.status())
+            .map_err(|_| Error::Request(ureq::Error::BadStatus))?;
+        let mut headers = HeaderMap::new();
+        for name in res.headers_names() {
+            let header = HeaderName::from_bytes(name.as_bytes())
+                .map_err(|_| Error::Request(ureq::Error::BadHeader))?;
+            for value in res.all(&name) {
+                let value = HeaderValue::from_str(value)
+                    .map_err(|_| Error::Request(ureq::Error::BadHeader))?;
+                let _ = headers.append(header.clone(), value);
+            }
+        }
+        let mut body = Vec::new();
+        let _ = res
+            .into_reader()
+            .read_to_end(&mut body)
+            .map_err(|err| ureq_error(ureq::Error::Io(err)))?;
+        Ok(Response {
+            status,
+            headers,
+            body: Bytes::from(body),
+        })
+    }
+}
+
+impl From<Agent> for Ureq {
+    #[inline]
+    fn from(agent: Agent) -> Self {
+        Self::new(agent)
+    }
+}
+
+/// Flags errors of `ureq` which may succeed when retried
+fn ureq_error(err: ureq::Error) -> Error<ureq::Error> {
+    match err {
+        ureq::Error::ConnectionFailed(_) | ureq::Error::Io(_) => Error::TransientRequest(err),
+        err => Error::Request(err),
+    }
+}
+
+impl Transport<ureq::Error> for Ureq {
+    #[inline]
+    fn send(&self, req: Request<Vec<u8>>) -> Result<Response, Error<ureq::Error>> {
+        Self::send(self, req)
+    }
+}
@@ -0,0 +1,61 @@
+use crate::{types::AccessGroup, Error, HttpRequest, Response, Tenable};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `access_group_by_id` function
+#[derive(Clone, Debug)]
+pub struct AccessGroupByIdReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// UUID which identifies the access group
+    pub access_group_id: Cow<'a, str>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for AccessGroupByIdReq<'_> {
+    type Output = Option<AccessGroup>;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let req = Request::builder()
+            .uri(format!(
+                "{}/v2/access-groups/{access_group_id}",
+                self.tenable.uri,
+                access_group_id = self.access_group_id
+            ))
+            .method(Method::GET)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .body(Vec::new())?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            StatusCode::NOT_FOUND => return Ok(None),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(Some(data))
+    }
+}
+
+impl<'a> From<AccessGroupByIdReq<'a>> for Cow<'a, AccessGroupByIdReq<'a>> {
+    #[inline]
+    fn from(req: AccessGroupByIdReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a AccessGroupByIdReq<'a>> for Cow<'a, AccessGroupByIdReq<'a>> {
+    #[inline]
+    fn from(req: &'a AccessGroupByIdReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
@@ -0,0 +1,96 @@
+use crate::{
+    types::{AccessGroup, AccessGroupType, AccessGroupsPrincipals, AccessGroupsRules},
+    Error, HttpRequest, Response, Tenable,
+};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `access_group_create` function
+#[derive(Clone, Debug)]
+pub struct AccessGroupCreateReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// Definition which details the access group
+    pub access_group_def: Cow<'a, AccessGroupDef>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for AccessGroupCreateReq<'_> {
+    type Output = AccessGroup;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let payload = serde_json::to_vec(&self.access_group_def)?;
+        let req = Request::builder()
+            .uri(format!("{}/v2/access-groups", self.tenable.uri))
+            .method(Method::POST)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .header("Content-Type", HeaderValue::from_static("application/json"))
+            .body(payload)?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(data)
+    }
+}
+
+impl<'a> From<AccessGroupCreateReq<'a>> for Cow<'a, AccessGroupCreateReq<'a>> {
+    #[inline]
+    fn from(req: AccessGroupCreateReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a AccessGroupCreateReq<'a>> for Cow<'a, AccessGroupCreateReq<'a>> {
+    #[inline]
+    fn from(req: &'a AccessGroupCreateReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+/// Details an access group to create or update
+pub struct AccessGroupDef {
+    /// The name of the access group. Must be unique within the container.
+    #[serde(rename = "name")]
+    pub name: String,
+    /// The type of the access group. Defaults to `MANAGE_ASSETS`.
+    #[serde(rename = "access_group_type", skip_serializing_if = "Option::is_none")]
+    pub access_group_type: Option<AccessGroupType>,
+    /// Grants all users of the container access to the assets of the access group. Ignores `principals` if set.
+    #[serde(rename = "all_users", skip_serializing_if = "Option::is_none")]
+    pub all_users: Option<bool>,
+    /// The rules Tenable.io uses to assign assets to the access group.
+    #[serde(rename = "rules", skip_serializing_if = "Option::is_none")]
+    pub rules: Option<Vec<AccessGroupsRules>>,
+    /// The users and user groups which have access to the assets of the access group.
+    #[serde(rename = "principals", skip_serializing_if = "Option::is_none")]
+    pub principals: Option<Vec<AccessGroupsPrincipals>>,
+}
+
+impl From<AccessGroupDef> for Cow<'_, AccessGroupDef> {
+    #[inline]
+    fn from(req: AccessGroupDef) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a AccessGroupDef> for Cow<'a, AccessGroupDef> {
+    #[inline]
+    fn from(req: &'a AccessGroupDef) -> Self {
+        Cow::Borrowed(req)
+    }
+}
@@ -0,0 +1,60 @@
+use crate::{Error, HttpRequest, Response, Tenable};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `access_group_delete` function
+#[derive(Clone, Debug)]
+pub struct AccessGroupDeleteReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// UUID which identifies the access group
+    pub access_group_id: Cow<'a, str>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for AccessGroupDeleteReq<'_> {
+    type Output = Option<()>;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let req = Request::builder()
+            .uri(format!(
+                "{}/v2/access-groups/{access_group_id}",
+                self.tenable.uri,
+                access_group_id = self.access_group_id
+            ))
+            .method(Method::DELETE)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .body(Vec::new())?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK | StatusCode::NO_CONTENT => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            StatusCode::NOT_FOUND => return Ok(None),
+            _ => return Err(Error::from_response(res)),
+        }
+        Ok(Some(()))
+    }
+}
+
+impl<'a> From<AccessGroupDeleteReq<'a>> for Cow<'a, AccessGroupDeleteReq<'a>> {
+    #[inline]
+    fn from(req: AccessGroupDeleteReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a AccessGroupDeleteReq<'a>> for Cow<'a, AccessGroupDeleteReq<'a>> {
+    #[inline]
+    fn from(req: &'a AccessGroupDeleteReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
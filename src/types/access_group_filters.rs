@@ -0,0 +1,66 @@
+use crate::{types::Filter, Error, HttpRequest, Response, Tenable};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `access_group_filters` function
+#[derive(Clone, Debug)]
+pub struct AccessGroupFiltersReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for AccessGroupFiltersReq<'_> {
+    type Output = AccessGroupFilters;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let req = Request::builder()
+            .uri(format!("{}/v2/access-groups/filters", self.tenable.uri))
+            .method(Method::GET)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .body(Vec::new())?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(data)
+    }
+}
+
+impl<'a> From<AccessGroupFiltersReq<'a>> for Cow<'a, AccessGroupFiltersReq<'a>> {
+    #[inline]
+    fn from(req: AccessGroupFiltersReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a AccessGroupFiltersReq<'a>> for Cow<'a, AccessGroupFiltersReq<'a>> {
+    #[inline]
+    fn from(req: &'a AccessGroupFiltersReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Filters returned by `access_group_filters`
+pub struct AccessGroupFilters {
+    /// The filters which can be used to narrow down the list of access groups.
+    #[serde(rename = "filters", skip_serializing_if = "Option::is_none")]
+    pub filters: Option<Vec<Filter>>,
+    /// The fields searched by the wildcard filter.
+    #[serde(rename = "wildcard_fields", skip_serializing_if = "Option::is_none")]
+    pub wildcard_fields: Option<Vec<String>>,
+}
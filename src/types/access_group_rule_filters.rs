@@ -0,0 +1,66 @@
+use crate::{types::Filter, Error, HttpRequest, Response, Tenable};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `access_group_rule_filters` function
+#[derive(Clone, Debug)]
+pub struct AccessGroupRuleFiltersReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for AccessGroupRuleFiltersReq<'_> {
+    type Output = AccessGroupRuleFilters;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let req = Request::builder()
+            .uri(format!(
+                "{}/v2/access-groups/rules/filters",
+                self.tenable.uri
+            ))
+            .method(Method::GET)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .body(Vec::new())?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(data)
+    }
+}
+
+impl<'a> From<AccessGroupRuleFiltersReq<'a>> for Cow<'a, AccessGroupRuleFiltersReq<'a>> {
+    #[inline]
+    fn from(req: AccessGroupRuleFiltersReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a AccessGroupRuleFiltersReq<'a>> for Cow<'a, AccessGroupRuleFiltersReq<'a>> {
+    #[inline]
+    fn from(req: &'a AccessGroupRuleFiltersReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Rule filters returned by `access_group_rule_filters`
+pub struct AccessGroupRuleFilters {
+    /// The rule types, operators and terms which can be used in the `rules` of an access group.
+    #[serde(rename = "rules", skip_serializing_if = "Option::is_none")]
+    pub rules: Option<Vec<Filter>>,
+}
@@ -0,0 +1,68 @@
+use crate::{
+    types::{AccessGroup, AccessGroupDef},
+    Error, HttpRequest, Response, Tenable,
+};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `access_group_update` function
+#[derive(Clone, Debug)]
+pub struct AccessGroupUpdateReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// UUID which identifies the access group
+    pub access_group_id: Cow<'a, str>,
+    /// Definition which details the access group
+    pub access_group_def: Cow<'a, AccessGroupDef>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for AccessGroupUpdateReq<'_> {
+    type Output = Option<AccessGroup>;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let payload = serde_json::to_vec(&self.access_group_def)?;
+        let req = Request::builder()
+            .uri(format!(
+                "{}/v2/access-groups/{access_group_id}",
+                self.tenable.uri,
+                access_group_id = self.access_group_id
+            ))
+            .method(Method::PUT)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .header("Content-Type", HeaderValue::from_static("application/json"))
+            .body(payload)?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            StatusCode::NOT_FOUND => return Ok(None),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(Some(data))
+    }
+}
+
+impl<'a> From<AccessGroupUpdateReq<'a>> for Cow<'a, AccessGroupUpdateReq<'a>> {
+    #[inline]
+    fn from(req: AccessGroupUpdateReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a AccessGroupUpdateReq<'a>> for Cow<'a, AccessGroupUpdateReq<'a>> {
+    #[inline]
+    fn from(req: &'a AccessGroupUpdateReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
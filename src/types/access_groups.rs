@@ -0,0 +1,156 @@
+use crate::{
+    types::{encode_query, AccessGroupsPrincipals, AccessGroupsRules, Pagination},
+    Error, HttpRequest, Response, Tenable,
+};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `access_groups` function
+#[derive(Clone, Debug)]
+pub struct AccessGroupsReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// Maximum number of access groups to return
+    pub limit: Option<u64>,
+    /// Number of access groups to skip
+    pub offset: Option<u64>,
+    /// Sorts the access groups by the given field and order, for example `name:asc`
+    pub sort: Option<Cow<'a, str>>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for AccessGroupsReq<'_> {
+    type Output = AccessGroups;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let mut query = Vec::new();
+        if let Some(limit) = self.limit {
+            query.push(format!("limit={}", limit));
+        }
+        if let Some(offset) = self.offset {
+            query.push(format!("offset={}", offset));
+        }
+        if let Some(ref sort) = self.sort {
+            query.push(format!("sort={}", encode_query(sort)));
+        }
+        let mut uri = format!("{}/v2/access-groups", self.tenable.uri);
+        if !query.is_empty() {
+            uri = format!("{}?{}", uri, query.join("&"));
+        }
+        let req = Request::builder()
+            .uri(uri)
+            .method(Method::GET)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .body(Vec::new())?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(data)
+    }
+}
+
+impl<'a> From<AccessGroupsReq<'a>> for Cow<'a, AccessGroupsReq<'a>> {
+    #[inline]
+    fn from(req: AccessGroupsReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a AccessGroupsReq<'a>> for Cow<'a, AccessGroupsReq<'a>> {
+    #[inline]
+    fn from(req: &'a AccessGroupsReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Access groups returned by `access_groups`
+pub struct AccessGroups {
+    /// The access groups.
+    #[serde(rename = "access_groups", skip_serializing_if = "Option::is_none")]
+    pub access_groups: Option<Vec<AccessGroup>>,
+    /// The position of the returned access groups within all access groups.
+    #[serde(rename = "pagination", skip_serializing_if = "Option::is_none")]
+    pub pagination: Option<Pagination>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Represents an access group, which grants users and user groups access to assets
+pub struct AccessGroup {
+    /// The UUID of the access group.
+    #[serde(rename = "id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The name of the access group.
+    #[serde(rename = "name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Indicates whether the access group contains all assets. Only the `All Assets` group does.
+    #[serde(rename = "all_assets", skip_serializing_if = "Option::is_none")]
+    pub all_assets: Option<bool>,
+    /// Indicates whether all users of the container are principals of the access group.
+    #[serde(rename = "all_users", skip_serializing_if = "Option::is_none")]
+    pub all_users: Option<bool>,
+    /// The type of the access group.
+    #[serde(rename = "access_group_type", skip_serializing_if = "Option::is_none")]
+    pub access_group_type: Option<AccessGroupType>,
+    /// The version of the access group, increased on every update.
+    #[serde(rename = "version", skip_serializing_if = "Option::is_none")]
+    pub version: Option<u64>,
+    /// The status of assigning assets to the access group, like `PROCESSING` or `COMPLETED`.
+    #[serde(rename = "status", skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    /// The percentage of assets Tenable.io has already evaluated against the rules of the access group.
+    #[serde(
+        rename = "processing_percent_complete",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub processing_percent_complete: Option<u8>,
+    /// The ISO timestamp when the access group was created.
+    #[serde(rename = "created_at", skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    /// The ISO timestamp when the access group was last updated.
+    #[serde(rename = "updated_at", skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+    /// The UUID of the user who created the access group.
+    #[serde(rename = "created_by_uuid", skip_serializing_if = "Option::is_none")]
+    pub created_by_uuid: Option<String>,
+    /// The name of the user who created the access group.
+    #[serde(rename = "created_by_name", skip_serializing_if = "Option::is_none")]
+    pub created_by_name: Option<String>,
+    /// The UUID of the user who last updated the access group.
+    #[serde(rename = "updated_by_uuid", skip_serializing_if = "Option::is_none")]
+    pub updated_by_uuid: Option<String>,
+    /// The name of the user who last updated the access group.
+    #[serde(rename = "updated_by_name", skip_serializing_if = "Option::is_none")]
+    pub updated_by_name: Option<String>,
+    /// The rules Tenable.io uses to assign assets to the access group. Not returned when listing access groups.
+    #[serde(rename = "rules", skip_serializing_if = "Option::is_none")]
+    pub rules: Option<Vec<AccessGroupsRules>>,
+    /// The users and user groups which have access to the assets of the access group. Not returned when listing access groups.
+    #[serde(rename = "principals", skip_serializing_if = "Option::is_none")]
+    pub principals: Option<Vec<AccessGroupsPrincipals>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Copy)]
+/// Type of an access group
+pub enum AccessGroupType {
+    /// Grants principals access to view the assets and their vulnerabilities.
+    #[serde(rename = "MANAGE_ASSETS")]
+    ManageAssets,
+    /// Grants principals access to scan the assets.
+    #[serde(rename = "SCAN_TARGETS")]
+    ScanTargets,
+}
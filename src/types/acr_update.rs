@@ -13,7 +13,7 @@ pub struct AcrUpdate<'a> {
 }
 
 impl<RE: fmt::Debug> HttpRequest<RE> for AcrUpdate<'_> {
-    type Output = ();
+    type Output = UpdatedAcrs;
 
     #[inline]
     fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
@@ -36,9 +36,10 @@ impl<RE: fmt::Debug> HttpRequest<RE> for AcrUpdate<'_> {
             StatusCode::OK => {}
             StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
             StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
-            code => return Err(Error::UnexpectedStatusCode(code)),
+            _ => return Err(Error::from_response(res)),
         }
-        Ok(())
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(data)
     }
 }
 
@@ -114,6 +115,17 @@ pub struct AcrAsset {
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Information about the ACR update
+pub struct UpdatedAcrs {
+    /// The number of assets affected by the operation.
+    #[serde(rename = "asset_count", skip_serializing_if = "Option::is_none")]
+    pub asset_count: Option<i32>,
+    /// The UUID of the job updating the assets. Use `asset_bulk_job` to follow it up.
+    #[serde(rename = "job_uuid", skip_serializing_if = "Option::is_none")]
+    pub job_uuid: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 /// `AccessGroupsRules` blueprint
 pub struct AccessGroupsRules {
     /// The type of asset rule. The asset rule type corresponds to the type of data you can specify in the `terms` parameter. For a complete list of supported rule types, use the [GET /access-groups/filters](ref:io-v1-access-groups-list-filters) endpoint.
@@ -127,7 +139,7 @@ pub struct AccessGroupsRules {
     pub terms: Option<Vec<String>>,
 }
 
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 /// `AccessGroupsPrincipals` blueprint
 pub struct AccessGroupsPrincipals {
     /// (Required) The type of principal. Valid values include:  - user—Grants access to the user you specify.  - group—Grants access to all users assigned to the user group you specify.
@@ -139,4 +151,18 @@ pub struct AccessGroupsPrincipals {
     /// The name of the user or user group. This parameter is required if the request omits the `principal_id` parameter. If a request includes both `principal_id` and `principal_name`, Tenable.io assigns the user or user group to the access group based on the `principal_id` parameter, and ignores the `principal_name` parameter in the request.
     #[serde(rename = "principal_name", skip_serializing_if = "Option::is_none")]
     pub principal_name: Option<String>,
+    /// The permissions the principal has on the assets of the access group. Only used by `/v2/access-groups`, which requires at least one permission.
+    #[serde(rename = "permissions", skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<Vec<AccessGroupPermission>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Copy)]
+/// Permissions a principal can have on the assets of an access group
+pub enum AccessGroupPermission {
+    /// The principal can view the assets and their vulnerabilities.
+    #[serde(rename = "CAN_VIEW")]
+    CanView,
+    /// The principal can scan the assets.
+    #[serde(rename = "CAN_SCAN")]
+    CanScan,
 }
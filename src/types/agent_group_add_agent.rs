@@ -0,0 +1,66 @@
+use crate::{Error, HttpRequest, Response, Tenable};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `agent_group_add_agent` function
+#[derive(Clone, Debug)]
+pub struct AgentGroupAddAgentReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// ID which identifies the scanner the agents are linked to
+    pub scanner_id: u64,
+    /// ID which identifies the agent group
+    pub group_id: u64,
+    /// ID which identifies the agent
+    pub agent_id: u64,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for AgentGroupAddAgentReq<'_> {
+    type Output = Option<()>;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let req = Request::builder()
+            .uri(format!(
+                "{}/scanners/{scanner_id}/agent-groups/{group_id}/agents/{agent_id}",
+                self.tenable.uri,
+                scanner_id = self.scanner_id,
+                group_id = self.group_id,
+                agent_id = self.agent_id
+            ))
+            .method(Method::PUT)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .body(Vec::new())?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            StatusCode::NOT_FOUND => return Ok(None),
+            _ => return Err(Error::from_response(res)),
+        }
+        Ok(Some(()))
+    }
+}
+
+impl<'a> From<AgentGroupAddAgentReq<'a>> for Cow<'a, AgentGroupAddAgentReq<'a>> {
+    #[inline]
+    fn from(req: AgentGroupAddAgentReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a AgentGroupAddAgentReq<'a>> for Cow<'a, AgentGroupAddAgentReq<'a>> {
+    #[inline]
+    fn from(req: &'a AgentGroupAddAgentReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
@@ -0,0 +1,67 @@
+use crate::{types::AgentBulkTask, Error, HttpRequest, Response, Tenable};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `agent_group_bulk_status` function
+#[derive(Clone, Debug)]
+pub struct AgentGroupBulkStatusReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// ID which identifies the scanner the agents are linked to
+    pub scanner_id: u64,
+    /// ID which identifies the agent group
+    pub group_id: u64,
+    /// UUID which identifies the bulk operation
+    pub task_uuid: Cow<'a, str>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for AgentGroupBulkStatusReq<'_> {
+    type Output = Option<AgentBulkTask>;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let req = Request::builder()
+            .uri(format!(
+                "{}/scanners/{scanner_id}/agent-groups/{group_id}/agents/_bulk/{task_uuid}",
+                self.tenable.uri,
+                scanner_id = self.scanner_id,
+                group_id = self.group_id,
+                task_uuid = self.task_uuid
+            ))
+            .method(Method::GET)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .body(Vec::new())?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            StatusCode::NOT_FOUND => return Ok(None),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(Some(data))
+    }
+}
+
+impl<'a> From<AgentGroupBulkStatusReq<'a>> for Cow<'a, AgentGroupBulkStatusReq<'a>> {
+    #[inline]
+    fn from(req: AgentGroupBulkStatusReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a AgentGroupBulkStatusReq<'a>> for Cow<'a, AgentGroupBulkStatusReq<'a>> {
+    #[inline]
+    fn from(req: &'a AgentGroupBulkStatusReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
@@ -0,0 +1,64 @@
+use crate::{types::AgentGroup, Error, HttpRequest, Response, Tenable};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `agent_group_by_id` function
+#[derive(Clone, Debug)]
+pub struct AgentGroupByIdReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// ID which identifies the scanner the agents are linked to
+    pub scanner_id: u64,
+    /// ID which identifies the agent group
+    pub group_id: u64,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for AgentGroupByIdReq<'_> {
+    type Output = Option<AgentGroup>;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let req = Request::builder()
+            .uri(format!(
+                "{}/scanners/{scanner_id}/agent-groups/{group_id}",
+                self.tenable.uri,
+                scanner_id = self.scanner_id,
+                group_id = self.group_id
+            ))
+            .method(Method::GET)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .body(Vec::new())?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            StatusCode::NOT_FOUND => return Ok(None),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(Some(data))
+    }
+}
+
+impl<'a> From<AgentGroupByIdReq<'a>> for Cow<'a, AgentGroupByIdReq<'a>> {
+    #[inline]
+    fn from(req: AgentGroupByIdReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a AgentGroupByIdReq<'a>> for Cow<'a, AgentGroupByIdReq<'a>> {
+    #[inline]
+    fn from(req: &'a AgentGroupByIdReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
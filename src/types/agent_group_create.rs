@@ -0,0 +1,88 @@
+use crate::{types::AgentGroup, Error, HttpRequest, Response, Tenable};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `agent_group_create` function
+#[derive(Clone, Debug)]
+pub struct AgentGroupCreateReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// ID which identifies the scanner the agents are linked to
+    pub scanner_id: u64,
+    /// Definition which details the agent group
+    pub agent_group_def: Cow<'a, AgentGroupDef>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for AgentGroupCreateReq<'_> {
+    type Output = Option<AgentGroup>;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let payload = serde_json::to_vec(&self.agent_group_def)?;
+        let req = Request::builder()
+            .uri(format!(
+                "{}/scanners/{scanner_id}/agent-groups",
+                self.tenable.uri,
+                scanner_id = self.scanner_id
+            ))
+            .method(Method::POST)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .header("Content-Type", HeaderValue::from_static("application/json"))
+            .body(payload)?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            StatusCode::NOT_FOUND => return Ok(None),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(Some(data))
+    }
+}
+
+impl<'a> From<AgentGroupCreateReq<'a>> for Cow<'a, AgentGroupCreateReq<'a>> {
+    #[inline]
+    fn from(req: AgentGroupCreateReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a AgentGroupCreateReq<'a>> for Cow<'a, AgentGroupCreateReq<'a>> {
+    #[inline]
+    fn from(req: &'a AgentGroupCreateReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+/// Details an agent group to create or update
+pub struct AgentGroupDef {
+    /// The name of the agent group.
+    #[serde(rename = "name")]
+    pub name: String,
+}
+
+impl From<AgentGroupDef> for Cow<'_, AgentGroupDef> {
+    #[inline]
+    fn from(req: AgentGroupDef) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a AgentGroupDef> for Cow<'a, AgentGroupDef> {
+    #[inline]
+    fn from(req: &'a AgentGroupDef) -> Self {
+        Cow::Borrowed(req)
+    }
+}
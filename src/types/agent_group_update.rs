@@ -0,0 +1,71 @@
+use crate::{
+    types::{AgentGroup, AgentGroupDef},
+    Error, HttpRequest, Response, Tenable,
+};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `agent_group_update` function
+#[derive(Clone, Debug)]
+pub struct AgentGroupUpdateReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// ID which identifies the scanner the agents are linked to
+    pub scanner_id: u64,
+    /// ID which identifies the agent group
+    pub group_id: u64,
+    /// Definition which details the agent group
+    pub agent_group_def: Cow<'a, AgentGroupDef>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for AgentGroupUpdateReq<'_> {
+    type Output = Option<AgentGroup>;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let payload = serde_json::to_vec(&self.agent_group_def)?;
+        let req = Request::builder()
+            .uri(format!(
+                "{}/scanners/{scanner_id}/agent-groups/{group_id}",
+                self.tenable.uri,
+                scanner_id = self.scanner_id,
+                group_id = self.group_id
+            ))
+            .method(Method::PUT)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .header("Content-Type", HeaderValue::from_static("application/json"))
+            .body(payload)?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            StatusCode::NOT_FOUND => return Ok(None),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(Some(data))
+    }
+}
+
+impl<'a> From<AgentGroupUpdateReq<'a>> for Cow<'a, AgentGroupUpdateReq<'a>> {
+    #[inline]
+    fn from(req: AgentGroupUpdateReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a AgentGroupUpdateReq<'a>> for Cow<'a, AgentGroupUpdateReq<'a>> {
+    #[inline]
+    fn from(req: &'a AgentGroupUpdateReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
@@ -0,0 +1,111 @@
+use crate::{
+    types::{Agent, Pagination},
+    Error, HttpRequest, Response, Tenable,
+};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `agent_groups` function
+#[derive(Clone, Debug)]
+pub struct AgentGroupsReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// ID which identifies the scanner the agents are linked to
+    pub scanner_id: u64,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for AgentGroupsReq<'_> {
+    type Output = Option<AgentGroups>;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let req = Request::builder()
+            .uri(format!(
+                "{}/scanners/{scanner_id}/agent-groups",
+                self.tenable.uri,
+                scanner_id = self.scanner_id
+            ))
+            .method(Method::GET)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .body(Vec::new())?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            StatusCode::NOT_FOUND => return Ok(None),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(Some(data))
+    }
+}
+
+impl<'a> From<AgentGroupsReq<'a>> for Cow<'a, AgentGroupsReq<'a>> {
+    #[inline]
+    fn from(req: AgentGroupsReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a AgentGroupsReq<'a>> for Cow<'a, AgentGroupsReq<'a>> {
+    #[inline]
+    fn from(req: &'a AgentGroupsReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Agent groups returned by `agent_groups`
+pub struct AgentGroups {
+    /// The agent groups.
+    #[serde(rename = "groups", skip_serializing_if = "Option::is_none")]
+    pub groups: Option<Vec<AgentGroup>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Represents a group of agents, used as target of agent scans
+pub struct AgentGroup {
+    /// The ID of the agent group.
+    #[serde(rename = "id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    /// The UUID of the agent group.
+    #[serde(rename = "uuid", skip_serializing_if = "Option::is_none")]
+    pub uuid: Option<String>,
+    /// The name of the agent group.
+    #[serde(rename = "name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The number of agents in the group.
+    #[serde(rename = "agents_count", skip_serializing_if = "Option::is_none")]
+    pub agents_count: Option<u64>,
+    /// The name of the owner of the agent group.
+    #[serde(rename = "owner_name", skip_serializing_if = "Option::is_none")]
+    pub owner_name: Option<String>,
+    /// The UUID of the owner of the agent group.
+    #[serde(rename = "owner_uuid", skip_serializing_if = "Option::is_none")]
+    pub owner_uuid: Option<String>,
+    /// The unix timestamp when the agent group was created.
+    #[serde(rename = "creation_date", skip_serializing_if = "Option::is_none")]
+    pub creation_date: Option<u64>,
+    /// The unix timestamp when the agent group was last updated.
+    #[serde(
+        rename = "last_modification_date",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub last_modification_date: Option<u64>,
+    /// The agents in the group. Only returned by `agent_group_by_id`.
+    #[serde(rename = "agents", skip_serializing_if = "Option::is_none")]
+    pub agents: Option<Vec<Agent>>,
+    /// The position of the returned agents within all agents of the group. Only returned by `agent_group_by_id`.
+    #[serde(rename = "pagination", skip_serializing_if = "Option::is_none")]
+    pub pagination: Option<Pagination>,
+}
@@ -0,0 +1,63 @@
+use crate::{Error, HttpRequest, Response, Tenable};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `agent_unlink` function
+#[derive(Clone, Debug)]
+pub struct AgentUnlinkReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// ID which identifies the scanner the agents are linked to
+    pub scanner_id: u64,
+    /// ID which identifies the agent
+    pub agent_id: u64,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for AgentUnlinkReq<'_> {
+    type Output = Option<()>;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let req = Request::builder()
+            .uri(format!(
+                "{}/scanners/{scanner_id}/agents/{agent_id}",
+                self.tenable.uri,
+                scanner_id = self.scanner_id,
+                agent_id = self.agent_id
+            ))
+            .method(Method::DELETE)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .body(Vec::new())?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            StatusCode::NOT_FOUND => return Ok(None),
+            _ => return Err(Error::from_response(res)),
+        }
+        Ok(Some(()))
+    }
+}
+
+impl<'a> From<AgentUnlinkReq<'a>> for Cow<'a, AgentUnlinkReq<'a>> {
+    #[inline]
+    fn from(req: AgentUnlinkReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a AgentUnlinkReq<'a>> for Cow<'a, AgentUnlinkReq<'a>> {
+    #[inline]
+    fn from(req: &'a AgentUnlinkReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
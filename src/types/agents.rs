@@ -0,0 +1,177 @@
+use crate::{
+    types::{encode_query, FilterType, Pagination, QueryFilter},
+    Error, HttpRequest, Response, Tenable,
+};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `agents` function
+#[derive(Clone, Debug)]
+pub struct AgentsReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// ID which identifies the scanner the agents are linked to
+    pub scanner_id: u64,
+    /// Returns only agents matching the filters, for example `status:eq:off`
+    pub filters: Option<Vec<QueryFilter>>,
+    /// Defines whether agents must match all or any of the filters
+    pub filter_type: Option<FilterType>,
+    /// Returns only agents containing the given text in their name, platform or IP address
+    pub wildcard: Option<Cow<'a, str>>,
+    /// Maximum number of agents to return
+    pub limit: Option<u64>,
+    /// Number of agents to skip
+    pub offset: Option<u64>,
+    /// Sorts the agents by the given field and order, for example `last_connect:desc`
+    pub sort: Option<Cow<'a, str>>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for AgentsReq<'_> {
+    type Output = Option<Agents>;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let mut query = Vec::new();
+        if let Some(ref filters) = self.filters {
+            for filter in filters.iter() {
+                query.push(format!("f={}", encode_query(&filter.to_string())));
+            }
+        }
+        if let Some(filter_type) = self.filter_type {
+            query.push(format!("ft={}", filter_type));
+        }
+        if let Some(ref wildcard) = self.wildcard {
+            query.push(format!("w={}", encode_query(wildcard)));
+        }
+        if let Some(limit) = self.limit {
+            query.push(format!("limit={}", limit));
+        }
+        if let Some(offset) = self.offset {
+            query.push(format!("offset={}", offset));
+        }
+        if let Some(ref sort) = self.sort {
+            query.push(format!("sort={}", encode_query(sort)));
+        }
+        let mut uri = format!(
+            "{}/scanners/{scanner_id}/agents",
+            self.tenable.uri,
+            scanner_id = self.scanner_id
+        );
+        if !query.is_empty() {
+            uri = format!("{}?{}", uri, query.join("&"));
+        }
+        let req = Request::builder()
+            .uri(uri)
+            .method(Method::GET)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .body(Vec::new())?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            StatusCode::NOT_FOUND => return Ok(None),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(Some(data))
+    }
+}
+
+impl<'a> From<AgentsReq<'a>> for Cow<'a, AgentsReq<'a>> {
+    #[inline]
+    fn from(req: AgentsReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a AgentsReq<'a>> for Cow<'a, AgentsReq<'a>> {
+    #[inline]
+    fn from(req: &'a AgentsReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Agents returned by `agents`
+pub struct Agents {
+    /// The agents.
+    #[serde(rename = "agents", skip_serializing_if = "Option::is_none")]
+    pub agents: Option<Vec<Agent>>,
+    /// The position of the returned agents within all agents.
+    #[serde(rename = "pagination", skip_serializing_if = "Option::is_none")]
+    pub pagination: Option<Pagination>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Represents a Nessus agent linked to Tenable.io
+pub struct Agent {
+    /// The ID of the agent.
+    #[serde(rename = "id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    /// The UUID of the agent.
+    #[serde(rename = "uuid", skip_serializing_if = "Option::is_none")]
+    pub uuid: Option<String>,
+    /// The name of the agent, usually the hostname of the asset.
+    #[serde(rename = "name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The platform the agent runs on, like `LINUX` or `WINDOWS`.
+    #[serde(rename = "platform", skip_serializing_if = "Option::is_none")]
+    pub platform: Option<String>,
+    /// The distribution of the operating system the agent runs on.
+    #[serde(rename = "distro", skip_serializing_if = "Option::is_none")]
+    pub distro: Option<String>,
+    /// The IP address of the asset the agent runs on.
+    #[serde(rename = "ip", skip_serializing_if = "Option::is_none")]
+    pub ip: Option<String>,
+    /// The status of the agent, like `on`, `off` or `init`.
+    #[serde(rename = "status", skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    /// The version of the agent.
+    #[serde(rename = "core_version", skip_serializing_if = "Option::is_none")]
+    pub core_version: Option<String>,
+    /// The build of the agent.
+    #[serde(rename = "core_build", skip_serializing_if = "Option::is_none")]
+    pub core_build: Option<String>,
+    /// The version of the plugins loaded by the agent.
+    #[serde(rename = "plugin_feed_id", skip_serializing_if = "Option::is_none")]
+    pub plugin_feed_id: Option<String>,
+    /// The unix timestamp when the agent was linked.
+    #[serde(rename = "linked_on", skip_serializing_if = "Option::is_none")]
+    pub linked_on: Option<u64>,
+    /// The unix timestamp when the agent last connected to Tenable.io.
+    #[serde(rename = "last_connect", skip_serializing_if = "Option::is_none")]
+    pub last_connect: Option<u64>,
+    /// The unix timestamp when the agent last scanned the asset.
+    #[serde(rename = "last_scanned", skip_serializing_if = "Option::is_none")]
+    pub last_scanned: Option<u64>,
+    /// The groups the agent belongs to.
+    #[serde(rename = "groups", skip_serializing_if = "Option::is_none")]
+    pub groups: Option<Vec<AgentGroupRef>>,
+    /// The UUID of the network the agent belongs to.
+    #[serde(rename = "network_uuid", skip_serializing_if = "Option::is_none")]
+    pub network_uuid: Option<String>,
+    /// The name of the network the agent belongs to.
+    #[serde(rename = "network_name", skip_serializing_if = "Option::is_none")]
+    pub network_name: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Agent group an agent belongs to
+pub struct AgentGroupRef {
+    /// The ID of the agent group.
+    #[serde(rename = "id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    /// The name of the agent group.
+    #[serde(rename = "name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
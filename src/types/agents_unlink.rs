@@ -0,0 +1,180 @@
+use crate::{
+    types::{FilterType, QueryFilter},
+    Error, HttpRequest, Response, Tenable,
+};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `agents_unlink` function
+#[derive(Clone, Debug)]
+pub struct AgentsUnlinkReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// ID which identifies the scanner the agents are linked to
+    pub scanner_id: u64,
+    /// Definition which details the agents to unlink
+    pub agent_bulk_def: Cow<'a, AgentBulkDef>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for AgentsUnlinkReq<'_> {
+    type Output = Option<AgentBulkTask>;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let payload = serde_json::to_vec(&self.agent_bulk_def)?;
+        let req = Request::builder()
+            .uri(format!(
+                "{}/scanners/{scanner_id}/agents/_bulk/unlink",
+                self.tenable.uri,
+                scanner_id = self.scanner_id
+            ))
+            .method(Method::POST)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .header("Content-Type", HeaderValue::from_static("application/json"))
+            .body(payload)?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            StatusCode::NOT_FOUND => return Ok(None),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(Some(data))
+    }
+}
+
+impl<'a> From<AgentsUnlinkReq<'a>> for Cow<'a, AgentsUnlinkReq<'a>> {
+    #[inline]
+    fn from(req: AgentsUnlinkReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a AgentsUnlinkReq<'a>> for Cow<'a, AgentsUnlinkReq<'a>> {
+    #[inline]
+    fn from(req: &'a AgentsUnlinkReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+/// Details the agents a bulk operation applies to. Either list the agents in
+/// `items` or select them using `criteria`.
+pub struct AgentBulkDef {
+    /// The IDs of the agents to include.
+    #[serde(rename = "items", skip_serializing_if = "Option::is_none")]
+    pub items: Option<Vec<u64>>,
+    /// The IDs of the agents to exclude, even if they match `criteria`.
+    #[serde(rename = "not_items", skip_serializing_if = "Option::is_none")]
+    pub not_items: Option<Vec<u64>>,
+    /// The criteria selecting the agents to include.
+    #[serde(rename = "criteria", skip_serializing_if = "Option::is_none")]
+    pub criteria: Option<AgentBulkCriteria>,
+}
+
+impl From<AgentBulkDef> for Cow<'_, AgentBulkDef> {
+    #[inline]
+    fn from(req: AgentBulkDef) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a AgentBulkDef> for Cow<'a, AgentBulkDef> {
+    #[inline]
+    fn from(req: &'a AgentBulkDef) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+/// Selects the agents a bulk operation applies to
+pub struct AgentBulkCriteria {
+    /// Includes all agents. Use `not_items` to exclude some of them.
+    #[serde(rename = "all_agents", skip_serializing_if = "Option::is_none")]
+    pub all_agents: Option<bool>,
+    /// Includes the agents matching the filters, for example `last_connect:lt:1590969600`.
+    #[serde(rename = "filters", skip_serializing_if = "Option::is_none")]
+    pub filters: Option<Vec<QueryFilter>>,
+    /// Defines whether agents must match all or any of the filters.
+    #[serde(rename = "filter_type", skip_serializing_if = "Option::is_none")]
+    pub filter_type: Option<FilterType>,
+    /// Includes the agents containing the given text in their name, platform or IP address.
+    #[serde(rename = "wildcard", skip_serializing_if = "Option::is_none")]
+    pub wildcard: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Progress of a bulk operation on agents
+pub struct AgentBulkTask {
+    /// The UUID of the bulk operation.
+    #[serde(rename = "task_id", skip_serializing_if = "Option::is_none")]
+    pub task_id: Option<String>,
+    /// The UUID of the container the agents belong to.
+    #[serde(rename = "container_uuid", skip_serializing_if = "Option::is_none")]
+    pub container_uuid: Option<String>,
+    /// The state of the bulk operation.
+    #[serde(rename = "status")]
+    pub status: AgentBulkTaskState,
+    /// Details about the state, like the reason the operation failed.
+    #[serde(rename = "message", skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// The number of agents the operation applies to.
+    #[serde(rename = "total_count", skip_serializing_if = "Option::is_none")]
+    pub total_count: Option<u64>,
+    /// The number of agents the operation has already processed.
+    #[serde(rename = "processed_count", skip_serializing_if = "Option::is_none")]
+    pub processed_count: Option<u64>,
+    /// The number of agents the operation failed for.
+    #[serde(rename = "failure_count", skip_serializing_if = "Option::is_none")]
+    pub failure_count: Option<u64>,
+    /// The unix timestamp in milliseconds when the operation started.
+    #[serde(rename = "start_time", skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<u64>,
+    /// The unix timestamp in milliseconds when the operation was last updated.
+    #[serde(rename = "last_update_time", skip_serializing_if = "Option::is_none")]
+    pub last_update_time: Option<u64>,
+    /// The unix timestamp in milliseconds when the operation ended.
+    #[serde(rename = "end_time", skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<u64>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Copy)]
+/// The states a bulk operation on agents passes through
+pub enum AgentBulkTaskState {
+    /// Tenable.io has queued the operation.
+    #[serde(rename = "NEW")]
+    New,
+    /// Tenable.io is processing the operation.
+    #[serde(rename = "RUNNING")]
+    Running,
+    /// Tenable.io has applied the operation to all agents.
+    #[serde(rename = "COMPLETED")]
+    Completed,
+    /// Tenable.io encountered an error while processing the operation.
+    #[serde(rename = "FAILED")]
+    Failed,
+    /// A state this crate does not know about yet.
+    #[serde(other)]
+    Unknown,
+}
+
+impl AgentBulkTaskState {
+    /// Returns whether the operation has ended, successfully or not, and does
+    /// not have to be polled anymore.
+    #[must_use]
+    #[inline]
+    pub fn is_finished(self) -> bool {
+        matches!(self, Self::Completed | Self::Failed)
+    }
+}
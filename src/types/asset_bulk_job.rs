@@ -0,0 +1,118 @@
+use crate::{Error, HttpRequest, Response, Tenable};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `asset_bulk_job` function
+#[derive(Clone, Debug)]
+pub struct AssetBulkJobReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// UUID which identifies the bulk job
+    pub job_uuid: Cow<'a, str>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for AssetBulkJobReq<'_> {
+    type Output = Option<AssetBulkJob>;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let req = Request::builder()
+            .uri(format!(
+                "{}/api/v2/assets/bulk-jobs/{job_uuid}",
+                self.tenable.uri,
+                job_uuid = self.job_uuid
+            ))
+            .method(Method::GET)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .body(Vec::new())?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            StatusCode::NOT_FOUND => return Ok(None),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(Some(data))
+    }
+}
+
+impl<'a> From<AssetBulkJobReq<'a>> for Cow<'a, AssetBulkJobReq<'a>> {
+    #[inline]
+    fn from(req: AssetBulkJobReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a AssetBulkJobReq<'a>> for Cow<'a, AssetBulkJobReq<'a>> {
+    #[inline]
+    fn from(req: &'a AssetBulkJobReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Progress of a bulk asset operation as returned by `asset_bulk_job`
+pub struct AssetBulkJob {
+    /// The UUID of the job.
+    #[serde(rename = "job_uuid", skip_serializing_if = "Option::is_none")]
+    pub job_uuid: Option<String>,
+    /// The operation the job performs, like `DELETE`, `MOVE` or `ACR`.
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub job_type: Option<String>,
+    /// The state of the job.
+    #[serde(rename = "status")]
+    pub status: AssetBulkJobState,
+    /// The number of assets the job operates on.
+    #[serde(rename = "asset_count", skip_serializing_if = "Option::is_none")]
+    pub asset_count: Option<i32>,
+    /// The number of assets the job has already processed.
+    #[serde(rename = "processed_count", skip_serializing_if = "Option::is_none")]
+    pub processed_count: Option<i32>,
+    /// The ISO timestamp when the job was created.
+    #[serde(rename = "created_at", skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    /// The ISO timestamp when the job was last updated.
+    #[serde(rename = "updated_at", skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Copy)]
+/// The states a bulk asset operation passes through
+pub enum AssetBulkJobState {
+    /// Tenable.io has queued the job until it completes other jobs currently in process.
+    #[serde(rename = "QUEUED")]
+    Queued,
+    /// Tenable.io has started processing the job.
+    #[serde(rename = "PROCESSING")]
+    Processing,
+    /// Tenable.io has applied the operation to all assets.
+    #[serde(rename = "COMPLETED")]
+    Completed,
+    /// Tenable.io encountered an error while processing the job.
+    #[serde(rename = "FAILED")]
+    Failed,
+    /// A state this crate does not know about yet.
+    #[serde(other)]
+    Unknown,
+}
+
+impl AssetBulkJobState {
+    /// Returns whether the job has ended, successfully or not, and does not
+    /// have to be polled anymore.
+    #[must_use]
+    #[inline]
+    pub fn is_finished(self) -> bool {
+        matches!(self, Self::Completed | Self::Failed)
+    }
+}
@@ -43,7 +43,7 @@ impl<RE: fmt::Debug> HttpRequest<RE> for AssetByUuidReq<'_> {
             StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
             StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
             StatusCode::NOT_FOUND => return Ok(None),
-            code => return Err(Error::UnexpectedStatusCode(code)),
+            _ => return Err(Error::from_response(res)),
         }
         let data = serde_json::from_slice(&res.body)?;
         Ok(Some(data))
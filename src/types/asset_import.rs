@@ -0,0 +1,275 @@
+use crate::{Error, HttpRequest, Response, Tenable};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `asset_import` function
+#[derive(Clone, Debug)]
+pub struct AssetImportReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// Name of the source the assets are imported from
+    pub source: Cow<'a, str>,
+    /// Assets to import, at most `ASSET_IMPORT_BATCH_SIZE` and `ASSET_IMPORT_BATCH_BYTES`
+    pub assets: Cow<'a, [ImportAsset]>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for AssetImportReq<'_> {
+    type Output = AssetImportJobUuid;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let payload = serde_json::to_vec(&AssetImportPayload {
+            source: &self.source,
+            assets: &self.assets,
+        })?;
+        let req = Request::builder()
+            .uri(format!("{}/import/assets", self.tenable.uri))
+            .method(Method::POST)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .header("Content-Type", HeaderValue::from_static("application/json"))
+            .body(payload)?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(data)
+    }
+}
+
+impl<'a> From<AssetImportReq<'a>> for Cow<'a, AssetImportReq<'a>> {
+    #[inline]
+    fn from(req: AssetImportReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a AssetImportReq<'a>> for Cow<'a, AssetImportReq<'a>> {
+    #[inline]
+    fn from(req: &'a AssetImportReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+/// Maximum number of assets Tenable.io accepts within a single import
+pub const ASSET_IMPORT_BATCH_SIZE: usize = 5_000;
+
+/// Maximum size in bytes of the body Tenable.io accepts for a single import
+pub const ASSET_IMPORT_BATCH_BYTES: usize = 5_000_000;
+
+impl<'a> AssetImportReq<'a> {
+    /// Splits the assets into requests of at most `ASSET_IMPORT_BATCH_SIZE`
+    /// assets and `ASSET_IMPORT_BATCH_BYTES` bytes. An asset which exceeds the
+    /// size limit on its own is sent in a request of its own.
+    pub(crate) fn batches(
+        tenable: &'a Tenable<'a>,
+        source: Cow<'a, str>,
+        assets: Cow<'a, [ImportAsset]>,
+    ) -> Vec<Self> {
+        let lengths = batch_lengths(&source, &assets);
+        let batch = |assets| Self {
+            tenable,
+            source: source.clone(),
+            assets,
+        };
+        match assets {
+            Cow::Borrowed(mut assets) => lengths
+                .into_iter()
+                .map(|length| {
+                    let (chunk, rest) = assets.split_at(length);
+                    assets = rest;
+                    batch(Cow::Borrowed(chunk))
+                })
+                .collect(),
+            Cow::Owned(assets) => {
+                let mut assets = assets.into_iter();
+                lengths
+                    .into_iter()
+                    .map(|length| batch(Cow::Owned(assets.by_ref().take(length).collect())))
+                    .collect()
+            }
+        }
+    }
+}
+
+/// Number of assets within each batch
+fn batch_lengths(source: &str, assets: &[ImportAsset]) -> Vec<usize> {
+    // serialization errors are reported once the request is built
+    let empty = serde_json::to_vec(&AssetImportPayload {
+        source,
+        assets: &[],
+    })
+    .map_or(0, |json| json.len());
+    let mut lengths = Vec::new();
+    let (mut length, mut bytes) = (0, empty);
+    for asset in assets {
+        // one more byte for the comma separating the assets
+        let asset_bytes = serde_json::to_vec(asset).map_or(0, |json| json.len()) + 1;
+        if length > 0
+            && (length == ASSET_IMPORT_BATCH_SIZE || bytes + asset_bytes > ASSET_IMPORT_BATCH_BYTES)
+        {
+            lengths.push(length);
+            length = 0;
+            bytes = empty;
+        }
+        length += 1;
+        bytes += asset_bytes;
+    }
+    if length > 0 {
+        lengths.push(length);
+    }
+    lengths
+}
+
+/// Body of the `asset_import` request
+#[derive(Serialize)]
+struct AssetImportPayload<'a> {
+    source: &'a str,
+    assets: &'a [ImportAsset],
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+/// Asset to import. Uses the attribute names of `AssetByUuid`. Tenable.io
+/// requires at least one of `fqdn`, `ipv4`, `netbios_name` or `mac_address`.
+pub struct ImportAsset {
+    /// The fully-qualified domain names of the asset.
+    #[serde(rename = "fqdn", skip_serializing_if = "Option::is_none")]
+    pub fqdn: Option<Vec<String>>,
+    /// The IPv4 addresses of the asset.
+    #[serde(rename = "ipv4", skip_serializing_if = "Option::is_none")]
+    pub ipv4: Option<Vec<String>>,
+    /// The IPv6 addresses of the asset.
+    #[serde(rename = "ipv6", skip_serializing_if = "Option::is_none")]
+    pub ipv6: Option<Vec<String>>,
+    /// The MAC addresses of the asset.
+    #[serde(rename = "mac_address", skip_serializing_if = "Option::is_none")]
+    pub mac_address: Option<Vec<String>>,
+    /// The hostnames of the asset.
+    #[serde(rename = "hostname", skip_serializing_if = "Option::is_none")]
+    pub hostname: Option<Vec<String>>,
+    /// The NetBIOS name of the asset.
+    #[serde(rename = "netbios_name", skip_serializing_if = "Option::is_none")]
+    pub netbios_name: Option<String>,
+    /// The operating systems running on the asset.
+    #[serde(rename = "operating_system", skip_serializing_if = "Option::is_none")]
+    pub operating_system: Option<Vec<String>>,
+    /// The software installed on the asset as CPE 2.2 values.
+    #[serde(rename = "installed_software", skip_serializing_if = "Option::is_none")]
+    pub installed_software: Option<Vec<String>>,
+    /// The SSH key fingerprint of the asset.
+    #[serde(rename = "ssh_fingerprint", skip_serializing_if = "Option::is_none")]
+    pub ssh_fingerprint: Option<String>,
+    /// The BIOS UUID of the asset.
+    #[serde(rename = "bios_uuid", skip_serializing_if = "Option::is_none")]
+    pub bios_uuid: Option<String>,
+    /// The unique identifier of the instance in Amazon EC2.
+    #[serde(
+        rename = "aws_ec2_instance_id",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub aws_ec2_instance_id: Option<String>,
+    /// The unique identifier of the AMI image the instance was launched from.
+    #[serde(
+        rename = "aws_ec2_instance_ami_id",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub aws_ec2_instance_ami_id: Option<String>,
+    /// The canonical user identifier of the AWS account owning the instance.
+    #[serde(rename = "aws_owner_id", skip_serializing_if = "Option::is_none")]
+    pub aws_owner_id: Option<String>,
+    /// The availability zone where AWS hosts the instance, for example `us-east-1a`.
+    #[serde(
+        rename = "aws_availability_zone",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub aws_availability_zone: Option<String>,
+    /// The region where AWS hosts the instance, for example `us-east-1`.
+    #[serde(rename = "aws_region", skip_serializing_if = "Option::is_none")]
+    pub aws_region: Option<String>,
+    /// The unique identifier of the AWS virtual private cloud of the instance.
+    #[serde(rename = "aws_vpc_id", skip_serializing_if = "Option::is_none")]
+    pub aws_vpc_id: Option<String>,
+    /// The group of the instance in AWS.
+    #[serde(
+        rename = "aws_ec2_instance_group_name",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub aws_ec2_instance_group_name: Option<String>,
+    /// The state of the instance in AWS, for example `running`.
+    #[serde(
+        rename = "aws_ec2_instance_state_name",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub aws_ec2_instance_state_name: Option<String>,
+    /// The type of the instance in AWS EC2.
+    #[serde(
+        rename = "aws_ec2_instance_type",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub aws_ec2_instance_type: Option<String>,
+    /// The unique identifier of the AWS subnet of the instance.
+    #[serde(rename = "aws_subnet_id", skip_serializing_if = "Option::is_none")]
+    pub aws_subnet_id: Option<String>,
+    /// The product code of the AMI the instance was launched from.
+    #[serde(
+        rename = "aws_ec2_product_code",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub aws_ec2_product_code: Option<String>,
+    /// The name of the instance in AWS EC2.
+    #[serde(rename = "aws_ec2_name", skip_serializing_if = "Option::is_none")]
+    pub aws_ec2_name: Option<String>,
+    /// The unique identifier of the Microsoft Azure virtual machine.
+    #[serde(rename = "azure_vm_id", skip_serializing_if = "Option::is_none")]
+    pub azure_vm_id: Option<String>,
+    /// The unique identifier of the resource in the Azure Resource Manager.
+    #[serde(rename = "azure_resource_id", skip_serializing_if = "Option::is_none")]
+    pub azure_resource_id: Option<String>,
+    /// The project of the instance in Google Cloud Platform.
+    #[serde(rename = "gcp_project_id", skip_serializing_if = "Option::is_none")]
+    pub gcp_project_id: Option<String>,
+    /// The zone where the instance runs in Google Cloud Platform.
+    #[serde(rename = "gcp_zone", skip_serializing_if = "Option::is_none")]
+    pub gcp_zone: Option<String>,
+    /// The unique identifier of the instance in Google Cloud Platform.
+    #[serde(rename = "gcp_instance_id", skip_serializing_if = "Option::is_none")]
+    pub gcp_instance_id: Option<String>,
+    /// The unique identifier of the asset in McAfee ePolicy Orchestrator.
+    #[serde(rename = "mcafee_epo_guid", skip_serializing_if = "Option::is_none")]
+    pub mcafee_epo_guid: Option<String>,
+    /// The unique identifier of the McAfee ePO agent of the asset.
+    #[serde(
+        rename = "mcafee_epo_agent_guid",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub mcafee_epo_agent_guid: Option<String>,
+    /// The Asset ID of the asset in Qualys.
+    #[serde(rename = "qualys_asset_id", skip_serializing_if = "Option::is_none")]
+    pub qualys_asset_id: Option<String>,
+    /// The Host ID of the asset in Qualys.
+    #[serde(rename = "qualys_host_id", skip_serializing_if = "Option::is_none")]
+    pub qualys_host_id: Option<String>,
+    /// The unique record identifier of the asset in ServiceNow.
+    #[serde(rename = "servicenow_sysid", skip_serializing_if = "Option::is_none")]
+    pub servicenow_sysid: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Import job returned by `asset_import`
+pub struct AssetImportJobUuid {
+    /// The UUID of the job importing the assets. Use `asset_import_job` to follow it up.
+    #[serde(rename = "asset_import_job_uuid")]
+    pub asset_import_job_uuid: String,
+}
@@ -0,0 +1,61 @@
+use crate::{types::AssetImportJob, Error, HttpRequest, Response, Tenable};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `asset_import_job` function
+#[derive(Clone, Debug)]
+pub struct AssetImportJobReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// UUID which identifies the import job
+    pub job_uuid: Cow<'a, str>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for AssetImportJobReq<'_> {
+    type Output = Option<AssetImportJob>;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let req = Request::builder()
+            .uri(format!(
+                "{}/import/asset-jobs/{job_uuid}",
+                self.tenable.uri,
+                job_uuid = self.job_uuid
+            ))
+            .method(Method::GET)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .body(Vec::new())?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            StatusCode::NOT_FOUND => return Ok(None),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(Some(data))
+    }
+}
+
+impl<'a> From<AssetImportJobReq<'a>> for Cow<'a, AssetImportJobReq<'a>> {
+    #[inline]
+    fn from(req: AssetImportJobReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a AssetImportJobReq<'a>> for Cow<'a, AssetImportJobReq<'a>> {
+    #[inline]
+    fn from(req: &'a AssetImportJobReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
@@ -0,0 +1,101 @@
+use crate::{Error, HttpRequest, Response, Tenable};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `asset_import_jobs` function
+#[derive(Clone, Debug)]
+pub struct AssetImportJobsReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for AssetImportJobsReq<'_> {
+    type Output = AssetImportJobs;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let req = Request::builder()
+            .uri(format!("{}/import/asset-jobs", self.tenable.uri))
+            .method(Method::GET)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .body(Vec::new())?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(data)
+    }
+}
+
+impl<'a> From<AssetImportJobsReq<'a>> for Cow<'a, AssetImportJobsReq<'a>> {
+    #[inline]
+    fn from(req: AssetImportJobsReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a AssetImportJobsReq<'a>> for Cow<'a, AssetImportJobsReq<'a>> {
+    #[inline]
+    fn from(req: &'a AssetImportJobsReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Asset import jobs returned by `asset_import_jobs` and `asset_imports`
+pub struct AssetImportJobs {
+    /// The import jobs.
+    #[serde(rename = "asset_import_jobs", skip_serializing_if = "Option::is_none")]
+    pub asset_import_jobs: Option<Vec<AssetImportJob>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Represents a job importing assets
+pub struct AssetImportJob {
+    /// The UUID of the job.
+    #[serde(rename = "job_id", skip_serializing_if = "Option::is_none")]
+    pub job_id: Option<String>,
+    /// The UUID of the container the assets are imported into.
+    #[serde(rename = "container_id", skip_serializing_if = "Option::is_none")]
+    pub container_id: Option<String>,
+    /// The name of the source the assets are imported from.
+    #[serde(rename = "source", skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+    /// The status of the job, like `COMPLETE` or `FAILED`.
+    #[serde(rename = "status", skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    /// Details about the status of the job, like the reason it failed.
+    #[serde(rename = "status_message", skip_serializing_if = "Option::is_none")]
+    pub status_message: Option<String>,
+    /// The number of batches the job consists of.
+    #[serde(rename = "batches", skip_serializing_if = "Option::is_none")]
+    pub batches: Option<u64>,
+    /// The number of assets Tenable.io imported.
+    #[serde(rename = "uploaded_assets", skip_serializing_if = "Option::is_none")]
+    pub uploaded_assets: Option<u64>,
+    /// The number of assets Tenable.io failed to import.
+    #[serde(rename = "failed_assets", skip_serializing_if = "Option::is_none")]
+    pub failed_assets: Option<u64>,
+    /// The unix timestamp in milliseconds when the job started.
+    #[serde(rename = "start_time", skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<u64>,
+    /// The unix timestamp in milliseconds when the job was last updated.
+    #[serde(rename = "last_update_time", skip_serializing_if = "Option::is_none")]
+    pub last_update_time: Option<u64>,
+    /// The unix timestamp in milliseconds when the job ended.
+    #[serde(rename = "end_time", skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<u64>,
+}
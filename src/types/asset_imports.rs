@@ -0,0 +1,54 @@
+use crate::{types::AssetImportJobs, Error, HttpRequest, Response, Tenable};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `asset_imports` function
+#[derive(Clone, Debug)]
+pub struct AssetImportsReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for AssetImportsReq<'_> {
+    type Output = AssetImportJobs;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let req = Request::builder()
+            .uri(format!("{}/import/assets", self.tenable.uri))
+            .method(Method::GET)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .body(Vec::new())?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(data)
+    }
+}
+
+impl<'a> From<AssetImportsReq<'a>> for Cow<'a, AssetImportsReq<'a>> {
+    #[inline]
+    fn from(req: AssetImportsReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a AssetImportsReq<'a>> for Cow<'a, AssetImportsReq<'a>> {
+    #[inline]
+    fn from(req: &'a AssetImportsReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
@@ -36,7 +36,7 @@ impl<RE: fmt::Debug> HttpRequest<RE> for AssetsReq<'_> {
             StatusCode::OK => {}
             StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
             StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
-            code => return Err(Error::UnexpectedStatusCode(code)),
+            _ => return Err(Error::from_response(res)),
         }
         let data = serde_json::from_slice(&res.body)?;
         Ok(data)
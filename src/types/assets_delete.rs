@@ -0,0 +1,123 @@
+use crate::{Error, HttpRequest, Response, Tenable};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `assets_delete` function
+#[derive(Clone, Debug)]
+pub struct AssetsDeleteReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// Definition which details the assets to delete
+    pub assets_delete_def: Cow<'a, AssetsDeleteDef>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for AssetsDeleteReq<'_> {
+    type Output = DeletedAssets;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let payload = serde_json::to_vec(&self.assets_delete_def)?;
+        let req = Request::builder()
+            .uri(format!(
+                "{}/api/v2/assets/bulk-jobs/delete",
+                self.tenable.uri
+            ))
+            .method(Method::POST)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .header("Content-Type", HeaderValue::from_static("application/json"))
+            .body(payload)?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(data)
+    }
+}
+
+impl<'a> From<AssetsDeleteReq<'a>> for Cow<'a, AssetsDeleteReq<'a>> {
+    #[inline]
+    fn from(req: AssetsDeleteReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a AssetsDeleteReq<'a>> for Cow<'a, AssetsDeleteReq<'a>> {
+    #[inline]
+    fn from(req: &'a AssetsDeleteReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Details which assets to delete
+pub struct AssetsDeleteDef {
+    /// The query selecting the assets to delete.
+    #[serde(rename = "query")]
+    pub query: AssetQuery,
+    /// Deletes the assets and their vulnerabilities permanently instead of only removing them from the asset count.
+    #[serde(rename = "hard_delete", skip_serializing_if = "Option::is_none")]
+    pub hard_delete: Option<bool>,
+}
+
+impl From<AssetsDeleteDef> for Cow<'_, AssetsDeleteDef> {
+    #[inline]
+    fn from(req: AssetsDeleteDef) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a AssetsDeleteDef> for Cow<'a, AssetsDeleteDef> {
+    #[inline]
+    fn from(req: &'a AssetsDeleteDef) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Combines the filters of a bulk asset operation
+pub enum AssetQuery {
+    /// Assets have to match all filters.
+    #[serde(rename = "and")]
+    And(Vec<AssetFilter>),
+    /// Assets have to match at least one filter.
+    #[serde(rename = "or")]
+    Or(Vec<AssetFilter>),
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Single filter of a bulk asset operation
+pub struct AssetFilter {
+    /// The asset attribute to match, for example `host.id`, `host.hostname` or `ipv4`.
+    #[serde(rename = "field")]
+    pub field: String,
+    /// The operator used to match the attribute, for example `eq`, `match` or `lt`.
+    #[serde(rename = "operator")]
+    pub operator: String,
+    /// The value to match.
+    #[serde(rename = "value")]
+    pub value: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Information about the delete operation
+pub struct DeletedAssets {
+    /// The number of assets affected by the operation.
+    #[serde(rename = "asset_count", skip_serializing_if = "Option::is_none")]
+    pub asset_count: Option<i32>,
+    /// The UUID of the job deleting the assets. Use `asset_bulk_job` to follow it up.
+    #[serde(rename = "job_uuid", skip_serializing_if = "Option::is_none")]
+    pub job_uuid: Option<String>,
+}
@@ -0,0 +1,139 @@
+use crate::{
+    types::{ExportUuid, TagFilters},
+    Error, HttpRequest, Response, Tenable,
+};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `assets_export` function
+#[derive(Clone, Debug)]
+pub struct AssetsExportReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// Definition which details the export
+    pub assets_export_def: Cow<'a, AssetsExportDef>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for AssetsExportReq<'_> {
+    type Output = ExportUuid;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let payload = serde_json::to_vec(&self.assets_export_def)?;
+        let req = Request::builder()
+            .uri(format!("{}/assets/export", self.tenable.uri))
+            .method(Method::POST)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .header("Content-Type", HeaderValue::from_static("application/json"))
+            .body(payload)?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(data)
+    }
+}
+
+impl<'a> From<AssetsExportReq<'a>> for Cow<'a, AssetsExportReq<'a>> {
+    #[inline]
+    fn from(req: AssetsExportReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a AssetsExportReq<'a>> for Cow<'a, AssetsExportReq<'a>> {
+    #[inline]
+    fn from(req: &'a AssetsExportReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Details which assets to export and how to split them into chunks
+pub struct AssetsExportDef {
+    /// Specifies the number of assets per exported chunk. The range is 100-10000. Defaults to 1000.
+    #[serde(rename = "chunk_size")]
+    pub chunk_size: u64,
+    /// Specifies filters for exported assets. To return all assets, omit the filters object.
+    #[serde(rename = "filters", skip_serializing_if = "Option::is_none")]
+    pub filters: Option<AssetsExportFilters>,
+}
+
+/// Exports all assets in chunks of 1000 assets
+impl Default for AssetsExportDef {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            chunk_size: 1000,
+            filters: None,
+        }
+    }
+}
+
+impl From<AssetsExportDef> for Cow<'_, AssetsExportDef> {
+    #[inline]
+    fn from(req: AssetsExportDef) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a AssetsExportDef> for Cow<'a, AssetsExportDef> {
+    #[inline]
+    fn from(req: &'a AssetsExportDef) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+/// Filters for the `assets_export` request. All timestamps are Unix timestamps in seconds.
+pub struct AssetsExportFilters {
+    /// Returns all assets created later than the date specified.
+    #[serde(rename = "created_at", skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<u64>,
+    /// Returns all assets updated later than the date specified.
+    #[serde(rename = "updated_at", skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<u64>,
+    /// Returns all assets terminated later than the date specified.
+    #[serde(rename = "terminated_at", skip_serializing_if = "Option::is_none")]
+    pub terminated_at: Option<u64>,
+    /// Returns all assets deleted later than the date specified.
+    #[serde(rename = "deleted_at", skip_serializing_if = "Option::is_none")]
+    pub deleted_at: Option<u64>,
+    /// Returns all assets with a first scan time later than the date specified.
+    #[serde(rename = "first_scan_time", skip_serializing_if = "Option::is_none")]
+    pub first_scan_time: Option<u64>,
+    /// Returns all assets with a last credentialed scan time later than the date specified.
+    #[serde(
+        rename = "last_authenticated_scan_time",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub last_authenticated_scan_time: Option<u64>,
+    /// Returns all assets with a last assessed time later than the date specified. Tenable.io considers an asset assessed if it has been scanned by a credentialed or non-credentialed scan.
+    #[serde(rename = "last_assessed", skip_serializing_if = "Option::is_none")]
+    pub last_assessed: Option<u64>,
+    /// If `true`, returns only assets that have a ServiceNow Sys ID. If `false`, returns only assets that do not have a ServiceNow Sys ID.
+    #[serde(rename = "servicenow_sysid", skip_serializing_if = "Option::is_none")]
+    pub servicenow_sysid: Option<bool>,
+    /// Returns assets that have the specified source. An asset source is the entity that reported the asset details, for example `AWS` or `NESSUS_SCAN`.
+    #[serde(rename = "sources", skip_serializing_if = "Option::is_none")]
+    pub sources: Option<Vec<String>>,
+    /// If `true`, returns only assets that have plugin results associated with them.
+    #[serde(rename = "has_plugin_results", skip_serializing_if = "Option::is_none")]
+    pub has_plugin_results: Option<bool>,
+    /// Returns assets with the specified tags, keyed by tag category.
+    #[serde(flatten)]
+    pub tags: TagFilters,
+}
@@ -0,0 +1,317 @@
+use crate::{types::Source, Error, HttpRequest, Response, Tenable};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `assets_export_chunk` function
+#[derive(Clone, Debug)]
+pub struct AssetsExportChunkReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// UUID which identifies the export
+    pub export_uuid: Cow<'a, str>,
+    /// ID of the chunk to download
+    pub chunk_id: u64,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for AssetsExportChunkReq<'_> {
+    type Output = Option<Vec<AssetExport>>;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let req = Request::builder()
+            .uri(format!(
+                "{}/assets/export/{export_uuid}/chunks/{chunk_id}",
+                self.tenable.uri,
+                export_uuid = self.export_uuid,
+                chunk_id = self.chunk_id
+            ))
+            .method(Method::GET)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header(
+                "Accept",
+                HeaderValue::from_static("application/octet-stream"),
+            )
+            .body(Vec::new())?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            StatusCode::NOT_FOUND => return Ok(None),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(Some(data))
+    }
+}
+
+impl<'a> From<AssetsExportChunkReq<'a>> for Cow<'a, AssetsExportChunkReq<'a>> {
+    #[inline]
+    fn from(req: AssetsExportChunkReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a AssetsExportChunkReq<'a>> for Cow<'a, AssetsExportChunkReq<'a>> {
+    #[inline]
+    fn from(req: &'a AssetsExportChunkReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+/// Represents the `Asset` returned by `assets_export_chunk`
+///
+/// Fields which also exist on `Asset` and `AssetByUuid` keep their names, even
+/// though the export uses plural attribute names for them.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AssetExport {
+    /// The UUID of the asset. Use this value as the unique key for the asset.
+    #[serde(rename = "id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Specifies whether a Nessus agent scan identified the asset.
+    #[serde(rename = "has_agent", skip_serializing_if = "Option::is_none")]
+    pub has_agent: Option<bool>,
+    /// Specifies whether the asset has plugin results associated with it.
+    #[serde(rename = "has_plugin_results", skip_serializing_if = "Option::is_none")]
+    pub has_plugin_results: Option<bool>,
+    /// The time and date when Tenable.io created the asset record.
+    #[serde(rename = "created_at", skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    /// The time and date when a user terminated the Amazon Web Service (AWS) virtual machine instance of the asset.
+    #[serde(rename = "terminated_at", skip_serializing_if = "Option::is_none")]
+    pub terminated_at: Option<String>,
+    /// The user UUID of the user who terminated the AWS instance of the asset.
+    #[serde(rename = "terminated_by", skip_serializing_if = "Option::is_none")]
+    pub terminated_by: Option<String>,
+    /// The time and date when the asset record was last updated.
+    #[serde(rename = "updated_at", skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+    /// The time and date when a user deleted the asset record.
+    #[serde(rename = "deleted_at", skip_serializing_if = "Option::is_none")]
+    pub deleted_at: Option<String>,
+    /// The UUID of the user who deleted the asset record.
+    #[serde(rename = "deleted_by", skip_serializing_if = "Option::is_none")]
+    pub deleted_by: Option<String>,
+    /// The time and date when a scan first identified the asset.
+    #[serde(rename = "first_seen", skip_serializing_if = "Option::is_none")]
+    pub first_seen: Option<String>,
+    /// The time and date of the scan that most recently identified the asset.
+    #[serde(rename = "last_seen", skip_serializing_if = "Option::is_none")]
+    pub last_seen: Option<String>,
+    /// The time and date of the first scan run against the asset.
+    #[serde(rename = "first_scan_time", skip_serializing_if = "Option::is_none")]
+    pub first_scan_time: Option<String>,
+    /// The time and date of the last scan run against the asset.
+    #[serde(rename = "last_scan_time", skip_serializing_if = "Option::is_none")]
+    pub last_scan_time: Option<String>,
+    /// The time and date of the last credentialed scan run on the asset.
+    #[serde(
+        rename = "last_authenticated_scan_date",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub last_authenticated_scan_date: Option<String>,
+    /// The time and date of the last scan that identified the asset as licensed.
+    #[serde(
+        rename = "last_licensed_scan_date",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub last_licensed_scan_date: Option<String>,
+    /// The UUID of the scan configuration used during the last scan of the asset.
+    #[serde(rename = "last_scan_id", skip_serializing_if = "Option::is_none")]
+    pub last_scan_id: Option<String>,
+    /// The `schedule_uuid` for the last scan of the asset.
+    #[serde(rename = "last_schedule_id", skip_serializing_if = "Option::is_none")]
+    pub last_schedule_id: Option<String>,
+    /// The unique identifier of the Microsoft Azure virtual machine instance.
+    #[serde(rename = "azure_vm_id", skip_serializing_if = "Option::is_none")]
+    pub azure_vm_id: Option<String>,
+    /// The unique identifier of the resource in the Azure Resource Manager.
+    #[serde(rename = "azure_resource_id", skip_serializing_if = "Option::is_none")]
+    pub azure_resource_id: Option<String>,
+    /// The customized name of the project to which the virtual machine instance belongs in Google Cloud Platform (GCP).
+    #[serde(rename = "gcp_project_id", skip_serializing_if = "Option::is_none")]
+    pub gcp_project_id: Option<String>,
+    /// The zone where the virtual machine instance runs in GCP.
+    #[serde(rename = "gcp_zone", skip_serializing_if = "Option::is_none")]
+    pub gcp_zone: Option<String>,
+    /// The unique identifier of the virtual machine instance in GCP.
+    #[serde(rename = "gcp_instance_id", skip_serializing_if = "Option::is_none")]
+    pub gcp_instance_id: Option<String>,
+    /// The unique identifier of the Linux AMI image in Amazon Elastic Compute Cloud (Amazon EC2).
+    #[serde(
+        rename = "aws_ec2_instance_ami_id",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub aws_ec2_instance_ami_id: Option<String>,
+    /// The unique identifier of the Linux instance in Amazon EC2.
+    #[serde(
+        rename = "aws_ec2_instance_id",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub aws_ec2_instance_id: Option<String>,
+    /// The UUID of the agent present on the asset.
+    #[serde(rename = "agent_uuid", skip_serializing_if = "Option::is_none")]
+    pub agent_uuid: Option<String>,
+    /// The BIOS UUID of the asset.
+    #[serde(rename = "bios_uuid", skip_serializing_if = "Option::is_none")]
+    pub bios_uuid: Option<String>,
+    /// The ID of the network object to which the asset belongs.
+    #[serde(rename = "network_id", skip_serializing_if = "Option::is_none")]
+    pub network_id: Option<String>,
+    /// The name of the network object to which the asset belongs.
+    #[serde(rename = "network_name", skip_serializing_if = "Option::is_none")]
+    pub network_name: Option<String>,
+    /// The canonical user identifier for the AWS account associated with the virtual machine instance.
+    #[serde(rename = "aws_owner_id", skip_serializing_if = "Option::is_none")]
+    pub aws_owner_id: Option<String>,
+    /// The availability zone where Amazon Web Services hosts the virtual machine instance.
+    #[serde(
+        rename = "aws_availability_zone",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub aws_availability_zone: Option<String>,
+    /// The region where AWS hosts the virtual machine instance.
+    #[serde(rename = "aws_region", skip_serializing_if = "Option::is_none")]
+    pub aws_region: Option<String>,
+    /// The unique identifier for the public cloud that hosts the AWS virtual machine instance.
+    #[serde(rename = "aws_vpc_id", skip_serializing_if = "Option::is_none")]
+    pub aws_vpc_id: Option<String>,
+    /// The virtual machine instance's group in AWS.
+    #[serde(
+        rename = "aws_ec2_instance_group_name",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub aws_ec2_instance_group_name: Option<String>,
+    /// The state of the virtual machine instance in AWS at the time of the scan.
+    #[serde(
+        rename = "aws_ec2_instance_state_name",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub aws_ec2_instance_state_name: Option<String>,
+    /// The type of instance in AWS EC2.
+    #[serde(
+        rename = "aws_ec2_instance_type",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub aws_ec2_instance_type: Option<String>,
+    /// The unique identifier of the AWS subnet where the virtual machine instance was running at the time of the scan.
+    #[serde(rename = "aws_subnet_id", skip_serializing_if = "Option::is_none")]
+    pub aws_subnet_id: Option<String>,
+    /// The product code associated with the AMI used to launch the virtual machine instance in AWS EC2.
+    #[serde(
+        rename = "aws_ec2_product_code",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub aws_ec2_product_code: Option<String>,
+    /// The name of the virtual machine instance in AWS EC2.
+    #[serde(rename = "aws_ec2_name", skip_serializing_if = "Option::is_none")]
+    pub aws_ec2_name: Option<String>,
+    /// The unique identifier of the asset in McAfee ePolicy Orchestrator (ePO).
+    #[serde(rename = "mcafee_epo_guid", skip_serializing_if = "Option::is_none")]
+    pub mcafee_epo_guid: Option<String>,
+    /// The unique identifier of the McAfee ePO agent that identified the asset.
+    #[serde(
+        rename = "mcafee_epo_agent_guid",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub mcafee_epo_agent_guid: Option<String>,
+    /// The unique record identifier of the asset in ServiceNow.
+    #[serde(rename = "servicenow_sysid", skip_serializing_if = "Option::is_none")]
+    pub servicenow_sysid: Option<String>,
+    /// The names of any Nessus agents that scanned and identified the asset.
+    #[serde(rename = "agent_names", skip_serializing_if = "Option::is_none")]
+    pub agent_name: Option<Vec<String>>,
+    /// A list of Common Platform Enumeration (CPE) values that represent software applications a scan identified as present on an asset.
+    #[serde(rename = "installed_software", skip_serializing_if = "Option::is_none")]
+    pub installed_software: Option<Vec<String>>,
+    /// The IPv4 addresses that scans have associated with the asset record.
+    #[serde(rename = "ipv4s", skip_serializing_if = "Option::is_none")]
+    pub ipv4: Option<Vec<String>>,
+    /// The IPv6 addresses that scans have associated with the asset record.
+    #[serde(rename = "ipv6s", skip_serializing_if = "Option::is_none")]
+    pub ipv6: Option<Vec<String>>,
+    /// The fully-qualified domain names that scans have associated with the asset record.
+    #[serde(rename = "fqdns", skip_serializing_if = "Option::is_none")]
+    pub fqdn: Option<Vec<String>>,
+    /// The MAC addresses that scans have associated with the asset record.
+    #[serde(rename = "mac_addresses", skip_serializing_if = "Option::is_none")]
+    pub mac_address: Option<Vec<String>>,
+    /// The NetBIOS names that scans have associated with the asset record.
+    #[serde(rename = "netbios_names", skip_serializing_if = "Option::is_none")]
+    pub netbios_name: Option<Vec<String>>,
+    /// The operating systems that scans have associated with the asset record.
+    #[serde(rename = "operating_systems", skip_serializing_if = "Option::is_none")]
+    pub operating_system: Option<Vec<String>>,
+    /// The system types as reported by Plugin ID 54615.
+    #[serde(rename = "system_types", skip_serializing_if = "Option::is_none")]
+    pub system_type: Option<Vec<String>>,
+    /// The hostnames that scans have associated with the asset record.
+    #[serde(rename = "hostnames", skip_serializing_if = "Option::is_none")]
+    pub hostname: Option<Vec<String>>,
+    /// The SSH key fingerprints that scans have associated with the asset record.
+    #[serde(rename = "ssh_fingerprints", skip_serializing_if = "Option::is_none")]
+    pub ssh_fingerprint: Option<Vec<String>>,
+    /// The Asset IDs of the asset in Qualys.
+    #[serde(rename = "qualys_asset_ids", skip_serializing_if = "Option::is_none")]
+    pub qualys_asset_id: Option<Vec<String>>,
+    /// The Host IDs of the asset in Qualys.
+    #[serde(rename = "qualys_host_ids", skip_serializing_if = "Option::is_none")]
+    pub qualys_host_id: Option<Vec<String>>,
+    /// The sources of the scans that identified the asset.
+    #[serde(rename = "sources", skip_serializing_if = "Option::is_none")]
+    pub sources: Option<Vec<Source>>,
+    /// Category tags assigned to the asset in Tenable.io.
+    #[serde(rename = "tags", skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<AssetExportTag>>,
+    /// The network interfaces that scans identified on the asset.
+    #[serde(rename = "network_interfaces", skip_serializing_if = "Option::is_none")]
+    pub network_interfaces: Option<Vec<NetworkInterface>>,
+}
+
+/// `AssetExportTag` blueprint
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AssetExportTag {
+    /// The UUID of the tag.
+    #[serde(rename = "uuid", skip_serializing_if = "Option::is_none")]
+    pub uuid: Option<String>,
+    /// The tag category (the first half of the category:value pair).
+    #[serde(rename = "key", skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+    /// The tag value (the second half of the category:value pair).
+    #[serde(rename = "value", skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    /// The UUID of the user who assigned the tag to the asset.
+    #[serde(rename = "added_by", skip_serializing_if = "Option::is_none")]
+    pub added_by: Option<String>,
+    /// The ISO timestamp when the tag was assigned to the asset.
+    #[serde(rename = "added_at", skip_serializing_if = "Option::is_none")]
+    pub added_at: Option<String>,
+}
+
+/// `NetworkInterface` blueprint
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct NetworkInterface {
+    /// The name of the interface.
+    #[serde(rename = "name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The MAC addresses of the interface.
+    #[serde(rename = "mac_address", skip_serializing_if = "Option::is_none")]
+    pub mac_address: Option<Vec<String>>,
+    /// One or more IPv4 addresses belonging to the interface.
+    #[serde(rename = "ipv4", skip_serializing_if = "Option::is_none")]
+    pub ipv4: Option<Vec<String>>,
+    /// One or more IPv6 addresses belonging to the interface.
+    #[serde(rename = "ipv6", skip_serializing_if = "Option::is_none")]
+    pub ipv6: Option<Vec<String>>,
+    /// One or more FQDN belonging to the interface.
+    #[serde(rename = "fqdn", skip_serializing_if = "Option::is_none")]
+    pub fqdn: Option<Vec<String>>,
+}
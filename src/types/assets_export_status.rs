@@ -0,0 +1,61 @@
+use crate::{types::ExportStatus, Error, HttpRequest, Response, Tenable};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `assets_export_status` function
+#[derive(Clone, Debug)]
+pub struct AssetsExportStatusReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// UUID which identifies the export
+    pub export_uuid: Cow<'a, str>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for AssetsExportStatusReq<'_> {
+    type Output = Option<ExportStatus>;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let req = Request::builder()
+            .uri(format!(
+                "{}/assets/export/{export_uuid}/status",
+                self.tenable.uri,
+                export_uuid = self.export_uuid
+            ))
+            .method(Method::GET)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .body(Vec::new())?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            StatusCode::NOT_FOUND => return Ok(None),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(Some(data))
+    }
+}
+
+impl<'a> From<AssetsExportStatusReq<'a>> for Cow<'a, AssetsExportStatusReq<'a>> {
+    #[inline]
+    fn from(req: AssetsExportStatusReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a AssetsExportStatusReq<'a>> for Cow<'a, AssetsExportStatusReq<'a>> {
+    #[inline]
+    fn from(req: &'a AssetsExportStatusReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
@@ -40,7 +40,7 @@ impl<RE: fmt::Debug> HttpRequest<RE> for AssetsMove<'_> {
             StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
             StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
             StatusCode::NOT_FOUND => return Ok(None),
-            code => return Err(Error::UnexpectedStatusCode(code)),
+            _ => return Err(Error::from_response(res)),
         }
         let data = serde_json::from_slice(&res.body)?;
         Ok(Some(data))
@@ -90,10 +90,13 @@ impl<'a> From<&'a AssetsMoveDef> for Cow<'a, AssetsMoveDef> {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Copy)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 /// Information about the move operation
 pub struct MovedAssets {
     /// The number of assets affected by the operation.
     #[serde(rename = "asset_count", skip_serializing_if = "Option::is_none")]
     pub asset_count: Option<i32>,
+    /// The UUID of the job moving the assets. Use `asset_bulk_job` to follow it up.
+    #[serde(rename = "job_uuid", skip_serializing_if = "Option::is_none")]
+    pub job_uuid: Option<String>,
 }
@@ -0,0 +1,91 @@
+use crate::{
+    types::{CredentialPermission, CredentialRef, CredentialSettings},
+    Error, HttpRequest, Response, Tenable,
+};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `credential_by_uuid` function
+#[derive(Clone, Debug)]
+pub struct CredentialByUuidReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// UUID which identifies the credential
+    pub credential_uuid: Cow<'a, str>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for CredentialByUuidReq<'_> {
+    type Output = Option<CredentialDetails>;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let req = Request::builder()
+            .uri(format!(
+                "{}/credentials/{credential_uuid}",
+                self.tenable.uri,
+                credential_uuid = self.credential_uuid
+            ))
+            .method(Method::GET)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .body(Vec::new())?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            StatusCode::NOT_FOUND => return Ok(None),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(Some(data))
+    }
+}
+
+impl<'a> From<CredentialByUuidReq<'a>> for Cow<'a, CredentialByUuidReq<'a>> {
+    #[inline]
+    fn from(req: CredentialByUuidReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a CredentialByUuidReq<'a>> for Cow<'a, CredentialByUuidReq<'a>> {
+    #[inline]
+    fn from(req: &'a CredentialByUuidReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Managed credential including its settings, as returned by `credential_by_uuid`
+pub struct CredentialDetails {
+    /// The name of the credential.
+    #[serde(rename = "name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The description of the credential.
+    #[serde(rename = "description", skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The category of the credential, for example `Host`.
+    #[serde(rename = "category", skip_serializing_if = "Option::is_none")]
+    pub category: Option<CredentialRef>,
+    /// The type and type-specific settings of the credential. Secrets are masked by Tenable.io.
+    #[serde(flatten)]
+    pub settings: CredentialSettings,
+    /// Indicates whether the credential is only used by a single scan.
+    #[serde(rename = "ad_hoc", skip_serializing_if = "Option::is_none")]
+    pub ad_hoc: Option<bool>,
+    /// The permissions the current user has for the credential.
+    #[serde(rename = "user_permissions", skip_serializing_if = "Option::is_none")]
+    pub user_permissions: Option<u32>,
+    /// The users and groups the credential is shared with.
+    #[serde(rename = "permissions", skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<Vec<CredentialPermission>>,
+}
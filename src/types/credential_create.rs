@@ -0,0 +1,332 @@
+use crate::{
+    types::{EditorValue, Secret},
+    Error, HttpRequest, Response, Tenable,
+};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use serde::{de, ser::SerializeMap, Deserialize, Deserializer, Serialize, Serializer};
+use std::{borrow::Cow, collections::BTreeMap, fmt};
+
+/// Request Object for the `credential_create` function
+#[derive(Clone, Debug)]
+pub struct CredentialCreateReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// Definition which details the credential
+    pub credential_def: Cow<'a, CredentialDef>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for CredentialCreateReq<'_> {
+    type Output = CredentialUuid;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let payload = serde_json::to_vec(&self.credential_def)?;
+        let req = Request::builder()
+            .uri(format!("{}/credentials", self.tenable.uri))
+            .method(Method::POST)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .header("Content-Type", HeaderValue::from_static("application/json"))
+            .body(payload)?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(data)
+    }
+}
+
+impl<'a> From<CredentialCreateReq<'a>> for Cow<'a, CredentialCreateReq<'a>> {
+    #[inline]
+    fn from(req: CredentialCreateReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a CredentialCreateReq<'a>> for Cow<'a, CredentialCreateReq<'a>> {
+    #[inline]
+    fn from(req: &'a CredentialCreateReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Details the credential to create
+pub struct CredentialDef {
+    /// The name of the credential.
+    #[serde(rename = "name")]
+    pub name: String,
+    /// The description of the credential.
+    #[serde(rename = "description", skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The type and type-specific settings of the credential. Sent as `type` and `settings`.
+    #[serde(flatten)]
+    pub settings: CredentialSettings,
+    /// The users and groups the credential is shared with.
+    #[serde(rename = "permissions", skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<Vec<CredentialPermission>>,
+    /// Indicates whether the credential is only used by a single scan instead of being managed.
+    #[serde(rename = "ad_hoc", skip_serializing_if = "Option::is_none")]
+    pub ad_hoc: Option<bool>,
+}
+
+impl From<CredentialDef> for Cow<'_, CredentialDef> {
+    #[inline]
+    fn from(req: CredentialDef) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a CredentialDef> for Cow<'a, CredentialDef> {
+    #[inline]
+    fn from(req: &'a CredentialDef) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+/// Type of a credential together with its type-specific settings
+///
+/// Passwords, keys and other secrets are wrapped in `Secret`, so they are not included in the
+/// `Debug` output. Tenable.io returns secrets masked as `********`.
+pub enum CredentialSettings {
+    /// Settings of the `SSH` credential type.
+    Ssh(SshCredential),
+    /// Settings of the `Windows` credential type.
+    Windows(WindowsCredential),
+    /// Settings of the `SNMPv3` credential type.
+    Snmpv3(Snmpv3Credential),
+    /// Settings of the `Amazon AWS` credential type.
+    Aws(AwsCredential),
+    /// Settings of any other credential type. All settings are treated as secret.
+    Other {
+        /// The ID of the credential type as returned by `credential_types`.
+        credential_type: String,
+        /// The settings of the credential, keyed by the ID of their input.
+        settings: Secret<BTreeMap<String, EditorValue>>,
+    },
+}
+
+impl CredentialSettings {
+    /// Returns the ID of the credential type, for example `SSH`.
+    #[must_use]
+    #[inline]
+    pub fn credential_type(&self) -> &str {
+        match self {
+            Self::Ssh(_) => "SSH",
+            Self::Windows(_) => "Windows",
+            Self::Snmpv3(_) => "SNMPv3",
+            Self::Aws(_) => "Amazon AWS",
+            Self::Other {
+                credential_type, ..
+            } => credential_type,
+        }
+    }
+}
+
+impl Serialize for CredentialSettings {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(2))?;
+        map.serialize_entry("type", self.credential_type())?;
+        match self {
+            Self::Ssh(settings) => map.serialize_entry("settings", settings)?,
+            Self::Windows(settings) => map.serialize_entry("settings", settings)?,
+            Self::Snmpv3(settings) => map.serialize_entry("settings", settings)?,
+            Self::Aws(settings) => map.serialize_entry("settings", settings)?,
+            Self::Other { settings, .. } => map.serialize_entry("settings", settings)?,
+        }
+        map.end()
+    }
+}
+
+/// Tenable.io sends the type as ID when creating a credential, but returns it as object
+impl<'de> Deserialize<'de> for CredentialSettings {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum CredentialType {
+            Id(String),
+            Ref { id: String },
+        }
+
+        #[derive(Deserialize)]
+        struct Raw {
+            #[serde(rename = "type")]
+            credential_type: CredentialType,
+            #[serde(default)]
+            settings: serde_json::Value,
+        }
+
+        let raw = Raw::deserialize(deserializer)?;
+        let credential_type = match raw.credential_type {
+            CredentialType::Id(id) | CredentialType::Ref { id } => id,
+        };
+        let settings = match credential_type.as_str() {
+            "SSH" => serde_json::from_value(raw.settings).map(Self::Ssh),
+            "Windows" => serde_json::from_value(raw.settings).map(Self::Windows),
+            "SNMPv3" => serde_json::from_value(raw.settings).map(Self::Snmpv3),
+            "Amazon AWS" => serde_json::from_value(raw.settings).map(Self::Aws),
+            _ => serde_json::from_value(raw.settings).map(|settings| Self::Other {
+                credential_type,
+                settings,
+            }),
+        };
+        settings.map_err(de::Error::custom)
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+/// Settings of the `SSH` credential type
+pub struct SshCredential {
+    /// How to authenticate: `password`, `public key`, `certificate` or `Kerberos`.
+    #[serde(rename = "auth_method", skip_serializing_if = "Option::is_none")]
+    pub auth_method: Option<String>,
+    /// The username to log in with.
+    #[serde(rename = "username", skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    /// The password to log in with.
+    #[serde(rename = "password", skip_serializing_if = "Option::is_none")]
+    pub password: Option<Secret>,
+    /// The name of the private key file returned by `credential_file_upload`.
+    #[serde(rename = "private_key", skip_serializing_if = "Option::is_none")]
+    pub private_key: Option<String>,
+    /// The passphrase of the private key.
+    #[serde(
+        rename = "private_key_passphrase",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub private_key_passphrase: Option<Secret>,
+    /// The method used to elevate privileges, for example `Nothing`, `sudo` or `su`.
+    #[serde(
+        rename = "elevate_privileges_with",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub elevate_privileges_with: Option<String>,
+    /// The account to elevate privileges to.
+    #[serde(rename = "escalation_account", skip_serializing_if = "Option::is_none")]
+    pub escalation_account: Option<String>,
+    /// The password used to elevate privileges.
+    #[serde(
+        rename = "escalation_password",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub escalation_password: Option<Secret>,
+    /// Settings not covered by the fields above, like the `kdc_*` settings of
+    /// `Kerberos` or the `user_cert` of `certificate` authentication, keyed by the
+    /// ID of their input. All of them are treated as secret.
+    #[serde(flatten)]
+    pub other: Secret<BTreeMap<String, EditorValue>>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+/// Settings of the `Windows` credential type
+pub struct WindowsCredential {
+    /// How to authenticate: `Password`, `NTLM Hash` or `Kerberos`.
+    #[serde(rename = "auth_method", skip_serializing_if = "Option::is_none")]
+    pub auth_method: Option<String>,
+    /// The username to log in with.
+    #[serde(rename = "username", skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    /// The password or NTLM hash to log in with.
+    #[serde(rename = "password", skip_serializing_if = "Option::is_none")]
+    pub password: Option<Secret>,
+    /// The Windows domain of the user.
+    #[serde(rename = "domain", skip_serializing_if = "Option::is_none")]
+    pub domain: Option<String>,
+    /// Settings not covered by the fields above, like the `kdc_*` settings of
+    /// `Kerberos` authentication, keyed by the ID of their input. All of them are
+    /// treated as secret.
+    #[serde(flatten)]
+    pub other: Secret<BTreeMap<String, EditorValue>>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+/// Settings of the `SNMPv3` credential type
+pub struct Snmpv3Credential {
+    /// The username to authenticate with.
+    #[serde(rename = "username", skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    /// The security level, for example `Authentication and privacy`.
+    #[serde(rename = "security_level", skip_serializing_if = "Option::is_none")]
+    pub security_level: Option<String>,
+    /// The authentication algorithm, `SHA1` or `MD5`.
+    #[serde(rename = "auth_algorithm", skip_serializing_if = "Option::is_none")]
+    pub auth_algorithm: Option<String>,
+    /// The authentication password.
+    #[serde(rename = "auth_password", skip_serializing_if = "Option::is_none")]
+    pub auth_password: Option<Secret>,
+    /// The privacy algorithm, `AES` or `DES`.
+    #[serde(rename = "privacy_algorithm", skip_serializing_if = "Option::is_none")]
+    pub privacy_algorithm: Option<String>,
+    /// The privacy password.
+    #[serde(rename = "privacy_password", skip_serializing_if = "Option::is_none")]
+    pub privacy_password: Option<Secret>,
+    /// Settings not covered by the fields above, keyed by the ID of their input.
+    /// All of them are treated as secret.
+    #[serde(flatten)]
+    pub other: Secret<BTreeMap<String, EditorValue>>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+/// Settings of the `Amazon AWS` credential type
+pub struct AwsCredential {
+    /// The ID of the AWS access key.
+    #[serde(rename = "access_key_id", skip_serializing_if = "Option::is_none")]
+    pub access_key_id: Option<String>,
+    /// The secret of the AWS access key.
+    #[serde(rename = "secret_key", skip_serializing_if = "Option::is_none")]
+    pub secret_key: Option<Secret>,
+    /// Settings not covered by the fields above, keyed by the ID of their input.
+    /// All of them are treated as secret.
+    #[serde(flatten)]
+    pub other: Secret<BTreeMap<String, EditorValue>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// User or group a credential is shared with
+pub struct CredentialPermission {
+    /// The UUID of the user or group.
+    #[serde(rename = "grantee_uuid")]
+    pub grantee_uuid: String,
+    /// Whether the grantee is a user or a group.
+    #[serde(rename = "type")]
+    pub grantee_type: CredentialGrantee,
+    /// The permissions of the grantee: CAN USE [32], CAN EDIT [64] or OWNER [128].
+    #[serde(rename = "permissions")]
+    pub permissions: u32,
+    /// The name of the user or group. Only returned by Tenable.io.
+    #[serde(rename = "name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Copy)]
+/// Kind of grantee of a `CredentialPermission`
+pub enum CredentialGrantee {
+    /// The credential is shared with a single user.
+    #[serde(rename = "user")]
+    User,
+    /// The credential is shared with all users of a group.
+    #[serde(rename = "group")]
+    Group,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Identifies the credential created by `credential_create`
+pub struct CredentialUuid {
+    /// The UUID of the new credential.
+    #[serde(rename = "uuid")]
+    pub uuid: String,
+}
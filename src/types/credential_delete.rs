@@ -0,0 +1,60 @@
+use crate::{Error, HttpRequest, Response, Tenable};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `credential_delete` function
+#[derive(Clone, Debug)]
+pub struct CredentialDeleteReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// UUID which identifies the credential
+    pub credential_uuid: Cow<'a, str>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for CredentialDeleteReq<'_> {
+    type Output = Option<()>;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let req = Request::builder()
+            .uri(format!(
+                "{}/credentials/{credential_uuid}",
+                self.tenable.uri,
+                credential_uuid = self.credential_uuid
+            ))
+            .method(Method::DELETE)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .body(Vec::new())?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            StatusCode::NOT_FOUND => return Ok(None),
+            _ => return Err(Error::from_response(res)),
+        }
+        Ok(Some(()))
+    }
+}
+
+impl<'a> From<CredentialDeleteReq<'a>> for Cow<'a, CredentialDeleteReq<'a>> {
+    #[inline]
+    fn from(req: CredentialDeleteReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a CredentialDeleteReq<'a>> for Cow<'a, CredentialDeleteReq<'a>> {
+    #[inline]
+    fn from(req: &'a CredentialDeleteReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
@@ -0,0 +1,76 @@
+use crate::{
+    types::{multipart_file, Secret},
+    Error, HttpRequest, Response, Tenable,
+};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `credential_file_upload` function
+#[derive(Clone, Debug)]
+pub struct CredentialFileUploadReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// Name of the file, for example `id_rsa`. Must not contain control characters
+    /// like line breaks
+    pub file_name: Cow<'a, str>,
+    /// Content of the file. Not included in the `Debug` output
+    pub content: Secret<Cow<'a, [u8]>>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for CredentialFileUploadReq<'_> {
+    type Output = CredentialFile;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let (boundary, body) = multipart_file(&self.file_name, &self.content.0)?;
+        let req = Request::builder()
+            .uri(format!("{}/credentials/files", self.tenable.uri))
+            .method(Method::POST)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .header(
+                "Content-Type",
+                HeaderValue::from_str(&format!("multipart/form-data; boundary={}", boundary))?,
+            )
+            .body(body)?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(data)
+    }
+}
+
+impl<'a> From<CredentialFileUploadReq<'a>> for Cow<'a, CredentialFileUploadReq<'a>> {
+    #[inline]
+    fn from(req: CredentialFileUploadReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a CredentialFileUploadReq<'a>> for Cow<'a, CredentialFileUploadReq<'a>> {
+    #[inline]
+    fn from(req: &'a CredentialFileUploadReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// File returned by `credential_file_upload`
+pub struct CredentialFile {
+    /// The name of the uploaded file. Use it as value of the setting the file belongs to.
+    #[serde(rename = "fileuploaded")]
+    pub fileuploaded: String,
+}
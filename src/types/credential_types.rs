@@ -0,0 +1,94 @@
+use crate::{types::EditorInput, Error, HttpRequest, Response, Tenable};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `credential_types` function
+#[derive(Clone, Debug)]
+pub struct CredentialTypesReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for CredentialTypesReq<'_> {
+    type Output = CredentialTypes;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let req = Request::builder()
+            .uri(format!("{}/credentials/types", self.tenable.uri))
+            .method(Method::GET)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .body(Vec::new())?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(data)
+    }
+}
+
+impl<'a> From<CredentialTypesReq<'a>> for Cow<'a, CredentialTypesReq<'a>> {
+    #[inline]
+    fn from(req: CredentialTypesReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a CredentialTypesReq<'a>> for Cow<'a, CredentialTypesReq<'a>> {
+    #[inline]
+    fn from(req: &'a CredentialTypesReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Credential types returned by `credential_types`
+pub struct CredentialTypes {
+    /// The categories of credential types.
+    #[serde(rename = "credentials", skip_serializing_if = "Option::is_none")]
+    pub credentials: Option<Vec<CredentialCategory>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Category of credential types, for example `Host` or `Cloud Services`
+pub struct CredentialCategory {
+    /// The ID of the category.
+    #[serde(rename = "id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The name of the category.
+    #[serde(rename = "category", skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+    /// The credential types of the category.
+    #[serde(rename = "types", skip_serializing_if = "Option::is_none")]
+    pub types: Option<Vec<CredentialType>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Credential type and the settings it supports
+pub struct CredentialType {
+    /// The ID of the type. Used as `type` when creating a credential.
+    #[serde(rename = "id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The name of the type.
+    #[serde(rename = "name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The maximum number of credentials of this type a scan can use.
+    #[serde(rename = "max", skip_serializing_if = "Option::is_none")]
+    pub max: Option<u32>,
+    /// The settings of the type.
+    #[serde(rename = "configuration", skip_serializing_if = "Option::is_none")]
+    pub configuration: Option<Vec<EditorInput>>,
+}
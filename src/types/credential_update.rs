@@ -0,0 +1,102 @@
+use crate::{
+    types::{CredentialPermission, CredentialSettings},
+    Error, HttpRequest, Response, Tenable,
+};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `credential_update` function
+#[derive(Clone, Debug)]
+pub struct CredentialUpdateReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// UUID which identifies the credential
+    pub credential_uuid: Cow<'a, str>,
+    /// Definition which details the changes
+    pub credential_update_def: Cow<'a, CredentialUpdateDef>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for CredentialUpdateReq<'_> {
+    type Output = Option<()>;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let payload = serde_json::to_vec(&self.credential_update_def)?;
+        let req = Request::builder()
+            .uri(format!(
+                "{}/credentials/{credential_uuid}",
+                self.tenable.uri,
+                credential_uuid = self.credential_uuid
+            ))
+            .method(Method::PUT)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .header("Content-Type", HeaderValue::from_static("application/json"))
+            .body(payload)?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            StatusCode::NOT_FOUND => return Ok(None),
+            _ => return Err(Error::from_response(res)),
+        }
+        Ok(Some(()))
+    }
+}
+
+impl<'a> From<CredentialUpdateReq<'a>> for Cow<'a, CredentialUpdateReq<'a>> {
+    #[inline]
+    fn from(req: CredentialUpdateReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a CredentialUpdateReq<'a>> for Cow<'a, CredentialUpdateReq<'a>> {
+    #[inline]
+    fn from(req: &'a CredentialUpdateReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+/// Details the changes of a credential. Fields which are `None` are not changed.
+pub struct CredentialUpdateDef {
+    /// The new name of the credential.
+    #[serde(rename = "name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The new description of the credential.
+    #[serde(rename = "description", skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The new settings of the credential. The type of a credential cannot be changed.
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
+    pub settings: Option<CredentialSettings>,
+    /// The users and groups the credential is shared with. Replaces the current permissions.
+    #[serde(rename = "permissions", skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<Vec<CredentialPermission>>,
+    /// Indicates whether the credential is only used by a single scan.
+    #[serde(rename = "ad_hoc", skip_serializing_if = "Option::is_none")]
+    pub ad_hoc: Option<bool>,
+}
+
+impl From<CredentialUpdateDef> for Cow<'_, CredentialUpdateDef> {
+    #[inline]
+    fn from(req: CredentialUpdateDef) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a CredentialUpdateDef> for Cow<'a, CredentialUpdateDef> {
+    #[inline]
+    fn from(req: &'a CredentialUpdateDef) -> Self {
+        Cow::Borrowed(req)
+    }
+}
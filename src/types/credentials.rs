@@ -0,0 +1,158 @@
+use crate::{
+    types::{encode_query, FilterType, Pagination, QueryFilter},
+    Error, HttpRequest, Response, Tenable,
+};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `credentials` function
+#[derive(Clone, Debug)]
+pub struct CredentialsReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// Returns only credentials matching the filters, for example `type:eq:SSH`
+    pub filters: Option<Vec<QueryFilter>>,
+    /// Defines whether credentials must match all or any of the filters
+    pub filter_type: Option<FilterType>,
+    /// Maximum number of credentials to return
+    pub limit: Option<u64>,
+    /// Number of credentials to skip
+    pub offset: Option<u64>,
+    /// Sorts the credentials by the given field and order, for example `name:asc`
+    pub sort: Option<Cow<'a, str>>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for CredentialsReq<'_> {
+    type Output = Credentials;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let mut query = Vec::new();
+        if let Some(ref filters) = self.filters {
+            for filter in filters.iter() {
+                query.push(format!("f={}", encode_query(&filter.to_string())));
+            }
+        }
+        if let Some(filter_type) = self.filter_type {
+            query.push(format!("ft={}", filter_type));
+        }
+        if let Some(limit) = self.limit {
+            query.push(format!("limit={}", limit));
+        }
+        if let Some(offset) = self.offset {
+            query.push(format!("offset={}", offset));
+        }
+        if let Some(ref sort) = self.sort {
+            query.push(format!("sort={}", encode_query(sort)));
+        }
+        let mut uri = format!("{}/credentials", self.tenable.uri);
+        if !query.is_empty() {
+            uri = format!("{}?{}", uri, query.join("&"));
+        }
+        let req = Request::builder()
+            .uri(uri)
+            .method(Method::GET)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .body(Vec::new())?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(data)
+    }
+}
+
+impl<'a> From<CredentialsReq<'a>> for Cow<'a, CredentialsReq<'a>> {
+    #[inline]
+    fn from(req: CredentialsReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a CredentialsReq<'a>> for Cow<'a, CredentialsReq<'a>> {
+    #[inline]
+    fn from(req: &'a CredentialsReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Credentials returned by `credentials`
+pub struct Credentials {
+    /// The managed credentials the current user can view. Settings are not included.
+    #[serde(rename = "credentials", skip_serializing_if = "Option::is_none")]
+    pub credentials: Option<Vec<Credential>>,
+    /// The position of the returned page within all credentials.
+    #[serde(rename = "pagination", skip_serializing_if = "Option::is_none")]
+    pub pagination: Option<Pagination>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Managed credential without its settings
+pub struct Credential {
+    /// The UUID of the credential.
+    #[serde(rename = "uuid", skip_serializing_if = "Option::is_none")]
+    pub uuid: Option<String>,
+    /// The name of the credential.
+    #[serde(rename = "name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The description of the credential.
+    #[serde(rename = "description", skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The category of the credential, for example `Host`.
+    #[serde(rename = "category", skip_serializing_if = "Option::is_none")]
+    pub category: Option<CredentialRef>,
+    /// The type of the credential, for example `SSH`.
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub credential_type: Option<CredentialRef>,
+    /// The Unix timestamp when the credential was created.
+    #[serde(rename = "created_date", skip_serializing_if = "Option::is_none")]
+    pub created_date: Option<u64>,
+    /// The user who created the credential.
+    #[serde(rename = "created_by", skip_serializing_if = "Option::is_none")]
+    pub created_by: Option<CredentialUser>,
+    /// The user who last used the credential in a scan.
+    #[serde(rename = "last_used_by", skip_serializing_if = "Option::is_none")]
+    pub last_used_by: Option<CredentialUser>,
+    /// The permissions the current user has for the credential.
+    #[serde(rename = "user_permissions", skip_serializing_if = "Option::is_none")]
+    pub user_permissions: Option<u32>,
+    /// Indicates whether the credential is shared with other users.
+    #[serde(rename = "shared", skip_serializing_if = "Option::is_none")]
+    pub shared: Option<bool>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Category or type of a credential
+pub struct CredentialRef {
+    /// The ID of the category or type.
+    #[serde(rename = "id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The name of the category or type.
+    #[serde(rename = "name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// User who created or used a credential
+pub struct CredentialUser {
+    /// The UUID of the user.
+    #[serde(rename = "id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The name of the user.
+    #[serde(rename = "display_name", skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+}
@@ -0,0 +1,61 @@
+use crate::{types::EditorDetails, Error, HttpRequest, Response, Tenable};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `editor_policy_by_id` function
+#[derive(Clone, Debug)]
+pub struct EditorPolicyByIdReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// ID which identifies the policy
+    pub policy_id: u64,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for EditorPolicyByIdReq<'_> {
+    type Output = Option<EditorDetails>;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let req = Request::builder()
+            .uri(format!(
+                "{}/editor/policy/{policy_id}",
+                self.tenable.uri,
+                policy_id = self.policy_id
+            ))
+            .method(Method::GET)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .body(Vec::new())?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            StatusCode::NOT_FOUND => return Ok(None),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(Some(data))
+    }
+}
+
+impl<'a> From<EditorPolicyByIdReq<'a>> for Cow<'a, EditorPolicyByIdReq<'a>> {
+    #[inline]
+    fn from(req: EditorPolicyByIdReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a EditorPolicyByIdReq<'a>> for Cow<'a, EditorPolicyByIdReq<'a>> {
+    #[inline]
+    fn from(req: &'a EditorPolicyByIdReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
@@ -0,0 +1,294 @@
+use crate::{types::EditorType, Error, HttpRequest, Response, Tenable};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, collections::BTreeMap, fmt};
+
+/// Request Object for the `editor_template_by_uuid` function
+#[derive(Clone, Debug)]
+pub struct EditorTemplateByUuidReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// Whether the template is a scan or policy template
+    pub editor_type: EditorType,
+    /// UUID which identifies the template
+    pub template_uuid: Cow<'a, str>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for EditorTemplateByUuidReq<'_> {
+    type Output = Option<EditorDetails>;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let req = Request::builder()
+            .uri(format!(
+                "{}/editor/{editor_type}/templates/{template_uuid}",
+                self.tenable.uri,
+                editor_type = self.editor_type,
+                template_uuid = self.template_uuid
+            ))
+            .method(Method::GET)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .body(Vec::new())?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            StatusCode::NOT_FOUND => return Ok(None),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(Some(data))
+    }
+}
+
+impl<'a> From<EditorTemplateByUuidReq<'a>> for Cow<'a, EditorTemplateByUuidReq<'a>> {
+    #[inline]
+    fn from(req: EditorTemplateByUuidReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a EditorTemplateByUuidReq<'a>> for Cow<'a, EditorTemplateByUuidReq<'a>> {
+    #[inline]
+    fn from(req: &'a EditorTemplateByUuidReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Editor form of a template or policy as returned by `editor_template_by_uuid` and `editor_policy_by_id`
+pub struct EditorDetails {
+    /// The UUID of the template.
+    #[serde(rename = "uuid", skip_serializing_if = "Option::is_none")]
+    pub uuid: Option<String>,
+    /// The short name of the template.
+    #[serde(rename = "name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The title of the template.
+    #[serde(rename = "title", skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// The username of the owner of the template or policy.
+    #[serde(rename = "owner", skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    /// Indicates whether the template is used by agent scans.
+    #[serde(rename = "is_agent", skip_serializing_if = "Option::is_none")]
+    pub is_agent: Option<bool>,
+    /// Indicates whether the template is used by web application scans.
+    #[serde(rename = "is_was", skip_serializing_if = "Option::is_none")]
+    pub is_was: Option<bool>,
+    /// The permissions the current user has for the template or policy.
+    #[serde(rename = "user_permissions", skip_serializing_if = "Option::is_none")]
+    pub user_permissions: Option<u32>,
+    /// The settings of the editor, grouped by category.
+    #[serde(rename = "settings", skip_serializing_if = "Option::is_none")]
+    pub settings: Option<EditorSettings>,
+    /// The plugin families and whether they are enabled.
+    #[serde(rename = "plugins", skip_serializing_if = "Option::is_none")]
+    pub plugins: Option<EditorPlugins>,
+}
+
+impl EditorDetails {
+    /// Returns the default value of every setting, keyed by the ID of its input.
+    ///
+    /// The result has the same shape as `PolicyDef::settings` and `PolicyDetails::settings`,
+    /// so it can be compared with an existing policy or used as a base for a new one.
+    #[must_use]
+    #[inline]
+    pub fn default_settings(&self) -> BTreeMap<String, EditorValue> {
+        self.settings
+            .iter()
+            .flat_map(EditorSettings::inputs)
+            .filter_map(|input| Some((input.id.clone()?, input.default.clone()?)))
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+/// Settings of the editor, grouped by the tabs of the user interface
+pub struct EditorSettings {
+    /// General settings, for example the name and targets.
+    #[serde(rename = "basic", skip_serializing_if = "Option::is_none")]
+    pub basic: Option<EditorCategory>,
+    /// Settings of host and port discovery.
+    #[serde(rename = "discovery", skip_serializing_if = "Option::is_none")]
+    pub discovery: Option<EditorCategory>,
+    /// Settings of the vulnerability assessment.
+    #[serde(rename = "assessment", skip_serializing_if = "Option::is_none")]
+    pub assessment: Option<EditorCategory>,
+    /// Settings of the generated report.
+    #[serde(rename = "report", skip_serializing_if = "Option::is_none")]
+    pub report: Option<EditorCategory>,
+    /// Settings of performance and debugging.
+    #[serde(rename = "advanced", skip_serializing_if = "Option::is_none")]
+    pub advanced: Option<EditorCategory>,
+}
+
+impl EditorSettings {
+    /// Returns all inputs of all categories, groups and sections.
+    #[must_use]
+    #[inline]
+    pub fn inputs(&self) -> Vec<&EditorInput> {
+        [
+            &self.basic,
+            &self.discovery,
+            &self.assessment,
+            &self.report,
+            &self.advanced,
+        ]
+        .iter()
+        .filter_map(|category| category.as_ref())
+        .flat_map(EditorCategory::inputs)
+        .collect()
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+/// Tab of the editor
+pub struct EditorCategory {
+    /// The title of the category.
+    #[serde(rename = "title", skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Inputs which do not belong to a group.
+    #[serde(rename = "inputs", skip_serializing_if = "Option::is_none")]
+    pub inputs: Option<Vec<EditorInput>>,
+    /// Groups of related sections.
+    #[serde(rename = "groups", skip_serializing_if = "Option::is_none")]
+    pub groups: Option<Vec<EditorGroup>>,
+    /// Sections which do not belong to a group.
+    #[serde(rename = "sections", skip_serializing_if = "Option::is_none")]
+    pub sections: Option<Vec<EditorSection>>,
+}
+
+impl EditorCategory {
+    /// Returns the inputs of the category and of all of its groups and sections.
+    #[must_use]
+    #[inline]
+    pub fn inputs(&self) -> Vec<&EditorInput> {
+        let groups = self.groups.iter().flatten();
+        let sections = self.sections.iter().flatten().chain(
+            groups
+                .clone()
+                .flat_map(|group| group.sections.iter().flatten()),
+        );
+        self.inputs
+            .iter()
+            .flatten()
+            .chain(groups.flat_map(|group| group.inputs.iter().flatten()))
+            .chain(sections.flat_map(|section| section.inputs.iter().flatten()))
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+/// Group of sections within a tab of the editor
+pub struct EditorGroup {
+    /// The name of the group.
+    #[serde(rename = "name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The title of the group.
+    #[serde(rename = "title", skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Inputs which do not belong to a section.
+    #[serde(rename = "inputs", skip_serializing_if = "Option::is_none")]
+    pub inputs: Option<Vec<EditorInput>>,
+    /// The sections of the group.
+    #[serde(rename = "sections", skip_serializing_if = "Option::is_none")]
+    pub sections: Option<Vec<EditorSection>>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+/// Section of related inputs
+pub struct EditorSection {
+    /// The name of the section.
+    #[serde(rename = "name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The title of the section.
+    #[serde(rename = "title", skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// The inputs of the section.
+    #[serde(rename = "inputs", skip_serializing_if = "Option::is_none")]
+    pub inputs: Option<Vec<EditorInput>>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+/// Single setting of the editor
+pub struct EditorInput {
+    /// The ID of the setting. Used as key within `PolicyDef::settings`.
+    #[serde(rename = "id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The label of the setting.
+    #[serde(rename = "name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The kind of input, for example `entry`, `textarea`, `checkbox`, `radio`, `select` or `password`.
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub input_type: Option<String>,
+    /// The value of the setting if it is not specified.
+    #[serde(rename = "default", skip_serializing_if = "Option::is_none")]
+    pub default: Option<EditorValue>,
+    /// The values which can be selected. Only returned by `radio` and `select` inputs.
+    #[serde(rename = "options", skip_serializing_if = "Option::is_none")]
+    pub options: Option<Vec<EditorValue>>,
+    /// Additional help text for the setting.
+    #[serde(rename = "hint", skip_serializing_if = "Option::is_none")]
+    pub hint: Option<String>,
+    /// The example shown in an empty input.
+    #[serde(rename = "placeholder", skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<String>,
+    /// The regular expression a value must match.
+    #[serde(rename = "regex", skip_serializing_if = "Option::is_none")]
+    pub regex: Option<String>,
+    /// Indicates whether the setting must be specified.
+    #[serde(rename = "required", skip_serializing_if = "Option::is_none")]
+    pub required: Option<bool>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Value of an editor or policy setting
+#[serde(untagged)]
+pub enum EditorValue {
+    /// No value, for example of a setting which was never set.
+    Null,
+    /// A flag, for example of a `checkbox` input.
+    Bool(bool),
+    /// A whole number.
+    Integer(i64),
+    /// A decimal number.
+    Float(f64),
+    /// A text. Most settings are returned as text, even if they hold numbers or `yes`/`no`.
+    Text(String),
+    /// A list of values.
+    List(Vec<Self>),
+    /// A nested object.
+    Map(BTreeMap<String, Self>),
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+/// Plugin families of the editor
+pub struct EditorPlugins {
+    /// The plugin families, keyed by name.
+    #[serde(rename = "families", skip_serializing_if = "Option::is_none")]
+    pub families: Option<BTreeMap<String, EditorPluginFamily>>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+/// Plugin family of the editor
+pub struct EditorPluginFamily {
+    /// The ID of the plugin family.
+    #[serde(rename = "id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    /// The number of plugins in the family.
+    #[serde(rename = "count", skip_serializing_if = "Option::is_none")]
+    pub count: Option<u64>,
+    /// Whether the family is `enabled`, `disabled` or `mixed`.
+    #[serde(rename = "status", skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+}
@@ -0,0 +1,110 @@
+use crate::{types::EditorType, Error, HttpRequest, Response, Tenable};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `editor_templates` function
+#[derive(Clone, Debug)]
+pub struct EditorTemplatesReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// Whether to list scan or policy templates
+    pub editor_type: EditorType,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for EditorTemplatesReq<'_> {
+    type Output = EditorTemplates;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let req = Request::builder()
+            .uri(format!(
+                "{}/editor/{editor_type}/templates",
+                self.tenable.uri,
+                editor_type = self.editor_type
+            ))
+            .method(Method::GET)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .body(Vec::new())?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(data)
+    }
+}
+
+impl<'a> From<EditorTemplatesReq<'a>> for Cow<'a, EditorTemplatesReq<'a>> {
+    #[inline]
+    fn from(req: EditorTemplatesReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a EditorTemplatesReq<'a>> for Cow<'a, EditorTemplatesReq<'a>> {
+    #[inline]
+    fn from(req: &'a EditorTemplatesReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Templates returned by `editor_templates`
+pub struct EditorTemplates {
+    /// The templates available for the editor type.
+    #[serde(rename = "templates", skip_serializing_if = "Option::is_none")]
+    pub templates: Option<Vec<EditorTemplate>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Tenable-provided template of a scan or policy
+pub struct EditorTemplate {
+    /// The UUID of the template. Use it as `uuid` when creating a scan or policy.
+    #[serde(rename = "uuid", skip_serializing_if = "Option::is_none")]
+    pub uuid: Option<String>,
+    /// The short name of the template, for example `basic` or `advanced`.
+    #[serde(rename = "name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The title of the template as shown in the user interface.
+    #[serde(rename = "title", skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// The description of the template.
+    #[serde(rename = "desc", skip_serializing_if = "Option::is_none")]
+    pub desc: Option<String>,
+    /// Indicates whether the template can only be used by cloud scanners.
+    #[serde(rename = "cloud_only", skip_serializing_if = "Option::is_none")]
+    pub cloud_only: Option<bool>,
+    /// Indicates whether the template requires a subscription.
+    #[serde(rename = "subscription_only", skip_serializing_if = "Option::is_none")]
+    pub subscription_only: Option<bool>,
+    /// Indicates whether the template is used by agent scans.
+    #[serde(rename = "is_agent", skip_serializing_if = "Option::is_none")]
+    pub is_agent: Option<bool>,
+    /// Indicates whether the template is used by web application scans.
+    #[serde(rename = "is_was", skip_serializing_if = "Option::is_none")]
+    pub is_was: Option<bool>,
+    /// Indicates whether the template is only available to Nessus Manager.
+    #[serde(rename = "manager_only", skip_serializing_if = "Option::is_none")]
+    pub manager_only: Option<bool>,
+    /// Indicates whether the template is no longer supported.
+    #[serde(rename = "unsupported", skip_serializing_if = "Option::is_none")]
+    pub unsupported: Option<bool>,
+    /// A link to more information about the template.
+    #[serde(rename = "more_info", skip_serializing_if = "Option::is_none")]
+    pub more_info: Option<String>,
+    /// The position of the template in the user interface.
+    #[serde(rename = "order", skip_serializing_if = "Option::is_none")]
+    pub order: Option<u32>,
+}
@@ -0,0 +1,77 @@
+use crate::{
+    types::{multipart_file, Secret},
+    Error, HttpRequest, Response, Tenable,
+};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `file_upload` function
+#[derive(Clone, Debug)]
+pub struct FileUploadReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// Name of the file, for example `policy.nessus`. Must not contain control characters
+    /// like line breaks
+    pub file_name: Cow<'a, str>,
+    /// Content of the file. Not included in the `Debug` output
+    pub content: Secret<Cow<'a, [u8]>>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for FileUploadReq<'_> {
+    type Output = UploadedFile;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let (boundary, body) = multipart_file(&self.file_name, &self.content.0)?;
+        let req = Request::builder()
+            .uri(format!("{}/file/upload", self.tenable.uri))
+            .method(Method::POST)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .header(
+                "Content-Type",
+                HeaderValue::from_str(&format!("multipart/form-data; boundary={}", boundary))?,
+            )
+            .body(body)?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(data)
+    }
+}
+
+impl<'a> From<FileUploadReq<'a>> for Cow<'a, FileUploadReq<'a>> {
+    #[inline]
+    fn from(req: FileUploadReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a FileUploadReq<'a>> for Cow<'a, FileUploadReq<'a>> {
+    #[inline]
+    fn from(req: &'a FileUploadReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// File returned by `file_upload`
+pub struct UploadedFile {
+    /// The name of the file on the server. Use it to refer to the file in other requests,
+    /// like `policy_import`.
+    #[serde(rename = "fileuploaded")]
+    pub fileuploaded: String,
+}
@@ -0,0 +1,63 @@
+use crate::{Error, HttpRequest, Response, Tenable};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `group_add_user` function
+#[derive(Clone, Debug)]
+pub struct GroupAddUserReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// ID which identifies the group
+    pub group_id: u64,
+    /// ID which identifies the user
+    pub user_id: u64,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for GroupAddUserReq<'_> {
+    type Output = Option<()>;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let req = Request::builder()
+            .uri(format!(
+                "{}/groups/{group_id}/users/{user_id}",
+                self.tenable.uri,
+                group_id = self.group_id,
+                user_id = self.user_id
+            ))
+            .method(Method::POST)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .body(Vec::new())?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            StatusCode::NOT_FOUND => return Ok(None),
+            _ => return Err(Error::from_response(res)),
+        }
+        Ok(Some(()))
+    }
+}
+
+impl<'a> From<GroupAddUserReq<'a>> for Cow<'a, GroupAddUserReq<'a>> {
+    #[inline]
+    fn from(req: GroupAddUserReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a GroupAddUserReq<'a>> for Cow<'a, GroupAddUserReq<'a>> {
+    #[inline]
+    fn from(req: &'a GroupAddUserReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
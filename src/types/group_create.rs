@@ -0,0 +1,81 @@
+use crate::{types::Group, Error, HttpRequest, Response, Tenable};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `group_create` function
+#[derive(Clone, Debug)]
+pub struct GroupCreateReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// Definition which details the group
+    pub group_def: Cow<'a, GroupDef>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for GroupCreateReq<'_> {
+    type Output = Group;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let payload = serde_json::to_vec(&self.group_def)?;
+        let req = Request::builder()
+            .uri(format!("{}/groups", self.tenable.uri))
+            .method(Method::POST)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .header("Content-Type", HeaderValue::from_static("application/json"))
+            .body(payload)?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(data)
+    }
+}
+
+impl<'a> From<GroupCreateReq<'a>> for Cow<'a, GroupCreateReq<'a>> {
+    #[inline]
+    fn from(req: GroupCreateReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a GroupCreateReq<'a>> for Cow<'a, GroupCreateReq<'a>> {
+    #[inline]
+    fn from(req: &'a GroupCreateReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+/// Details the group to create or rename
+pub struct GroupDef {
+    /// The name of the group.
+    #[serde(rename = "name")]
+    pub name: String,
+}
+
+impl From<GroupDef> for Cow<'_, GroupDef> {
+    #[inline]
+    fn from(req: GroupDef) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a GroupDef> for Cow<'a, GroupDef> {
+    #[inline]
+    fn from(req: &'a GroupDef) -> Self {
+        Cow::Borrowed(req)
+    }
+}
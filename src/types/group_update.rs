@@ -0,0 +1,68 @@
+use crate::{
+    types::{Group, GroupDef},
+    Error, HttpRequest, Response, Tenable,
+};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `group_update` function
+#[derive(Clone, Debug)]
+pub struct GroupUpdateReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// ID which identifies the group
+    pub group_id: u64,
+    /// Definition which details the group
+    pub group_def: Cow<'a, GroupDef>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for GroupUpdateReq<'_> {
+    type Output = Option<Group>;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let payload = serde_json::to_vec(&self.group_def)?;
+        let req = Request::builder()
+            .uri(format!(
+                "{}/groups/{group_id}",
+                self.tenable.uri,
+                group_id = self.group_id
+            ))
+            .method(Method::PUT)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .header("Content-Type", HeaderValue::from_static("application/json"))
+            .body(payload)?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            StatusCode::NOT_FOUND => return Ok(None),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(Some(data))
+    }
+}
+
+impl<'a> From<GroupUpdateReq<'a>> for Cow<'a, GroupUpdateReq<'a>> {
+    #[inline]
+    fn from(req: GroupUpdateReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a GroupUpdateReq<'a>> for Cow<'a, GroupUpdateReq<'a>> {
+    #[inline]
+    fn from(req: &'a GroupUpdateReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
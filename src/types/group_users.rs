@@ -0,0 +1,61 @@
+use crate::{types::Users, Error, HttpRequest, Response, Tenable};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `group_users` function
+#[derive(Clone, Debug)]
+pub struct GroupUsersReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// ID which identifies the group
+    pub group_id: u64,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for GroupUsersReq<'_> {
+    type Output = Option<Users>;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let req = Request::builder()
+            .uri(format!(
+                "{}/groups/{group_id}/users",
+                self.tenable.uri,
+                group_id = self.group_id
+            ))
+            .method(Method::GET)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .body(Vec::new())?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            StatusCode::NOT_FOUND => return Ok(None),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(Some(data))
+    }
+}
+
+impl<'a> From<GroupUsersReq<'a>> for Cow<'a, GroupUsersReq<'a>> {
+    #[inline]
+    fn from(req: GroupUsersReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a GroupUsersReq<'a>> for Cow<'a, GroupUsersReq<'a>> {
+    #[inline]
+    fn from(req: &'a GroupUsersReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
@@ -0,0 +1,86 @@
+use crate::{Error, HttpRequest, Response, Tenable};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `groups` function
+#[derive(Clone, Debug)]
+pub struct GroupsReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for GroupsReq<'_> {
+    type Output = Groups;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let req = Request::builder()
+            .uri(format!("{}/groups", self.tenable.uri))
+            .method(Method::GET)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .body(Vec::new())?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(data)
+    }
+}
+
+impl<'a> From<GroupsReq<'a>> for Cow<'a, GroupsReq<'a>> {
+    #[inline]
+    fn from(req: GroupsReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a GroupsReq<'a>> for Cow<'a, GroupsReq<'a>> {
+    #[inline]
+    fn from(req: &'a GroupsReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Groups returned by `groups`
+pub struct Groups {
+    /// The user groups of the container.
+    #[serde(rename = "groups", skip_serializing_if = "Option::is_none")]
+    pub groups: Option<Vec<Group>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// User group
+pub struct Group {
+    /// The unique ID of the group.
+    #[serde(rename = "id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    /// The UUID of the group.
+    #[serde(rename = "uuid", skip_serializing_if = "Option::is_none")]
+    pub uuid: Option<String>,
+    /// The name of the group.
+    #[serde(rename = "name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The permissions of the group.
+    #[serde(rename = "permissions", skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<u32>,
+    /// The number of users in the group.
+    #[serde(rename = "user_count", skip_serializing_if = "Option::is_none")]
+    pub user_count: Option<u64>,
+    /// The UUID of the container the group belongs to.
+    #[serde(rename = "container_uuid", skip_serializing_if = "Option::is_none")]
+    pub container_uuid: Option<String>,
+}
@@ -1,16 +1,260 @@
 //! Tenable Types returned or expected by server
 
+mod access_group_by_id;
+mod access_group_create;
+mod access_group_delete;
+mod access_group_filters;
+mod access_group_rule_filters;
+mod access_group_update;
+mod access_groups;
 mod acr_update;
+mod agent_bulk_status;
+mod agent_group_add_agent;
+mod agent_group_bulk_status;
+mod agent_group_by_id;
+mod agent_group_create;
+mod agent_group_delete;
+mod agent_group_remove_agent;
+mod agent_group_update;
+mod agent_groups;
+mod agent_unlink;
+mod agents;
+mod agents_unlink;
+mod asset_bulk_job;
 mod asset_by_uuid;
+mod asset_import;
+mod asset_import_job;
+mod asset_import_jobs;
+mod asset_imports;
 mod assets;
+mod assets_delete;
+mod assets_export;
+mod assets_export_cancel;
+mod assets_export_chunk;
+mod assets_export_status;
 mod assets_move;
+mod credential_by_uuid;
+mod credential_create;
+mod credential_delete;
+mod credential_file_upload;
+mod credential_types;
+mod credential_update;
+mod credentials;
+mod editor_policy_by_id;
+mod editor_template_by_uuid;
+mod editor_templates;
+mod file_upload;
+mod group_add_user;
+mod group_create;
+mod group_delete;
+mod group_remove_user;
+mod group_update;
+mod group_users;
+mod groups;
+mod network_assets_not_seen;
+mod network_assignable_scanners;
+mod network_by_id;
+mod network_create;
+mod network_delete;
+mod network_scanner_assign;
+mod network_scanners;
+mod network_update;
+mod networks;
+mod policies;
+mod policy_by_id;
+mod policy_copy;
+mod policy_create;
+mod policy_delete;
+mod policy_export;
+mod policy_import;
+mod policy_update;
+mod scan_by_id;
+mod scan_create;
+mod scan_delete;
+mod scan_export;
+mod scan_export_download;
+mod scan_export_status;
+mod scan_history;
+mod scan_history_by_id;
+mod scan_host_details;
+mod scan_latest_status;
+mod scan_launch;
+mod scan_pause;
+mod scan_plugin_output;
+mod scan_resume;
+mod scan_stop;
+mod scan_update;
+mod scanner_aws_targets;
+mod scanner_by_id;
+mod scanner_delete;
+mod scanner_group_add_scanner;
+mod scanner_group_by_id;
+mod scanner_group_create;
+mod scanner_group_delete;
+mod scanner_group_remove_scanner;
+mod scanner_group_scanners;
+mod scanner_group_update;
+mod scanner_groups;
+mod scanner_link;
+mod scanner_scans;
+mod scanner_update;
+mod scanners;
+mod scans;
+mod tag_assignments;
+mod tag_categories;
+mod tag_category_by_uuid;
+mod tag_category_create;
+mod tag_category_delete;
+mod tag_category_update;
+mod tag_value_by_uuid;
+mod tag_value_create;
+mod tag_value_delete;
+mod tag_value_update;
+mod tag_values;
+mod user_authorizations;
+mod user_by_id;
+mod user_create;
+mod user_delete;
+mod user_enable;
+mod user_keys;
+mod user_update;
+mod users;
+mod vulns_export;
+mod vulns_export_cancel;
+mod vulns_export_chunk;
+mod vulns_export_status;
 
+pub use access_group_by_id::*;
+pub use access_group_create::*;
+pub use access_group_delete::*;
+pub use access_group_filters::*;
+pub use access_group_rule_filters::*;
+pub use access_group_update::*;
+pub use access_groups::*;
 pub use acr_update::*;
+pub use agent_bulk_status::*;
+pub use agent_group_add_agent::*;
+pub use agent_group_bulk_status::*;
+pub use agent_group_by_id::*;
+pub use agent_group_create::*;
+pub use agent_group_delete::*;
+pub use agent_group_remove_agent::*;
+pub use agent_group_update::*;
+pub use agent_groups::*;
+pub use agent_unlink::*;
+pub use agents::*;
+pub use agents_unlink::*;
+pub use asset_bulk_job::*;
 pub use asset_by_uuid::*;
+pub use asset_import::*;
+pub use asset_import_job::*;
+pub use asset_import_jobs::*;
+pub use asset_imports::*;
 pub use assets::*;
+pub use assets_delete::*;
+pub use assets_export::*;
+pub use assets_export_cancel::*;
+pub use assets_export_chunk::*;
+pub use assets_export_status::*;
 pub use assets_move::*;
+pub use credential_by_uuid::*;
+pub use credential_create::*;
+pub use credential_delete::*;
+pub use credential_file_upload::*;
+pub use credential_types::*;
+pub use credential_update::*;
+pub use credentials::*;
+pub use editor_policy_by_id::*;
+pub use editor_template_by_uuid::*;
+pub use editor_templates::*;
+pub use file_upload::*;
+pub use group_add_user::*;
+pub use group_create::*;
+pub use group_delete::*;
+pub use group_remove_user::*;
+pub use group_update::*;
+pub use group_users::*;
+pub use groups::*;
+pub use network_assets_not_seen::*;
+pub use network_assignable_scanners::*;
+pub use network_by_id::*;
+pub use network_create::*;
+pub use network_delete::*;
+pub use network_scanner_assign::*;
+pub use network_scanners::*;
+pub use network_update::*;
+pub use networks::*;
+pub use policies::*;
+pub use policy_by_id::*;
+pub use policy_copy::*;
+pub use policy_create::*;
+pub use policy_delete::*;
+pub use policy_export::*;
+pub use policy_import::*;
+pub use policy_update::*;
+pub use scan_by_id::*;
+pub use scan_create::*;
+pub use scan_delete::*;
+pub use scan_export::*;
+pub use scan_export_download::*;
+pub use scan_export_status::*;
+pub use scan_history::*;
+pub use scan_history_by_id::*;
+pub use scan_host_details::*;
+pub use scan_latest_status::*;
+pub use scan_launch::*;
+pub use scan_pause::*;
+pub use scan_plugin_output::*;
+pub use scan_resume::*;
+pub use scan_stop::*;
+pub use scan_update::*;
+pub use scanner_aws_targets::*;
+pub use scanner_by_id::*;
+pub use scanner_delete::*;
+pub use scanner_group_add_scanner::*;
+pub use scanner_group_by_id::*;
+pub use scanner_group_create::*;
+pub use scanner_group_delete::*;
+pub use scanner_group_remove_scanner::*;
+pub use scanner_group_scanners::*;
+pub use scanner_group_update::*;
+pub use scanner_groups::*;
+pub use scanner_link::*;
+pub use scanner_scans::*;
+pub use scanner_update::*;
+pub use scanners::*;
+pub use scans::*;
+pub use tag_assignments::*;
+pub use tag_categories::*;
+pub use tag_category_by_uuid::*;
+pub use tag_category_create::*;
+pub use tag_category_delete::*;
+pub use tag_category_update::*;
+pub use tag_value_by_uuid::*;
+pub use tag_value_create::*;
+pub use tag_value_delete::*;
+pub use tag_value_update::*;
+pub use tag_values::*;
+pub use user_authorizations::*;
+pub use user_by_id::*;
+pub use user_create::*;
+pub use user_delete::*;
+pub use user_enable::*;
+pub use user_keys::*;
+pub use user_update::*;
+pub use users::*;
+pub use vulns_export::*;
+pub use vulns_export_cancel::*;
+pub use vulns_export_chunk::*;
+pub use vulns_export_status::*;
 
-use serde::{Deserialize, Serialize};
+use crate::Error;
+use serde::{
+    de::{self, IgnoredAny, MapAccess, Visitor},
+    ser::SerializeMap,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+use std::{collections::BTreeMap, fmt};
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 /// `Source` blueprint
@@ -50,3 +294,390 @@ pub struct ScanFrequency {
     #[serde(rename = "licensed", skip_serializing_if = "Option::is_none")]
     pub licensed: Option<bool>,
 }
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Identifies an export job started by one of the `*_export` requests
+pub struct ExportUuid {
+    /// The UUID for the export request. Use this value to check the status of and download the export.
+    #[serde(rename = "export_uuid")]
+    pub export_uuid: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Status of an export job
+pub struct ExportStatus {
+    /// The UUID of the export request. Only returned by vulnerability exports.
+    #[serde(rename = "uuid", skip_serializing_if = "Option::is_none")]
+    pub uuid: Option<String>,
+    /// The status of the export request.
+    #[serde(rename = "status")]
+    pub status: ExportState,
+    /// A list of completed chunks available for download.
+    #[serde(rename = "chunks_available", skip_serializing_if = "Option::is_none")]
+    pub chunks_available: Option<Vec<u64>>,
+    /// A list of chunks for which the export process failed. Only returned by vulnerability exports.
+    #[serde(rename = "chunks_failed", skip_serializing_if = "Option::is_none")]
+    pub chunks_failed: Option<Vec<u64>>,
+    /// A list of chunks for which the export process was cancelled. Only returned by vulnerability exports.
+    #[serde(rename = "chunks_cancelled", skip_serializing_if = "Option::is_none")]
+    pub chunks_cancelled: Option<Vec<u64>>,
+    /// The total number of chunks associated with the export job as a whole. Only returned by vulnerability exports.
+    #[serde(rename = "total_chunks", skip_serializing_if = "Option::is_none")]
+    pub total_chunks: Option<u64>,
+    /// The number of chunks that have been processed and are available for download. Only returned by vulnerability exports.
+    #[serde(rename = "finished_chunks", skip_serializing_if = "Option::is_none")]
+    pub finished_chunks: Option<u64>,
+    /// The number of assets contained in each export chunk. Only returned by vulnerability exports.
+    #[serde(
+        rename = "num_assets_per_chunk",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub num_assets_per_chunk: Option<u64>,
+    /// The Unix timestamp in milliseconds when the export job was created. Only returned by vulnerability exports.
+    #[serde(rename = "created", skip_serializing_if = "Option::is_none")]
+    pub created: Option<u64>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Copy)]
+/// The states an export job passes through
+pub enum ExportState {
+    /// Tenable.io has queued the export request until it completes other requests currently in process.
+    #[serde(rename = "QUEUED")]
+    Queued,
+    /// Tenable.io has started processing the export request.
+    #[serde(rename = "PROCESSING")]
+    Processing,
+    /// Tenable.io has completed processing the export request. The list of chunks is complete.
+    #[serde(rename = "FINISHED")]
+    Finished,
+    /// An administrator has cancelled the export request.
+    #[serde(rename = "CANCELLED")]
+    Cancelled,
+    /// Tenable.io encountered an error while processing the export request.
+    #[serde(rename = "ERROR")]
+    Error,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Copy)]
+/// The states a scan run passes through
+pub enum ScanStatus {
+    /// The scan run is waiting for a scanner.
+    #[serde(rename = "pending")]
+    Pending,
+    /// The scanner is preparing the scan run.
+    #[serde(rename = "initializing")]
+    Initializing,
+    /// The scan run is in progress.
+    #[serde(rename = "running")]
+    Running,
+    /// The scan run is being paused.
+    #[serde(rename = "pausing")]
+    Pausing,
+    /// The scan run is paused and can be resumed.
+    #[serde(rename = "paused")]
+    Paused,
+    /// The paused scan run is being resumed.
+    #[serde(rename = "resuming")]
+    Resuming,
+    /// The scan run is being stopped.
+    #[serde(rename = "stopping")]
+    Stopping,
+    /// The scan run was stopped before it completed.
+    #[serde(rename = "stopped")]
+    Stopped,
+    /// The scan run is being cancelled.
+    #[serde(rename = "canceling")]
+    Canceling,
+    /// The scan run was cancelled.
+    #[serde(rename = "canceled")]
+    Canceled,
+    /// The scan run was aborted because of an error.
+    #[serde(rename = "aborted")]
+    Aborted,
+    /// Tenable.io is processing the results of the scan run.
+    #[serde(rename = "processing")]
+    Processing,
+    /// The scan run completed and the results are available.
+    #[serde(rename = "completed")]
+    Completed,
+    /// The results of the scan were imported.
+    #[serde(rename = "imported")]
+    Imported,
+    /// The scan has never been launched.
+    #[serde(rename = "empty")]
+    Empty,
+    /// A status this crate does not know about yet.
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Position of a page within a paginated list
+pub struct Pagination {
+    /// The total number of entries in the list.
+    #[serde(rename = "total", skip_serializing_if = "Option::is_none")]
+    pub total: Option<u64>,
+    /// The number of entries per page.
+    #[serde(rename = "limit", skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    /// The index of the first entry of the page.
+    #[serde(rename = "offset", skip_serializing_if = "Option::is_none")]
+    pub offset: Option<u64>,
+    /// The fields the list is sorted by.
+    #[serde(rename = "sort", skip_serializing_if = "Option::is_none")]
+    pub sort: Option<Vec<PaginationSort>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Field a paginated list is sorted by
+pub struct PaginationSort {
+    /// The name of the field.
+    #[serde(rename = "name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The sort order, either `asc` or `desc`.
+    #[serde(rename = "order", skip_serializing_if = "Option::is_none")]
+    pub order: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Field which can be used to filter a list
+pub struct Filter {
+    /// The name of the field, as used in filter queries.
+    #[serde(rename = "name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The name of the field as displayed in the user interface.
+    #[serde(rename = "readable_name", skip_serializing_if = "Option::is_none")]
+    pub readable_name: Option<String>,
+    /// The operators the field supports, like `eq`, `match` or `starts`.
+    #[serde(rename = "operators", skip_serializing_if = "Option::is_none")]
+    pub operators: Option<Vec<String>>,
+    /// Describes the values the field accepts.
+    #[serde(rename = "control", skip_serializing_if = "Option::is_none")]
+    pub control: Option<FilterControl>,
+    /// The group the field belongs to in the user interface.
+    #[serde(rename = "group_name", skip_serializing_if = "Option::is_none")]
+    pub group_name: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Values accepted by a filter
+pub struct FilterControl {
+    /// The kind of input, like `entry` for free text or `dropdown` for a fixed list.
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub control_type: Option<String>,
+    /// The regular expression values must match.
+    #[serde(rename = "regex", skip_serializing_if = "Option::is_none")]
+    pub regex: Option<String>,
+    /// Example of a valid value.
+    #[serde(rename = "readable_regex", skip_serializing_if = "Option::is_none")]
+    pub readable_regex: Option<String>,
+}
+
+/// Filters assets by tag. Each entry maps a tag category to the tag values to
+/// match and is sent to Tenable as `tag.<category>`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TagFilters(pub BTreeMap<String, Vec<String>>);
+
+impl Serialize for TagFilters {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+        for (category, values) in &self.0 {
+            map.serialize_entry(&format!("tag.{}", category), values)?;
+        }
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for TagFilters {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct TagFiltersVisitor;
+
+        impl<'de> Visitor<'de> for TagFiltersVisitor {
+            type Value = TagFilters;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "a map of `tag.<category>` keys")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut access: A) -> Result<Self::Value, A::Error> {
+                let mut tags = BTreeMap::new();
+                while let Some(key) = access.next_key::<String>()? {
+                    if let Some(category) = key.strip_prefix("tag.") {
+                        let _ = tags.insert(category.to_owned(), access.next_value()?);
+                    } else {
+                        let _ = access.next_value::<IgnoredAny>()?;
+                    }
+                }
+                Ok(TagFilters(tags))
+            }
+        }
+
+        deserializer.deserialize_map(TagFiltersVisitor)
+    }
+}
+
+/// Narrows down a list to the entries whose `field` matches `value` using
+/// `operator`. Sent to Tenable as `f=<field>:<operator>:<value>`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct QueryFilter {
+    /// The field to filter on, for example `name`.
+    pub field: String,
+    /// The operator comparing the field with the value, for example `eq` or `match`.
+    pub operator: String,
+    /// The value to compare the field with.
+    pub value: String,
+}
+
+impl fmt::Display for QueryFilter {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}:{}", self.field, self.operator, self.value)
+    }
+}
+
+impl Serialize for QueryFilter {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for QueryFilter {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let filter = String::deserialize(deserializer)?;
+        let mut parts = filter.splitn(3, ':');
+        match (parts.next(), parts.next(), parts.next()) {
+            (Some(field), Some(operator), Some(value)) => Ok(Self {
+                field: field.to_owned(),
+                operator: operator.to_owned(),
+                value: value.to_owned(),
+            }),
+            _ => Err(de::Error::invalid_value(
+                de::Unexpected::Str(&filter),
+                &"a filter like `<field>:<operator>:<value>`",
+            )),
+        }
+    }
+}
+
+/// Defines how multiple `QueryFilter`s are combined. Sent to Tenable as `ft`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Copy)]
+pub enum FilterType {
+    /// Entries must match all filters.
+    #[serde(rename = "and")]
+    And,
+    /// Entries must match at least one filter.
+    #[serde(rename = "or")]
+    Or,
+}
+
+impl fmt::Display for FilterType {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::And => write!(f, "and"),
+            Self::Or => write!(f, "or"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Copy)]
+/// Kind of templates offered by the editor
+pub enum EditorType {
+    /// Templates used to create scans.
+    #[serde(rename = "scan")]
+    Scan,
+    /// Templates used to create policies.
+    #[serde(rename = "policy")]
+    Policy,
+}
+
+impl fmt::Display for EditorType {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Scan => write!(f, "scan"),
+            Self::Policy => write!(f, "policy"),
+        }
+    }
+}
+
+#[derive(Clone, Default, PartialEq, Serialize, Deserialize)]
+/// Sensitive value like a password or private key. It is sent to Tenable as is, but
+/// replaced by `<redacted>` in the `Debug` output, so it does not end up in logs.
+#[serde(transparent)]
+pub struct Secret<T = String>(pub T);
+
+impl<T> fmt::Debug for Secret<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("<redacted>")
+    }
+}
+
+impl From<String> for Secret {
+    #[inline]
+    fn from(secret: String) -> Self {
+        Self(secret)
+    }
+}
+
+impl From<&str> for Secret {
+    #[inline]
+    fn from(secret: &str) -> Self {
+        Self(secret.to_owned())
+    }
+}
+
+/// Percent-encodes `value` so it can be used within a query string
+pub(crate) fn encode_query(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b':' => {
+                encoded.push(char::from(byte));
+            }
+            _ => {
+                encoded.push_str(&format!("%{:02X}", byte));
+            }
+        }
+    }
+    encoded
+}
+
+/// Prefix of the boundaries separating the parts of a `multipart/form-data` body
+const BOUNDARY: &str = "----TenableFileBoundary";
+
+/// Builds a `multipart/form-data` body containing `content` as the `Filedata`
+/// part, as expected by the upload endpoints. Returns the boundary, which is
+/// chosen so that it does not occur within `content`, together with the body.
+pub(crate) fn multipart_file<RE: fmt::Debug>(
+    file_name: &str,
+    content: &[u8],
+) -> Result<(String, Vec<u8>), Error<RE>> {
+    // line breaks would end the part header and inject headers of their own
+    if file_name.chars().any(char::is_control) {
+        return Err(Error::InvalidFileName(file_name.to_owned()));
+    }
+    let mut boundary = BOUNDARY.to_owned();
+    let mut counter: u64 = 0;
+    while content
+        .windows(boundary.len())
+        .any(|window| window == boundary.as_bytes())
+    {
+        counter = counter.wrapping_add(1);
+        boundary = format!("{}{:016x}", BOUNDARY, counter);
+    }
+    let mut body = format!(
+        "--{}\r\nContent-Disposition: form-data; name=\"Filedata\"; filename=\"{}\"\r\nContent-Type: application/octet-stream\r\n\r\n",
+        boundary,
+        file_name.replace('"', "%22")
+    )
+    .into_bytes();
+    body.extend_from_slice(content);
+    body.extend_from_slice(format!("\r\n--{}--\r\n", boundary).as_bytes());
+    Ok((boundary, body))
+}
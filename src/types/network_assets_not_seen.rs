@@ -0,0 +1,73 @@
+use crate::{Error, HttpRequest, Response, Tenable};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `network_assets_not_seen` function
+#[derive(Clone, Debug)]
+pub struct NetworkAssetsNotSeenReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// UUID which identifies the network
+    pub network_id: Cow<'a, str>,
+    /// Number of days the assets have not been seen in
+    pub days: u32,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for NetworkAssetsNotSeenReq<'_> {
+    type Output = Option<NetworkAssetsNotSeen>;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let req = Request::builder()
+            .uri(format!(
+                "{}/networks/{network_id}/counts/assets-not-seen-in/{days}",
+                self.tenable.uri,
+                network_id = self.network_id,
+                days = self.days
+            ))
+            .method(Method::GET)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .body(Vec::new())?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            StatusCode::NOT_FOUND => return Ok(None),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(Some(data))
+    }
+}
+
+impl<'a> From<NetworkAssetsNotSeenReq<'a>> for Cow<'a, NetworkAssetsNotSeenReq<'a>> {
+    #[inline]
+    fn from(req: NetworkAssetsNotSeenReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a NetworkAssetsNotSeenReq<'a>> for Cow<'a, NetworkAssetsNotSeenReq<'a>> {
+    #[inline]
+    fn from(req: &'a NetworkAssetsNotSeenReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Copy)]
+/// Number of assets returned by `network_assets_not_seen`
+pub struct NetworkAssetsNotSeen {
+    /// The number of assets of the network which have not been seen in the given number of days.
+    #[serde(rename = "numAssetsNotSeen", skip_serializing_if = "Option::is_none")]
+    pub num_assets_not_seen: Option<u64>,
+}
@@ -0,0 +1,61 @@
+use crate::{types::NetworkScanners, Error, HttpRequest, Response, Tenable};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `network_assignable_scanners` function
+#[derive(Clone, Debug)]
+pub struct NetworkAssignableScannersReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// UUID which identifies the network
+    pub network_id: Cow<'a, str>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for NetworkAssignableScannersReq<'_> {
+    type Output = Option<NetworkScanners>;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let req = Request::builder()
+            .uri(format!(
+                "{}/networks/{network_id}/assignable-scanners",
+                self.tenable.uri,
+                network_id = self.network_id
+            ))
+            .method(Method::GET)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .body(Vec::new())?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            StatusCode::NOT_FOUND => return Ok(None),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(Some(data))
+    }
+}
+
+impl<'a> From<NetworkAssignableScannersReq<'a>> for Cow<'a, NetworkAssignableScannersReq<'a>> {
+    #[inline]
+    fn from(req: NetworkAssignableScannersReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a NetworkAssignableScannersReq<'a>> for Cow<'a, NetworkAssignableScannersReq<'a>> {
+    #[inline]
+    fn from(req: &'a NetworkAssignableScannersReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
@@ -0,0 +1,87 @@
+use crate::{types::Network, Error, HttpRequest, Response, Tenable};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `network_create` function
+#[derive(Clone, Debug)]
+pub struct NetworkCreateReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// Definition which details the network
+    pub network_def: Cow<'a, NetworkDef>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for NetworkCreateReq<'_> {
+    type Output = Network;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let payload = serde_json::to_vec(&self.network_def)?;
+        let req = Request::builder()
+            .uri(format!("{}/networks", self.tenable.uri))
+            .method(Method::POST)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .header("Content-Type", HeaderValue::from_static("application/json"))
+            .body(payload)?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(data)
+    }
+}
+
+impl<'a> From<NetworkCreateReq<'a>> for Cow<'a, NetworkCreateReq<'a>> {
+    #[inline]
+    fn from(req: NetworkCreateReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a NetworkCreateReq<'a>> for Cow<'a, NetworkCreateReq<'a>> {
+    #[inline]
+    fn from(req: &'a NetworkCreateReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+/// Details a network to create or update
+pub struct NetworkDef {
+    /// The name of the network. Must be unique within the container.
+    #[serde(rename = "name")]
+    pub name: String,
+    /// The description of the network.
+    #[serde(rename = "description", skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The number of days, between 1 and 365, after which Tenable.io deletes assets of the network which have not been seen.
+    #[serde(rename = "assets_ttl_days", skip_serializing_if = "Option::is_none")]
+    pub assets_ttl_days: Option<u32>,
+}
+
+impl From<NetworkDef> for Cow<'_, NetworkDef> {
+    #[inline]
+    fn from(req: NetworkDef) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a NetworkDef> for Cow<'a, NetworkDef> {
+    #[inline]
+    fn from(req: &'a NetworkDef) -> Self {
+        Cow::Borrowed(req)
+    }
+}
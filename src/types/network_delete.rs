@@ -0,0 +1,60 @@
+use crate::{Error, HttpRequest, Response, Tenable};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `network_delete` function
+#[derive(Clone, Debug)]
+pub struct NetworkDeleteReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// UUID which identifies the network
+    pub network_id: Cow<'a, str>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for NetworkDeleteReq<'_> {
+    type Output = Option<()>;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let req = Request::builder()
+            .uri(format!(
+                "{}/networks/{network_id}",
+                self.tenable.uri,
+                network_id = self.network_id
+            ))
+            .method(Method::DELETE)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .body(Vec::new())?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            StatusCode::NOT_FOUND => return Ok(None),
+            _ => return Err(Error::from_response(res)),
+        }
+        Ok(Some(()))
+    }
+}
+
+impl<'a> From<NetworkDeleteReq<'a>> for Cow<'a, NetworkDeleteReq<'a>> {
+    #[inline]
+    fn from(req: NetworkDeleteReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a NetworkDeleteReq<'a>> for Cow<'a, NetworkDeleteReq<'a>> {
+    #[inline]
+    fn from(req: &'a NetworkDeleteReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
@@ -0,0 +1,70 @@
+use crate::{types::Scanner, Error, HttpRequest, Response, Tenable};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `network_scanners` function
+#[derive(Clone, Debug)]
+pub struct NetworkScannersReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// UUID which identifies the network
+    pub network_id: Cow<'a, str>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for NetworkScannersReq<'_> {
+    type Output = Option<NetworkScanners>;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let req = Request::builder()
+            .uri(format!(
+                "{}/networks/{network_id}/scanners",
+                self.tenable.uri,
+                network_id = self.network_id
+            ))
+            .method(Method::GET)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .body(Vec::new())?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            StatusCode::NOT_FOUND => return Ok(None),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(Some(data))
+    }
+}
+
+impl<'a> From<NetworkScannersReq<'a>> for Cow<'a, NetworkScannersReq<'a>> {
+    #[inline]
+    fn from(req: NetworkScannersReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a NetworkScannersReq<'a>> for Cow<'a, NetworkScannersReq<'a>> {
+    #[inline]
+    fn from(req: &'a NetworkScannersReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Scanners returned by `network_scanners` and `network_assignable_scanners`
+pub struct NetworkScanners {
+    /// The scanners and scanner groups.
+    #[serde(rename = "scanners", skip_serializing_if = "Option::is_none")]
+    pub scanners: Option<Vec<Scanner>>,
+}
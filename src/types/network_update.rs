@@ -0,0 +1,68 @@
+use crate::{
+    types::{Network, NetworkDef},
+    Error, HttpRequest, Response, Tenable,
+};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `network_update` function
+#[derive(Clone, Debug)]
+pub struct NetworkUpdateReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// UUID which identifies the network
+    pub network_id: Cow<'a, str>,
+    /// Definition which details the network
+    pub network_def: Cow<'a, NetworkDef>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for NetworkUpdateReq<'_> {
+    type Output = Option<Network>;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let payload = serde_json::to_vec(&self.network_def)?;
+        let req = Request::builder()
+            .uri(format!(
+                "{}/networks/{network_id}",
+                self.tenable.uri,
+                network_id = self.network_id
+            ))
+            .method(Method::PUT)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .header("Content-Type", HeaderValue::from_static("application/json"))
+            .body(payload)?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            StatusCode::NOT_FOUND => return Ok(None),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(Some(data))
+    }
+}
+
+impl<'a> From<NetworkUpdateReq<'a>> for Cow<'a, NetworkUpdateReq<'a>> {
+    #[inline]
+    fn from(req: NetworkUpdateReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a NetworkUpdateReq<'a>> for Cow<'a, NetworkUpdateReq<'a>> {
+    #[inline]
+    fn from(req: &'a NetworkUpdateReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
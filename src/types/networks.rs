@@ -0,0 +1,150 @@
+use crate::{
+    types::{encode_query, FilterType, Pagination, QueryFilter},
+    Error, HttpRequest, Response, Tenable,
+};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `networks` function
+#[derive(Clone, Debug)]
+pub struct NetworksReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// Returns only networks matching the filters, for example `name:eq:Default`
+    pub filters: Option<Vec<QueryFilter>>,
+    /// Defines whether networks must match all or any of the filters
+    pub filter_type: Option<FilterType>,
+    /// Maximum number of networks to return
+    pub limit: Option<u64>,
+    /// Number of networks to skip
+    pub offset: Option<u64>,
+    /// Sorts the networks by the given field and order, for example `name:asc`
+    pub sort: Option<Cow<'a, str>>,
+    /// Includes networks which have been deleted
+    pub include_deleted: Option<bool>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for NetworksReq<'_> {
+    type Output = Networks;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let mut query = Vec::new();
+        if let Some(ref filters) = self.filters {
+            for filter in filters.iter() {
+                query.push(format!("f={}", encode_query(&filter.to_string())));
+            }
+        }
+        if let Some(filter_type) = self.filter_type {
+            query.push(format!("ft={}", filter_type));
+        }
+        if let Some(limit) = self.limit {
+            query.push(format!("limit={}", limit));
+        }
+        if let Some(offset) = self.offset {
+            query.push(format!("offset={}", offset));
+        }
+        if let Some(ref sort) = self.sort {
+            query.push(format!("sort={}", encode_query(sort)));
+        }
+        if let Some(include_deleted) = self.include_deleted {
+            query.push(format!("includeDeleted={}", include_deleted));
+        }
+        let mut uri = format!("{}/networks", self.tenable.uri);
+        if !query.is_empty() {
+            uri = format!("{}?{}", uri, query.join("&"));
+        }
+        let req = Request::builder()
+            .uri(uri)
+            .method(Method::GET)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .body(Vec::new())?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(data)
+    }
+}
+
+impl<'a> From<NetworksReq<'a>> for Cow<'a, NetworksReq<'a>> {
+    #[inline]
+    fn from(req: NetworksReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a NetworksReq<'a>> for Cow<'a, NetworksReq<'a>> {
+    #[inline]
+    fn from(req: &'a NetworksReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Networks returned by `networks`
+pub struct Networks {
+    /// The networks.
+    #[serde(rename = "networks", skip_serializing_if = "Option::is_none")]
+    pub networks: Option<Vec<Network>>,
+    /// The position of the returned networks within all networks.
+    #[serde(rename = "pagination", skip_serializing_if = "Option::is_none")]
+    pub pagination: Option<Pagination>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Represents a network, which separates assets with overlapping IP addresses
+pub struct Network {
+    /// The UUID of the network.
+    #[serde(rename = "uuid", skip_serializing_if = "Option::is_none")]
+    pub uuid: Option<String>,
+    /// The name of the network.
+    #[serde(rename = "name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The description of the network.
+    #[serde(rename = "description", skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Indicates whether the network is the `Default` network, which cannot be changed or deleted.
+    #[serde(rename = "is_default", skip_serializing_if = "Option::is_none")]
+    pub is_default: Option<bool>,
+    /// The number of days after which Tenable.io deletes assets of the network which have not been seen.
+    #[serde(rename = "assets_ttl_days", skip_serializing_if = "Option::is_none")]
+    pub assets_ttl_days: Option<u32>,
+    /// The number of scanners and scanner groups assigned to the network.
+    #[serde(rename = "scanner_count", skip_serializing_if = "Option::is_none")]
+    pub scanner_count: Option<u64>,
+    /// The UUID of the owner of the network.
+    #[serde(rename = "owner_uuid", skip_serializing_if = "Option::is_none")]
+    pub owner_uuid: Option<String>,
+    /// The unix timestamp in milliseconds when the network was created.
+    #[serde(rename = "created", skip_serializing_if = "Option::is_none")]
+    pub created: Option<u64>,
+    /// The UUID of the user who created the network.
+    #[serde(rename = "created_by", skip_serializing_if = "Option::is_none")]
+    pub created_by: Option<String>,
+    /// The unix timestamp in milliseconds when the network was last updated.
+    #[serde(rename = "modified", skip_serializing_if = "Option::is_none")]
+    pub modified: Option<u64>,
+    /// The UUID of the user who last updated the network.
+    #[serde(rename = "modified_by", skip_serializing_if = "Option::is_none")]
+    pub modified_by: Option<String>,
+    /// The unix timestamp in milliseconds when the network was deleted. Only returned for deleted networks.
+    #[serde(rename = "deleted", skip_serializing_if = "Option::is_none")]
+    pub deleted: Option<u64>,
+    /// The UUID of the user who deleted the network. Only returned for deleted networks.
+    #[serde(rename = "deleted_by", skip_serializing_if = "Option::is_none")]
+    pub deleted_by: Option<String>,
+}
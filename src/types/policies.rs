@@ -0,0 +1,104 @@
+use crate::{Error, HttpRequest, Response, Tenable};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `policies` function
+#[derive(Clone, Debug)]
+pub struct PoliciesReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for PoliciesReq<'_> {
+    type Output = Policies;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let req = Request::builder()
+            .uri(format!("{}/policies", self.tenable.uri))
+            .method(Method::GET)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .body(Vec::new())?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(data)
+    }
+}
+
+impl<'a> From<PoliciesReq<'a>> for Cow<'a, PoliciesReq<'a>> {
+    #[inline]
+    fn from(req: PoliciesReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a PoliciesReq<'a>> for Cow<'a, PoliciesReq<'a>> {
+    #[inline]
+    fn from(req: &'a PoliciesReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Policies returned by `policies`
+pub struct Policies {
+    /// The policies the current user can view.
+    #[serde(rename = "policies", skip_serializing_if = "Option::is_none")]
+    pub policies: Option<Vec<Policy>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// User-defined scan policy
+pub struct Policy {
+    /// The unique ID of the policy.
+    #[serde(rename = "id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    /// The UUID of the template the policy is based on.
+    #[serde(rename = "template_uuid", skip_serializing_if = "Option::is_none")]
+    pub template_uuid: Option<String>,
+    /// The name of the policy.
+    #[serde(rename = "name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The description of the policy.
+    #[serde(rename = "description", skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The username of the owner of the policy.
+    #[serde(rename = "owner", skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    /// The ID of the owner of the policy.
+    #[serde(rename = "owner_id", skip_serializing_if = "Option::is_none")]
+    pub owner_id: Option<u64>,
+    /// The visibility of the policy, `private` or `shared`.
+    #[serde(rename = "visibility", skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<String>,
+    /// `1` if the policy is shared with other users, `0` otherwise.
+    #[serde(rename = "shared", skip_serializing_if = "Option::is_none")]
+    pub shared: Option<u8>,
+    /// The permissions the current user has for the policy.
+    #[serde(rename = "user_permissions", skip_serializing_if = "Option::is_none")]
+    pub user_permissions: Option<u32>,
+    /// The Unix timestamp when the policy was created.
+    #[serde(rename = "creation_date", skip_serializing_if = "Option::is_none")]
+    pub creation_date: Option<u64>,
+    /// The Unix timestamp when the policy was last modified.
+    #[serde(
+        rename = "last_modification_date",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub last_modification_date: Option<u64>,
+}
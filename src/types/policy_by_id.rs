@@ -0,0 +1,73 @@
+use crate::{types::EditorValue, Error, HttpRequest, Response, Tenable};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, collections::BTreeMap, fmt};
+
+/// Request Object for the `policy_by_id` function
+#[derive(Clone, Debug)]
+pub struct PolicyByIdReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// ID which identifies the policy
+    pub policy_id: u64,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for PolicyByIdReq<'_> {
+    type Output = Option<PolicyDetails>;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let req = Request::builder()
+            .uri(format!(
+                "{}/policies/{policy_id}",
+                self.tenable.uri,
+                policy_id = self.policy_id
+            ))
+            .method(Method::GET)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .body(Vec::new())?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            StatusCode::NOT_FOUND => return Ok(None),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(Some(data))
+    }
+}
+
+impl<'a> From<PolicyByIdReq<'a>> for Cow<'a, PolicyByIdReq<'a>> {
+    #[inline]
+    fn from(req: PolicyByIdReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a PolicyByIdReq<'a>> for Cow<'a, PolicyByIdReq<'a>> {
+    #[inline]
+    fn from(req: &'a PolicyByIdReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Template and settings of a policy as returned by `policy_by_id`
+pub struct PolicyDetails {
+    /// The UUID of the template the policy is based on.
+    #[serde(rename = "uuid", skip_serializing_if = "Option::is_none")]
+    pub uuid: Option<String>,
+    /// The settings of the policy, keyed by the ID of their `EditorInput`.
+    #[serde(rename = "settings", skip_serializing_if = "Option::is_none")]
+    pub settings: Option<BTreeMap<String, EditorValue>>,
+}
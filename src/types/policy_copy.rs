@@ -0,0 +1,73 @@
+use crate::{Error, HttpRequest, Response, Tenable};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `policy_copy` function
+#[derive(Clone, Debug)]
+pub struct PolicyCopyReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// ID which identifies the policy
+    pub policy_id: u64,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for PolicyCopyReq<'_> {
+    type Output = Option<PolicyCopy>;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let req = Request::builder()
+            .uri(format!(
+                "{}/policies/{policy_id}/copy",
+                self.tenable.uri,
+                policy_id = self.policy_id
+            ))
+            .method(Method::POST)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .body(Vec::new())?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            StatusCode::NOT_FOUND => return Ok(None),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(Some(data))
+    }
+}
+
+impl<'a> From<PolicyCopyReq<'a>> for Cow<'a, PolicyCopyReq<'a>> {
+    #[inline]
+    fn from(req: PolicyCopyReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a PolicyCopyReq<'a>> for Cow<'a, PolicyCopyReq<'a>> {
+    #[inline]
+    fn from(req: &'a PolicyCopyReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Policy returned by `policy_copy`
+pub struct PolicyCopy {
+    /// The unique ID of the copy.
+    #[serde(rename = "id")]
+    pub id: u64,
+    /// The name of the copy.
+    #[serde(rename = "name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
@@ -0,0 +1,95 @@
+use crate::{types::EditorValue, Error, HttpRequest, Response, Tenable};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, collections::BTreeMap, fmt};
+
+/// Request Object for the `policy_create` function
+#[derive(Clone, Debug)]
+pub struct PolicyCreateReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// Definition which details the policy
+    pub policy_def: Cow<'a, PolicyDef>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for PolicyCreateReq<'_> {
+    type Output = PolicyCreated;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let payload = serde_json::to_vec(&self.policy_def)?;
+        let req = Request::builder()
+            .uri(format!("{}/policies", self.tenable.uri))
+            .method(Method::POST)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .header("Content-Type", HeaderValue::from_static("application/json"))
+            .body(payload)?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(data)
+    }
+}
+
+impl<'a> From<PolicyCreateReq<'a>> for Cow<'a, PolicyCreateReq<'a>> {
+    #[inline]
+    fn from(req: PolicyCreateReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a PolicyCreateReq<'a>> for Cow<'a, PolicyCreateReq<'a>> {
+    #[inline]
+    fn from(req: &'a PolicyCreateReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+/// Details the template and settings of a policy to create or update
+pub struct PolicyDef {
+    /// The UUID of the template the policy is based on. Use `editor_templates` to find the UUIDs of the available templates.
+    #[serde(rename = "uuid")]
+    pub uuid: String,
+    /// The settings of the policy, keyed by the ID of their `EditorInput`. The `name` setting is required.
+    #[serde(rename = "settings")]
+    pub settings: BTreeMap<String, EditorValue>,
+}
+
+impl From<PolicyDef> for Cow<'_, PolicyDef> {
+    #[inline]
+    fn from(req: PolicyDef) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a PolicyDef> for Cow<'a, PolicyDef> {
+    #[inline]
+    fn from(req: &'a PolicyDef) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Policy returned by `policy_create`
+pub struct PolicyCreated {
+    /// The unique ID of the new policy.
+    #[serde(rename = "policy_id")]
+    pub policy_id: u64,
+    /// The name of the new policy.
+    #[serde(rename = "policy_name", skip_serializing_if = "Option::is_none")]
+    pub policy_name: Option<String>,
+}
@@ -0,0 +1,60 @@
+use crate::{Error, HttpRequest, Response, Tenable};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `policy_delete` function
+#[derive(Clone, Debug)]
+pub struct PolicyDeleteReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// ID which identifies the policy
+    pub policy_id: u64,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for PolicyDeleteReq<'_> {
+    type Output = Option<()>;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let req = Request::builder()
+            .uri(format!(
+                "{}/policies/{policy_id}",
+                self.tenable.uri,
+                policy_id = self.policy_id
+            ))
+            .method(Method::DELETE)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .body(Vec::new())?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            StatusCode::NOT_FOUND => return Ok(None),
+            _ => return Err(Error::from_response(res)),
+        }
+        Ok(Some(()))
+    }
+}
+
+impl<'a> From<PolicyDeleteReq<'a>> for Cow<'a, PolicyDeleteReq<'a>> {
+    #[inline]
+    fn from(req: PolicyDeleteReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a PolicyDeleteReq<'a>> for Cow<'a, PolicyDeleteReq<'a>> {
+    #[inline]
+    fn from(req: &'a PolicyDeleteReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
@@ -0,0 +1,64 @@
+use crate::{Error, HttpRequest, Response, Tenable};
+use bytes::Bytes;
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `policy_export` function
+#[derive(Clone, Debug)]
+pub struct PolicyExportReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// ID which identifies the policy
+    pub policy_id: u64,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for PolicyExportReq<'_> {
+    type Output = Option<Bytes>;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let req = Request::builder()
+            .uri(format!(
+                "{}/policies/{policy_id}/export",
+                self.tenable.uri,
+                policy_id = self.policy_id
+            ))
+            .method(Method::GET)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header(
+                "Accept",
+                HeaderValue::from_static("application/octet-stream"),
+            )
+            .body(Vec::new())?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            StatusCode::NOT_FOUND => return Ok(None),
+            _ => return Err(Error::from_response(res)),
+        }
+        Ok(Some(res.body))
+    }
+}
+
+impl<'a> From<PolicyExportReq<'a>> for Cow<'a, PolicyExportReq<'a>> {
+    #[inline]
+    fn from(req: PolicyExportReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a PolicyExportReq<'a>> for Cow<'a, PolicyExportReq<'a>> {
+    #[inline]
+    fn from(req: &'a PolicyExportReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
@@ -0,0 +1,65 @@
+use crate::{types::Policy, Error, HttpRequest, Response, Tenable};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use serde::Serialize;
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `policy_import` function
+#[derive(Clone, Debug)]
+pub struct PolicyImportReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// Name of the `.nessus` policy file as returned by `file_upload`
+    pub file: Cow<'a, str>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for PolicyImportReq<'_> {
+    type Output = Policy;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let payload = serde_json::to_vec(&PolicyImportPayload { file: &self.file })?;
+        let req = Request::builder()
+            .uri(format!("{}/policies/import", self.tenable.uri))
+            .method(Method::POST)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .header("Content-Type", HeaderValue::from_static("application/json"))
+            .body(payload)?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(data)
+    }
+}
+
+impl<'a> From<PolicyImportReq<'a>> for Cow<'a, PolicyImportReq<'a>> {
+    #[inline]
+    fn from(req: PolicyImportReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a PolicyImportReq<'a>> for Cow<'a, PolicyImportReq<'a>> {
+    #[inline]
+    fn from(req: &'a PolicyImportReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+/// Body of the `policy_import` request
+#[derive(Serialize)]
+struct PolicyImportPayload<'a> {
+    file: &'a str,
+}
@@ -0,0 +1,64 @@
+use crate::{types::PolicyDef, Error, HttpRequest, Response, Tenable};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `policy_update` function
+#[derive(Clone, Debug)]
+pub struct PolicyUpdateReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// ID which identifies the policy
+    pub policy_id: u64,
+    /// Definition which details the policy
+    pub policy_def: Cow<'a, PolicyDef>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for PolicyUpdateReq<'_> {
+    type Output = Option<()>;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let payload = serde_json::to_vec(&self.policy_def)?;
+        let req = Request::builder()
+            .uri(format!(
+                "{}/policies/{policy_id}",
+                self.tenable.uri,
+                policy_id = self.policy_id
+            ))
+            .method(Method::PUT)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .header("Content-Type", HeaderValue::from_static("application/json"))
+            .body(payload)?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            StatusCode::NOT_FOUND => return Ok(None),
+            _ => return Err(Error::from_response(res)),
+        }
+        Ok(Some(()))
+    }
+}
+
+impl<'a> From<PolicyUpdateReq<'a>> for Cow<'a, PolicyUpdateReq<'a>> {
+    #[inline]
+    fn from(req: PolicyUpdateReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a PolicyUpdateReq<'a>> for Cow<'a, PolicyUpdateReq<'a>> {
+    #[inline]
+    fn from(req: &'a PolicyUpdateReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
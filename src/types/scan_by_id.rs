@@ -0,0 +1,202 @@
+use crate::{types::ScanStatus, Error, HttpRequest, Response, Tenable};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `scan_by_id` function
+#[derive(Clone, Debug)]
+pub struct ScanByIdReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// ID or UUID which identifies the scan
+    pub scan_id: Cow<'a, str>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for ScanByIdReq<'_> {
+    type Output = Option<ScanDetails>;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let req = Request::builder()
+            .uri(format!(
+                "{}/scans/{scan_id}",
+                self.tenable.uri,
+                scan_id = self.scan_id
+            ))
+            .method(Method::GET)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .body(Vec::new())?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            StatusCode::NOT_FOUND => return Ok(None),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(Some(data))
+    }
+}
+
+impl<'a> From<ScanByIdReq<'a>> for Cow<'a, ScanByIdReq<'a>> {
+    #[inline]
+    fn from(req: ScanByIdReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a ScanByIdReq<'a>> for Cow<'a, ScanByIdReq<'a>> {
+    #[inline]
+    fn from(req: &'a ScanByIdReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Results of the latest run of a scan as returned by `scan_by_id`
+pub struct ScanDetails {
+    /// General information about the scan.
+    #[serde(rename = "info", skip_serializing_if = "Option::is_none")]
+    pub info: Option<ScanInfo>,
+    /// The hosts found by the scan.
+    #[serde(rename = "hosts", skip_serializing_if = "Option::is_none")]
+    pub hosts: Option<Vec<ScanHost>>,
+    /// The vulnerabilities found by the scan, grouped by plugin.
+    #[serde(rename = "vulnerabilities", skip_serializing_if = "Option::is_none")]
+    pub vulnerabilities: Option<Vec<ScanVulnerability>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// General information about a scan
+pub struct ScanInfo {
+    /// The unique ID of the scan.
+    #[serde(rename = "object_id", skip_serializing_if = "Option::is_none")]
+    pub object_id: Option<u64>,
+    /// The UUID of the scan run.
+    #[serde(rename = "uuid", skip_serializing_if = "Option::is_none")]
+    pub uuid: Option<String>,
+    /// The UUID of the scan schedule.
+    #[serde(rename = "schedule_uuid", skip_serializing_if = "Option::is_none")]
+    pub schedule_uuid: Option<String>,
+    /// The name of the scan.
+    #[serde(rename = "name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The status of the scan run.
+    #[serde(rename = "status", skip_serializing_if = "Option::is_none")]
+    pub status: Option<ScanStatus>,
+    /// The username of the owner of the scan.
+    #[serde(rename = "owner", skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    /// The name of the policy used by the scan.
+    #[serde(rename = "policy", skip_serializing_if = "Option::is_none")]
+    pub policy: Option<String>,
+    /// The type of the scan, for example `remote` or `agent`.
+    #[serde(rename = "scan_type", skip_serializing_if = "Option::is_none")]
+    pub scan_type: Option<String>,
+    /// The targets of the scan run.
+    #[serde(rename = "targets", skip_serializing_if = "Option::is_none")]
+    pub targets: Option<String>,
+    /// Indicates whether the scan run used `alt_targets` instead of the configured targets.
+    #[serde(rename = "alt_targets_used", skip_serializing_if = "Option::is_none")]
+    pub alt_targets_used: Option<bool>,
+    /// The number of hosts found by the scan run.
+    #[serde(rename = "hostcount", skip_serializing_if = "Option::is_none")]
+    pub hostcount: Option<u64>,
+    /// The ID of the folder containing the scan.
+    #[serde(rename = "folder_id", skip_serializing_if = "Option::is_none")]
+    pub folder_id: Option<u64>,
+    /// Indicates whether the user is able to launch, pause or stop the scan.
+    #[serde(rename = "control", skip_serializing_if = "Option::is_none")]
+    pub control: Option<bool>,
+    /// Indicates whether the user is able to edit the scan.
+    #[serde(rename = "edit_allowed", skip_serializing_if = "Option::is_none")]
+    pub edit_allowed: Option<bool>,
+    /// The permissions the current user has for the scan.
+    #[serde(rename = "user_permissions", skip_serializing_if = "Option::is_none")]
+    pub user_permissions: Option<u32>,
+    /// The Unix timestamp when the scan run started.
+    #[serde(rename = "scan_start", skip_serializing_if = "Option::is_none")]
+    pub scan_start: Option<u64>,
+    /// The Unix timestamp when the scan run finished.
+    #[serde(rename = "scan_end", skip_serializing_if = "Option::is_none")]
+    pub scan_end: Option<u64>,
+    /// The Unix timestamp when the scanner started the scan run.
+    #[serde(rename = "scanner_start", skip_serializing_if = "Option::is_none")]
+    pub scanner_start: Option<u64>,
+    /// The Unix timestamp when the scanner finished the scan run.
+    #[serde(rename = "scanner_end", skip_serializing_if = "Option::is_none")]
+    pub scanner_end: Option<u64>,
+    /// The Unix timestamp when the results were last updated.
+    #[serde(rename = "timestamp", skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<u64>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Represents a host found by a scan
+pub struct ScanHost {
+    /// The ID of the host within the scan results.
+    #[serde(rename = "host_id", skip_serializing_if = "Option::is_none")]
+    pub host_id: Option<u64>,
+    /// The UUID of the asset the host belongs to.
+    #[serde(rename = "uuid", skip_serializing_if = "Option::is_none")]
+    pub uuid: Option<String>,
+    /// The name, IP address or FQDN of the host.
+    #[serde(rename = "hostname", skip_serializing_if = "Option::is_none")]
+    pub hostname: Option<String>,
+    /// The number of critical findings on the host.
+    #[serde(rename = "critical", skip_serializing_if = "Option::is_none")]
+    pub critical: Option<u64>,
+    /// The number of high findings on the host.
+    #[serde(rename = "high", skip_serializing_if = "Option::is_none")]
+    pub high: Option<u64>,
+    /// The number of medium findings on the host.
+    #[serde(rename = "medium", skip_serializing_if = "Option::is_none")]
+    pub medium: Option<u64>,
+    /// The number of low findings on the host.
+    #[serde(rename = "low", skip_serializing_if = "Option::is_none")]
+    pub low: Option<u64>,
+    /// The number of informational findings on the host.
+    #[serde(rename = "info", skip_serializing_if = "Option::is_none")]
+    pub info: Option<u64>,
+    /// The weighted score of the findings on the host.
+    #[serde(rename = "score", skip_serializing_if = "Option::is_none")]
+    pub score: Option<u64>,
+    /// The progress of the scan on the host, compared to `scanprogresstotal`.
+    #[serde(
+        rename = "scanprogresscurrent",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub scanprogresscurrent: Option<u64>,
+    /// The total progress of the scan on the host.
+    #[serde(rename = "scanprogresstotal", skip_serializing_if = "Option::is_none")]
+    pub scanprogresstotal: Option<u64>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Represents a plugin which reported findings during a scan
+pub struct ScanVulnerability {
+    /// The ID of the plugin.
+    #[serde(rename = "plugin_id", skip_serializing_if = "Option::is_none")]
+    pub plugin_id: Option<u64>,
+    /// The name of the plugin.
+    #[serde(rename = "plugin_name", skip_serializing_if = "Option::is_none")]
+    pub plugin_name: Option<String>,
+    /// The family of the plugin.
+    #[serde(rename = "plugin_family", skip_serializing_if = "Option::is_none")]
+    pub plugin_family: Option<String>,
+    /// The number of hosts on which the plugin reported a finding.
+    #[serde(rename = "count", skip_serializing_if = "Option::is_none")]
+    pub count: Option<u64>,
+    /// The severity of the finding: 0 for info, 1 for low, 2 for medium, 3 for high and 4 for critical.
+    #[serde(rename = "severity", skip_serializing_if = "Option::is_none")]
+    pub severity: Option<u8>,
+}
@@ -0,0 +1,216 @@
+use crate::{Error, HttpRequest, Response, Tenable};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `scan_create` function
+#[derive(Clone, Debug)]
+pub struct ScanCreateReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// Definition which details the scan
+    pub scan_def: Cow<'a, ScanDef>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for ScanCreateReq<'_> {
+    type Output = ScanCreated;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let payload = serde_json::to_vec(&self.scan_def)?;
+        let req = Request::builder()
+            .uri(format!("{}/scans", self.tenable.uri))
+            .method(Method::POST)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .header("Content-Type", HeaderValue::from_static("application/json"))
+            .body(payload)?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(data)
+    }
+}
+
+impl<'a> From<ScanCreateReq<'a>> for Cow<'a, ScanCreateReq<'a>> {
+    #[inline]
+    fn from(req: ScanCreateReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a ScanCreateReq<'a>> for Cow<'a, ScanCreateReq<'a>> {
+    #[inline]
+    fn from(req: &'a ScanCreateReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+/// Details the template and settings of a scan to create or update
+pub struct ScanDef {
+    /// The UUID of the Tenable-provided template to use. Use `editor_templates` to find the UUIDs of the available templates.
+    #[serde(rename = "uuid")]
+    pub uuid: String,
+    /// The settings of the scan.
+    #[serde(rename = "settings")]
+    pub settings: ScanSettings,
+}
+
+impl From<ScanDef> for Cow<'_, ScanDef> {
+    #[inline]
+    fn from(req: ScanDef) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a ScanDef> for Cow<'a, ScanDef> {
+    #[inline]
+    fn from(req: &'a ScanDef) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+/// Settings of a scan. All fields except `name` are optional.
+pub struct ScanSettings {
+    /// The name of the scan.
+    #[serde(rename = "name")]
+    pub name: String,
+    /// The description of the scan.
+    #[serde(rename = "description", skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The ID of the user-defined policy to use instead of the policy of the template.
+    #[serde(rename = "policy_id", skip_serializing_if = "Option::is_none")]
+    pub policy_id: Option<u64>,
+    /// The ID of the folder in which to store the scan.
+    #[serde(rename = "folder_id", skip_serializing_if = "Option::is_none")]
+    pub folder_id: Option<u64>,
+    /// The UUID of the scanner or scanner group to use. Use `AUTO-ROUTED` to let Tenable.io select the scanner based on the targets.
+    #[serde(rename = "scanner_id", skip_serializing_if = "Option::is_none")]
+    pub scanner_id: Option<String>,
+    /// The UUID of the network the targets belong to.
+    #[serde(
+        rename = "target_network_uuid",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub target_network_uuid: Option<String>,
+    /// Indicates whether the scan schedule is active.
+    #[serde(rename = "enabled", skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    /// When to launch the scan: `ON_DEMAND`, `DAILY`, `WEEKLY`, `MONTHLY` or `YEARLY`.
+    #[serde(rename = "launch", skip_serializing_if = "Option::is_none")]
+    pub launch: Option<String>,
+    /// The start time of the scan schedule, in the format `YYYYMMDDTHHMMSS`.
+    #[serde(rename = "starttime", skip_serializing_if = "Option::is_none")]
+    pub starttime: Option<String>,
+    /// The interval at which the scan repeats, in iCalendar `RRULE` format, for example `FREQ=WEEKLY;INTERVAL=1;BYDAY=MO`.
+    #[serde(rename = "rrules", skip_serializing_if = "Option::is_none")]
+    pub rrules: Option<String>,
+    /// The timezone of the scan schedule, for example `Europe/Berlin`.
+    #[serde(rename = "timezone", skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+    /// The targets to scan as comma-separated list of IP addresses, ranges, CIDRs or hostnames.
+    #[serde(rename = "text_targets", skip_serializing_if = "Option::is_none")]
+    pub text_targets: Option<String>,
+    /// The IDs of the target groups to scan.
+    #[serde(rename = "target_groups", skip_serializing_if = "Option::is_none")]
+    pub target_groups: Option<Vec<u64>>,
+    /// The name of a previously uploaded file containing the targets to scan.
+    #[serde(rename = "file_targets", skip_serializing_if = "Option::is_none")]
+    pub file_targets: Option<String>,
+    /// The UUIDs of the tags whose assets are scanned.
+    #[serde(rename = "tag_targets", skip_serializing_if = "Option::is_none")]
+    pub tag_targets: Option<Vec<String>>,
+    /// The UUIDs of the agent groups to scan. Only used by agent scans.
+    #[serde(rename = "agent_group_id", skip_serializing_if = "Option::is_none")]
+    pub agent_group_id: Option<Vec<String>>,
+    /// Comma-separated list of email addresses to notify once the scan completes.
+    #[serde(rename = "emails", skip_serializing_if = "Option::is_none")]
+    pub emails: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Scan returned by `scan_create`
+pub struct ScanCreated {
+    /// The newly created scan.
+    #[serde(rename = "scan")]
+    pub scan: ScanConfig,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Configuration of a scan as returned by `scan_create` and `scan_update`
+pub struct ScanConfig {
+    /// The unique ID of the scan.
+    #[serde(rename = "id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    /// The UUID of the scan schedule.
+    #[serde(rename = "uuid", skip_serializing_if = "Option::is_none")]
+    pub uuid: Option<String>,
+    /// The name of the scan.
+    #[serde(rename = "name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The description of the scan.
+    #[serde(rename = "description", skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The type of the scan, for example `public` or `agent`.
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub scan_type: Option<String>,
+    /// The username of the owner of the scan.
+    #[serde(rename = "owner", skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    /// The UUID of the owner of the scan.
+    #[serde(rename = "owner_uuid", skip_serializing_if = "Option::is_none")]
+    pub owner_uuid: Option<String>,
+    /// The ID of the policy used by the scan.
+    #[serde(rename = "policy_id", skip_serializing_if = "Option::is_none")]
+    pub policy_id: Option<u64>,
+    /// The UUID of the scanner or scanner group used by the scan.
+    #[serde(rename = "scanner_id", skip_serializing_if = "Option::is_none")]
+    pub scanner_id: Option<String>,
+    /// Indicates whether the scan schedule is active.
+    #[serde(rename = "enabled", skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    /// The targets of the scan.
+    #[serde(rename = "custom_targets", skip_serializing_if = "Option::is_none")]
+    pub custom_targets: Option<String>,
+    /// The interval at which the scan repeats, in iCalendar `RRULE` format.
+    #[serde(rename = "rrules", skip_serializing_if = "Option::is_none")]
+    pub rrules: Option<String>,
+    /// The scheduled start time of the scan, in the format `YYYYMMDDTHHMMSS`.
+    #[serde(rename = "starttime", skip_serializing_if = "Option::is_none")]
+    pub starttime: Option<String>,
+    /// The timezone of the scan schedule.
+    #[serde(rename = "timezone", skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+    /// The email addresses notified once the scan completes.
+    #[serde(rename = "emails", skip_serializing_if = "Option::is_none")]
+    pub emails: Option<String>,
+    /// Indicates whether the scan is shared with other users.
+    #[serde(rename = "shared", skip_serializing_if = "Option::is_none")]
+    pub shared: Option<bool>,
+    /// The permissions the current user has for the scan.
+    #[serde(rename = "user_permissions", skip_serializing_if = "Option::is_none")]
+    pub user_permissions: Option<u32>,
+    /// The Unix timestamp when the scan was created.
+    #[serde(rename = "creation_date", skip_serializing_if = "Option::is_none")]
+    pub creation_date: Option<u64>,
+    /// The Unix timestamp when the scan was last modified.
+    #[serde(
+        rename = "last_modification_date",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub last_modification_date: Option<u64>,
+}
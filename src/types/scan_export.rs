@@ -0,0 +1,243 @@
+use crate::{types::encode_query, Error, HttpRequest, Response, Tenable};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `scan_export` function
+#[derive(Clone, Debug)]
+pub struct ScanExportReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// ID or UUID which identifies the scan
+    pub scan_id: Cow<'a, str>,
+    /// Definition which details the format and content of the export
+    pub scan_export_def: Cow<'a, ScanExportDef>,
+    /// Exports the results of the scan run with the given ID instead of the latest one
+    pub history_id: Option<u64>,
+    /// Exports the results of the scan run with the given UUID instead of the latest one
+    pub history_uuid: Option<Cow<'a, str>>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for ScanExportReq<'_> {
+    type Output = Option<ScanExportFile>;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let payload = serde_json::to_vec(&self.scan_export_def)?;
+        let mut query = Vec::new();
+        if let Some(history_id) = self.history_id {
+            query.push(format!("history_id={}", history_id));
+        }
+        if let Some(ref history_uuid) = self.history_uuid {
+            query.push(format!("history_uuid={}", encode_query(history_uuid)));
+        }
+        let mut uri = format!(
+            "{}/scans/{scan_id}/export",
+            self.tenable.uri,
+            scan_id = self.scan_id
+        );
+        if !query.is_empty() {
+            uri = format!("{}?{}", uri, query.join("&"));
+        }
+        let req = Request::builder()
+            .uri(uri)
+            .method(Method::POST)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .header("Content-Type", HeaderValue::from_static("application/json"))
+            .body(payload)?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            StatusCode::NOT_FOUND => return Ok(None),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(Some(data))
+    }
+}
+
+impl<'a> From<ScanExportReq<'a>> for Cow<'a, ScanExportReq<'a>> {
+    #[inline]
+    fn from(req: ScanExportReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a ScanExportReq<'a>> for Cow<'a, ScanExportReq<'a>> {
+    #[inline]
+    fn from(req: &'a ScanExportReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Details the format and content of a scan export
+pub struct ScanExportDef {
+    /// The file format of the export.
+    #[serde(rename = "format")]
+    pub format: ScanExportFormat,
+    /// The chapters to include. Required for the `html` and `pdf` formats, ignored otherwise.
+    #[serde(
+        rename = "chapters",
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_chapters",
+        deserialize_with = "deserialize_chapters",
+        default
+    )]
+    pub chapters: Option<Vec<ScanExportChapter>>,
+}
+
+impl From<ScanExportDef> for Cow<'_, ScanExportDef> {
+    #[inline]
+    fn from(req: ScanExportDef) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a ScanExportDef> for Cow<'a, ScanExportDef> {
+    #[inline]
+    fn from(req: &'a ScanExportDef) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+/// Chapters are sent as a single string separated by semicolons
+fn serialize_chapters<S: Serializer>(
+    chapters: &Option<Vec<ScanExportChapter>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let chapters: Vec<_> = chapters
+        .iter()
+        .flatten()
+        .map(|chapter| chapter.as_str())
+        .collect();
+    serializer.serialize_str(&chapters.join(";"))
+}
+
+fn deserialize_chapters<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<Vec<ScanExportChapter>>, D::Error> {
+    let chapters = String::deserialize(deserializer)?;
+    chapters
+        .split(';')
+        .filter(|chapter| !chapter.is_empty())
+        .map(|chapter| {
+            ScanExportChapter::from_str(chapter)
+                .ok_or_else(|| de::Error::unknown_variant(chapter, ScanExportChapter::VARIANTS))
+        })
+        .collect::<Result<_, _>>()
+        .map(Some)
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Copy)]
+/// File formats a scan can be exported to
+pub enum ScanExportFormat {
+    /// Nessus v2 XML, which contains the full results including plugin output.
+    #[serde(rename = "nessus")]
+    Nessus,
+    /// Comma-separated values with one finding per row.
+    #[serde(rename = "csv")]
+    Csv,
+    /// Report as web page. Requires `chapters`.
+    #[serde(rename = "html")]
+    Html,
+    /// Report as PDF document. Requires `chapters`.
+    #[serde(rename = "pdf")]
+    Pdf,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Copy)]
+/// Chapters of an `html` or `pdf` report
+pub enum ScanExportChapter {
+    /// Overview of the findings per host.
+    #[serde(rename = "vuln_hosts_summary")]
+    VulnHostsSummary,
+    /// Findings grouped by host.
+    #[serde(rename = "vuln_by_host")]
+    VulnByHost,
+    /// Findings grouped by plugin.
+    #[serde(rename = "vuln_by_plugin")]
+    VulnByPlugin,
+    /// Executive summary of the compliance checks.
+    #[serde(rename = "compliance_exec")]
+    ComplianceExec,
+    /// Results of the compliance checks.
+    #[serde(rename = "compliance")]
+    Compliance,
+    /// Suggested remediations.
+    #[serde(rename = "remediations")]
+    Remediations,
+}
+
+impl ScanExportChapter {
+    const VARIANTS: &'static [&'static str] = &[
+        "vuln_hosts_summary",
+        "vuln_by_host",
+        "vuln_by_plugin",
+        "compliance_exec",
+        "compliance",
+        "remediations",
+    ];
+
+    /// Name of the chapter as expected by Tenable.io
+    #[must_use]
+    #[inline]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::VulnHostsSummary => "vuln_hosts_summary",
+            Self::VulnByHost => "vuln_by_host",
+            Self::VulnByPlugin => "vuln_by_plugin",
+            Self::ComplianceExec => "compliance_exec",
+            Self::Compliance => "compliance",
+            Self::Remediations => "remediations",
+        }
+    }
+
+    fn from_str(chapter: &str) -> Option<Self> {
+        match chapter {
+            "vuln_hosts_summary" => Some(Self::VulnHostsSummary),
+            "vuln_by_host" => Some(Self::VulnByHost),
+            "vuln_by_plugin" => Some(Self::VulnByPlugin),
+            "compliance_exec" => Some(Self::ComplianceExec),
+            "compliance" => Some(Self::Compliance),
+            "remediations" => Some(Self::Remediations),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Identifies the file created by `scan_export`
+pub struct ScanExportFile {
+    /// The ID of the file. Use it to check the status of and download the export.
+    #[serde(rename = "file", deserialize_with = "deserialize_file_id")]
+    pub file: String,
+    /// A token which allows downloading the file without authentication.
+    #[serde(rename = "temp_token", skip_serializing_if = "Option::is_none")]
+    pub temp_token: Option<String>,
+}
+
+/// Tenable.io returns the file ID either as string or as number
+fn deserialize_file_id<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum FileId {
+        String(String),
+        Number(u64),
+    }
+
+    match FileId::deserialize(deserializer)? {
+        FileId::String(file) => Ok(file),
+        FileId::Number(file) => Ok(file.to_string()),
+    }
+}
@@ -0,0 +1,67 @@
+use crate::{Error, HttpRequest, Response, Tenable};
+use bytes::Bytes;
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `scan_export_download` function
+#[derive(Clone, Debug)]
+pub struct ScanExportDownloadReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// ID or UUID which identifies the scan
+    pub scan_id: Cow<'a, str>,
+    /// ID which identifies the exported file
+    pub file_id: Cow<'a, str>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for ScanExportDownloadReq<'_> {
+    type Output = Option<Bytes>;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let req = Request::builder()
+            .uri(format!(
+                "{}/scans/{scan_id}/export/{file_id}/download",
+                self.tenable.uri,
+                scan_id = self.scan_id,
+                file_id = self.file_id
+            ))
+            .method(Method::GET)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header(
+                "Accept",
+                HeaderValue::from_static("application/octet-stream"),
+            )
+            .body(Vec::new())?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            StatusCode::NOT_FOUND => return Ok(None),
+            _ => return Err(Error::from_response(res)),
+        }
+        Ok(Some(res.body))
+    }
+}
+
+impl<'a> From<ScanExportDownloadReq<'a>> for Cow<'a, ScanExportDownloadReq<'a>> {
+    #[inline]
+    fn from(req: ScanExportDownloadReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a ScanExportDownloadReq<'a>> for Cow<'a, ScanExportDownloadReq<'a>> {
+    #[inline]
+    fn from(req: &'a ScanExportDownloadReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
@@ -0,0 +1,87 @@
+use crate::{Error, HttpRequest, Response, Tenable};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `scan_export_status` function
+#[derive(Clone, Debug)]
+pub struct ScanExportStatusReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// ID or UUID which identifies the scan
+    pub scan_id: Cow<'a, str>,
+    /// ID which identifies the exported file
+    pub file_id: Cow<'a, str>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for ScanExportStatusReq<'_> {
+    type Output = Option<ScanExportStatus>;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let req = Request::builder()
+            .uri(format!(
+                "{}/scans/{scan_id}/export/{file_id}/status",
+                self.tenable.uri,
+                scan_id = self.scan_id,
+                file_id = self.file_id
+            ))
+            .method(Method::GET)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .body(Vec::new())?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            StatusCode::NOT_FOUND => return Ok(None),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(Some(data))
+    }
+}
+
+impl<'a> From<ScanExportStatusReq<'a>> for Cow<'a, ScanExportStatusReq<'a>> {
+    #[inline]
+    fn from(req: ScanExportStatusReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a ScanExportStatusReq<'a>> for Cow<'a, ScanExportStatusReq<'a>> {
+    #[inline]
+    fn from(req: &'a ScanExportStatusReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Copy)]
+/// Status of a file created by `scan_export`
+pub struct ScanExportStatus {
+    /// The status of the file.
+    #[serde(rename = "status")]
+    pub status: ScanExportState,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Copy)]
+/// The states a file created by `scan_export` passes through
+pub enum ScanExportState {
+    /// Tenable.io is still generating the file.
+    #[serde(rename = "loading")]
+    Loading,
+    /// The file is ready for download.
+    #[serde(rename = "ready")]
+    Ready,
+    /// Tenable.io was unable to generate the file.
+    #[serde(rename = "error")]
+    Error,
+}
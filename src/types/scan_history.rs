@@ -0,0 +1,136 @@
+use crate::{
+    types::{Pagination, ScanStatus},
+    Error, HttpRequest, Response, Tenable,
+};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `scan_history` function
+#[derive(Clone, Debug)]
+pub struct ScanHistoryReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// ID or UUID which identifies the scan
+    pub scan_id: Cow<'a, str>,
+    /// Maximum number of runs to return
+    pub limit: Option<u64>,
+    /// Number of runs to skip
+    pub offset: Option<u64>,
+    /// Excludes runs which were rolled over into a later run of an agent scan
+    pub exclude_rollover: Option<bool>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for ScanHistoryReq<'_> {
+    type Output = Option<ScanHistory>;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let mut query = Vec::new();
+        if let Some(limit) = self.limit {
+            query.push(format!("limit={}", limit));
+        }
+        if let Some(offset) = self.offset {
+            query.push(format!("offset={}", offset));
+        }
+        if let Some(exclude_rollover) = self.exclude_rollover {
+            query.push(format!("exclude_rollover={}", exclude_rollover));
+        }
+        let mut uri = format!(
+            "{}/scans/{scan_id}/history",
+            self.tenable.uri,
+            scan_id = self.scan_id
+        );
+        if !query.is_empty() {
+            uri = format!("{}?{}", uri, query.join("&"));
+        }
+        let req = Request::builder()
+            .uri(uri)
+            .method(Method::GET)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .body(Vec::new())?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            StatusCode::NOT_FOUND => return Ok(None),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(Some(data))
+    }
+}
+
+impl<'a> From<ScanHistoryReq<'a>> for Cow<'a, ScanHistoryReq<'a>> {
+    #[inline]
+    fn from(req: ScanHistoryReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a ScanHistoryReq<'a>> for Cow<'a, ScanHistoryReq<'a>> {
+    #[inline]
+    fn from(req: &'a ScanHistoryReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Runs of a scan returned by `scan_history`
+pub struct ScanHistory {
+    /// The position of the returned runs within all runs of the scan.
+    #[serde(rename = "pagination", skip_serializing_if = "Option::is_none")]
+    pub pagination: Option<Pagination>,
+    /// The runs of the scan, most recent first.
+    #[serde(rename = "history", skip_serializing_if = "Option::is_none")]
+    pub history: Option<Vec<ScanRun>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Represents a single run of a scan
+pub struct ScanRun {
+    /// The unique ID of the run.
+    #[serde(rename = "id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    /// The UUID of the run. Use it as `history_uuid` to fetch the results of this run.
+    #[serde(rename = "scan_uuid", skip_serializing_if = "Option::is_none")]
+    pub scan_uuid: Option<String>,
+    /// The status of the run.
+    #[serde(rename = "status", skip_serializing_if = "Option::is_none")]
+    pub status: Option<ScanStatus>,
+    /// The Unix timestamp when the run started.
+    #[serde(rename = "time_start", skip_serializing_if = "Option::is_none")]
+    pub time_start: Option<u64>,
+    /// The Unix timestamp when the run finished.
+    #[serde(rename = "time_end", skip_serializing_if = "Option::is_none")]
+    pub time_end: Option<u64>,
+    /// Indicates whether the results of the run are archived and only available as export.
+    #[serde(rename = "is_archived", skip_serializing_if = "Option::is_none")]
+    pub is_archived: Option<bool>,
+    /// The visibility of the run, either `public` or `private`.
+    #[serde(rename = "visibility", skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<String>,
+    /// The targets the run used.
+    #[serde(rename = "targets", skip_serializing_if = "Option::is_none")]
+    pub targets: Option<ScanRunTargets>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Copy)]
+/// Describes which targets a scan run used
+pub struct ScanRunTargets {
+    /// Indicates whether the run used `alt_targets`.
+    #[serde(rename = "custom", skip_serializing_if = "Option::is_none")]
+    pub custom: Option<bool>,
+    /// Indicates whether the run used the configured targets.
+    #[serde(rename = "default", skip_serializing_if = "Option::is_none")]
+    pub default: Option<bool>,
+}
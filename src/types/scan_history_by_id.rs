@@ -0,0 +1,109 @@
+use crate::{
+    types::{ScanRunTargets, ScanStatus},
+    Error, HttpRequest, Response, Tenable,
+};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `scan_history_by_id` function
+#[derive(Clone, Debug)]
+pub struct ScanHistoryByIdReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// ID or UUID which identifies the scan
+    pub scan_id: Cow<'a, str>,
+    /// UUID which identifies the run of the scan
+    pub history_uuid: Cow<'a, str>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for ScanHistoryByIdReq<'_> {
+    type Output = Option<ScanRunDetails>;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let req = Request::builder()
+            .uri(format!(
+                "{}/scans/{scan_id}/history/{history_uuid}",
+                self.tenable.uri,
+                scan_id = self.scan_id,
+                history_uuid = self.history_uuid
+            ))
+            .method(Method::GET)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .body(Vec::new())?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            StatusCode::NOT_FOUND => return Ok(None),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(Some(data))
+    }
+}
+
+impl<'a> From<ScanHistoryByIdReq<'a>> for Cow<'a, ScanHistoryByIdReq<'a>> {
+    #[inline]
+    fn from(req: ScanHistoryByIdReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a ScanHistoryByIdReq<'a>> for Cow<'a, ScanHistoryByIdReq<'a>> {
+    #[inline]
+    fn from(req: &'a ScanHistoryByIdReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Details of a single run of a scan as returned by `scan_history_by_id`
+pub struct ScanRunDetails {
+    /// The unique ID of the scan.
+    #[serde(rename = "object_id", skip_serializing_if = "Option::is_none")]
+    pub object_id: Option<u64>,
+    /// The UUID of the run.
+    #[serde(rename = "scan_uuid", skip_serializing_if = "Option::is_none")]
+    pub scan_uuid: Option<String>,
+    /// The UUID of the scan schedule.
+    #[serde(rename = "schedule_uuid", skip_serializing_if = "Option::is_none")]
+    pub schedule_uuid: Option<String>,
+    /// The name of the scan.
+    #[serde(rename = "name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The status of the run.
+    #[serde(rename = "status", skip_serializing_if = "Option::is_none")]
+    pub status: Option<ScanStatus>,
+    /// The ID of the owner of the scan.
+    #[serde(rename = "owner_id", skip_serializing_if = "Option::is_none")]
+    pub owner_id: Option<u64>,
+    /// The type of the scan, for example `remote` or `agent`.
+    #[serde(rename = "scan_type", skip_serializing_if = "Option::is_none")]
+    pub scan_type: Option<String>,
+    /// The targets the run used.
+    #[serde(rename = "targets", skip_serializing_if = "Option::is_none")]
+    pub targets: Option<ScanRunTargets>,
+    /// The Unix timestamp when the run started.
+    #[serde(rename = "scan_start", skip_serializing_if = "Option::is_none")]
+    pub scan_start: Option<u64>,
+    /// The Unix timestamp when the run finished.
+    #[serde(rename = "scan_end", skip_serializing_if = "Option::is_none")]
+    pub scan_end: Option<u64>,
+    /// Indicates whether the results of the run are archived and only available as export.
+    #[serde(rename = "is_archived", skip_serializing_if = "Option::is_none")]
+    pub is_archived: Option<bool>,
+    /// Indicates whether Tenable.io is reindexing the results of the run.
+    #[serde(rename = "reindexing", skip_serializing_if = "Option::is_none")]
+    pub reindexing: Option<bool>,
+}
@@ -0,0 +1,152 @@
+use crate::{types::encode_query, Error, HttpRequest, Response, Tenable};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `scan_host_details` function
+#[derive(Clone, Debug)]
+pub struct ScanHostDetailsReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// ID or UUID which identifies the scan
+    pub scan_id: Cow<'a, str>,
+    /// ID which identifies the host within the scan results
+    pub host_id: u64,
+    /// Returns the results of the scan run with the given ID instead of the latest one
+    pub history_id: Option<u64>,
+    /// Returns the results of the scan run with the given UUID instead of the latest one
+    pub history_uuid: Option<Cow<'a, str>>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for ScanHostDetailsReq<'_> {
+    type Output = Option<ScanHostDetails>;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let mut query = Vec::new();
+        if let Some(history_id) = self.history_id {
+            query.push(format!("history_id={}", history_id));
+        }
+        if let Some(ref history_uuid) = self.history_uuid {
+            query.push(format!("history_uuid={}", encode_query(history_uuid)));
+        }
+        let mut uri = format!(
+            "{}/scans/{scan_id}/hosts/{host_id}",
+            self.tenable.uri,
+            scan_id = self.scan_id,
+            host_id = self.host_id
+        );
+        if !query.is_empty() {
+            uri = format!("{}?{}", uri, query.join("&"));
+        }
+        let req = Request::builder()
+            .uri(uri)
+            .method(Method::GET)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .body(Vec::new())?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            StatusCode::NOT_FOUND => return Ok(None),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(Some(data))
+    }
+}
+
+impl<'a> From<ScanHostDetailsReq<'a>> for Cow<'a, ScanHostDetailsReq<'a>> {
+    #[inline]
+    fn from(req: ScanHostDetailsReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a ScanHostDetailsReq<'a>> for Cow<'a, ScanHostDetailsReq<'a>> {
+    #[inline]
+    fn from(req: &'a ScanHostDetailsReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Results of a scan run for a single host as returned by `scan_host_details`
+pub struct ScanHostDetails {
+    /// General information about the host.
+    #[serde(rename = "info", skip_serializing_if = "Option::is_none")]
+    pub info: Option<ScanHostInfo>,
+    /// The vulnerabilities found on the host, grouped by plugin.
+    #[serde(rename = "vulnerabilities", skip_serializing_if = "Option::is_none")]
+    pub vulnerabilities: Option<Vec<ScanHostVulnerability>>,
+    /// The results of compliance checks on the host, grouped by plugin.
+    #[serde(rename = "compliance", skip_serializing_if = "Option::is_none")]
+    pub compliance: Option<Vec<ScanHostVulnerability>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// General information about a scanned host
+pub struct ScanHostInfo {
+    /// The IP address of the host.
+    #[serde(rename = "host-ip", skip_serializing_if = "Option::is_none")]
+    pub host_ip: Option<String>,
+    /// The fully-qualified domain name of the host.
+    #[serde(rename = "host-fqdn", skip_serializing_if = "Option::is_none")]
+    pub host_fqdn: Option<String>,
+    /// The NetBIOS name of the host.
+    #[serde(rename = "netbios-name", skip_serializing_if = "Option::is_none")]
+    pub netbios_name: Option<String>,
+    /// The MAC address of the host.
+    #[serde(rename = "mac-address", skip_serializing_if = "Option::is_none")]
+    pub mac_address: Option<String>,
+    /// The operating systems the scan detected on the host.
+    #[serde(rename = "operating-system", skip_serializing_if = "Option::is_none")]
+    pub operating_system: Option<Vec<String>>,
+    /// The time when the scan of the host started, for example `Mon Jun  1 12:30:10 2020`.
+    #[serde(rename = "host_start", skip_serializing_if = "Option::is_none")]
+    pub host_start: Option<String>,
+    /// The time when the scan of the host ended, for example `Mon Jun  1 12:50:00 2020`.
+    #[serde(rename = "host_end", skip_serializing_if = "Option::is_none")]
+    pub host_end: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Represents a plugin which reported findings on a host
+pub struct ScanHostVulnerability {
+    /// The ID of the host within the scan results.
+    #[serde(rename = "host_id", skip_serializing_if = "Option::is_none")]
+    pub host_id: Option<u64>,
+    /// The name, IP address or FQDN of the host.
+    #[serde(rename = "hostname", skip_serializing_if = "Option::is_none")]
+    pub hostname: Option<String>,
+    /// The ID of the plugin.
+    #[serde(rename = "plugin_id", skip_serializing_if = "Option::is_none")]
+    pub plugin_id: Option<u64>,
+    /// The name of the plugin.
+    #[serde(rename = "plugin_name", skip_serializing_if = "Option::is_none")]
+    pub plugin_name: Option<String>,
+    /// The family of the plugin.
+    #[serde(rename = "plugin_family", skip_serializing_if = "Option::is_none")]
+    pub plugin_family: Option<String>,
+    /// The number of findings the plugin reported on the host.
+    #[serde(rename = "count", skip_serializing_if = "Option::is_none")]
+    pub count: Option<u64>,
+    /// The severity of the finding: 0 for info, 1 for low, 2 for medium, 3 for high and 4 for critical.
+    #[serde(rename = "severity", skip_serializing_if = "Option::is_none")]
+    pub severity: Option<u8>,
+    /// The position of the severity within the list of severities.
+    #[serde(rename = "severity_index", skip_serializing_if = "Option::is_none")]
+    pub severity_index: Option<u64>,
+    /// The index of the finding within the scan results.
+    #[serde(rename = "vuln_index", skip_serializing_if = "Option::is_none")]
+    pub vuln_index: Option<u64>,
+}
@@ -0,0 +1,83 @@
+use crate::{Error, HttpRequest, Response, Tenable};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `scan_launch` function
+#[derive(Clone, Debug)]
+pub struct ScanLaunchReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// ID or UUID which identifies the scan
+    pub scan_id: Cow<'a, str>,
+    /// Targets to scan instead of the configured ones, like IP addresses,
+    /// ranges or hostnames
+    pub alt_targets: Option<Cow<'a, [String]>>,
+}
+
+#[derive(Serialize)]
+struct ScanLaunchDef<'a> {
+    #[serde(rename = "alt_targets", skip_serializing_if = "Option::is_none")]
+    alt_targets: Option<&'a [String]>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for ScanLaunchReq<'_> {
+    type Output = Option<ScanLaunched>;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let payload = serde_json::to_vec(&ScanLaunchDef {
+            alt_targets: self.alt_targets.as_deref(),
+        })?;
+        let req = Request::builder()
+            .uri(format!(
+                "{}/scans/{scan_id}/launch",
+                self.tenable.uri,
+                scan_id = self.scan_id
+            ))
+            .method(Method::POST)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .header("Content-Type", HeaderValue::from_static("application/json"))
+            .body(payload)?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            StatusCode::NOT_FOUND => return Ok(None),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(Some(data))
+    }
+}
+
+impl<'a> From<ScanLaunchReq<'a>> for Cow<'a, ScanLaunchReq<'a>> {
+    #[inline]
+    fn from(req: ScanLaunchReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a ScanLaunchReq<'a>> for Cow<'a, ScanLaunchReq<'a>> {
+    #[inline]
+    fn from(req: &'a ScanLaunchReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Identifies the scan run started by `scan_launch`
+pub struct ScanLaunched {
+    /// The UUID of the scan run. Also known as history UUID.
+    #[serde(rename = "scan_uuid")]
+    pub scan_uuid: String,
+}
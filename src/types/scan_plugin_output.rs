@@ -0,0 +1,184 @@
+use crate::{types::encode_query, Error, HttpRequest, Response, Tenable};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, collections::BTreeMap, fmt};
+
+/// Request Object for the `scan_plugin_output` function
+#[derive(Clone, Debug)]
+pub struct ScanPluginOutputReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// ID or UUID which identifies the scan
+    pub scan_id: Cow<'a, str>,
+    /// ID which identifies the host within the scan results
+    pub host_id: u64,
+    /// ID which identifies the plugin
+    pub plugin_id: u64,
+    /// Returns the output of the scan run with the given ID instead of the latest one
+    pub history_id: Option<u64>,
+    /// Returns the output of the scan run with the given UUID instead of the latest one
+    pub history_uuid: Option<Cow<'a, str>>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for ScanPluginOutputReq<'_> {
+    type Output = Option<ScanPluginOutput>;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let mut query = Vec::new();
+        if let Some(history_id) = self.history_id {
+            query.push(format!("history_id={}", history_id));
+        }
+        if let Some(ref history_uuid) = self.history_uuid {
+            query.push(format!("history_uuid={}", encode_query(history_uuid)));
+        }
+        let mut uri = format!(
+            "{}/scans/{scan_id}/hosts/{host_id}/plugins/{plugin_id}",
+            self.tenable.uri,
+            scan_id = self.scan_id,
+            host_id = self.host_id,
+            plugin_id = self.plugin_id
+        );
+        if !query.is_empty() {
+            uri = format!("{}?{}", uri, query.join("&"));
+        }
+        let req = Request::builder()
+            .uri(uri)
+            .method(Method::GET)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .body(Vec::new())?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            StatusCode::NOT_FOUND => return Ok(None),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(Some(data))
+    }
+}
+
+impl<'a> From<ScanPluginOutputReq<'a>> for Cow<'a, ScanPluginOutputReq<'a>> {
+    #[inline]
+    fn from(req: ScanPluginOutputReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a ScanPluginOutputReq<'a>> for Cow<'a, ScanPluginOutputReq<'a>> {
+    #[inline]
+    fn from(req: &'a ScanPluginOutputReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Output of a plugin for a single host as returned by `scan_plugin_output`
+pub struct ScanPluginOutput {
+    /// Information about the plugin.
+    #[serde(rename = "info", skip_serializing_if = "Option::is_none")]
+    pub info: Option<ScanPluginInfo>,
+    /// The outputs of the plugin. Plugins report one output per distinct result.
+    #[serde(rename = "outputs", skip_serializing_if = "Option::is_none")]
+    pub outputs: Option<Vec<ScanPluginResult>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Wraps the description of the plugin
+pub struct ScanPluginInfo {
+    /// The description of the plugin.
+    #[serde(rename = "plugindescription", skip_serializing_if = "Option::is_none")]
+    pub plugindescription: Option<ScanPluginDescription>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Describes a plugin
+pub struct ScanPluginDescription {
+    /// The ID of the plugin.
+    #[serde(rename = "pluginid", skip_serializing_if = "Option::is_none")]
+    pub pluginid: Option<String>,
+    /// The name of the plugin.
+    #[serde(rename = "pluginname", skip_serializing_if = "Option::is_none")]
+    pub pluginname: Option<String>,
+    /// The family of the plugin.
+    #[serde(rename = "pluginfamily", skip_serializing_if = "Option::is_none")]
+    pub pluginfamily: Option<String>,
+    /// The severity of the finding: 0 for info, 1 for low, 2 for medium, 3 for high and 4 for critical.
+    #[serde(rename = "severity", skip_serializing_if = "Option::is_none")]
+    pub severity: Option<u8>,
+    /// Further attributes of the plugin like its synopsis, solution and risk information.
+    #[serde(rename = "pluginattributes", skip_serializing_if = "Option::is_none")]
+    pub pluginattributes: Option<ScanPluginAttributes>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Attributes of a plugin
+pub struct ScanPluginAttributes {
+    /// A brief summary of the finding.
+    #[serde(rename = "synopsis", skip_serializing_if = "Option::is_none")]
+    pub synopsis: Option<String>,
+    /// The full description of the finding.
+    #[serde(rename = "description", skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The recommended way to fix the finding.
+    #[serde(rename = "solution", skip_serializing_if = "Option::is_none")]
+    pub solution: Option<String>,
+    /// Links to further information about the finding.
+    #[serde(rename = "see_also", skip_serializing_if = "Option::is_none")]
+    pub see_also: Option<Vec<String>>,
+    /// The file name of the plugin.
+    #[serde(rename = "fname", skip_serializing_if = "Option::is_none")]
+    pub fname: Option<String>,
+    /// The risk of the finding.
+    #[serde(rename = "risk_information", skip_serializing_if = "Option::is_none")]
+    pub risk_information: Option<ScanPluginRisk>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Risk of a finding reported by a plugin
+pub struct ScanPluginRisk {
+    /// The risk factor of the finding, like `None`, `Low` or `Critical`.
+    #[serde(rename = "risk_factor", skip_serializing_if = "Option::is_none")]
+    pub risk_factor: Option<String>,
+    /// The CVSSv2 base score of the finding as text, for example `5.0`.
+    #[serde(rename = "cvss_base_score", skip_serializing_if = "Option::is_none")]
+    pub cvss_base_score: Option<String>,
+    /// The CVSSv3 base score of the finding as text, for example `6.5`.
+    #[serde(rename = "cvss3_base_score", skip_serializing_if = "Option::is_none")]
+    pub cvss3_base_score: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Single result reported by a plugin
+pub struct ScanPluginResult {
+    /// The text the plugin reported.
+    #[serde(rename = "plugin_output", skip_serializing_if = "Option::is_none")]
+    pub plugin_output: Option<String>,
+    /// The severity of the result: 0 for info, 1 for low, 2 for medium, 3 for high and 4 for critical.
+    #[serde(rename = "severity", skip_serializing_if = "Option::is_none")]
+    pub severity: Option<u8>,
+    /// The ports the result was reported for, keyed like `443 / tcp / www`.
+    #[serde(rename = "ports", skip_serializing_if = "Option::is_none")]
+    pub ports: Option<BTreeMap<String, Vec<ScanPluginPortHost>>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Host a plugin result was reported for
+pub struct ScanPluginPortHost {
+    /// The ID of the host within the scan results.
+    #[serde(rename = "host_id", skip_serializing_if = "Option::is_none")]
+    pub host_id: Option<u64>,
+    /// The name, IP address or FQDN of the host.
+    #[serde(rename = "hostname", skip_serializing_if = "Option::is_none")]
+    pub hostname: Option<String>,
+}
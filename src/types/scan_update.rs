@@ -0,0 +1,68 @@
+use crate::{
+    types::{ScanConfig, ScanDef},
+    Error, HttpRequest, Response, Tenable,
+};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `scan_update` function
+#[derive(Clone, Debug)]
+pub struct ScanUpdateReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// ID or UUID which identifies the scan
+    pub scan_id: Cow<'a, str>,
+    /// Definition which replaces the current settings of the scan
+    pub scan_def: Cow<'a, ScanDef>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for ScanUpdateReq<'_> {
+    type Output = Option<ScanConfig>;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let payload = serde_json::to_vec(&self.scan_def)?;
+        let req = Request::builder()
+            .uri(format!(
+                "{}/scans/{scan_id}",
+                self.tenable.uri,
+                scan_id = self.scan_id
+            ))
+            .method(Method::PUT)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .header("Content-Type", HeaderValue::from_static("application/json"))
+            .body(payload)?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            StatusCode::NOT_FOUND => return Ok(None),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(Some(data))
+    }
+}
+
+impl<'a> From<ScanUpdateReq<'a>> for Cow<'a, ScanUpdateReq<'a>> {
+    #[inline]
+    fn from(req: ScanUpdateReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a ScanUpdateReq<'a>> for Cow<'a, ScanUpdateReq<'a>> {
+    #[inline]
+    fn from(req: &'a ScanUpdateReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
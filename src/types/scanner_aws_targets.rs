@@ -0,0 +1,93 @@
+use crate::{Error, HttpRequest, Response, Tenable};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `scanner_aws_targets` function
+#[derive(Clone, Debug)]
+pub struct ScannerAwsTargetsReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// ID which identifies the scanner
+    pub scanner_id: u64,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for ScannerAwsTargetsReq<'_> {
+    type Output = Option<ScannerAwsTargets>;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let req = Request::builder()
+            .uri(format!(
+                "{}/scanners/{scanner_id}/aws-targets",
+                self.tenable.uri,
+                scanner_id = self.scanner_id
+            ))
+            .method(Method::GET)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .body(Vec::new())?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            StatusCode::NOT_FOUND => return Ok(None),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(Some(data))
+    }
+}
+
+impl<'a> From<ScannerAwsTargetsReq<'a>> for Cow<'a, ScannerAwsTargetsReq<'a>> {
+    #[inline]
+    fn from(req: ScannerAwsTargetsReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a ScannerAwsTargetsReq<'a>> for Cow<'a, ScannerAwsTargetsReq<'a>> {
+    #[inline]
+    fn from(req: &'a ScannerAwsTargetsReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// AWS instances returned by `scanner_aws_targets`
+pub struct ScannerAwsTargets {
+    /// The AWS instances the scanner can scan.
+    #[serde(rename = "targets", skip_serializing_if = "Option::is_none")]
+    pub targets: Option<Vec<ScannerAwsTarget>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// AWS instance an AWS scanner can scan
+pub struct ScannerAwsTarget {
+    /// The unique identifier of the instance in Amazon EC2.
+    #[serde(rename = "instance_id", skip_serializing_if = "Option::is_none")]
+    pub instance_id: Option<String>,
+    /// The name of the instance.
+    #[serde(rename = "name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The private IP address of the instance.
+    #[serde(rename = "private_ip", skip_serializing_if = "Option::is_none")]
+    pub private_ip: Option<String>,
+    /// The public IP address of the instance.
+    #[serde(rename = "public_ip", skip_serializing_if = "Option::is_none")]
+    pub public_ip: Option<String>,
+    /// The state of the instance, for example `running`.
+    #[serde(rename = "state", skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+    /// The availability zone where AWS hosts the instance.
+    #[serde(rename = "zone", skip_serializing_if = "Option::is_none")]
+    pub zone: Option<String>,
+}
@@ -0,0 +1,61 @@
+use crate::{types::Scanner, Error, HttpRequest, Response, Tenable};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `scanner_by_id` function
+#[derive(Clone, Debug)]
+pub struct ScannerByIdReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// ID which identifies the scanner
+    pub scanner_id: u64,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for ScannerByIdReq<'_> {
+    type Output = Option<Scanner>;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let req = Request::builder()
+            .uri(format!(
+                "{}/scanners/{scanner_id}",
+                self.tenable.uri,
+                scanner_id = self.scanner_id
+            ))
+            .method(Method::GET)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .body(Vec::new())?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            StatusCode::NOT_FOUND => return Ok(None),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(Some(data))
+    }
+}
+
+impl<'a> From<ScannerByIdReq<'a>> for Cow<'a, ScannerByIdReq<'a>> {
+    #[inline]
+    fn from(req: ScannerByIdReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a ScannerByIdReq<'a>> for Cow<'a, ScannerByIdReq<'a>> {
+    #[inline]
+    fn from(req: &'a ScannerByIdReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
@@ -0,0 +1,84 @@
+use crate::{types::ScannerGroup, Error, HttpRequest, Response, Tenable};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `scanner_group_create` function
+#[derive(Clone, Debug)]
+pub struct ScannerGroupCreateReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// Definition which details the scanner group
+    pub scanner_group_def: Cow<'a, ScannerGroupDef>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for ScannerGroupCreateReq<'_> {
+    type Output = ScannerGroup;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let payload = serde_json::to_vec(&self.scanner_group_def)?;
+        let req = Request::builder()
+            .uri(format!("{}/scanner-groups", self.tenable.uri))
+            .method(Method::POST)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .header("Content-Type", HeaderValue::from_static("application/json"))
+            .body(payload)?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(data)
+    }
+}
+
+impl<'a> From<ScannerGroupCreateReq<'a>> for Cow<'a, ScannerGroupCreateReq<'a>> {
+    #[inline]
+    fn from(req: ScannerGroupCreateReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a ScannerGroupCreateReq<'a>> for Cow<'a, ScannerGroupCreateReq<'a>> {
+    #[inline]
+    fn from(req: &'a ScannerGroupCreateReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+/// Details a scanner group to create or update
+pub struct ScannerGroupDef {
+    /// The name of the scanner group.
+    #[serde(rename = "name")]
+    pub name: String,
+    /// The type of the scanner group. Defaults to `load_balancing`, the only supported type.
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub group_type: Option<String>,
+}
+
+impl From<ScannerGroupDef> for Cow<'_, ScannerGroupDef> {
+    #[inline]
+    fn from(req: ScannerGroupDef) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a ScannerGroupDef> for Cow<'a, ScannerGroupDef> {
+    #[inline]
+    fn from(req: &'a ScannerGroupDef) -> Self {
+        Cow::Borrowed(req)
+    }
+}
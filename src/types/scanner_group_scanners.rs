@@ -0,0 +1,61 @@
+use crate::{types::Scanners, Error, HttpRequest, Response, Tenable};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `scanner_group_scanners` function
+#[derive(Clone, Debug)]
+pub struct ScannerGroupScannersReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// ID which identifies the scanner group
+    pub group_id: u64,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for ScannerGroupScannersReq<'_> {
+    type Output = Option<Scanners>;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let req = Request::builder()
+            .uri(format!(
+                "{}/scanner-groups/{group_id}/scanners",
+                self.tenable.uri,
+                group_id = self.group_id
+            ))
+            .method(Method::GET)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .body(Vec::new())?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            StatusCode::NOT_FOUND => return Ok(None),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(Some(data))
+    }
+}
+
+impl<'a> From<ScannerGroupScannersReq<'a>> for Cow<'a, ScannerGroupScannersReq<'a>> {
+    #[inline]
+    fn from(req: ScannerGroupScannersReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a ScannerGroupScannersReq<'a>> for Cow<'a, ScannerGroupScannersReq<'a>> {
+    #[inline]
+    fn from(req: &'a ScannerGroupScannersReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
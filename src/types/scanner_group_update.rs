@@ -0,0 +1,64 @@
+use crate::{types::ScannerGroupDef, Error, HttpRequest, Response, Tenable};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `scanner_group_update` function
+#[derive(Clone, Debug)]
+pub struct ScannerGroupUpdateReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// ID which identifies the scanner group
+    pub group_id: u64,
+    /// Definition which details the scanner group
+    pub scanner_group_def: Cow<'a, ScannerGroupDef>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for ScannerGroupUpdateReq<'_> {
+    type Output = Option<()>;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let payload = serde_json::to_vec(&self.scanner_group_def)?;
+        let req = Request::builder()
+            .uri(format!(
+                "{}/scanner-groups/{group_id}",
+                self.tenable.uri,
+                group_id = self.group_id
+            ))
+            .method(Method::PUT)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .header("Content-Type", HeaderValue::from_static("application/json"))
+            .body(payload)?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            StatusCode::NOT_FOUND => return Ok(None),
+            _ => return Err(Error::from_response(res)),
+        }
+        Ok(Some(()))
+    }
+}
+
+impl<'a> From<ScannerGroupUpdateReq<'a>> for Cow<'a, ScannerGroupUpdateReq<'a>> {
+    #[inline]
+    fn from(req: ScannerGroupUpdateReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a ScannerGroupUpdateReq<'a>> for Cow<'a, ScannerGroupUpdateReq<'a>> {
+    #[inline]
+    fn from(req: &'a ScannerGroupUpdateReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
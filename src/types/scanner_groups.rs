@@ -0,0 +1,101 @@
+use crate::{Error, HttpRequest, Response, Tenable};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `scanner_groups` function
+#[derive(Clone, Debug)]
+pub struct ScannerGroupsReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for ScannerGroupsReq<'_> {
+    type Output = ScannerGroups;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let req = Request::builder()
+            .uri(format!("{}/scanner-groups", self.tenable.uri))
+            .method(Method::GET)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .body(Vec::new())?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(data)
+    }
+}
+
+impl<'a> From<ScannerGroupsReq<'a>> for Cow<'a, ScannerGroupsReq<'a>> {
+    #[inline]
+    fn from(req: ScannerGroupsReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a ScannerGroupsReq<'a>> for Cow<'a, ScannerGroupsReq<'a>> {
+    #[inline]
+    fn from(req: &'a ScannerGroupsReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Scanner groups returned by `scanner_groups`
+pub struct ScannerGroups {
+    /// The scanner groups.
+    #[serde(rename = "scanner_pools", skip_serializing_if = "Option::is_none")]
+    pub scanner_pools: Option<Vec<ScannerGroup>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Represents a group of scanners which share the scans targeting the group
+pub struct ScannerGroup {
+    /// The ID of the scanner group.
+    #[serde(rename = "id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    /// The UUID of the scanner group.
+    #[serde(rename = "uuid", skip_serializing_if = "Option::is_none")]
+    pub uuid: Option<String>,
+    /// The name of the scanner group.
+    #[serde(rename = "name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The type of the scanner group. Only `load_balancing` is supported.
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub group_type: Option<String>,
+    /// The number of scanners in the group.
+    #[serde(rename = "scanner_count", skip_serializing_if = "Option::is_none")]
+    pub scanner_count: Option<u64>,
+    /// The name of the network the scanner group is assigned to.
+    #[serde(rename = "network_name", skip_serializing_if = "Option::is_none")]
+    pub network_name: Option<String>,
+    /// The name of the owner of the scanner group.
+    #[serde(rename = "owner_name", skip_serializing_if = "Option::is_none")]
+    pub owner_name: Option<String>,
+    /// The UUID of the owner of the scanner group.
+    #[serde(rename = "owner_uuid", skip_serializing_if = "Option::is_none")]
+    pub owner_uuid: Option<String>,
+    /// The unix timestamp when the scanner group was created.
+    #[serde(rename = "creation_date", skip_serializing_if = "Option::is_none")]
+    pub creation_date: Option<u64>,
+    /// The unix timestamp when the scanner group was last updated.
+    #[serde(
+        rename = "last_modification_date",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub last_modification_date: Option<u64>,
+}
@@ -0,0 +1,73 @@
+use crate::{Error, HttpRequest, Response, Tenable};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use serde::Serialize;
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `scanner_link` function
+#[derive(Clone, Debug)]
+pub struct ScannerLinkReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// ID which identifies the scanner
+    pub scanner_id: u64,
+    /// Links the scanner if `true`, unlinks it if `false`
+    pub link: bool,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for ScannerLinkReq<'_> {
+    type Output = Option<()>;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let payload = serde_json::to_vec(&ScannerLinkPayload {
+            link: u8::from(self.link),
+        })?;
+        let req = Request::builder()
+            .uri(format!(
+                "{}/scanners/{scanner_id}/link",
+                self.tenable.uri,
+                scanner_id = self.scanner_id
+            ))
+            .method(Method::PUT)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .header("Content-Type", HeaderValue::from_static("application/json"))
+            .body(payload)?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            StatusCode::NOT_FOUND => return Ok(None),
+            _ => return Err(Error::from_response(res)),
+        }
+        Ok(Some(()))
+    }
+}
+
+impl<'a> From<ScannerLinkReq<'a>> for Cow<'a, ScannerLinkReq<'a>> {
+    #[inline]
+    fn from(req: ScannerLinkReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a ScannerLinkReq<'a>> for Cow<'a, ScannerLinkReq<'a>> {
+    #[inline]
+    fn from(req: &'a ScannerLinkReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+/// Body of the `scanner_link` request
+#[derive(Serialize)]
+struct ScannerLinkPayload {
+    link: u8,
+}
@@ -0,0 +1,99 @@
+use crate::{types::ScanStatus, Error, HttpRequest, Response, Tenable};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `scanner_scans` function
+#[derive(Clone, Debug)]
+pub struct ScannerScansReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// ID which identifies the scanner
+    pub scanner_id: u64,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for ScannerScansReq<'_> {
+    type Output = Option<ScannerScans>;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let req = Request::builder()
+            .uri(format!(
+                "{}/scanners/{scanner_id}/scans",
+                self.tenable.uri,
+                scanner_id = self.scanner_id
+            ))
+            .method(Method::GET)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .body(Vec::new())?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            StatusCode::NOT_FOUND => return Ok(None),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(Some(data))
+    }
+}
+
+impl<'a> From<ScannerScansReq<'a>> for Cow<'a, ScannerScansReq<'a>> {
+    #[inline]
+    fn from(req: ScannerScansReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a ScannerScansReq<'a>> for Cow<'a, ScannerScansReq<'a>> {
+    #[inline]
+    fn from(req: &'a ScannerScansReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Running scans returned by `scanner_scans`
+pub struct ScannerScans {
+    /// The scans running on the scanner.
+    #[serde(rename = "scans", skip_serializing_if = "Option::is_none")]
+    pub scans: Option<Vec<ScannerScan>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Scan running on a scanner
+pub struct ScannerScan {
+    /// The UUID of the scan run.
+    #[serde(rename = "id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The ID of the scan.
+    #[serde(rename = "scan_id", skip_serializing_if = "Option::is_none")]
+    pub scan_id: Option<u64>,
+    /// The name of the scan.
+    #[serde(rename = "name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The status of the scan run.
+    #[serde(rename = "status", skip_serializing_if = "Option::is_none")]
+    pub status: Option<ScanStatus>,
+    /// The user who launched the scan.
+    #[serde(rename = "user", skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+    /// The unix timestamp when the scan run started.
+    #[serde(rename = "start_time", skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<u64>,
+    /// The unix timestamp when the scan run was last updated.
+    #[serde(
+        rename = "last_modification_date",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub last_modification_date: Option<u64>,
+}
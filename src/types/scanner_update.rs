@@ -0,0 +1,105 @@
+use crate::{Error, HttpRequest, Response, Tenable};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `scanner_update` function
+#[derive(Clone, Debug)]
+pub struct ScannerUpdateReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// ID which identifies the scanner
+    pub scanner_id: u64,
+    /// Definition which details the update
+    pub scanner_update_def: Cow<'a, ScannerUpdateDef>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for ScannerUpdateReq<'_> {
+    type Output = Option<()>;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let payload = serde_json::to_vec(&self.scanner_update_def)?;
+        let req = Request::builder()
+            .uri(format!(
+                "{}/scanners/{scanner_id}",
+                self.tenable.uri,
+                scanner_id = self.scanner_id
+            ))
+            .method(Method::PUT)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .header("Content-Type", HeaderValue::from_static("application/json"))
+            .body(payload)?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            StatusCode::NOT_FOUND => return Ok(None),
+            _ => return Err(Error::from_response(res)),
+        }
+        Ok(Some(()))
+    }
+}
+
+impl<'a> From<ScannerUpdateReq<'a>> for Cow<'a, ScannerUpdateReq<'a>> {
+    #[inline]
+    fn from(req: ScannerUpdateReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a ScannerUpdateReq<'a>> for Cow<'a, ScannerUpdateReq<'a>> {
+    #[inline]
+    fn from(req: &'a ScannerUpdateReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+/// Details how to update a scanner
+pub struct ScannerUpdateDef {
+    /// Forces the scanner to update its plugins.
+    #[serde(
+        rename = "force_plugin_update",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub force_plugin_update: Option<bool>,
+    /// Forces the scanner to update its user interface.
+    #[serde(rename = "force_ui_update", skip_serializing_if = "Option::is_none")]
+    pub force_ui_update: Option<bool>,
+    /// Reboots the scanner to finish a pending software update.
+    #[serde(rename = "finish_update", skip_serializing_if = "Option::is_none")]
+    pub finish_update: Option<bool>,
+    /// The registration code of the scanner.
+    #[serde(rename = "registration_code", skip_serializing_if = "Option::is_none")]
+    pub registration_code: Option<String>,
+    /// The interval in minutes in which the scanner refreshes its AWS targets.
+    #[serde(
+        rename = "aws_update_interval",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub aws_update_interval: Option<u32>,
+}
+
+impl From<ScannerUpdateDef> for Cow<'_, ScannerUpdateDef> {
+    #[inline]
+    fn from(req: ScannerUpdateDef) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a ScannerUpdateDef> for Cow<'a, ScannerUpdateDef> {
+    #[inline]
+    fn from(req: &'a ScannerUpdateDef) -> Self {
+        Cow::Borrowed(req)
+    }
+}
@@ -0,0 +1,125 @@
+use crate::{Error, HttpRequest, Response, Tenable};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `scanners` function
+#[derive(Clone, Debug)]
+pub struct ScannersReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for ScannersReq<'_> {
+    type Output = Scanners;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let req = Request::builder()
+            .uri(format!("{}/scanners", self.tenable.uri))
+            .method(Method::GET)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .body(Vec::new())?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(data)
+    }
+}
+
+impl<'a> From<ScannersReq<'a>> for Cow<'a, ScannersReq<'a>> {
+    #[inline]
+    fn from(req: ScannersReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a ScannersReq<'a>> for Cow<'a, ScannersReq<'a>> {
+    #[inline]
+    fn from(req: &'a ScannersReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Scanners returned by `scanners`
+pub struct Scanners {
+    /// The scanners.
+    #[serde(rename = "scanners", skip_serializing_if = "Option::is_none")]
+    pub scanners: Option<Vec<Scanner>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Represents a scanner or scanner group
+pub struct Scanner {
+    /// The ID of the scanner.
+    #[serde(rename = "id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    /// The UUID of the scanner.
+    #[serde(rename = "uuid", skip_serializing_if = "Option::is_none")]
+    pub uuid: Option<String>,
+    /// The name of the scanner.
+    #[serde(rename = "name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The type of the scanner, like `local`, `managed` or `pool`.
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub scanner_type: Option<String>,
+    /// The status of the scanner, like `on` or `off`.
+    #[serde(rename = "status", skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    /// Indicates whether the scanner is a scanner group.
+    #[serde(rename = "pool", skip_serializing_if = "Option::is_none")]
+    pub pool: Option<bool>,
+    /// Indicates whether the scanner is linked to Tenable.io: `1` if linked, `0` if not.
+    #[serde(rename = "linked", skip_serializing_if = "Option::is_none")]
+    pub linked: Option<u8>,
+    /// Indicates whether the scanner belongs to a scanner group.
+    #[serde(rename = "group", skip_serializing_if = "Option::is_none")]
+    pub group: Option<bool>,
+    /// The source of the scanner, like `service` for scanners managed by Tenable or `local` for on-prem scanners.
+    #[serde(rename = "source", skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+    /// The number of scans running on the scanner.
+    #[serde(rename = "scan_count", skip_serializing_if = "Option::is_none")]
+    pub scan_count: Option<u64>,
+    /// The version of the scan engine.
+    #[serde(rename = "engine_version", skip_serializing_if = "Option::is_none")]
+    pub engine_version: Option<String>,
+    /// The platform the scanner runs on, like `LINUX` or `WINDOWS`.
+    #[serde(rename = "platform", skip_serializing_if = "Option::is_none")]
+    pub platform: Option<String>,
+    /// The version of the plugins loaded by the scanner.
+    #[serde(rename = "loaded_plugin_set", skip_serializing_if = "Option::is_none")]
+    pub loaded_plugin_set: Option<String>,
+    /// The name of the network the scanner is assigned to.
+    #[serde(rename = "network_name", skip_serializing_if = "Option::is_none")]
+    pub network_name: Option<String>,
+    /// The name of the owner of the scanner.
+    #[serde(rename = "owner_name", skip_serializing_if = "Option::is_none")]
+    pub owner_name: Option<String>,
+    /// The unix timestamp when the scanner was created.
+    #[serde(rename = "creation_date", skip_serializing_if = "Option::is_none")]
+    pub creation_date: Option<u64>,
+    /// The unix timestamp when the scanner last connected to Tenable.io.
+    #[serde(rename = "last_connect", skip_serializing_if = "Option::is_none")]
+    pub last_connect: Option<u64>,
+    /// The unix timestamp when the scanner was last updated.
+    #[serde(
+        rename = "last_modification_date",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub last_modification_date: Option<u64>,
+}
@@ -0,0 +1,173 @@
+use crate::{types::ScanStatus, Error, HttpRequest, Response, Tenable};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `scans` function
+#[derive(Clone, Debug)]
+pub struct ScansReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// Only returns scans in the folder with the given ID
+    pub folder_id: Option<u64>,
+    /// Only returns scans which have been modified after the given Unix timestamp
+    pub last_modification_date: Option<u64>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for ScansReq<'_> {
+    type Output = Scans;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let mut query = Vec::new();
+        if let Some(folder_id) = self.folder_id {
+            query.push(format!("folder_id={}", folder_id));
+        }
+        if let Some(last_modification_date) = self.last_modification_date {
+            query.push(format!("last_modification_date={}", last_modification_date));
+        }
+        let uri = if query.is_empty() {
+            format!("{}/scans", self.tenable.uri)
+        } else {
+            format!("{}/scans?{}", self.tenable.uri, query.join("&"))
+        };
+        let req = Request::builder()
+            .uri(uri)
+            .method(Method::GET)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .body(Vec::new())?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(data)
+    }
+}
+
+impl<'a> From<ScansReq<'a>> for Cow<'a, ScansReq<'a>> {
+    #[inline]
+    fn from(req: ScansReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a ScansReq<'a>> for Cow<'a, ScansReq<'a>> {
+    #[inline]
+    fn from(req: &'a ScansReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Scans and folders returned by `scans`
+pub struct Scans {
+    /// The folders of the current user.
+    #[serde(rename = "folders", skip_serializing_if = "Option::is_none")]
+    pub folders: Option<Vec<Folder>>,
+    /// The scans the current user has access to.
+    #[serde(rename = "scans", skip_serializing_if = "Option::is_none")]
+    pub scans: Option<Vec<Scan>>,
+    /// The Unix timestamp when Tenable.io created the list. Use it as `last_modification_date` to only fetch scans changed since.
+    #[serde(rename = "timestamp", skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<u64>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Represents a `Folder` returned by `scans`
+pub struct Folder {
+    /// The unique ID of the folder.
+    #[serde(rename = "id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    /// The name of the folder.
+    #[serde(rename = "name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The type of the folder: `main` for the default folder, `trash` for the trash folder or `custom` for user-defined folders.
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub folder_type: Option<String>,
+    /// Indicates whether the folder is the default folder of the user.
+    #[serde(rename = "default_tag", skip_serializing_if = "Option::is_none")]
+    pub default_tag: Option<u8>,
+    /// Indicates whether the folder was created by the user.
+    #[serde(rename = "custom", skip_serializing_if = "Option::is_none")]
+    pub custom: Option<u8>,
+    /// The number of scans in the folder which the user has not yet viewed.
+    #[serde(rename = "unread_count", skip_serializing_if = "Option::is_none")]
+    pub unread_count: Option<u64>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Represents a `Scan` returned by `scans`
+pub struct Scan {
+    /// The unique ID of the scan.
+    #[serde(rename = "id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    /// The UUID of the scan.
+    #[serde(rename = "uuid", skip_serializing_if = "Option::is_none")]
+    pub uuid: Option<String>,
+    /// The name of the scan.
+    #[serde(rename = "name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The type of the scan, for example `remote` or `agent`.
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub scan_type: Option<String>,
+    /// The username of the owner of the scan.
+    #[serde(rename = "owner", skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    /// The status of the latest run of the scan.
+    #[serde(rename = "status", skip_serializing_if = "Option::is_none")]
+    pub status: Option<ScanStatus>,
+    /// The ID of the folder containing the scan.
+    #[serde(rename = "folder_id", skip_serializing_if = "Option::is_none")]
+    pub folder_id: Option<u64>,
+    /// Indicates whether the user has viewed the results of the scan.
+    #[serde(rename = "read", skip_serializing_if = "Option::is_none")]
+    pub read: Option<bool>,
+    /// Indicates whether the scan is shared with other users.
+    #[serde(rename = "shared", skip_serializing_if = "Option::is_none")]
+    pub shared: Option<bool>,
+    /// Indicates whether the user is able to launch, pause or stop the scan.
+    #[serde(rename = "control", skip_serializing_if = "Option::is_none")]
+    pub control: Option<bool>,
+    /// Indicates whether the scan schedule is active.
+    #[serde(rename = "enabled", skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    /// The permissions the current user has for the scan.
+    #[serde(rename = "user_permissions", skip_serializing_if = "Option::is_none")]
+    pub user_permissions: Option<u32>,
+    /// The Unix timestamp when the scan was created.
+    #[serde(rename = "creation_date", skip_serializing_if = "Option::is_none")]
+    pub creation_date: Option<u64>,
+    /// The Unix timestamp when the scan was last modified.
+    #[serde(
+        rename = "last_modification_date",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub last_modification_date: Option<u64>,
+    /// The UUID of the scan schedule.
+    #[serde(rename = "schedule_uuid", skip_serializing_if = "Option::is_none")]
+    pub schedule_uuid: Option<String>,
+    /// The UUID of the template the scan is based on.
+    #[serde(rename = "template_uuid", skip_serializing_if = "Option::is_none")]
+    pub template_uuid: Option<String>,
+    /// The interval at which the scan repeats, in iCalendar `RRULE` format.
+    #[serde(rename = "rrules", skip_serializing_if = "Option::is_none")]
+    pub rrules: Option<String>,
+    /// The scheduled start time of the scan, in the format `YYYYMMDDTHHMMSS`.
+    #[serde(rename = "starttime", skip_serializing_if = "Option::is_none")]
+    pub starttime: Option<String>,
+    /// The timezone of the scan schedule.
+    #[serde(rename = "timezone", skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+}
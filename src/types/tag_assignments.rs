@@ -0,0 +1,106 @@
+use crate::{Error, HttpRequest, Response, Tenable};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `tag_assignments` function
+#[derive(Clone, Debug)]
+pub struct TagAssignmentsReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// Definition which details the assets and tags
+    pub tag_assignments_def: Cow<'a, TagAssignmentsDef>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for TagAssignmentsReq<'_> {
+    type Output = TagAssignmentJob;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let payload = serde_json::to_vec(&self.tag_assignments_def)?;
+        let req = Request::builder()
+            .uri(format!("{}/tags/assets/assignments", self.tenable.uri))
+            .method(Method::POST)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .header("Content-Type", HeaderValue::from_static("application/json"))
+            .body(payload)?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(data)
+    }
+}
+
+impl<'a> From<TagAssignmentsReq<'a>> for Cow<'a, TagAssignmentsReq<'a>> {
+    #[inline]
+    fn from(req: TagAssignmentsReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a TagAssignmentsReq<'a>> for Cow<'a, TagAssignmentsReq<'a>> {
+    #[inline]
+    fn from(req: &'a TagAssignmentsReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Details which tags to add to or remove from which assets
+pub struct TagAssignmentsDef {
+    /// Whether to add or remove the tags.
+    #[serde(rename = "action")]
+    pub action: TagAssignmentAction,
+    /// The UUIDs of the assets.
+    #[serde(rename = "assets")]
+    pub assets: Vec<String>,
+    /// The UUIDs of the tag values.
+    #[serde(rename = "tags")]
+    pub tags: Vec<String>,
+}
+
+impl From<TagAssignmentsDef> for Cow<'_, TagAssignmentsDef> {
+    #[inline]
+    fn from(req: TagAssignmentsDef) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a TagAssignmentsDef> for Cow<'a, TagAssignmentsDef> {
+    #[inline]
+    fn from(req: &'a TagAssignmentsDef) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Copy)]
+/// Action to perform on the assets
+pub enum TagAssignmentAction {
+    /// Assigns the tags to the assets.
+    #[serde(rename = "add")]
+    Add,
+    /// Removes the tags from the assets.
+    #[serde(rename = "remove")]
+    Remove,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Identifies the job Tenable.io started to assign the tags
+pub struct TagAssignmentJob {
+    /// The UUID of the job.
+    #[serde(rename = "job_uuid")]
+    pub job_uuid: String,
+}
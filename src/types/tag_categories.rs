@@ -0,0 +1,118 @@
+use crate::{
+    types::{encode_query, Pagination},
+    Error, HttpRequest, Response, Tenable,
+};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `tag_categories` function
+#[derive(Clone, Debug)]
+pub struct TagCategoriesReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// Maximum number of categories to return
+    pub limit: Option<u64>,
+    /// Number of categories to skip
+    pub offset: Option<u64>,
+    /// Sorts the categories by the given field and order, for example `name:asc`
+    pub sort: Option<Cow<'a, str>>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for TagCategoriesReq<'_> {
+    type Output = TagCategories;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let mut query = Vec::new();
+        if let Some(limit) = self.limit {
+            query.push(format!("limit={}", limit));
+        }
+        if let Some(offset) = self.offset {
+            query.push(format!("offset={}", offset));
+        }
+        if let Some(ref sort) = self.sort {
+            query.push(format!("sort={}", encode_query(sort)));
+        }
+        let mut uri = format!("{}/tags/categories", self.tenable.uri);
+        if !query.is_empty() {
+            uri = format!("{}?{}", uri, query.join("&"));
+        }
+        let req = Request::builder()
+            .uri(uri)
+            .method(Method::GET)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .body(Vec::new())?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(data)
+    }
+}
+
+impl<'a> From<TagCategoriesReq<'a>> for Cow<'a, TagCategoriesReq<'a>> {
+    #[inline]
+    fn from(req: TagCategoriesReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a TagCategoriesReq<'a>> for Cow<'a, TagCategoriesReq<'a>> {
+    #[inline]
+    fn from(req: &'a TagCategoriesReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Tag categories returned by `tag_categories`
+pub struct TagCategories {
+    /// The tag categories.
+    #[serde(rename = "categories", skip_serializing_if = "Option::is_none")]
+    pub categories: Option<Vec<TagCategory>>,
+    /// The position of the returned categories within all categories.
+    #[serde(rename = "pagination", skip_serializing_if = "Option::is_none")]
+    pub pagination: Option<Pagination>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Represents a tag category, the first half of the category:value pair
+pub struct TagCategory {
+    /// The UUID of the category.
+    #[serde(rename = "uuid", skip_serializing_if = "Option::is_none")]
+    pub uuid: Option<String>,
+    /// The name of the category.
+    #[serde(rename = "name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The description of the category.
+    #[serde(rename = "description", skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Indicates whether the category is managed by Tenable.io and cannot be changed.
+    #[serde(rename = "reserved", skip_serializing_if = "Option::is_none")]
+    pub reserved: Option<bool>,
+    /// The ISO timestamp when the category was created.
+    #[serde(rename = "created_at", skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    /// The user who created the category.
+    #[serde(rename = "created_by", skip_serializing_if = "Option::is_none")]
+    pub created_by: Option<String>,
+    /// The ISO timestamp when the category was last updated.
+    #[serde(rename = "updated_at", skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+    /// The user who last updated the category.
+    #[serde(rename = "updated_by", skip_serializing_if = "Option::is_none")]
+    pub updated_by: Option<String>,
+}
@@ -0,0 +1,61 @@
+use crate::{types::TagCategory, Error, HttpRequest, Response, Tenable};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `tag_category_by_uuid` function
+#[derive(Clone, Debug)]
+pub struct TagCategoryByUuidReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// UUID which identifies the category
+    pub category_uuid: Cow<'a, str>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for TagCategoryByUuidReq<'_> {
+    type Output = Option<TagCategory>;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let req = Request::builder()
+            .uri(format!(
+                "{}/tags/categories/{category_uuid}",
+                self.tenable.uri,
+                category_uuid = self.category_uuid
+            ))
+            .method(Method::GET)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .body(Vec::new())?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            StatusCode::NOT_FOUND => return Ok(None),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(Some(data))
+    }
+}
+
+impl<'a> From<TagCategoryByUuidReq<'a>> for Cow<'a, TagCategoryByUuidReq<'a>> {
+    #[inline]
+    fn from(req: TagCategoryByUuidReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a TagCategoryByUuidReq<'a>> for Cow<'a, TagCategoryByUuidReq<'a>> {
+    #[inline]
+    fn from(req: &'a TagCategoryByUuidReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
@@ -0,0 +1,84 @@
+use crate::{types::TagCategory, Error, HttpRequest, Response, Tenable};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `tag_category_create` function
+#[derive(Clone, Debug)]
+pub struct TagCategoryCreateReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// Definition which details the category
+    pub tag_category_def: Cow<'a, TagCategoryDef>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for TagCategoryCreateReq<'_> {
+    type Output = TagCategory;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let payload = serde_json::to_vec(&self.tag_category_def)?;
+        let req = Request::builder()
+            .uri(format!("{}/tags/categories", self.tenable.uri))
+            .method(Method::POST)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .header("Content-Type", HeaderValue::from_static("application/json"))
+            .body(payload)?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(data)
+    }
+}
+
+impl<'a> From<TagCategoryCreateReq<'a>> for Cow<'a, TagCategoryCreateReq<'a>> {
+    #[inline]
+    fn from(req: TagCategoryCreateReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a TagCategoryCreateReq<'a>> for Cow<'a, TagCategoryCreateReq<'a>> {
+    #[inline]
+    fn from(req: &'a TagCategoryCreateReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+/// Details a tag category to create or update
+pub struct TagCategoryDef {
+    /// The name of the category. Must be unique and is case-sensitive.
+    #[serde(rename = "name")]
+    pub name: String,
+    /// The description of the category.
+    #[serde(rename = "description", skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+impl From<TagCategoryDef> for Cow<'_, TagCategoryDef> {
+    #[inline]
+    fn from(req: TagCategoryDef) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a TagCategoryDef> for Cow<'a, TagCategoryDef> {
+    #[inline]
+    fn from(req: &'a TagCategoryDef) -> Self {
+        Cow::Borrowed(req)
+    }
+}
@@ -0,0 +1,68 @@
+use crate::{
+    types::{TagCategory, TagCategoryDef},
+    Error, HttpRequest, Response, Tenable,
+};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `tag_category_update` function
+#[derive(Clone, Debug)]
+pub struct TagCategoryUpdateReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// UUID which identifies the category
+    pub category_uuid: Cow<'a, str>,
+    /// Definition which details the category
+    pub tag_category_def: Cow<'a, TagCategoryDef>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for TagCategoryUpdateReq<'_> {
+    type Output = Option<TagCategory>;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let payload = serde_json::to_vec(&self.tag_category_def)?;
+        let req = Request::builder()
+            .uri(format!(
+                "{}/tags/categories/{category_uuid}",
+                self.tenable.uri,
+                category_uuid = self.category_uuid
+            ))
+            .method(Method::PUT)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .header("Content-Type", HeaderValue::from_static("application/json"))
+            .body(payload)?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            StatusCode::NOT_FOUND => return Ok(None),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(Some(data))
+    }
+}
+
+impl<'a> From<TagCategoryUpdateReq<'a>> for Cow<'a, TagCategoryUpdateReq<'a>> {
+    #[inline]
+    fn from(req: TagCategoryUpdateReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a TagCategoryUpdateReq<'a>> for Cow<'a, TagCategoryUpdateReq<'a>> {
+    #[inline]
+    fn from(req: &'a TagCategoryUpdateReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
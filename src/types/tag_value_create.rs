@@ -0,0 +1,147 @@
+use crate::{types::TagValue, Error, HttpRequest, Response, Tenable};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use serde::{de, Deserialize, Deserializer, Serialize};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `tag_value_create` function
+#[derive(Clone, Debug)]
+pub struct TagValueCreateReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// Definition which details the value
+    pub tag_value_def: Cow<'a, TagValueDef>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for TagValueCreateReq<'_> {
+    type Output = TagValue;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let payload = serde_json::to_vec(&self.tag_value_def)?;
+        let req = Request::builder()
+            .uri(format!("{}/tags/values", self.tenable.uri))
+            .method(Method::POST)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .header("Content-Type", HeaderValue::from_static("application/json"))
+            .body(payload)?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(data)
+    }
+}
+
+impl<'a> From<TagValueCreateReq<'a>> for Cow<'a, TagValueCreateReq<'a>> {
+    #[inline]
+    fn from(req: TagValueCreateReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a TagValueCreateReq<'a>> for Cow<'a, TagValueCreateReq<'a>> {
+    #[inline]
+    fn from(req: &'a TagValueCreateReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+/// Details a tag value to create or update
+pub struct TagValueDef {
+    /// The UUID of the category the value belongs to. Either `category_uuid` or `category_name` is required when creating a value, both are ignored on update.
+    #[serde(rename = "category_uuid", skip_serializing_if = "Option::is_none")]
+    pub category_uuid: Option<String>,
+    /// The name of the category the value belongs to. Tenable.io creates the category if it does not exist yet.
+    #[serde(rename = "category_name", skip_serializing_if = "Option::is_none")]
+    pub category_name: Option<String>,
+    /// The description of the category, used when Tenable.io creates the category.
+    #[serde(
+        rename = "category_description",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub category_description: Option<String>,
+    /// The value. Must be unique within the category.
+    #[serde(rename = "value")]
+    pub value: String,
+    /// The description of the value.
+    #[serde(rename = "description", skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The rules Tenable.io uses to assign the value to assets. Turns the value into a dynamic tag.
+    #[serde(rename = "filters", skip_serializing_if = "Option::is_none")]
+    pub filters: Option<TagValueFilters>,
+}
+
+impl From<TagValueDef> for Cow<'_, TagValueDef> {
+    #[inline]
+    fn from(req: TagValueDef) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a TagValueDef> for Cow<'a, TagValueDef> {
+    #[inline]
+    fn from(req: &'a TagValueDef) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Rules of a dynamic tag
+pub struct TagValueFilters {
+    /// The rules matched against asset attributes.
+    #[serde(rename = "asset", deserialize_with = "deserialize_tag_rules")]
+    pub asset: TagRules,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Combines the rules of a dynamic tag
+pub enum TagRules {
+    /// Assets have to match all rules.
+    #[serde(rename = "and")]
+    And(Vec<TagRule>),
+    /// Assets have to match at least one rule.
+    #[serde(rename = "or")]
+    Or(Vec<TagRule>),
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Single rule of a dynamic tag
+pub struct TagRule {
+    /// The asset attribute to match, for example `ipv4`, `fqdn` or `operating_system`.
+    #[serde(rename = "field")]
+    pub field: String,
+    /// The operator used to match the attribute, for example `eq`, `match` or `starts`.
+    #[serde(rename = "operator")]
+    pub operator: String,
+    /// The value to match. Multiple values are separated by commas.
+    #[serde(rename = "value")]
+    pub value: String,
+}
+
+/// Tenable.io expects the rules as object, but returns them as JSON encoded string
+fn deserialize_tag_rules<'de, D: Deserializer<'de>>(deserializer: D) -> Result<TagRules, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Rules {
+        String(String),
+        Rules(TagRules),
+    }
+
+    match Rules::deserialize(deserializer)? {
+        Rules::String(rules) => serde_json::from_str(&rules).map_err(de::Error::custom),
+        Rules::Rules(rules) => Ok(rules),
+    }
+}
@@ -0,0 +1,68 @@
+use crate::{
+    types::{TagValue, TagValueDef},
+    Error, HttpRequest, Response, Tenable,
+};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `tag_value_update` function
+#[derive(Clone, Debug)]
+pub struct TagValueUpdateReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// UUID which identifies the value
+    pub value_uuid: Cow<'a, str>,
+    /// Definition which details the value
+    pub tag_value_def: Cow<'a, TagValueDef>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for TagValueUpdateReq<'_> {
+    type Output = Option<TagValue>;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let payload = serde_json::to_vec(&self.tag_value_def)?;
+        let req = Request::builder()
+            .uri(format!(
+                "{}/tags/values/{value_uuid}",
+                self.tenable.uri,
+                value_uuid = self.value_uuid
+            ))
+            .method(Method::PUT)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .header("Content-Type", HeaderValue::from_static("application/json"))
+            .body(payload)?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            StatusCode::NOT_FOUND => return Ok(None),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(Some(data))
+    }
+}
+
+impl<'a> From<TagValueUpdateReq<'a>> for Cow<'a, TagValueUpdateReq<'a>> {
+    #[inline]
+    fn from(req: TagValueUpdateReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a TagValueUpdateReq<'a>> for Cow<'a, TagValueUpdateReq<'a>> {
+    #[inline]
+    fn from(req: &'a TagValueUpdateReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
@@ -0,0 +1,133 @@
+use crate::{
+    types::{encode_query, Pagination, TagValueFilters},
+    Error, HttpRequest, Response, Tenable,
+};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `tag_values` function
+#[derive(Clone, Debug)]
+pub struct TagValuesReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// Maximum number of values to return
+    pub limit: Option<u64>,
+    /// Number of values to skip
+    pub offset: Option<u64>,
+    /// Sorts the values by the given field and order, for example `name:asc`
+    pub sort: Option<Cow<'a, str>>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for TagValuesReq<'_> {
+    type Output = TagValues;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let mut query = Vec::new();
+        if let Some(limit) = self.limit {
+            query.push(format!("limit={}", limit));
+        }
+        if let Some(offset) = self.offset {
+            query.push(format!("offset={}", offset));
+        }
+        if let Some(ref sort) = self.sort {
+            query.push(format!("sort={}", encode_query(sort)));
+        }
+        let mut uri = format!("{}/tags/values", self.tenable.uri);
+        if !query.is_empty() {
+            uri = format!("{}?{}", uri, query.join("&"));
+        }
+        let req = Request::builder()
+            .uri(uri)
+            .method(Method::GET)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .body(Vec::new())?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(data)
+    }
+}
+
+impl<'a> From<TagValuesReq<'a>> for Cow<'a, TagValuesReq<'a>> {
+    #[inline]
+    fn from(req: TagValuesReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a TagValuesReq<'a>> for Cow<'a, TagValuesReq<'a>> {
+    #[inline]
+    fn from(req: &'a TagValuesReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Tag values returned by `tag_values`
+pub struct TagValues {
+    /// The tag values.
+    #[serde(rename = "values", skip_serializing_if = "Option::is_none")]
+    pub values: Option<Vec<TagValue>>,
+    /// The position of the returned values within all values.
+    #[serde(rename = "pagination", skip_serializing_if = "Option::is_none")]
+    pub pagination: Option<Pagination>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Represents a tag value, the second half of the category:value pair
+pub struct TagValue {
+    /// The UUID of the value. Use it to assign the tag to assets.
+    #[serde(rename = "uuid", skip_serializing_if = "Option::is_none")]
+    pub uuid: Option<String>,
+    /// The value.
+    #[serde(rename = "value", skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    /// The description of the value.
+    #[serde(rename = "description", skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The type of the value: `static` for manually assigned tags or `dynamic` for tags assigned by `filters`.
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub value_type: Option<String>,
+    /// The UUID of the category the value belongs to.
+    #[serde(rename = "category_uuid", skip_serializing_if = "Option::is_none")]
+    pub category_uuid: Option<String>,
+    /// The name of the category the value belongs to.
+    #[serde(rename = "category_name", skip_serializing_if = "Option::is_none")]
+    pub category_name: Option<String>,
+    /// The description of the category the value belongs to.
+    #[serde(
+        rename = "category_description",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub category_description: Option<String>,
+    /// The rules Tenable.io uses to assign the value to assets. Only returned for dynamic tags.
+    #[serde(rename = "filters", skip_serializing_if = "Option::is_none")]
+    pub filters: Option<TagValueFilters>,
+    /// The ISO timestamp when the value was created.
+    #[serde(rename = "created_at", skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    /// The user who created the value.
+    #[serde(rename = "created_by", skip_serializing_if = "Option::is_none")]
+    pub created_by: Option<String>,
+    /// The ISO timestamp when the value was last updated.
+    #[serde(rename = "updated_at", skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+    /// The user who last updated the value.
+    #[serde(rename = "updated_by", skip_serializing_if = "Option::is_none")]
+    pub updated_by: Option<String>,
+}
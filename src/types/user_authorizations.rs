@@ -0,0 +1,82 @@
+use crate::{Error, HttpRequest, Response, Tenable};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `user_authorizations` function
+#[derive(Clone, Debug)]
+pub struct UserAuthorizationsReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// ID which identifies the user
+    pub user_id: u64,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for UserAuthorizationsReq<'_> {
+    type Output = Option<UserAuthorizations>;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let req = Request::builder()
+            .uri(format!(
+                "{}/users/{user_id}/authorizations",
+                self.tenable.uri,
+                user_id = self.user_id
+            ))
+            .method(Method::GET)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .body(Vec::new())?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            StatusCode::NOT_FOUND => return Ok(None),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(Some(data))
+    }
+}
+
+impl<'a> From<UserAuthorizationsReq<'a>> for Cow<'a, UserAuthorizationsReq<'a>> {
+    #[inline]
+    fn from(req: UserAuthorizationsReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a UserAuthorizationsReq<'a>> for Cow<'a, UserAuthorizationsReq<'a>> {
+    #[inline]
+    fn from(req: &'a UserAuthorizationsReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Ways a user is allowed to authenticate, as returned by `user_authorizations`
+pub struct UserAuthorizations {
+    /// The UUID of the container the user belongs to.
+    #[serde(rename = "account_uuid", skip_serializing_if = "Option::is_none")]
+    pub account_uuid: Option<String>,
+    /// The UUID of the user.
+    #[serde(rename = "user_uuid", skip_serializing_if = "Option::is_none")]
+    pub user_uuid: Option<String>,
+    /// Indicates whether the user can use API keys.
+    #[serde(rename = "api_permitted", skip_serializing_if = "Option::is_none")]
+    pub api_permitted: Option<bool>,
+    /// Indicates whether the user can log in with username and password.
+    #[serde(rename = "password_permitted", skip_serializing_if = "Option::is_none")]
+    pub password_permitted: Option<bool>,
+    /// Indicates whether the user can log in using SAML.
+    #[serde(rename = "saml_permitted", skip_serializing_if = "Option::is_none")]
+    pub saml_permitted: Option<bool>,
+}
@@ -0,0 +1,61 @@
+use crate::{types::User, Error, HttpRequest, Response, Tenable};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `user_by_id` function
+#[derive(Clone, Debug)]
+pub struct UserByIdReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// ID which identifies the user
+    pub user_id: u64,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for UserByIdReq<'_> {
+    type Output = Option<User>;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let req = Request::builder()
+            .uri(format!(
+                "{}/users/{user_id}",
+                self.tenable.uri,
+                user_id = self.user_id
+            ))
+            .method(Method::GET)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .body(Vec::new())?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            StatusCode::NOT_FOUND => return Ok(None),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(Some(data))
+    }
+}
+
+impl<'a> From<UserByIdReq<'a>> for Cow<'a, UserByIdReq<'a>> {
+    #[inline]
+    fn from(req: UserByIdReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a UserByIdReq<'a>> for Cow<'a, UserByIdReq<'a>> {
+    #[inline]
+    fn from(req: &'a UserByIdReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
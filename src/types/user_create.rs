@@ -0,0 +1,99 @@
+use crate::{
+    types::{Secret, User},
+    Error, HttpRequest, Response, Tenable,
+};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `user_create` function
+#[derive(Clone, Debug)]
+pub struct UserCreateReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// Definition which details the user
+    pub user_def: Cow<'a, UserDef>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for UserCreateReq<'_> {
+    type Output = User;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let payload = serde_json::to_vec(&self.user_def)?;
+        let req = Request::builder()
+            .uri(format!("{}/users", self.tenable.uri))
+            .method(Method::POST)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .header("Content-Type", HeaderValue::from_static("application/json"))
+            .body(payload)?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(data)
+    }
+}
+
+impl<'a> From<UserCreateReq<'a>> for Cow<'a, UserCreateReq<'a>> {
+    #[inline]
+    fn from(req: UserCreateReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a UserCreateReq<'a>> for Cow<'a, UserCreateReq<'a>> {
+    #[inline]
+    fn from(req: &'a UserCreateReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Details the user to create
+pub struct UserDef {
+    /// The username of the user, usually an email address.
+    #[serde(rename = "username")]
+    pub username: String,
+    /// The initial password of the user.
+    #[serde(rename = "password")]
+    pub password: Secret,
+    /// The role of the user: BASIC [16], SCAN OPERATOR [24], STANDARD [32], SCAN MANAGER [40] or ADMINISTRATOR [64].
+    #[serde(rename = "permissions")]
+    pub permissions: u32,
+    /// The full name of the user.
+    #[serde(rename = "name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The email address of the user.
+    #[serde(rename = "email", skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    /// The type of the account. Only `local` is supported.
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub user_type: Option<String>,
+}
+
+impl From<UserDef> for Cow<'_, UserDef> {
+    #[inline]
+    fn from(req: UserDef) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a UserDef> for Cow<'a, UserDef> {
+    #[inline]
+    fn from(req: &'a UserDef) -> Self {
+        Cow::Borrowed(req)
+    }
+}
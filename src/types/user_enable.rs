@@ -0,0 +1,74 @@
+use crate::{types::User, Error, HttpRequest, Response, Tenable};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use serde::Serialize;
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `user_enable` function
+#[derive(Clone, Debug)]
+pub struct UserEnableReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// ID which identifies the user
+    pub user_id: u64,
+    /// Enables the user if `true`, disables it if `false`
+    pub enabled: bool,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for UserEnableReq<'_> {
+    type Output = Option<User>;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let payload = serde_json::to_vec(&UserEnablePayload {
+            enabled: self.enabled,
+        })?;
+        let req = Request::builder()
+            .uri(format!(
+                "{}/users/{user_id}/enabled",
+                self.tenable.uri,
+                user_id = self.user_id
+            ))
+            .method(Method::PUT)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .header("Content-Type", HeaderValue::from_static("application/json"))
+            .body(payload)?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            StatusCode::NOT_FOUND => return Ok(None),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(Some(data))
+    }
+}
+
+impl<'a> From<UserEnableReq<'a>> for Cow<'a, UserEnableReq<'a>> {
+    #[inline]
+    fn from(req: UserEnableReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a UserEnableReq<'a>> for Cow<'a, UserEnableReq<'a>> {
+    #[inline]
+    fn from(req: &'a UserEnableReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+/// Body of the `user_enable` request
+#[derive(Serialize)]
+struct UserEnablePayload {
+    enabled: bool,
+}
@@ -0,0 +1,73 @@
+use crate::{types::Secret, Error, HttpRequest, Response, Tenable};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `user_keys` function
+#[derive(Clone, Debug)]
+pub struct UserKeysReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// ID which identifies the user
+    pub user_id: u64,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for UserKeysReq<'_> {
+    type Output = Option<UserKeys>;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let req = Request::builder()
+            .uri(format!(
+                "{}/users/{user_id}/keys",
+                self.tenable.uri,
+                user_id = self.user_id
+            ))
+            .method(Method::PUT)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .body(Vec::new())?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            StatusCode::NOT_FOUND => return Ok(None),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(Some(data))
+    }
+}
+
+impl<'a> From<UserKeysReq<'a>> for Cow<'a, UserKeysReq<'a>> {
+    #[inline]
+    fn from(req: UserKeysReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a UserKeysReq<'a>> for Cow<'a, UserKeysReq<'a>> {
+    #[inline]
+    fn from(req: &'a UserKeysReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// API keys returned by `user_keys`
+pub struct UserKeys {
+    /// The new access key of the user.
+    #[serde(rename = "accessKey")]
+    pub access_key: String,
+    /// The new secret key of the user.
+    #[serde(rename = "secretKey")]
+    pub secret_key: Secret,
+}
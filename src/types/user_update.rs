@@ -0,0 +1,97 @@
+use crate::{types::User, Error, HttpRequest, Response, Tenable};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `user_update` function
+#[derive(Clone, Debug)]
+pub struct UserUpdateReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// ID which identifies the user
+    pub user_id: u64,
+    /// Definition which details the changes
+    pub user_update_def: Cow<'a, UserUpdateDef>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for UserUpdateReq<'_> {
+    type Output = Option<User>;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let payload = serde_json::to_vec(&self.user_update_def)?;
+        let req = Request::builder()
+            .uri(format!(
+                "{}/users/{user_id}",
+                self.tenable.uri,
+                user_id = self.user_id
+            ))
+            .method(Method::PUT)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .header("Content-Type", HeaderValue::from_static("application/json"))
+            .body(payload)?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            StatusCode::NOT_FOUND => return Ok(None),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(Some(data))
+    }
+}
+
+impl<'a> From<UserUpdateReq<'a>> for Cow<'a, UserUpdateReq<'a>> {
+    #[inline]
+    fn from(req: UserUpdateReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a UserUpdateReq<'a>> for Cow<'a, UserUpdateReq<'a>> {
+    #[inline]
+    fn from(req: &'a UserUpdateReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+/// Details the changes of a user. Fields which are `None` are not changed.
+pub struct UserUpdateDef {
+    /// The new role of the user: BASIC [16], SCAN OPERATOR [24], STANDARD [32], SCAN MANAGER [40] or ADMINISTRATOR [64].
+    #[serde(rename = "permissions", skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<u32>,
+    /// The new full name of the user.
+    #[serde(rename = "name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The new email address of the user.
+    #[serde(rename = "email", skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    /// Whether the user can log in.
+    #[serde(rename = "enabled", skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+}
+
+impl From<UserUpdateDef> for Cow<'_, UserUpdateDef> {
+    #[inline]
+    fn from(req: UserUpdateDef) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a UserUpdateDef> for Cow<'a, UserUpdateDef> {
+    #[inline]
+    fn from(req: &'a UserUpdateDef) -> Self {
+        Cow::Borrowed(req)
+    }
+}
@@ -0,0 +1,107 @@
+use crate::{Error, HttpRequest, Response, Tenable};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `users` function
+#[derive(Clone, Debug)]
+pub struct UsersReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for UsersReq<'_> {
+    type Output = Users;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let req = Request::builder()
+            .uri(format!("{}/users", self.tenable.uri))
+            .method(Method::GET)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .body(Vec::new())?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(data)
+    }
+}
+
+impl<'a> From<UsersReq<'a>> for Cow<'a, UsersReq<'a>> {
+    #[inline]
+    fn from(req: UsersReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a UsersReq<'a>> for Cow<'a, UsersReq<'a>> {
+    #[inline]
+    fn from(req: &'a UsersReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Users returned by `users` and `group_users`
+pub struct Users {
+    /// The users of the container or group.
+    #[serde(rename = "users", skip_serializing_if = "Option::is_none")]
+    pub users: Option<Vec<User>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// User of the container
+pub struct User {
+    /// The unique ID of the user.
+    #[serde(rename = "id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    /// The UUID of the user.
+    #[serde(rename = "uuid", skip_serializing_if = "Option::is_none")]
+    pub uuid: Option<String>,
+    /// The username of the user, usually an email address.
+    #[serde(rename = "username", skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    /// The full name of the user.
+    #[serde(rename = "name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The email address of the user.
+    #[serde(rename = "email", skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    /// The type of the account, for example `local`.
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub user_type: Option<String>,
+    /// The role of the user: BASIC [16], SCAN OPERATOR [24], STANDARD [32], SCAN MANAGER [40] or ADMINISTRATOR [64].
+    #[serde(rename = "permissions", skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<u32>,
+    /// Indicates whether the user can log in.
+    #[serde(rename = "enabled", skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    /// The number of failed logins since the last successful login.
+    #[serde(rename = "login_fail_count", skip_serializing_if = "Option::is_none")]
+    pub login_fail_count: Option<u32>,
+    /// The total number of failed logins.
+    #[serde(rename = "login_fail_total", skip_serializing_if = "Option::is_none")]
+    pub login_fail_total: Option<u32>,
+    /// The Unix timestamp in milliseconds of the last successful login.
+    #[serde(rename = "lastlogin", skip_serializing_if = "Option::is_none")]
+    pub lastlogin: Option<u64>,
+    /// The Unix timestamp in milliseconds when the API keys of the user were last used.
+    #[serde(rename = "last_apikey_access", skip_serializing_if = "Option::is_none")]
+    pub last_apikey_access: Option<u64>,
+    /// The UUID of the container the user belongs to.
+    #[serde(rename = "container_uuid", skip_serializing_if = "Option::is_none")]
+    pub container_uuid: Option<String>,
+}
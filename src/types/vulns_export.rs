@@ -0,0 +1,151 @@
+use crate::{
+    types::{ExportUuid, Severity, TagFilters, VulnState},
+    Error, HttpRequest, Response, Tenable,
+};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `vulns_export` function
+#[derive(Clone, Debug)]
+pub struct VulnsExportReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// Definition which details the export
+    pub vulns_export_def: Cow<'a, VulnsExportDef>,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for VulnsExportReq<'_> {
+    type Output = ExportUuid;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let payload = serde_json::to_vec(&self.vulns_export_def)?;
+        let req = Request::builder()
+            .uri(format!("{}/vulns/export", self.tenable.uri))
+            .method(Method::POST)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header("Accept", HeaderValue::from_static("application/json"))
+            .header("Content-Type", HeaderValue::from_static("application/json"))
+            .body(payload)?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(data)
+    }
+}
+
+impl<'a> From<VulnsExportReq<'a>> for Cow<'a, VulnsExportReq<'a>> {
+    #[inline]
+    fn from(req: VulnsExportReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a VulnsExportReq<'a>> for Cow<'a, VulnsExportReq<'a>> {
+    #[inline]
+    fn from(req: &'a VulnsExportReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+/// Details which vulnerabilities to export and how to split them into chunks
+pub struct VulnsExportDef {
+    /// Specifies the number of assets used to chunk the vulnerabilities. The range is 50-5000. Defaults to 50.
+    #[serde(rename = "num_assets", skip_serializing_if = "Option::is_none")]
+    pub num_assets: Option<u64>,
+    /// Specifies whether or not to include unlicensed assets. Defaults to `false`.
+    #[serde(rename = "include_unlicensed", skip_serializing_if = "Option::is_none")]
+    pub include_unlicensed: Option<bool>,
+    /// Specifies filters for exported vulnerabilities. To return all vulnerabilities, omit the filters object.
+    #[serde(rename = "filters", skip_serializing_if = "Option::is_none")]
+    pub filters: Option<VulnsExportFilters>,
+}
+
+impl From<VulnsExportDef> for Cow<'_, VulnsExportDef> {
+    #[inline]
+    fn from(req: VulnsExportDef) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a VulnsExportDef> for Cow<'a, VulnsExportDef> {
+    #[inline]
+    fn from(req: &'a VulnsExportDef) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+/// Filters for the `vulns_export` request. All timestamps are Unix timestamps in seconds.
+pub struct VulnsExportFilters {
+    /// Restricts search for vulnerabilities to assets assigned an IP address within the specified CIDR range, for example `192.0.2.0/24`.
+    #[serde(rename = "cidr_range", skip_serializing_if = "Option::is_none")]
+    pub cidr_range: Option<String>,
+    /// Returns vulnerabilities that were first found between the specified date and now.
+    #[serde(rename = "first_found", skip_serializing_if = "Option::is_none")]
+    pub first_found: Option<u64>,
+    /// Returns vulnerabilities that were last found between the specified date and now.
+    #[serde(rename = "last_found", skip_serializing_if = "Option::is_none")]
+    pub last_found: Option<u64>,
+    /// Returns vulnerabilities that were fixed between the specified date and now.
+    #[serde(rename = "last_fixed", skip_serializing_if = "Option::is_none")]
+    pub last_fixed: Option<u64>,
+    /// Returns vulnerabilities that were seen in any state since the specified date.
+    #[serde(rename = "since", skip_serializing_if = "Option::is_none")]
+    pub since: Option<u64>,
+    /// Returns vulnerabilities that match the specified plugin families.
+    #[serde(rename = "plugin_family", skip_serializing_if = "Option::is_none")]
+    pub plugin_family: Option<Vec<String>>,
+    /// Returns vulnerabilities that match the specified plugin IDs.
+    #[serde(rename = "plugin_id", skip_serializing_if = "Option::is_none")]
+    pub plugin_id: Option<Vec<u64>>,
+    /// Returns vulnerabilities with the specified severities.
+    #[serde(rename = "severity", skip_serializing_if = "Option::is_none")]
+    pub severity: Option<Vec<Severity>>,
+    /// Returns vulnerabilities with the specified states.
+    #[serde(rename = "state", skip_serializing_if = "Option::is_none")]
+    pub state: Option<Vec<VulnState>>,
+    /// Returns vulnerabilities with the specified Vulnerability Priority Rating (VPR) score or scores.
+    #[serde(rename = "vpr_score", skip_serializing_if = "Option::is_none")]
+    pub vpr_score: Option<VprScoreFilter>,
+    /// Returns vulnerabilities on assets with the specified tags, keyed by tag category.
+    #[serde(flatten)]
+    pub tags: TagFilters,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+/// Compares the Vulnerability Priority Rating (VPR) against the specified values. Combine several operators to filter for a range.
+pub struct VprScoreFilter {
+    /// Returns vulnerabilities with a VPR equal to one of the specified scores.
+    #[serde(rename = "eq", skip_serializing_if = "Option::is_none")]
+    pub eq: Option<Vec<f64>>,
+    /// Returns vulnerabilities with a VPR not equal to any of the specified scores.
+    #[serde(rename = "neq", skip_serializing_if = "Option::is_none")]
+    pub neq: Option<Vec<f64>>,
+    /// Returns vulnerabilities with a VPR greater than the specified score.
+    #[serde(rename = "gt", skip_serializing_if = "Option::is_none")]
+    pub gt: Option<f64>,
+    /// Returns vulnerabilities with a VPR greater than or equal to the specified score.
+    #[serde(rename = "gte", skip_serializing_if = "Option::is_none")]
+    pub gte: Option<f64>,
+    /// Returns vulnerabilities with a VPR less than the specified score.
+    #[serde(rename = "lt", skip_serializing_if = "Option::is_none")]
+    pub lt: Option<f64>,
+    /// Returns vulnerabilities with a VPR less than or equal to the specified score.
+    #[serde(rename = "lte", skip_serializing_if = "Option::is_none")]
+    pub lte: Option<f64>,
+}
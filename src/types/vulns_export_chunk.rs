@@ -0,0 +1,342 @@
+use crate::{Error, HttpRequest, Response, Tenable};
+use http::{header::HeaderValue, status::StatusCode, Method, Request};
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, fmt};
+
+/// Request Object for the `vulns_export_chunk` function
+#[derive(Clone, Debug)]
+pub struct VulnsExportChunkReq<'a> {
+    /// Inner tenable Client
+    pub tenable: &'a Tenable<'a>,
+    /// UUID which identifies the export
+    pub export_uuid: Cow<'a, str>,
+    /// ID of the chunk to download
+    pub chunk_id: u64,
+}
+
+impl<RE: fmt::Debug> HttpRequest<RE> for VulnsExportChunkReq<'_> {
+    type Output = Option<Vec<Finding>>;
+
+    #[inline]
+    fn to_request(&self) -> Result<Request<Vec<u8>>, Error<RE>> {
+        let req = Request::builder()
+            .uri(format!(
+                "{}/vulns/export/{export_uuid}/chunks/{chunk_id}",
+                self.tenable.uri,
+                export_uuid = self.export_uuid,
+                chunk_id = self.chunk_id
+            ))
+            .method(Method::GET)
+            .header(
+                "X-ApiKeys",
+                HeaderValue::from_str(self.tenable.auth.as_ref())?,
+            )
+            .header(
+                "Accept",
+                HeaderValue::from_static("application/octet-stream"),
+            )
+            .body(Vec::new())?;
+        Ok(req)
+    }
+
+    #[inline]
+    fn from_response(&self, res: Response) -> Result<Self::Output, Error<RE>> {
+        match res.status {
+            StatusCode::OK => {}
+            StatusCode::FORBIDDEN => return Err(Error::InsufficientPermission),
+            StatusCode::TOO_MANY_REQUESTS => return Err(Error::RateLimitReached),
+            StatusCode::NOT_FOUND => return Ok(None),
+            _ => return Err(Error::from_response(res)),
+        }
+        let data = serde_json::from_slice(&res.body)?;
+        Ok(Some(data))
+    }
+}
+
+impl<'a> From<VulnsExportChunkReq<'a>> for Cow<'a, VulnsExportChunkReq<'a>> {
+    #[inline]
+    fn from(req: VulnsExportChunkReq<'a>) -> Self {
+        Cow::Owned(req)
+    }
+}
+
+impl<'a> From<&'a VulnsExportChunkReq<'a>> for Cow<'a, VulnsExportChunkReq<'a>> {
+    #[inline]
+    fn from(req: &'a VulnsExportChunkReq<'a>) -> Self {
+        Cow::Borrowed(req)
+    }
+}
+
+/// Represents a single vulnerability returned by `vulns_export_chunk`
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Finding {
+    /// Information about the asset where the scan detected the vulnerability.
+    #[serde(rename = "asset", skip_serializing_if = "Option::is_none")]
+    pub asset: Option<FindingAsset>,
+    /// The text output of the Nessus scanner.
+    #[serde(rename = "output", skip_serializing_if = "Option::is_none")]
+    pub output: Option<String>,
+    /// Information about the plugin that detected the vulnerability.
+    #[serde(rename = "plugin", skip_serializing_if = "Option::is_none")]
+    pub plugin: Option<FindingPlugin>,
+    /// Information about the port the scanner used to connect to the asset.
+    #[serde(rename = "port", skip_serializing_if = "Option::is_none")]
+    pub port: Option<FindingPort>,
+    /// Information about the latest scan that detected the vulnerability.
+    #[serde(rename = "scan", skip_serializing_if = "Option::is_none")]
+    pub scan: Option<FindingScan>,
+    /// The severity of the vulnerability as defined using the Common Vulnerability Scoring System (CVSS) base score.
+    #[serde(rename = "severity", skip_serializing_if = "Option::is_none")]
+    pub severity: Option<Severity>,
+    /// The code for the severity assigned when a user recast the risk associated with the vulnerability. Possible values include `0` (info) to `4` (critical).
+    #[serde(rename = "severity_id", skip_serializing_if = "Option::is_none")]
+    pub severity_id: Option<u8>,
+    /// The code for the severity originally assigned to a vulnerability before a user recast the risk associated with the vulnerability.
+    #[serde(
+        rename = "severity_default_id",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub severity_default_id: Option<u8>,
+    /// The type of modification a user made to the vulnerability's severity. Possible values include `NONE`, `RECASTED` and `ACCEPTED`.
+    #[serde(
+        rename = "severity_modification_type",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub severity_modification_type: Option<String>,
+    /// The ISO date when a scan first detected the vulnerability on the asset.
+    #[serde(rename = "first_found", skip_serializing_if = "Option::is_none")]
+    pub first_found: Option<String>,
+    /// The ISO date when a scan last detected the vulnerability on the asset.
+    #[serde(rename = "last_found", skip_serializing_if = "Option::is_none")]
+    pub last_found: Option<String>,
+    /// The ISO date when a scan no longer detected the previously detected vulnerability on the asset.
+    #[serde(rename = "last_fixed", skip_serializing_if = "Option::is_none")]
+    pub last_fixed: Option<String>,
+    /// The ISO date when the vulnerability was indexed into Tenable.io.
+    #[serde(rename = "indexed", skip_serializing_if = "Option::is_none")]
+    pub indexed: Option<String>,
+    /// The state of the vulnerability as determined by the Tenable.io state service.
+    #[serde(rename = "state", skip_serializing_if = "Option::is_none")]
+    pub state: Option<VulnState>,
+}
+
+/// `FindingAsset` blueprint
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FindingAsset {
+    /// The UUID of the asset where a scan found the vulnerability.
+    #[serde(rename = "uuid", skip_serializing_if = "Option::is_none")]
+    pub uuid: Option<String>,
+    /// The name of the host where a scan found the vulnerability.
+    #[serde(rename = "hostname", skip_serializing_if = "Option::is_none")]
+    pub hostname: Option<String>,
+    /// The IPv4 address of the asset where a scan found the vulnerability.
+    #[serde(rename = "ipv4", skip_serializing_if = "Option::is_none")]
+    pub ipv4: Option<String>,
+    /// The IPv6 address of the asset where a scan found the vulnerability.
+    #[serde(rename = "ipv6", skip_serializing_if = "Option::is_none")]
+    pub ipv6: Option<String>,
+    /// The fully-qualified domain name of the asset where a scan found the vulnerability.
+    #[serde(rename = "fqdn", skip_serializing_if = "Option::is_none")]
+    pub fqdn: Option<String>,
+    /// The NetBIOS name of the asset where a scan found the vulnerability.
+    #[serde(rename = "netbios_name", skip_serializing_if = "Option::is_none")]
+    pub netbios_name: Option<String>,
+    /// The operating system of the asset where a scan found the vulnerability.
+    #[serde(rename = "operating_system", skip_serializing_if = "Option::is_none")]
+    pub operating_system: Option<Vec<String>>,
+    /// The MAC address of the asset where a scan found the vulnerability.
+    #[serde(rename = "mac_address", skip_serializing_if = "Option::is_none")]
+    pub mac_address: Option<String>,
+    /// The ID of the network object to which the asset belongs.
+    #[serde(rename = "network_id", skip_serializing_if = "Option::is_none")]
+    pub network_id: Option<String>,
+    /// A value specifying whether Tenable.io tracks the asset in the asset management system.
+    #[serde(rename = "tracked", skip_serializing_if = "Option::is_none")]
+    pub tracked: Option<bool>,
+    /// The type of asset where a scan found the vulnerability, for example `general-purpose`.
+    #[serde(rename = "device_type", skip_serializing_if = "Option::is_none")]
+    pub device_type: Option<String>,
+    /// The UUID of the agent that performed the scan where the vulnerability was found.
+    #[serde(rename = "agent_uuid", skip_serializing_if = "Option::is_none")]
+    pub agent_uuid: Option<String>,
+    /// The BIOS UUID of the asset where the vulnerability was found.
+    #[serde(rename = "bios_uuid", skip_serializing_if = "Option::is_none")]
+    pub bios_uuid: Option<String>,
+    /// The last date credentials were used successfully to scan the asset.
+    #[serde(
+        rename = "last_authenticated_results",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub last_authenticated_results: Option<String>,
+    /// The last date when the asset was scanned without using credentials.
+    #[serde(
+        rename = "last_unauthenticated_results",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub last_unauthenticated_results: Option<String>,
+}
+
+/// `FindingPlugin` blueprint
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FindingPlugin {
+    /// The ID of the plugin that identified the vulnerability.
+    #[serde(rename = "id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    /// The name of the plugin that identified the vulnerability.
+    #[serde(rename = "name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The family to which the plugin belongs.
+    #[serde(rename = "family", skip_serializing_if = "Option::is_none")]
+    pub family: Option<String>,
+    /// The ID of the plugin family.
+    #[serde(rename = "family_id", skip_serializing_if = "Option::is_none")]
+    pub family_id: Option<u64>,
+    /// Full text description of the vulnerability plugin.
+    #[serde(rename = "description", skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Brief description of the plugin or vulnerability.
+    #[serde(rename = "synopsis", skip_serializing_if = "Option::is_none")]
+    pub synopsis: Option<String>,
+    /// Remediation information for the vulnerability.
+    #[serde(rename = "solution", skip_serializing_if = "Option::is_none")]
+    pub solution: Option<String>,
+    /// Links to external websites that contain helpful information about the vulnerability.
+    #[serde(rename = "see_also", skip_serializing_if = "Option::is_none")]
+    pub see_also: Option<Vec<String>>,
+    /// The risk factor associated with the plugin. Possible values are `Low`, `Medium`, `High` or `Critical`.
+    #[serde(rename = "risk_factor", skip_serializing_if = "Option::is_none")]
+    pub risk_factor: Option<String>,
+    /// The CVSSv2 base score (intrinsic and fundamental characteristics of a vulnerability that are constant over time and user environments).
+    #[serde(rename = "cvss_base_score", skip_serializing_if = "Option::is_none")]
+    pub cvss_base_score: Option<f64>,
+    /// The CVSSv2 temporal score (characteristics of a vulnerability that change over time but not among user environments).
+    #[serde(
+        rename = "cvss_temporal_score",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub cvss_temporal_score: Option<f64>,
+    /// The CVSSv3 base score.
+    #[serde(rename = "cvss3_base_score", skip_serializing_if = "Option::is_none")]
+    pub cvss3_base_score: Option<f64>,
+    /// The CVSSv3 temporal score.
+    #[serde(
+        rename = "cvss3_temporal_score",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub cvss3_temporal_score: Option<f64>,
+    /// A list of Common Vulnerability and Exposure (CVE) IDs for the vulnerabilities associated with the plugin.
+    #[serde(rename = "cve", skip_serializing_if = "Option::is_none")]
+    pub cve: Option<Vec<String>>,
+    /// A list of Common Platform Enumeration (CPE) values the plugin applies to.
+    #[serde(rename = "cpe", skip_serializing_if = "Option::is_none")]
+    pub cpe: Option<Vec<String>>,
+    /// A value specifying whether the vendor has published a patch for the vulnerability.
+    #[serde(rename = "has_patch", skip_serializing_if = "Option::is_none")]
+    pub has_patch: Option<bool>,
+    /// A value specifying whether a public exploit exists for the vulnerability.
+    #[serde(rename = "exploit_available", skip_serializing_if = "Option::is_none")]
+    pub exploit_available: Option<bool>,
+    /// The date when Tenable originally published the plugin.
+    #[serde(rename = "publication_date", skip_serializing_if = "Option::is_none")]
+    pub publication_date: Option<String>,
+    /// The date when Tenable last updated the plugin.
+    #[serde(rename = "modification_date", skip_serializing_if = "Option::is_none")]
+    pub modification_date: Option<String>,
+    /// The date when the vendor published a patch for the vulnerability.
+    #[serde(
+        rename = "patch_publication_date",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub patch_publication_date: Option<String>,
+    /// The date when the vulnerability was first published.
+    #[serde(
+        rename = "vuln_publication_date",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub vuln_publication_date: Option<String>,
+    /// The version of the plugin used to perform the check.
+    #[serde(rename = "version", skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    /// The general type of plugin check, for example `local` or `remote`.
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub _type: Option<String>,
+    /// Information about the Vulnerability Priority Rating (VPR) for the vulnerability.
+    #[serde(rename = "vpr", skip_serializing_if = "Option::is_none")]
+    pub vpr: Option<Vpr>,
+}
+
+/// `Vpr` blueprint
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Vpr {
+    /// The Vulnerability Priority Rating (VPR) for the vulnerability.
+    #[serde(rename = "score", skip_serializing_if = "Option::is_none")]
+    pub score: Option<f64>,
+    /// The ISO timestamp when Tenable.io last imported the VPR for this vulnerability.
+    #[serde(rename = "updated", skip_serializing_if = "Option::is_none")]
+    pub updated: Option<String>,
+}
+
+/// `FindingPort` blueprint
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FindingPort {
+    /// The port the scanner used to communicate with the asset.
+    #[serde(rename = "port", skip_serializing_if = "Option::is_none")]
+    pub port: Option<u16>,
+    /// The protocol the scanner used to communicate with the asset.
+    #[serde(rename = "protocol", skip_serializing_if = "Option::is_none")]
+    pub protocol: Option<String>,
+    /// The service the scanner used to communicate with the asset.
+    #[serde(rename = "service", skip_serializing_if = "Option::is_none")]
+    pub service: Option<String>,
+}
+
+/// `FindingScan` blueprint
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FindingScan {
+    /// The UUID of the scan.
+    #[serde(rename = "uuid", skip_serializing_if = "Option::is_none")]
+    pub uuid: Option<String>,
+    /// The schedule UUID for the scan.
+    #[serde(rename = "schedule_uuid", skip_serializing_if = "Option::is_none")]
+    pub schedule_uuid: Option<String>,
+    /// The ISO date when the scan started.
+    #[serde(rename = "started_at", skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<String>,
+    /// The ISO date when the scan completed.
+    #[serde(rename = "completed_at", skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Copy)]
+/// The severity of a vulnerability as defined using the Common Vulnerability Scoring System (CVSS)
+pub enum Severity {
+    /// The vulnerability is informational only, like a detected service.
+    #[serde(rename = "info")]
+    Info,
+    /// The vulnerability has a CVSS base score between 0.1 and 3.9.
+    #[serde(rename = "low")]
+    Low,
+    /// The vulnerability has a CVSS base score between 4.0 and 6.9.
+    #[serde(rename = "medium")]
+    Medium,
+    /// The vulnerability has a CVSS base score between 7.0 and 9.9.
+    #[serde(rename = "high")]
+    High,
+    /// The vulnerability has a CVSS base score of 10.0.
+    #[serde(rename = "critical")]
+    Critical,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Copy)]
+/// The state of a vulnerability
+pub enum VulnState {
+    /// The vulnerability is currently present on an asset.
+    #[serde(rename = "OPEN")]
+    Open,
+    /// The vulnerability was previously marked as fixed on an asset, but has returned.
+    #[serde(rename = "REOPENED")]
+    Reopened,
+    /// The vulnerability was present on an asset, but is no longer detected.
+    #[serde(rename = "FIXED")]
+    Fixed,
+}
@@ -0,0 +1,115 @@
+mod common;
+
+const ACCESS_GROUP_ID: &str = "3f5a7c9e-1b3d-4f5a-8c7e-9b1d3f5a7c9e";
+
+#[test]
+fn access_groups() {
+    use std::borrow::Cow;
+    use tenable::{
+        requests::AccessGroupReq,
+        types::{
+            AccessGroupDef, AccessGroupPermission, AccessGroupType, AccessGroupsPrincipals,
+            AccessGroupsReq, AccessGroupsRules,
+        },
+        Tenable,
+    };
+
+    let payload = AccessGroupDef {
+        name: "Web servers".into(),
+        rules: Some(vec![AccessGroupsRules {
+            _type: Some("fqdn".into()),
+            operator: Some("ends".into()),
+            terms: Some(vec![".web.example.com".into()]),
+        }]),
+        principals: Some(vec![AccessGroupsPrincipals {
+            _type: Some("group".into()),
+            principal_name: Some("Web team".into()),
+            permissions: Some(vec![
+                AccessGroupPermission::CanView,
+                AccessGroupPermission::CanScan,
+            ]),
+            ..AccessGroupsPrincipals::default()
+        }]),
+        ..AccessGroupDef::default()
+    };
+
+    let server = common::server();
+    let tenable = common::get_tenable(&server);
+    let req = AccessGroupsReq {
+        limit: Some(50),
+        sort: Some("name:asc".into()),
+        ..tenable.access_groups()
+    };
+    let access_groups = Tenable::request(req, common::request)
+        .expect("Unable to list access groups")
+        .access_groups
+        .expect("No access groups returned");
+    assert_eq!(
+        access_groups[0].access_group_type,
+        Some(AccessGroupType::ManageAssets)
+    );
+
+    // test ref
+    let _req = tenable.access_group_create(&payload);
+    // test cow
+    let _req = tenable.access_group_create(Cow::Borrowed(&payload));
+    // test owned
+    let req = tenable.access_group_create(payload.clone());
+    let access_group =
+        Tenable::request(req, common::request).expect("Unable to create access group");
+    assert_eq!(access_group.id.as_deref(), Some(ACCESS_GROUP_ID));
+    assert_eq!(access_group.rules, payload.rules);
+
+    let req = tenable.access_group_by_id(ACCESS_GROUP_ID);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to get access group")
+        .is_some());
+    let req = tenable.access_group_update(ACCESS_GROUP_ID, &payload);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to update access group")
+        .is_some());
+    let req = tenable.access_group_delete(ACCESS_GROUP_ID);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to delete access group")
+        .is_some());
+
+    let requests = server.transport().requests();
+    assert_eq!(requests[0].uri.query(), Some("limit=50&sort=name:asc"));
+    let body: AccessGroupDef = serde_json::from_slice(&requests[1].body).expect("Invalid body");
+    assert_eq!(body, payload);
+
+    // not found
+    let req = tenable.access_group_by_id("notfound");
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to get access group")
+        .is_none());
+    let req = tenable.access_group_update("notfound", &payload);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to update access group")
+        .is_none());
+    let req = tenable.access_group_delete("notfound");
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to delete access group")
+        .is_none());
+}
+
+#[test]
+fn access_group_filters() {
+    use tenable::{requests::AccessGroupReq, Tenable};
+
+    let server = common::server();
+    let tenable = common::get_tenable(&server);
+    let filters = Tenable::request(tenable.access_group_filters(), common::request)
+        .expect("Unable to list filters");
+    assert_eq!(filters.wildcard_fields, Some(vec!["name".to_owned()]));
+
+    let rules = Tenable::request(tenable.access_group_rule_filters(), common::request)
+        .expect("Unable to list rule filters")
+        .rules
+        .expect("No rules returned");
+    let names: Vec<_> = rules
+        .iter()
+        .filter_map(|rule| rule.name.as_deref())
+        .collect();
+    assert_eq!(names, ["fqdn", "ipv4"]);
+}
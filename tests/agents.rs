@@ -0,0 +1,167 @@
+mod common;
+
+const SCANNER_ID: u64 = 1;
+const AGENT_ID: u64 = 42;
+const GROUP_ID: u64 = 7;
+const TASK_UUID: &str = "b2c4d6e8-f0a2-4b4c-9d6e-8f0a2b4c6d8e";
+
+#[test]
+fn agents() {
+    use tenable::{
+        requests::AgentReq,
+        types::{AgentBulkCriteria, AgentBulkDef, AgentBulkTaskState, AgentsReq, QueryFilter},
+        Tenable,
+    };
+
+    let stale = QueryFilter {
+        field: "last_connect".into(),
+        operator: "lt".into(),
+        value: "1590969600".into(),
+    };
+    let payload = AgentBulkDef {
+        criteria: Some(AgentBulkCriteria {
+            filters: Some(vec![stale.clone()]),
+            ..AgentBulkCriteria::default()
+        }),
+        not_items: Some(vec![7]),
+        ..AgentBulkDef::default()
+    };
+
+    let server = common::server();
+    let tenable = common::get_tenable(&server);
+    let req = AgentsReq {
+        filters: Some(vec![stale]),
+        wildcard: Some("web 01".into()),
+        limit: Some(50),
+        ..tenable.agents(SCANNER_ID)
+    };
+    let agents = Tenable::request(req, common::request)
+        .expect("Unable to list agents")
+        .expect("Scanner not found")
+        .agents
+        .expect("No agents returned");
+    assert_eq!(agents[0].id, Some(AGENT_ID));
+
+    let req = tenable.agent_unlink(SCANNER_ID, AGENT_ID);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to unlink agent")
+        .is_some());
+
+    let req = tenable.agents_unlink(SCANNER_ID, &payload);
+    let task = Tenable::request(req, common::request)
+        .expect("Unable to unlink agents")
+        .expect("Scanner not found");
+    assert_eq!(task.task_id.as_deref(), Some(TASK_UUID));
+    let req = tenable.agent_bulk_status(SCANNER_ID, TASK_UUID);
+    let task = Tenable::request(req, common::request)
+        .expect("Unable to fetch bulk operation")
+        .expect("Bulk operation not found");
+    assert_eq!(task.status, AgentBulkTaskState::Completed);
+    assert!(task.status.is_finished());
+
+    let requests = server.transport().requests();
+    assert_eq!(
+        requests[0].uri.query(),
+        Some("f=last_connect:lt:1590969600&w=web%2001&limit=50")
+    );
+    assert_eq!(
+        requests[2].body,
+        br#"{"not_items":[7],"criteria":{"filters":["last_connect:lt:1590969600"]}}"#
+    );
+    let body: AgentBulkDef = serde_json::from_slice(&requests[2].body).expect("Invalid body");
+    assert_eq!(body, payload);
+
+    // not found
+    let req = tenable.agents(404);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to list agents")
+        .is_none());
+    let req = tenable.agent_unlink(SCANNER_ID, 404);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to unlink agent")
+        .is_none());
+    let req = tenable.agent_bulk_status(SCANNER_ID, "notfound");
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to fetch bulk operation")
+        .is_none());
+}
+
+#[test]
+fn agent_groups() {
+    use std::borrow::Cow;
+    use tenable::{requests::AgentReq, types::AgentGroupDef, Tenable};
+
+    let payload = AgentGroupDef {
+        name: "Servers".into(),
+    };
+
+    let server = common::server();
+    let tenable = common::get_tenable(&server);
+    let groups = Tenable::request(tenable.agent_groups(SCANNER_ID), common::request)
+        .expect("Unable to list agent groups")
+        .expect("Scanner not found")
+        .groups
+        .expect("No agent groups returned");
+    assert_eq!(groups[0].id, Some(GROUP_ID));
+
+    // test ref
+    let _req = tenable.agent_group_create(SCANNER_ID, &payload);
+    // test cow
+    let _req = tenable.agent_group_create(SCANNER_ID, Cow::Borrowed(&payload));
+    // test owned
+    let req = tenable.agent_group_create(SCANNER_ID, payload.clone());
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to create agent group")
+        .is_some());
+
+    let req = tenable.agent_group_by_id(SCANNER_ID, GROUP_ID);
+    let group = Tenable::request(req, common::request)
+        .expect("Unable to fetch agent group")
+        .expect("Agent group not found");
+    assert_eq!(group.agents.map(|agents| agents.len()), Some(1));
+    let req = tenable.agent_group_update(SCANNER_ID, GROUP_ID, &payload);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to update agent group")
+        .is_some());
+    let req = tenable.agent_group_add_agent(SCANNER_ID, GROUP_ID, AGENT_ID);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to add agent")
+        .is_some());
+    let req = tenable.agent_group_remove_agent(SCANNER_ID, GROUP_ID, AGENT_ID);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to remove agent")
+        .is_some());
+    let req = tenable.agent_group_bulk_status(SCANNER_ID, GROUP_ID, TASK_UUID);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to fetch bulk operation")
+        .is_some());
+    let req = tenable.agent_group_delete(SCANNER_ID, GROUP_ID);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to delete agent group")
+        .is_some());
+
+    let requests = server.transport().requests();
+    let body: AgentGroupDef = serde_json::from_slice(&requests[1].body).expect("Invalid body");
+    assert_eq!(body, payload);
+    assert_eq!(
+        requests[4].uri.path(),
+        format!(
+            "/scanners/{}/agent-groups/{}/agents/{}",
+            SCANNER_ID, GROUP_ID, AGENT_ID
+        )
+    );
+
+    // not found
+    let req = tenable.agent_group_by_id(SCANNER_ID, 404);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to fetch agent group")
+        .is_none());
+    let req = tenable.agent_group_delete(SCANNER_ID, 404);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to delete agent group")
+        .is_none());
+    let req = tenable.agent_group_add_agent(SCANNER_ID, GROUP_ID, 404);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to add agent")
+        .is_none());
+}
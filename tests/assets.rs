@@ -4,7 +4,8 @@ mod common;
 fn assets() {
     use tenable::{requests::AssetReq, Tenable};
 
-    let tenable = common::get_tenable();
+    let server = common::server();
+    let tenable = common::get_tenable(&server);
     let req = tenable.assets();
     let _assets = Tenable::request(req, common::request).expect("Unable to list all assets");
 }
@@ -14,7 +15,8 @@ fn asset_by_uuid() {
     use std::borrow::Cow;
     use tenable::{requests::AssetReq, Tenable};
 
-    let tenable = common::get_tenable();
+    let server = common::server();
+    let tenable = common::get_tenable(&server);
     // test str
     let _req = tenable.asset_by_uuid(common::ASSET_ID);
     // test string
@@ -37,7 +39,8 @@ fn asset_by_uuid_with_backoff() {
     use std::{borrow::Cow, thread::sleep};
     use tenable::{requests::AssetReq, Tenable};
 
-    let tenable = common::get_tenable();
+    let server = common::server();
+    let tenable = common::get_tenable(&server);
     let req = tenable.asset_by_uuid(common::ASSET_ID);
     assert!(Tenable::request_with_backoff(req, common::request, sleep)
         .expect("Unable to get asset")
@@ -58,7 +61,8 @@ fn asset_by_uuid_async() {
 
     let mut rt = Runtime::new().expect("Unable to create runtime");
     rt.block_on(async {
-        let tenable = common::get_tenable();
+        let server = common::server();
+        let tenable = common::get_tenable(&server);
         let req = tenable.asset_by_uuid(common::ASSET_ID);
         assert!(Tenable::request_async(req, common::request_async)
             .await
@@ -82,7 +86,8 @@ fn asset_by_uuid_with_backoff_async() {
 
     let mut rt = Runtime::new().expect("Unable to create runtime");
     rt.block_on(async {
-        let tenable = common::get_tenable();
+        let server = common::server();
+        let tenable = common::get_tenable(&server);
         let req = tenable.asset_by_uuid(common::ASSET_ID);
         assert!(
             Tenable::request_with_backoff_async(req, common::request_async, delay_for)
@@ -105,20 +110,25 @@ fn asset_by_uuid_with_backoff_async() {
 #[test]
 fn acr_update() {
     use std::borrow::Cow;
-    use tenable::{requests::AssetReq, types::Acr, Error, Tenable};
+    use tenable::{mock::Fault, requests::AssetReq, types::Acr, Error, Tenable};
 
     let borrow: &[Acr] = &[];
 
-    let tenable = common::get_tenable();
+    let server = common::server();
+    let tenable = common::get_tenable(&server);
     // test str
     let _req = tenable.acr_update(Vec::new());
     // test string
     let _req = tenable.acr_update(borrow);
     // test cow
     let req = tenable.acr_update(Cow::Borrowed(borrow));
+    let updated = Tenable::request(&req, common::request).expect("Unable to update acrs");
+    assert_eq!(
+        updated.job_uuid.as_deref(),
+        Some("c1d3e5f7-a9b1-4c3d-8e5f-7a9b1c3d5e7f")
+    );
 
-    // TODO: handle permission
-    // Tenable::request(req, common::request).expect("Unable to update acrs");
+    let _ = server.transport().inject(Fault::Forbidden, 1);
     match Tenable::request(req, common::request) {
         Err(Error::InsufficientPermission) => {}
         res => panic!("Unexpected response: {:?}", res),
@@ -128,7 +138,7 @@ fn acr_update() {
 #[test]
 fn assets_move() {
     use std::borrow::Cow;
-    use tenable::{requests::AssetReq, types::AssetsMoveDef, Error, Tenable};
+    use tenable::{mock::Fault, requests::AssetReq, types::AssetsMoveDef, Error, Tenable};
 
     let payload = AssetsMoveDef {
         source: "not found".into(),
@@ -136,18 +146,239 @@ fn assets_move() {
         targets: "all the things".into(),
     };
 
-    let tenable = common::get_tenable();
+    let server = common::server();
+    let tenable = common::get_tenable(&server);
     // test str
     let _req = tenable.assets_move(&payload);
     // test cow
     let _req = tenable.assets_move(Cow::Borrowed(&payload));
     // test string
     let req = tenable.assets_move(payload);
+    let moved = Tenable::request(&req, common::request)
+        .expect("Unable to move assets")
+        .expect("Network not found");
+    assert_eq!(moved.asset_count, Some(1));
+    assert!(moved.job_uuid.is_some());
 
-    // TODO: handle permission
-    // assert!(Tenable::request(req, common::request).expect("Unable to get asset").is_none());
+    let _ = server.transport().inject(Fault::Forbidden, 1);
     match Tenable::request(req, common::request) {
         Err(Error::InsufficientPermission) => {}
         res => panic!("Unexpected response: {:?}", res),
     }
 }
+
+#[test]
+fn assets_delete() {
+    use std::borrow::Cow;
+    use tenable::{
+        requests::AssetReq,
+        types::{AssetBulkJobState, AssetFilter, AssetQuery, AssetsDeleteDef},
+        Tenable,
+    };
+
+    let payload = AssetsDeleteDef {
+        query: AssetQuery::Or(vec![
+            AssetFilter {
+                field: "host.hostname".into(),
+                operator: "eq".into(),
+                value: "web01.example.com".into(),
+            },
+            AssetFilter {
+                field: "ipv4".into(),
+                operator: "eq".into(),
+                value: "192.168.0.10".into(),
+            },
+        ]),
+        hard_delete: Some(true),
+    };
+
+    let server = common::server();
+    let tenable = common::get_tenable(&server);
+    // test ref
+    let _req = tenable.assets_delete(&payload);
+    // test cow
+    let _req = tenable.assets_delete(Cow::Borrowed(&payload));
+    // test owned
+    let req = tenable.assets_delete(payload.clone());
+    let deleted = Tenable::request(req, common::request).expect("Unable to delete assets");
+    assert_eq!(deleted.asset_count, Some(2));
+    assert_eq!(
+        server.transport().requests()[0].body,
+        br#"{"query":{"or":[{"field":"host.hostname","operator":"eq","value":"web01.example.com"},{"field":"ipv4","operator":"eq","value":"192.168.0.10"}]},"hard_delete":true}"#
+    );
+
+    let job_uuid = deleted.job_uuid.expect("No job returned");
+    let req = tenable.asset_bulk_job(&job_uuid);
+    let job = Tenable::request(req, common::request)
+        .expect("Unable to fetch job")
+        .expect("Job not found");
+    assert_eq!(job.job_uuid.as_ref(), Some(&job_uuid));
+    assert_eq!(job.status, AssetBulkJobState::Completed);
+    assert!(job.status.is_finished());
+    assert!(!AssetBulkJobState::Processing.is_finished());
+
+    let req = tenable.asset_bulk_job("notfound");
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to fetch job")
+        .is_none());
+}
+
+#[test]
+fn asset_import() {
+    use std::borrow::Cow;
+    use tenable::{
+        requests::AssetReq,
+        types::{ImportAsset, ASSET_IMPORT_BATCH_SIZE},
+        Tenable,
+    };
+
+    let asset = ImportAsset {
+        fqdn: Some(vec!["web01.example.com".into()]),
+        ipv4: Some(vec!["192.168.0.10".into()]),
+        servicenow_sysid: Some("9d385017c611228701d22104cc95c371".into()),
+        ..ImportAsset::default()
+    };
+    let assets = vec![asset; ASSET_IMPORT_BATCH_SIZE * 2 + 1];
+
+    let server = common::server();
+    let tenable = common::get_tenable(&server);
+    // test ref
+    let batches = tenable.asset_import("discovery", &assets[..]);
+    let sizes: Vec<_> = batches.iter().map(|req| req.assets.len()).collect();
+    assert_eq!(sizes, [ASSET_IMPORT_BATCH_SIZE, ASSET_IMPORT_BATCH_SIZE, 1]);
+    // test cow
+    let _batches = tenable.asset_import("discovery", Cow::Borrowed(&assets[..]));
+    // test owned
+    let batches = tenable.asset_import("discovery", assets.clone());
+    let sizes: Vec<_> = batches.iter().map(|req| req.assets.len()).collect();
+    assert_eq!(sizes, [ASSET_IMPORT_BATCH_SIZE, ASSET_IMPORT_BATCH_SIZE, 1]);
+    let batches = tenable.asset_import("discovery", assets[..1].to_vec());
+    assert_eq!(batches.len(), 1);
+    assert!(tenable.asset_import("discovery", Vec::new()).is_empty());
+
+    for req in batches {
+        let job = Tenable::request(req, common::request).expect("Unable to import assets");
+        assert_eq!(
+            job.asset_import_job_uuid,
+            "f4a6b8c0-d2e4-4f6a-8b8c-0d2e4f6a8b0c"
+        );
+    }
+    assert_eq!(
+        server.transport().requests()[0].body,
+        &br#"{"source":"discovery","assets":[{"fqdn":["web01.example.com"],"ipv4":["192.168.0.10"],"servicenow_sysid":"9d385017c611228701d22104cc95c371"}]}"#[..]
+    );
+
+    let jobs = Tenable::request(tenable.asset_import_jobs(), common::request)
+        .expect("Unable to list import jobs")
+        .asset_import_jobs
+        .expect("No import jobs returned");
+    let job_id = jobs[0].job_id.as_deref().expect("No job id returned");
+    let req = tenable.asset_import_job(job_id);
+    let job = Tenable::request(req, common::request)
+        .expect("Unable to fetch import job")
+        .expect("Import job not found");
+    assert_eq!(job.uploaded_assets, Some(2));
+    let _imports =
+        Tenable::request(tenable.asset_imports(), common::request).expect("Unable to list imports");
+
+    let req = tenable.asset_import_job("notfound");
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to fetch import job")
+        .is_none());
+}
+
+#[test]
+fn assets_export() {
+    use std::borrow::Cow;
+    use tenable::{
+        requests::AssetReq,
+        types::{AssetsExportDef, AssetsExportFilters, TagFilters},
+        Tenable,
+    };
+
+    let mut tags = TagFilters::default();
+    let _ = tags
+        .0
+        .insert("Location".into(), vec!["Headquarters".into()]);
+    let payload = AssetsExportDef {
+        chunk_size: 100,
+        filters: Some(AssetsExportFilters {
+            has_plugin_results: Some(true),
+            tags,
+            ..AssetsExportFilters::default()
+        }),
+    };
+
+    // the default is accepted by the API
+    assert_eq!(AssetsExportDef::default().chunk_size, 1000);
+
+    let server = common::server();
+    let tenable = common::get_tenable(&server);
+    // test ref
+    let _req = tenable.assets_export(&payload);
+    // test cow
+    let _req = tenable.assets_export(Cow::Borrowed(&payload));
+    // test owned
+    let req = tenable.assets_export(payload);
+    let export = Tenable::request(req, common::request).expect("Unable to export assets");
+
+    let req = tenable.assets_export_status(export.export_uuid.as_str());
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to get export status")
+        .is_some());
+
+    let req = tenable.assets_export_cancel(export.export_uuid.as_str());
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to cancel export")
+        .is_some());
+}
+
+#[test]
+fn assets_export_not_found() {
+    use tenable::{requests::AssetReq, Tenable};
+
+    let server = common::server();
+    let tenable = common::get_tenable(&server);
+    let req = tenable.assets_export_status("notfound");
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to get export status")
+        .is_none());
+
+    let req = tenable.assets_export_chunk("notfound", 1);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to get export chunk")
+        .is_none());
+}
+
+#[test]
+fn asset_import_payload_size() {
+    use std::convert::Infallible;
+    use tenable::{
+        requests::AssetReq,
+        types::{ImportAsset, ASSET_IMPORT_BATCH_BYTES},
+        HttpRequest, Tenable,
+    };
+
+    // each asset takes up about 40% of the payload limit
+    let asset = ImportAsset {
+        ipv4: Some(vec!["192.168.0.10".into()]),
+        installed_software: Some(vec![
+            "cpe:/a:example:app".into();
+            ASSET_IMPORT_BATCH_BYTES / 50
+        ]),
+        ..ImportAsset::default()
+    };
+    let assets = vec![asset; 5];
+
+    let tenable = Tenable::new("", "");
+    let batches = tenable.asset_import("discovery", &assets[..]);
+    let sizes: Vec<_> = batches.iter().map(|req| req.assets.len()).collect();
+    assert_eq!(sizes, [2, 2, 1]);
+    for req in &batches {
+        let req = HttpRequest::<Infallible>::to_request(req).expect("Unable to build request");
+        assert!(req.body().len() <= ASSET_IMPORT_BATCH_BYTES);
+    }
+    let batches = tenable.asset_import("discovery", assets);
+    let sizes: Vec<_> = batches.iter().map(|req| req.assets.len()).collect();
+    assert_eq!(sizes, [2, 2, 1]);
+}
@@ -0,0 +1,347 @@
+use bytes::Bytes;
+use http::{header::RETRY_AFTER, HeaderMap, HeaderValue, Request, StatusCode};
+use std::{
+    cell::{Cell, RefCell},
+    convert::Infallible,
+    time::Duration,
+};
+use tenable::{
+    backoff::{Backoff, Exponential, Linear, RetryAfter},
+    requests::AssetReq,
+    Error, Response, Tenable,
+};
+
+/// Answers with `429 Too Many Requests` until `limit` requests have been sent
+fn rate_limited(
+    calls: &Cell<u32>,
+    limit: u32,
+    retry_after: Option<&'static str>,
+) -> Result<Response, Error<Infallible>> {
+    calls.set(calls.get() + 1);
+    let mut headers = HeaderMap::new();
+    if calls.get() < limit {
+        if let Some(retry_after) = retry_after {
+            let _ = headers.insert(RETRY_AFTER, HeaderValue::from_static(retry_after));
+        }
+        return Ok(Response {
+            status: StatusCode::TOO_MANY_REQUESTS,
+            headers,
+            body: Bytes::new(),
+        });
+    }
+    Ok(Response {
+        status: StatusCode::OK,
+        headers,
+        body: Bytes::from_static(br#"{"assets":[],"total":0}"#),
+    })
+}
+
+#[test]
+fn request_with_backoff() {
+    let tenable = Tenable::new("", "");
+    let calls = Cell::new(0);
+    let waits = RefCell::new(Vec::new());
+    let req = tenable.assets();
+    let _assets = Tenable::request_with_backoff(
+        req,
+        |_: Request<Vec<u8>>| rate_limited(&calls, 4, Some("10")),
+        |wait| waits.borrow_mut().push(wait),
+    )
+    .expect("Unable to list all assets");
+    // the default ignores `Retry-After`
+    assert_eq!(
+        *waits.borrow(),
+        vec![
+            Duration::from_millis(100),
+            Duration::from_millis(200),
+            Duration::from_millis(300)
+        ]
+    );
+}
+
+#[test]
+fn request_with_policy_retry_after() {
+    let tenable = Tenable::new("", "");
+    let calls = Cell::new(0);
+    let waits = RefCell::new(Vec::new());
+    let req = tenable.assets();
+    let backoff = Backoff::new(RetryAfter {
+        fallback: Linear::default(),
+    });
+    let _assets = Tenable::request_with_policy(
+        req,
+        |_: Request<Vec<u8>>| rate_limited(&calls, 3, Some("7")),
+        |wait| waits.borrow_mut().push(wait),
+        backoff,
+    )
+    .expect("Unable to list all assets");
+    assert_eq!(
+        *waits.borrow(),
+        vec![Duration::from_secs(7), Duration::from_secs(7)]
+    );
+}
+
+#[test]
+fn request_with_policy_exponential() {
+    let tenable = Tenable::new("", "");
+    let calls = Cell::new(0);
+    let waits = RefCell::new(Vec::new());
+    let req = tenable.assets();
+    let backoff = Backoff::new(Exponential::new(
+        Duration::from_millis(100),
+        Duration::from_millis(500),
+        false,
+    ));
+    let _assets = Tenable::request_with_policy(
+        &req,
+        |_: Request<Vec<u8>>| rate_limited(&calls, 5, None),
+        |wait| waits.borrow_mut().push(wait),
+        backoff,
+    )
+    .expect("Unable to list all assets");
+    assert_eq!(
+        *waits.borrow(),
+        vec![
+            Duration::from_millis(100),
+            Duration::from_millis(200),
+            Duration::from_millis(400),
+            Duration::from_millis(500)
+        ]
+    );
+
+    // jitter never exceeds the calculated wait time
+    let calls = Cell::new(0);
+    let waits = RefCell::new(Vec::new());
+    let backoff = Backoff::new(Exponential::new(
+        Duration::from_millis(100),
+        Duration::from_millis(500),
+        true,
+    ));
+    let _assets = Tenable::request_with_policy(
+        &req,
+        |_: Request<Vec<u8>>| rate_limited(&calls, 5, None),
+        |wait| waits.borrow_mut().push(wait),
+        backoff,
+    )
+    .expect("Unable to list all assets");
+    assert!(waits
+        .borrow()
+        .iter()
+        .zip(&[100, 200, 400, 500])
+        .all(|(wait, max)| *wait <= Duration::from_millis(*max)));
+}
+
+#[test]
+fn request_with_policy_limits() {
+    let tenable = Tenable::new("", "");
+    let req = tenable.assets();
+
+    let calls = Cell::new(0);
+    let backoff = Backoff::new(Linear::default()).max_attempts(3);
+    match Tenable::request_with_policy(
+        &req,
+        |_: Request<Vec<u8>>| rate_limited(&calls, 10, None),
+        |_| {},
+        backoff,
+    ) {
+        Err(Error::MaximumAttemptsReached) => {}
+        res => panic!("Unexpected response: {:?}", res),
+    }
+    assert_eq!(calls.get(), 3);
+
+    let calls = Cell::new(0);
+    let backoff = Backoff::new(Linear::default()).max_elapsed(Duration::from_millis(350));
+    match Tenable::request_with_policy(
+        &req,
+        |_: Request<Vec<u8>>| rate_limited(&calls, 10, None),
+        |_| {},
+        backoff,
+    ) {
+        Err(Error::MaximumWaitTimeReached) => {}
+        res => panic!("Unexpected response: {:?}", res),
+    }
+    // waited 100ms and 200ms, another 300ms would exceed the limit
+    assert_eq!(calls.get(), 3);
+}
+
+#[test]
+fn request_with_policy_async() {
+    use std::future::ready;
+    use tokio::runtime::Runtime;
+
+    let mut rt = Runtime::new().expect("Unable to create runtime");
+    rt.block_on(async {
+        let tenable = Tenable::new("", "");
+        let calls = Cell::new(0);
+        let waits = RefCell::new(Vec::new());
+        let req = tenable.assets();
+        let backoff = Backoff::new(RetryAfter {
+            fallback: Linear::default(),
+        })
+        .max_attempts(5);
+        let _assets = Tenable::request_with_policy_async(
+            req,
+            |_: Request<Vec<u8>>| ready(rate_limited(&calls, 3, Some("1"))),
+            |wait| {
+                waits.borrow_mut().push(wait);
+                ready(())
+            },
+            backoff,
+        )
+        .await
+        .expect("Unable to list all assets");
+        assert_eq!(
+            *waits.borrow(),
+            vec![Duration::from_secs(1), Duration::from_secs(1)]
+        );
+    });
+}
+
+#[test]
+fn retry_after_header() {
+    use std::time::{SystemTime, UNIX_EPOCH};
+    use tenable::backoff::retry_after;
+
+    let mut headers = HeaderMap::new();
+    assert_eq!(retry_after(&headers), None);
+
+    let _ = headers.insert(RETRY_AFTER, HeaderValue::from_static("120"));
+    assert_eq!(retry_after(&headers), Some(Duration::from_secs(120)));
+
+    let _ = headers.insert(
+        RETRY_AFTER,
+        HeaderValue::from_static("Sun, 06 Nov 1994 08:49:37 GMT"),
+    );
+    assert_eq!(retry_after(&headers), Some(Duration::from_secs(0)));
+
+    let _ = headers.insert(
+        RETRY_AFTER,
+        HeaderValue::from_static("Fri, 01 Jan 2100 00:00:00 GMT"),
+    );
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("Clock before unix epoch");
+    let wait = retry_after(&headers).expect("Unable to parse date");
+    let expected = Duration::from_secs(4_102_444_800) - now;
+    assert!(wait <= expected && expected - wait < Duration::from_secs(5));
+
+    let _ = headers.insert(
+        RETRY_AFTER,
+        HeaderValue::from_static("Sun, 06 Nov 99999999999999 08:49:37 GMT"),
+    );
+    assert_eq!(retry_after(&headers), None);
+
+    let _ = headers.insert(RETRY_AFTER, HeaderValue::from_static("soon"));
+    assert_eq!(retry_after(&headers), None);
+}
+
+/// Fails with the given error until `limit` requests have been sent
+fn failing(
+    calls: &Cell<u32>,
+    limit: u32,
+    err: fn() -> Result<Response, Error<&'static str>>,
+) -> Result<Response, Error<&'static str>> {
+    calls.set(calls.get() + 1);
+    if calls.get() < limit {
+        return err();
+    }
+    Ok(Response {
+        status: StatusCode::OK,
+        headers: HeaderMap::new(),
+        body: Bytes::from_static(br#"{"assets":[],"total":0}"#),
+    })
+}
+
+fn service_unavailable() -> Result<Response, Error<&'static str>> {
+    Ok(Response {
+        status: StatusCode::SERVICE_UNAVAILABLE,
+        headers: HeaderMap::new(),
+        body: Bytes::new(),
+    })
+}
+
+#[test]
+fn request_with_policy_transient() {
+    let tenable = Tenable::new("", "");
+    let req = tenable.assets();
+
+    // only rate limits are retried by default
+    let calls = Cell::new(0);
+    match Tenable::request_with_policy(
+        &req,
+        |_: Request<Vec<u8>>| failing(&calls, 3, service_unavailable),
+        |_| {},
+        Backoff::new(Linear::default()),
+    ) {
+        Err(Error::Api {
+            status: StatusCode::SERVICE_UNAVAILABLE,
+            ..
+        }) => {}
+        res => panic!("Unexpected response: {:?}", res),
+    }
+    assert_eq!(calls.get(), 1);
+
+    let calls = Cell::new(0);
+    let _assets = Tenable::request_with_policy(
+        &req,
+        |_: Request<Vec<u8>>| failing(&calls, 3, service_unavailable),
+        |_| {},
+        Backoff::new(Linear::default()).retry_idempotent(true),
+    )
+    .expect("Unable to list all assets");
+    assert_eq!(calls.get(), 3);
+
+    let calls = Cell::new(0);
+    let _assets = Tenable::request_with_policy(
+        &req,
+        |_: Request<Vec<u8>>| failing(&calls, 3, || Err(Error::TransientRequest("reset"))),
+        |_| {},
+        Backoff::new(Linear::default()).retry_idempotent(true),
+    )
+    .expect("Unable to list all assets");
+    assert_eq!(calls.get(), 3);
+
+    // errors which are not flagged as transient are returned right away
+    let calls = Cell::new(0);
+    match Tenable::request_with_policy(
+        &req,
+        |_: Request<Vec<u8>>| failing(&calls, 3, || Err(Error::Request("refused"))),
+        |_| {},
+        Backoff::new(Linear::default()).retry_idempotent(true),
+    ) {
+        Err(Error::Request("refused")) => {}
+        res => panic!("Unexpected response: {:?}", res),
+    }
+    assert_eq!(calls.get(), 1);
+}
+
+#[test]
+fn request_with_policy_non_idempotent() {
+    let tenable = Tenable::new("", "");
+    let req = tenable.acr_update(Vec::new());
+
+    let calls = Cell::new(0);
+    match Tenable::request_with_policy(
+        &req,
+        |_: Request<Vec<u8>>| failing(&calls, 3, service_unavailable),
+        |_| {},
+        Backoff::new(Linear::default()).retry_idempotent(true),
+    ) {
+        Err(Error::Api {
+            status: StatusCode::SERVICE_UNAVAILABLE,
+            ..
+        }) => {}
+        res => panic!("Unexpected response: {:?}", res),
+    }
+    assert_eq!(calls.get(), 1);
+
+    let calls = Cell::new(0);
+    Tenable::request_with_policy(
+        &req,
+        |_: Request<Vec<u8>>| failing(&calls, 3, service_unavailable),
+        |_| {},
+        Backoff::new(Linear::default()).retry_non_idempotent(true),
+    )
+    .expect("Unable to update acrs");
+    assert_eq!(calls.get(), 3);
+}
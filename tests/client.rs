@@ -0,0 +1,93 @@
+use bytes::Bytes;
+use http::{HeaderMap, Request, StatusCode};
+use std::{cell::Cell, convert::Infallible, future::Future, pin::Pin};
+use tenable::{
+    backoff::{Backoff, Linear},
+    requests::AssetReq,
+    transport::{AsyncTransport, Transport},
+    Error, Response, Tenable, TenableClient,
+};
+
+fn assets() -> Response {
+    Response {
+        status: StatusCode::OK,
+        headers: HeaderMap::new(),
+        body: Bytes::from_static(br#"{"assets":[],"total":0}"#),
+    }
+}
+
+/// Transport which keeps track of the requests sent
+#[derive(Debug, Default)]
+struct Counting {
+    calls: Cell<u32>,
+}
+
+impl Transport<Infallible> for Counting {
+    fn send(&self, req: Request<Vec<u8>>) -> Result<Response, Error<Infallible>> {
+        assert_eq!(req.uri(), "https://cloud.tenable.com/assets");
+        self.calls.set(self.calls.get() + 1);
+        Ok(assets())
+    }
+}
+
+impl AsyncTransport<Infallible> for Counting {
+    type Future = Pin<Box<dyn Future<Output = Result<Response, Error<Infallible>>>>>;
+
+    fn send(&self, req: Request<Vec<u8>>) -> Self::Future {
+        let res = Transport::send(self, req);
+        Box::pin(async move { res })
+    }
+}
+
+#[test]
+fn send() {
+    let client = TenableClient::new(Tenable::new("", ""), Counting::default());
+    let req = client.tenable.assets();
+    let _assets = client.send(&req).expect("Unable to list all assets");
+    let _assets = client.send(req).expect("Unable to list all assets");
+    assert_eq!(client.transport.calls.get(), 2);
+}
+
+#[test]
+fn send_with_policy() {
+    let calls = Cell::new(0);
+    let client = TenableClient::new(Tenable::new("", ""), |_: Request<Vec<u8>>| {
+        calls.set(calls.get() + 1);
+        if calls.get() < 3 {
+            return Err(Error::TransientRequest("reset"));
+        }
+        Ok(assets())
+    });
+    let req = client.tenable.assets();
+    let _assets = client
+        .send_with_policy(
+            req,
+            |_| {},
+            Backoff::new(Linear::default()).retry_idempotent(true),
+        )
+        .expect("Unable to list all assets");
+    assert_eq!(calls.get(), 3);
+}
+
+#[test]
+fn send_async() {
+    let mut rt = tokio::runtime::Runtime::new().expect("Unable to create runtime");
+    rt.block_on(async {
+        let client = TenableClient::new(Tenable::new("", ""), Counting::default());
+        let req = client.tenable.assets();
+        let _assets = client
+            .send_async(req)
+            .await
+            .expect("Unable to list all assets");
+        assert_eq!(client.transport.calls.get(), 1);
+
+        let client = TenableClient::new(Tenable::new("", ""), |_: Request<Vec<u8>>| async {
+            Result::<_, Error<Infallible>>::Ok(assets())
+        });
+        let req = client.tenable.assets();
+        let _assets = client
+            .send_async(req)
+            .await
+            .expect("Unable to list all assets");
+    });
+}
@@ -1,40 +1,117 @@
-use http::Request;
-use reqwest::{blocking::Client as BClient, Client as AClient};
-use tenable::{Error, Response, Tenable};
+#![allow(dead_code)]
 
-pub const ACCESS_KEY: &str = "";
-pub const SECRET_KEY: &str = "";
-pub const ASSET_ID: &str = "";
+use http::{Method, Request, StatusCode};
+use tenable::{
+    mock::{MockServer, MockTransport},
+    transport::{ReqwestAsync, ReqwestBlocking},
+    Error, Response, Tenable,
+};
 
-pub fn get_tenable() -> Tenable<'static> {
-    Tenable::new(ACCESS_KEY, SECRET_KEY)
+/// Id of the asset served by the mock
+pub const ASSET_ID: &str = "0d3d9e7c-1b5a-4b8e-9f3a-6c2f2d1e4a10";
+
+/// Id of the scan served by the mock
+pub const SCAN_ID: &str = "42";
+
+/// Starts a local stand-in for Tenable.io which does not know the id `notfound`
+pub fn server() -> MockServer {
+    let transport = MockTransport::new();
+    for path in &[
+        "/assets/notfound",
+        "/assets/export/notfound/status",
+        "/assets/export/notfound/chunks/{chunk_id}",
+        "/vulns/export/notfound/status",
+        "/vulns/export/notfound/chunks/{chunk_id}",
+    ] {
+        let _ = transport.route(Method::GET, path, StatusCode::NOT_FOUND, "");
+    }
+    for (method, path) in &[
+        (Method::GET, "/scans/notfound"),
+        (Method::PUT, "/scans/notfound"),
+        (Method::DELETE, "/scans/notfound"),
+        (Method::POST, "/scans/notfound/{action}"),
+        (Method::GET, "/scans/notfound/latest-status"),
+        (Method::GET, "/scans/{scan_id}/export/notfound/{action}"),
+        (Method::GET, "/scans/notfound/history"),
+        (Method::GET, "/scans/{scan_id}/history/notfound"),
+        (Method::GET, "/scans/notfound/hosts/{host_id}"),
+        (
+            Method::GET,
+            "/scans/notfound/hosts/{host_id}/plugins/{plugin_id}",
+        ),
+        (Method::GET, "/tags/categories/notfound"),
+        (Method::PUT, "/tags/categories/notfound"),
+        (Method::DELETE, "/tags/categories/notfound"),
+        (Method::GET, "/tags/values/notfound"),
+        (Method::PUT, "/tags/values/notfound"),
+        (Method::DELETE, "/tags/values/notfound"),
+        (Method::GET, "/v2/access-groups/notfound"),
+        (Method::PUT, "/v2/access-groups/notfound"),
+        (Method::DELETE, "/v2/access-groups/notfound"),
+        (Method::GET, "/networks/notfound"),
+        (Method::PUT, "/networks/notfound"),
+        (Method::DELETE, "/networks/notfound"),
+        (
+            Method::GET,
+            "/networks/notfound/counts/assets-not-seen-in/{days}",
+        ),
+        (Method::POST, "/networks/notfound/scanners/{scanner_uuid}"),
+        (Method::GET, "/networks/notfound/scanners"),
+        (Method::GET, "/api/v2/assets/bulk-jobs/notfound"),
+        (Method::GET, "/import/asset-jobs/notfound"),
+        (Method::GET, "/scanners/404/agents"),
+        (Method::DELETE, "/scanners/{scanner_id}/agents/404"),
+        (Method::GET, "/scanners/{scanner_id}/agents/_bulk/notfound"),
+        (Method::GET, "/scanners/{scanner_id}/agent-groups/404"),
+        (Method::DELETE, "/scanners/{scanner_id}/agent-groups/404"),
+        (
+            Method::PUT,
+            "/scanners/{scanner_id}/agent-groups/{group_id}/agents/404",
+        ),
+        (Method::GET, "/scanners/404"),
+        (Method::PUT, "/scanners/404/link"),
+        (Method::DELETE, "/scanners/404"),
+        (Method::GET, "/scanners/404/scans"),
+        (Method::GET, "/scanner-groups/404"),
+        (Method::DELETE, "/scanner-groups/404"),
+        (Method::POST, "/scanner-groups/{group_id}/scanners/404"),
+        (Method::GET, "/editor/{editor_type}/templates/notfound"),
+        (Method::GET, "/editor/policy/404"),
+        (Method::GET, "/policies/404"),
+        (Method::PUT, "/policies/404"),
+        (Method::DELETE, "/policies/404"),
+        (Method::POST, "/policies/404/copy"),
+        (Method::GET, "/policies/404/export"),
+        (Method::GET, "/users/404"),
+        (Method::PUT, "/users/404"),
+        (Method::DELETE, "/users/404"),
+        (Method::PUT, "/users/404/enabled"),
+        (Method::PUT, "/users/404/keys"),
+        (Method::GET, "/users/404/authorizations"),
+        (Method::PUT, "/groups/404"),
+        (Method::DELETE, "/groups/404"),
+        (Method::GET, "/groups/404/users"),
+        (Method::POST, "/groups/{group_id}/users/404"),
+        (Method::GET, "/credentials/notfound"),
+        (Method::PUT, "/credentials/notfound"),
+        (Method::DELETE, "/credentials/notfound"),
+    ] {
+        let _ = transport.route(method.clone(), path, StatusCode::NOT_FOUND, "");
+    }
+    MockServer::start(transport).expect("Unable to start mock server")
+}
+
+pub fn get_tenable(server: &MockServer) -> Tenable<'static> {
+    Tenable {
+        uri: server.uri().into(),
+        ..Tenable::new("access", "secret")
+    }
 }
 
 pub fn request(req: Request<Vec<u8>>) -> Result<Response, Error<reqwest::Error>> {
-    let (req, body) = req.into_parts();
-    let res = BClient::new()
-        .request(req.method, &req.uri.to_string())
-        .headers(req.headers)
-        .body(body)
-        .send()
-        .map_err(Error::Request)?;
-    Ok(Response {
-        status: res.status(),
-        body: res.bytes().map_err(Error::Request)?,
-    })
+    ReqwestBlocking::default().send(req)
 }
 
 pub async fn request_async(req: Request<Vec<u8>>) -> Result<Response, Error<reqwest::Error>> {
-    let (req, body) = req.into_parts();
-    let res = AClient::new()
-        .request(req.method, &req.uri.to_string())
-        .headers(req.headers)
-        .body(body)
-        .send()
-        .await
-        .map_err(Error::Request)?;
-    Ok(Response {
-        status: res.status(),
-        body: res.bytes().await.map_err(Error::Request)?,
-    })
+    ReqwestAsync::default().send(req).await
 }
@@ -0,0 +1,126 @@
+use bytes::Bytes;
+use http::{HeaderMap, HeaderValue, Request, StatusCode};
+use std::convert::Infallible;
+use tenable::{requests::AssetReq, Error, Response, Tenable};
+
+/// Answers with the given status code and a Tenable.io error body
+fn error_response(status: StatusCode, body: &'static str) -> Result<Response, Error<Infallible>> {
+    let mut headers = HeaderMap::new();
+    let _ = headers.insert(
+        "X-Request-Uuid",
+        HeaderValue::from_static("3c1c5a6d9a3e4b0e8f2b7f1d2c4e6a80"),
+    );
+    Ok(Response {
+        status,
+        headers,
+        body: Bytes::from_static(body.as_bytes()),
+    })
+}
+
+#[test]
+fn bad_request() {
+    let tenable = Tenable::new("", "");
+    let req = tenable.assets();
+    match Tenable::request(req, |_: Request<Vec<u8>>| {
+        error_response(
+            StatusCode::BAD_REQUEST,
+            r#"{"statusCode":400,"error":"Bad Request","message":"Invalid filter: tag.Location"}"#,
+        )
+    }) {
+        Err(Error::BadRequest {
+            message,
+            request_id,
+        }) => {
+            assert_eq!(message.as_deref(), Some("Invalid filter: tag.Location"));
+            assert_eq!(
+                request_id.as_deref(),
+                Some("3c1c5a6d9a3e4b0e8f2b7f1d2c4e6a80")
+            );
+        }
+        res => panic!("Unexpected response: {:?}", res),
+    }
+}
+
+#[test]
+fn unauthorized() {
+    let tenable = Tenable::new("", "");
+    let req = tenable.assets();
+    match Tenable::request(req, |_: Request<Vec<u8>>| {
+        error_response(
+            StatusCode::UNAUTHORIZED,
+            r#"{"statusCode":401,"error":"Invalid Credentials"}"#,
+        )
+    }) {
+        Err(Error::Unauthorized { message, .. }) => {
+            assert_eq!(message.as_deref(), Some("Invalid Credentials"));
+        }
+        res => panic!("Unexpected response: {:?}", res),
+    }
+}
+
+#[test]
+fn conflict() {
+    let tenable = Tenable::new("", "");
+    let req = tenable.assets_export_cancel("4b0e8f2b-7f1d-2c4e-6a80-3c1c5a6d9a3e");
+    match Tenable::request(req, |_: Request<Vec<u8>>| {
+        error_response(
+            StatusCode::CONFLICT,
+            r#"{"statusCode":409,"error":"Conflict","message":"Export has already finished"}"#,
+        )
+    }) {
+        Err(Error::Conflict { message, .. }) => {
+            assert_eq!(message.as_deref(), Some("Export has already finished"));
+        }
+        res => panic!("Unexpected response: {:?}", res),
+    }
+}
+
+#[test]
+fn api() {
+    let tenable = Tenable::new("", "");
+    let req = tenable.assets();
+    match Tenable::request(&req, |_: Request<Vec<u8>>| {
+        error_response(StatusCode::INTERNAL_SERVER_ERROR, "<html>Oops</html>")
+    }) {
+        Err(err @ Error::Api { .. }) => {
+            assert!(err.is_retryable());
+            match err {
+                Error::Api {
+                    status,
+                    message,
+                    body,
+                    ..
+                } => {
+                    assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+                    assert_eq!(message, None);
+                    assert_eq!(body, Bytes::from_static(b"<html>Oops</html>"));
+                }
+                err => panic!("Unexpected error: {:?}", err),
+            }
+        }
+        res => panic!("Unexpected response: {:?}", res),
+    }
+
+    // responses of requests without optional output keep the status code
+    match Tenable::request(&req, |_: Request<Vec<u8>>| {
+        error_response(StatusCode::NOT_FOUND, r#"{"error":"Not Found"}"#)
+    }) {
+        Err(Error::Api {
+            status: StatusCode::NOT_FOUND,
+            message: Some(message),
+            ..
+        }) => assert_eq!(message, "Not Found"),
+        res => panic!("Unexpected response: {:?}", res),
+    }
+}
+
+#[test]
+fn unexpected_status_code() {
+    let err = Error::<Infallible>::UnexpectedStatusCode(StatusCode::SERVICE_UNAVAILABLE);
+    assert!(err.is_retryable());
+    assert_eq!(
+        err.to_string(),
+        "API returned unexpected status code: 503 Service Unavailable."
+    );
+    assert!(!Error::<Infallible>::UnexpectedStatusCode(StatusCode::IM_A_TEAPOT).is_retryable());
+}
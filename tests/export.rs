@@ -0,0 +1,199 @@
+use bytes::Bytes;
+use http::{HeaderMap, Method, Request, StatusCode};
+use std::{cell::Cell, convert::Infallible, time::Duration};
+use tenable::{Error, Response, Tenable};
+
+/// Serves an export which finishes after the second status request
+fn export_server(
+    status_calls: &Cell<u32>,
+    req: &Request<Vec<u8>>,
+) -> Result<Response, Error<Infallible>> {
+    let path = req.uri().path();
+    let body = if req.method() == Method::POST && path.ends_with("/export") {
+        r#"{"export_uuid":"1234"}"#.to_string()
+    } else if path.ends_with("/1234/status") {
+        status_calls.set(status_calls.get() + 1);
+        match status_calls.get() {
+            1 => r#"{"status":"QUEUED","chunks_available":[]}"#.to_string(),
+            2 => r#"{"status":"PROCESSING","chunks_available":[1]}"#.to_string(),
+            _ => r#"{"status":"FINISHED","chunks_available":[1,2]}"#.to_string(),
+        }
+    } else if path.contains("/1234/chunks/") {
+        let chunk = path.rsplit('/').next().unwrap_or_default();
+        if path.starts_with("/assets") {
+            format!(r#"[{{"id":"asset-{}"}}]"#, chunk)
+        } else {
+            format!(
+                r#"[{{"asset":{{"uuid":"asset-{}"}},"severity":"high"}}]"#,
+                chunk
+            )
+        }
+    } else {
+        return Ok(Response {
+            status: StatusCode::NOT_FOUND,
+            headers: HeaderMap::new(),
+            body: Bytes::new(),
+        });
+    };
+    Ok(Response {
+        status: StatusCode::OK,
+        headers: HeaderMap::new(),
+        body: Bytes::from(body),
+    })
+}
+
+#[test]
+fn export_assets() {
+    use tenable::{requests::AssetReq, types::AssetsExportDef};
+
+    let tenable = Tenable {
+        uri: "".into(),
+        ..Tenable::new("", "")
+    };
+    let status_calls = Cell::new(0);
+    let waits = Cell::new(0);
+    let req = tenable.assets_export(AssetsExportDef {
+        chunk_size: 100,
+        ..AssetsExportDef::default()
+    });
+    let chunks: Vec<_> = Tenable::export(
+        req,
+        |req| export_server(&status_calls, &req),
+        |_| waits.set(waits.get() + 1),
+        Duration::from_secs(1),
+    )
+    .collect::<Result<_, _>>()
+    .expect("Unable to export assets");
+
+    let ids: Vec<_> = chunks
+        .iter()
+        .flatten()
+        .map(|asset| asset.id.clone().expect("Asset without id"))
+        .collect();
+    assert_eq!(ids, vec!["asset-1", "asset-2"]);
+    assert_eq!(status_calls.get(), 3);
+    // only waits while no chunk is available
+    assert_eq!(waits.get(), 1);
+}
+
+#[test]
+fn export_failed() {
+    use tenable::{
+        requests::AssetReq,
+        types::{AssetsExportDef, ExportState},
+    };
+
+    let tenable = Tenable {
+        uri: "".into(),
+        ..Tenable::new("", "")
+    };
+    let req = tenable.assets_export(AssetsExportDef::default());
+    let mut chunks = Tenable::export(
+        req,
+        |req: Request<Vec<u8>>| {
+            let body = if req.method() == Method::POST {
+                r#"{"export_uuid":"1234"}"#
+            } else {
+                r#"{"status":"ERROR"}"#
+            };
+            Result::<_, Error<Infallible>>::Ok(Response {
+                status: StatusCode::OK,
+                headers: HeaderMap::new(),
+                body: Bytes::from(body),
+            })
+        },
+        |_| {},
+        Duration::from_secs(1),
+    );
+    match chunks.next() {
+        Some(Err(Error::ExportFailed(ExportState::Error))) => {}
+        res => panic!("Unexpected response: {:?}", res),
+    }
+    assert_eq!(chunks.export_uuid(), Some("1234"));
+    assert!(chunks.next().is_none());
+}
+
+#[test]
+fn export_cancelled_on_error() {
+    use std::cell::RefCell;
+    use tenable::{requests::AssetReq, types::AssetsExportDef};
+
+    let tenable = Tenable {
+        uri: "".into(),
+        ..Tenable::new("", "")
+    };
+    let paths = RefCell::new(Vec::new());
+    let req = tenable.assets_export(AssetsExportDef::default());
+    let mut chunks = Tenable::export(
+        req,
+        |req: Request<Vec<u8>>| {
+            let path = req.uri().path().to_string();
+            paths.borrow_mut().push(path.clone());
+            let (status, body) = if path == "/assets/export" {
+                (StatusCode::OK, r#"{"export_uuid":"1234"}"#)
+            } else if path.ends_with("/status") {
+                (
+                    StatusCode::OK,
+                    r#"{"status":"PROCESSING","chunks_available":[1]}"#,
+                )
+            } else if path.ends_with("/cancel") {
+                (StatusCode::OK, r#"{"status":"CANCELLED"}"#)
+            } else {
+                (StatusCode::BAD_REQUEST, "")
+            };
+            Result::<_, Error<Infallible>>::Ok(Response {
+                status,
+                headers: HeaderMap::new(),
+                body: Bytes::from(body),
+            })
+        },
+        |_| {},
+        Duration::from_secs(1),
+    );
+    assert!(matches!(chunks.next(), Some(Err(_))));
+    assert!(chunks.next().is_none());
+    assert_eq!(
+        *paths.borrow(),
+        [
+            "/assets/export",
+            "/assets/export/1234/status",
+            "/assets/export/1234/chunks/1",
+            "/assets/export/1234/cancel",
+        ]
+    );
+}
+
+#[test]
+fn export_vulns_async() {
+    use futures_util::stream::StreamExt;
+    use std::future::ready;
+    use tenable::{
+        requests::VulnReq,
+        types::{Severity, VulnsExportDef},
+    };
+    use tokio::runtime::Runtime;
+
+    let mut rt = Runtime::new().expect("Unable to create runtime");
+    rt.block_on(async {
+        let tenable = Tenable {
+            uri: "".into(),
+            ..Tenable::new("", "")
+        };
+        let status_calls = Cell::new(0);
+        let req = tenable.vulns_export(VulnsExportDef::default());
+        let chunks = Tenable::export_async(
+            req,
+            |req| ready(export_server(&status_calls, &req)),
+            |_| ready(()),
+            Duration::from_secs(1),
+        );
+        let findings: Vec<_> = chunks
+            .map(|chunk| chunk.expect("Unable to export vulns"))
+            .concat()
+            .await;
+        assert_eq!(findings.len(), 2);
+        assert!(findings
+            .iter()
+            .all(|finding| finding.severity == Some(Severity::High)));
+    });
+}
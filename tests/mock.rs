@@ -0,0 +1,221 @@
+use futures_util::stream::StreamExt;
+use http::{header::CONTENT_TYPE, Method, Request, StatusCode};
+use std::{cell::RefCell, convert::Infallible, time::Duration};
+use tenable::{
+    backoff::{Backoff, Linear, RetryAfter},
+    mock::{Fault, MockTransport},
+    requests::{AssetReq, VulnReq},
+    types::{AssetsExportDef, AssetsMoveDef, VulnsExportDef},
+    Error, Response, Tenable, TenableClient,
+};
+
+fn client() -> TenableClient<'static, MockTransport> {
+    TenableClient::new(Tenable::new("", ""), MockTransport::new())
+}
+
+#[test]
+fn fixtures() {
+    let client = client();
+    let tenable = &client.tenable;
+
+    let assets = client
+        .send(tenable.assets())
+        .expect("Unable to list all assets");
+    assert_eq!(assets.total, Some(1));
+    let asset = client
+        .send(tenable.asset_by_uuid("0d3d9e7c-1b5a-4b8e-9f3a-6c2f2d1e4a10"))
+        .expect("Unable to get asset")
+        .expect("Asset not found");
+    assert_eq!(asset.hostname, Some(vec!["web01".into()]));
+    client
+        .send(tenable.acr_update(Vec::new()))
+        .expect("Unable to update acrs");
+    let _moved = client
+        .send(tenable.assets_move(AssetsMoveDef {
+            source: "source".into(),
+            destination: "destination".into(),
+            targets: "10.0.0.1".into(),
+        }))
+        .expect("Unable to move assets")
+        .expect("Network not found");
+
+    let assets: Vec<_> = client
+        .export(
+            tenable.assets_export(AssetsExportDef::default()),
+            |_| {},
+            Duration::from_secs(0),
+        )
+        .collect::<Result<_, _>>()
+        .expect("Unable to export assets");
+    assert_eq!(assets.concat().len(), 1);
+    let _status = client
+        .send(tenable.assets_export_cancel("6a2f5d1c-9e8b-4a7f-b3c2-0d1e4f5a6b70"))
+        .expect("Unable to cancel export")
+        .expect("Export not found");
+
+    let findings: Vec<_> = client
+        .export(
+            tenable.vulns_export(VulnsExportDef::default()),
+            |_| {},
+            Duration::from_secs(0),
+        )
+        .collect::<Result<_, _>>()
+        .expect("Unable to export vulnerabilities");
+    assert_eq!(findings.concat().len(), 1);
+    let _status = client
+        .send(tenable.vulns_export_cancel("6a2f5d1c-9e8b-4a7f-b3c2-0d1e4f5a6b70"))
+        .expect("Unable to cancel export")
+        .expect("Export not found");
+}
+
+#[test]
+fn faults() {
+    let client = client();
+    let req = client.tenable.assets();
+
+    let _ = client
+        .transport
+        .inject(Fault::RateLimited(Some(2)), 2)
+        .inject(Fault::ServerError(StatusCode::SERVICE_UNAVAILABLE), 1);
+    let waits = RefCell::new(Vec::new());
+    let _assets = client
+        .send_with_policy(
+            &req,
+            |wait| waits.borrow_mut().push(wait),
+            Backoff::new(RetryAfter {
+                fallback: Linear::default(),
+            })
+            .retry_idempotent(true),
+        )
+        .expect("Unable to list all assets");
+    assert_eq!(
+        *waits.borrow(),
+        vec![
+            Duration::from_secs(2),
+            Duration::from_secs(2),
+            Duration::from_millis(300)
+        ]
+    );
+
+    let _ = client.transport.inject(Fault::Unauthorized, 1);
+    match client.send(&req) {
+        Err(Error::Unauthorized {
+            request_id: Some(_),
+            ..
+        }) => {}
+        res => panic!("Unexpected response: {:?}", res),
+    }
+
+    let _ = client.transport.inject(Fault::NotFound, 1);
+    assert!(client
+        .send(client.tenable.asset_by_uuid("notfound"))
+        .expect("Unable to get asset")
+        .is_none());
+}
+
+#[test]
+fn routes() {
+    let client = client();
+    let _ = client.transport.route(
+        Method::GET,
+        "/assets/{asset_uuid}",
+        StatusCode::CONFLICT,
+        r#"{"error":"Asset is being deleted"}"#,
+    );
+    match client.send(client.tenable.asset_by_uuid("deleted")) {
+        Err(Error::Conflict { message, .. }) => {
+            assert_eq!(message.as_deref(), Some("Asset is being deleted"))
+        }
+        res => panic!("Unexpected response: {:?}", res),
+    }
+
+    // unknown endpoints and missing credentials
+    let req = Request::get("/unknown")
+        .header("X-ApiKeys", "accessKey=;secretKey=")
+        .body(Vec::new())
+        .expect("Unable to build request");
+    let res: Response = client.transport.send(req).expect("Unable to send request");
+    assert_eq!(res.status, StatusCode::NOT_FOUND);
+    let req = Request::get("/assets")
+        .body(Vec::new())
+        .expect("Unable to build request");
+    let res = client.transport.send(req).expect("Unable to send request");
+    assert_eq!(res.status, StatusCode::UNAUTHORIZED);
+
+    let requests = client.transport.requests();
+    assert_eq!(requests.len(), 3);
+    assert_eq!(requests[0].uri.path(), "/assets/deleted");
+
+    // fixtures which are files are not labelled as JSON
+    let req = Request::get("/policies/12/export")
+        .header("X-ApiKeys", "accessKey=;secretKey=")
+        .body(Vec::new())
+        .expect("Unable to build request");
+    let res = client.transport.send(req).expect("Unable to send request");
+    assert_eq!(res.headers[CONTENT_TYPE], "application/octet-stream");
+    let _ = client
+        .transport
+        .inject(Fault::ServerError(StatusCode::BAD_GATEWAY), 1);
+    let req = Request::get("/policies/12/export")
+        .header("X-ApiKeys", "accessKey=;secretKey=")
+        .body(Vec::new())
+        .expect("Unable to build request");
+    let res = client.transport.send(req).expect("Unable to send request");
+    assert_eq!(res.headers[CONTENT_TYPE], "application/json");
+}
+
+#[test]
+fn server_content_length() {
+    use std::{
+        io::{Read, Write},
+        net::{Shutdown, TcpStream},
+    };
+    use tenable::mock::MockServer;
+
+    let server = MockServer::start(MockTransport::new()).expect("Unable to start server");
+    let addr = server.uri().replace("http://", "");
+    let mut stream = TcpStream::connect(addr).expect("Unable to connect");
+    stream
+        .write_all(b"POST /assets HTTP/1.1\r\nContent-Length: 18446744073709551615\r\n\r\n{}")
+        .expect("Unable to send request");
+    stream
+        .shutdown(Shutdown::Write)
+        .expect("Unable to close stream");
+    let mut res = Vec::new();
+    let _ = stream
+        .read_to_end(&mut res)
+        .expect("Unable to read response");
+    // the body is shorter than announced, so the connection is closed without a response
+    assert!(res.is_empty());
+    assert!(server.transport().requests().is_empty());
+}
+
+#[test]
+fn send_async() {
+    let mut rt = tokio::runtime::Runtime::new().expect("Unable to create runtime");
+    rt.block_on(async {
+        let client = client();
+        let _assets = client
+            .send_async(client.tenable.assets())
+            .await
+            .expect("Unable to list all assets");
+
+        let chunks = client.export_async(
+            client.tenable.vulns_export(VulnsExportDef::default()),
+            |_| async {},
+            Duration::from_secs(0),
+        );
+        let findings: Vec<_> = chunks.collect().await;
+        assert_eq!(findings.len(), 1);
+    });
+}
+
+#[test]
+fn request_fn() {
+    let tenable = Tenable::new("", "");
+    let transport = MockTransport::new();
+    let _assets = Tenable::request(tenable.assets(), |req| {
+        Result::<_, Error<Infallible>>::Ok(transport.send(req)?)
+    })
+    .expect("Unable to list all assets");
+}
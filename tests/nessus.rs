@@ -0,0 +1,123 @@
+mod common;
+
+#[test]
+fn report_hosts() {
+    use tenable::{nessus::NessusReader, requests::ScanReq, types::Severity, Tenable};
+
+    let server = common::server();
+    let tenable = common::get_tenable(&server);
+    let req = tenable.scan_export_download(common::SCAN_ID, "1036548617");
+    let report = Tenable::request(req, common::request)
+        .expect("Unable to download export")
+        .expect("Export not found");
+
+    let mut reader = NessusReader::new(report.as_ref());
+    let host = reader
+        .next()
+        .expect("No host found")
+        .expect("Unable to parse host");
+    assert_eq!(reader.report_name(), Some("Deployment"));
+    assert!(reader.next().is_none());
+
+    assert_eq!(host.name, "192.0.2.10");
+    assert_eq!(host.ipv4, Some(vec!["192.0.2.10".into()]));
+    assert_eq!(host.ipv6, None);
+    assert_eq!(host.fqdn, Some(vec!["web01.example.com".into()]));
+    assert_eq!(host.netbios_name, Some(vec!["WEB01".into()]));
+    assert_eq!(
+        host.mac_address,
+        Some(vec!["00:50:56:a1:b2:c3".into(), "00:50:56:a1:b2:c4".into()])
+    );
+    assert_eq!(
+        host.operating_system,
+        Some(vec!["Linux Kernel 4.15 on Ubuntu 18.04 (bionic)".into()])
+    );
+    assert_eq!(host.host_start, Some(1_591_014_610));
+    assert_eq!(host.host_end, Some(1_591_015_800));
+    assert_eq!(host.items.len(), 2);
+
+    let item = &host.items[1];
+    assert_eq!(item.plugin_id, 51192);
+    assert_eq!(item.port, 443);
+    assert_eq!(item.severity, Severity::High);
+    assert_eq!(item.cvss3_base_score, Some(6.5));
+    assert_eq!(
+        item.description.as_deref(),
+        Some("The server's X.509 certificate cannot be trusted.")
+    );
+    assert!(item
+        .plugin_output
+        .as_deref()
+        .map_or(false, |output| output.ends_with("CN=web01.example.com")));
+}
+
+#[test]
+fn report_items() {
+    use tenable::nessus::NessusReader;
+
+    let xml = r#"<NessusClientData_v2><Report name="Agents">
+        <ReportHost name="agent01"><HostProperties>
+            <tag name="host-ip">2001:db8::10</tag>
+            <tag name="operating-system">Microsoft Windows 10
+Microsoft Windows Server 2019</tag>
+            <tag name="patch-summary-total-cves"/>
+        </HostProperties>
+        <ReportItem port="0" svc_name="general" protocol="tcp" severity="4" pluginID="97833" pluginName="MS17-010" pluginFamily="Windows">
+            <cve>CVE-2017-0143</cve>
+            <cve>CVE-2017-0144</cve>
+            <plugin_type>local</plugin_type>
+            <plugin_output><![CDATA[<vulnerable>]]></plugin_output>
+            <exploit_available/>
+        </ReportItem>
+        </ReportHost>
+        <ReportHost name="agent02"/>
+    </Report></NessusClientData_v2>"#;
+
+    let hosts: Vec<_> = NessusReader::new(xml.as_bytes())
+        .collect::<Result<_, _>>()
+        .expect("Unable to parse hosts");
+    assert_eq!(hosts.len(), 2);
+    assert_eq!(hosts[0].ipv4, None);
+    assert_eq!(hosts[0].ipv6, Some(vec!["2001:db8::10".into()]));
+    assert_eq!(hosts[0].operating_system.as_ref().map(Vec::len), Some(2));
+    assert_eq!(
+        hosts[0].properties.get("patch-summary-total-cves"),
+        Some(&String::new())
+    );
+    let item = &hosts[0].items[0];
+    assert_eq!(item.cve, vec!["CVE-2017-0143", "CVE-2017-0144"]);
+    assert_eq!(item.plugin_output.as_deref(), Some("<vulnerable>"));
+    assert_eq!(
+        item.properties.get("plugin_type").map(String::as_str),
+        Some("local")
+    );
+    assert!(item.properties.contains_key("exploit_available"));
+    assert_eq!(hosts[1].name, "agent02");
+    assert!(hosts[1].items.is_empty());
+}
+
+#[test]
+fn parse_error() {
+    use tenable::nessus::{NessusReader, ParseError};
+
+    let xml = r#"<NessusClientData_v2><Report name="Deployment">
+        <ReportHost name="192.0.2.10">
+        <ReportItem port="https" severity="0" pluginID="19506"/>
+        </ReportHost>
+    </Report></NessusClientData_v2>"#;
+    let mut reader = NessusReader::new(xml.as_bytes());
+    match reader.next() {
+        Some(Err(ParseError::InvalidValue { name, value })) => {
+            assert_eq!(name, "port");
+            assert_eq!(value, "https");
+        }
+        res => panic!("Unexpected result: {:?}", res),
+    }
+    assert!(reader.next().is_none());
+
+    let xml = "<NessusClientData_v2><Report><ReportHost name=\"192.0.2.10\"></Report>";
+    match NessusReader::new(xml.as_bytes()).next() {
+        Some(Err(ParseError::Xml(_))) => {}
+        res => panic!("Unexpected result: {:?}", res),
+    }
+}
@@ -0,0 +1,139 @@
+mod common;
+
+const NETWORK_ID: &str = "9e1b3d5f-7a9c-4e1b-8d5f-7a9c1e3b5d7f";
+const SCANNER_UUID: &str = "00000000-0000-0000-0000-00000000000000000000000000001";
+
+#[test]
+fn networks() {
+    use std::borrow::Cow;
+    use tenable::{
+        requests::NetworkReq,
+        types::{FilterType, NetworkDef, NetworksReq, QueryFilter},
+        Tenable,
+    };
+
+    let payload = NetworkDef {
+        name: "Data center".into(),
+        description: Some("Assets in the data center".into()),
+        assets_ttl_days: Some(90),
+    };
+
+    let server = common::server();
+    let tenable = common::get_tenable(&server);
+    let req = NetworksReq {
+        filters: Some(vec![
+            QueryFilter {
+                field: "name".into(),
+                operator: "eq".into(),
+                value: "Data center".into(),
+            },
+            QueryFilter {
+                field: "name".into(),
+                operator: "eq".into(),
+                value: "Default".into(),
+            },
+        ]),
+        filter_type: Some(FilterType::Or),
+        include_deleted: Some(false),
+        ..tenable.networks()
+    };
+    let networks = Tenable::request(req, common::request)
+        .expect("Unable to list networks")
+        .networks
+        .expect("No networks returned");
+    assert_eq!(networks[0].is_default, Some(true));
+
+    // test ref
+    let _req = tenable.network_create(&payload);
+    // test cow
+    let _req = tenable.network_create(Cow::Borrowed(&payload));
+    // test owned
+    let req = tenable.network_create(payload.clone());
+    let network = Tenable::request(req, common::request).expect("Unable to create network");
+    assert_eq!(network.uuid.as_deref(), Some(NETWORK_ID));
+
+    let req = tenable.network_by_id(NETWORK_ID);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to get network")
+        .is_some());
+    let req = tenable.network_update(NETWORK_ID, &payload);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to update network")
+        .is_some());
+    let req = tenable.network_delete(NETWORK_ID);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to delete network")
+        .is_some());
+
+    let requests = server.transport().requests();
+    assert_eq!(
+        requests[0].uri.query(),
+        Some("f=name:eq:Data%20center&f=name:eq:Default&ft=or&includeDeleted=false")
+    );
+    let body: NetworkDef = serde_json::from_slice(&requests[1].body).expect("Invalid body");
+    assert_eq!(body, payload);
+
+    // not found
+    let req = tenable.network_by_id("notfound");
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to get network")
+        .is_none());
+    let req = tenable.network_update("notfound", &payload);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to update network")
+        .is_none());
+    let req = tenable.network_delete("notfound");
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to delete network")
+        .is_none());
+}
+
+#[test]
+fn network_scanners() {
+    use tenable::{requests::NetworkReq, Tenable};
+
+    let server = common::server();
+    let tenable = common::get_tenable(&server);
+    let req = tenable.network_assets_not_seen(NETWORK_ID, 30);
+    let count = Tenable::request(req, common::request)
+        .expect("Unable to count assets")
+        .expect("Network not found");
+    assert_eq!(count.num_assets_not_seen, Some(12));
+
+    let req = tenable.network_assignable_scanners(NETWORK_ID);
+    let scanners = Tenable::request(req, common::request)
+        .expect("Unable to list assignable scanners")
+        .expect("Network not found")
+        .scanners
+        .expect("No scanners returned");
+    assert_eq!(scanners[0].uuid.as_deref(), Some(SCANNER_UUID));
+
+    let req = tenable.network_scanner_assign(NETWORK_ID, SCANNER_UUID);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to assign scanner")
+        .is_some());
+    let req = tenable.network_scanners(NETWORK_ID);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to list scanners")
+        .is_some());
+
+    let requests = server.transport().requests();
+    assert_eq!(
+        requests[0].uri.path(),
+        format!("/networks/{}/counts/assets-not-seen-in/30", NETWORK_ID)
+    );
+
+    // not found
+    let req = tenable.network_assets_not_seen("notfound", 30);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to count assets")
+        .is_none());
+    let req = tenable.network_scanner_assign("notfound", SCANNER_UUID);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to assign scanner")
+        .is_none());
+    let req = tenable.network_scanners("notfound");
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to list scanners")
+        .is_none());
+}
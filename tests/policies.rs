@@ -0,0 +1,201 @@
+mod common;
+
+const TEMPLATE_UUID: &str = "731a8e52-3ea6-a291-ec0a-d2ff0619c19d7bd788d6be818b65";
+const POLICY_ID: u64 = 12;
+
+#[test]
+fn editor() {
+    use tenable::{
+        requests::EditorReq,
+        types::{EditorType, EditorValue},
+        Tenable,
+    };
+
+    let server = common::server();
+    let tenable = common::get_tenable(&server);
+    let templates = Tenable::request(
+        tenable.editor_templates(EditorType::Policy),
+        common::request,
+    )
+    .expect("Unable to list templates")
+    .templates
+    .expect("No templates returned");
+    assert_eq!(templates[0].uuid.as_deref(), Some(TEMPLATE_UUID));
+
+    let req = tenable.editor_template_by_uuid(EditorType::Policy, TEMPLATE_UUID);
+    let template = Tenable::request(req, common::request)
+        .expect("Unable to fetch template")
+        .expect("Template not found");
+    let settings = template.settings.as_ref().expect("No settings returned");
+    let ids: Vec<_> = settings
+        .inputs()
+        .iter()
+        .filter_map(|input| input.id.as_deref())
+        .collect();
+    assert_eq!(
+        ids,
+        [
+            "name",
+            "description",
+            "ping_the_remote_host",
+            "icmp_ping_retries",
+            "display_unreachable_hosts",
+            "report_verbosity",
+            "max_checks",
+            "network_receive_timeout",
+        ]
+    );
+    let defaults = template.default_settings();
+    assert_eq!(defaults.len(), 6);
+    assert_eq!(
+        defaults.get("ping_the_remote_host"),
+        Some(&EditorValue::Text("yes".into()))
+    );
+    assert_eq!(defaults.get("max_checks"), Some(&EditorValue::Integer(5)));
+    let families = template
+        .plugins
+        .and_then(|plugins| plugins.families)
+        .expect("No plugin families returned");
+    assert_eq!(families["Windows"].status.as_deref(), Some("mixed"));
+
+    let req = tenable.editor_policy_by_id(POLICY_ID);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to fetch policy")
+        .is_some());
+
+    let requests = server.transport().requests();
+    assert_eq!(requests[0].uri.path(), "/editor/policy/templates");
+    assert_eq!(
+        requests[1].uri.path(),
+        format!("/editor/policy/templates/{}", TEMPLATE_UUID)
+    );
+
+    // not found
+    let req = tenable.editor_template_by_uuid(EditorType::Scan, "notfound");
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to fetch template")
+        .is_none());
+    let req = tenable.editor_policy_by_id(404);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to fetch policy")
+        .is_none());
+}
+
+#[test]
+fn policies() {
+    use std::borrow::Cow;
+    use tenable::{
+        requests::{FileReq, PolicyReq},
+        types::{EditorValue, PolicyDef},
+        Tenable,
+    };
+
+    let payload = PolicyDef {
+        uuid: TEMPLATE_UUID.into(),
+        settings: vec![
+            ("name".to_owned(), EditorValue::Text("Web servers".into())),
+            (
+                "icmp_ping_retries".to_owned(),
+                EditorValue::Text("3".into()),
+            ),
+            ("max_checks".to_owned(), EditorValue::Integer(5)),
+        ]
+        .into_iter()
+        .collect(),
+    };
+
+    let server = common::server();
+    let tenable = common::get_tenable(&server);
+    let policies = Tenable::request(tenable.policies(), common::request)
+        .expect("Unable to list policies")
+        .policies
+        .expect("No policies returned");
+    assert_eq!(policies[0].id, Some(POLICY_ID));
+
+    // test ref
+    let _req = tenable.policy_create(&payload);
+    // test cow
+    let _req = tenable.policy_create(Cow::Borrowed(&payload));
+    // test owned
+    let req = tenable.policy_create(payload.clone());
+    let policy = Tenable::request(req, common::request).expect("Unable to create policy");
+    assert_eq!(policy.policy_id, POLICY_ID);
+
+    let req = tenable.policy_by_id(POLICY_ID);
+    let policy = Tenable::request(req, common::request)
+        .expect("Unable to fetch policy")
+        .expect("Policy not found");
+    let settings = policy.settings.expect("No settings returned");
+    let drift: Vec<_> = payload
+        .settings
+        .iter()
+        .filter(|(id, value)| settings.get(*id) != Some(value))
+        .map(|(id, _)| id.as_str())
+        .collect();
+    assert!(drift.is_empty(), "Unexpected drift: {:?}", drift);
+    assert_eq!(settings.get("acls"), Some(&EditorValue::Null));
+    assert_eq!(
+        settings.get("target_groups"),
+        Some(&EditorValue::List(vec![
+            EditorValue::Null,
+            EditorValue::Text("Web".into())
+        ]))
+    );
+
+    let req = tenable.policy_update(POLICY_ID, &payload);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to update policy")
+        .is_some());
+    let req = tenable.policy_copy(POLICY_ID);
+    let copy = Tenable::request(req, common::request)
+        .expect("Unable to copy policy")
+        .expect("Policy not found");
+    assert_eq!(copy.id, 13);
+    let req = tenable.file_upload("policy.nessus", &b"<?xml version=\"1.0\" ?>"[..]);
+    let file = Tenable::request(req, common::request).expect("Unable to upload policy");
+    assert_eq!(file.fileuploaded, "policy-1591014000.nessus");
+    let req = tenable.policy_import(file.fileuploaded);
+    let policy = Tenable::request(req, common::request).expect("Unable to import policy");
+    assert_eq!(policy.name.as_deref(), Some("Web servers"));
+    let req = tenable.policy_export(POLICY_ID);
+    let file = Tenable::request(req, common::request)
+        .expect("Unable to export policy")
+        .expect("Policy not found");
+    assert!(file.starts_with(b"<?xml"));
+    let req = tenable.policy_delete(POLICY_ID);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to delete policy")
+        .is_some());
+
+    let requests = server.transport().requests();
+    assert_eq!(
+        requests[1].body,
+        br#"{"uuid":"731a8e52-3ea6-a291-ec0a-d2ff0619c19d7bd788d6be818b65","settings":{"icmp_ping_retries":"3","max_checks":5,"name":"Web servers"}}"#
+    );
+    let body: PolicyDef = serde_json::from_slice(&requests[1].body).expect("Invalid body");
+    assert_eq!(body, payload);
+    assert_eq!(requests[5].uri.path(), "/file/upload");
+    assert_eq!(requests[6].body, br#"{"file":"policy-1591014000.nessus"}"#);
+
+    // not found
+    let req = tenable.policy_by_id(404);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to fetch policy")
+        .is_none());
+    let req = tenable.policy_update(404, &payload);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to update policy")
+        .is_none());
+    let req = tenable.policy_copy(404);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to copy policy")
+        .is_none());
+    let req = tenable.policy_export(404);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to export policy")
+        .is_none());
+    let req = tenable.policy_delete(404);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to delete policy")
+        .is_none());
+}
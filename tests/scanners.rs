@@ -0,0 +1,167 @@
+mod common;
+
+const SCANNER_ID: u64 = 2;
+const GROUP_ID: u64 = 7;
+
+#[test]
+fn scanners() {
+    use tenable::{
+        requests::ScannerReq,
+        types::{ScanStatus, ScannerUpdateDef},
+        Tenable,
+    };
+
+    let server = common::server();
+    let tenable = common::get_tenable(&server);
+    let scanners = Tenable::request(tenable.scanners(), common::request)
+        .expect("Unable to list scanners")
+        .scanners
+        .expect("No scanners returned");
+    let unlinked: Vec<_> = scanners
+        .iter()
+        .filter(|scanner| scanner.linked == Some(0))
+        .filter_map(|scanner| scanner.id)
+        .collect();
+    assert_eq!(unlinked, [SCANNER_ID]);
+
+    let req = tenable.scanner_by_id(SCANNER_ID);
+    let scanner = Tenable::request(req, common::request)
+        .expect("Unable to fetch scanner")
+        .expect("Scanner not found");
+    assert_eq!(scanner.network_name.as_deref(), Some("Data center"));
+
+    let req = tenable.scanner_link(SCANNER_ID, true);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to link scanner")
+        .is_some());
+    let req = tenable.scanner_update(
+        SCANNER_ID,
+        ScannerUpdateDef {
+            force_plugin_update: Some(true),
+            ..ScannerUpdateDef::default()
+        },
+    );
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to update scanner")
+        .is_some());
+
+    let req = tenable.scanner_scans(SCANNER_ID);
+    let scans = Tenable::request(req, common::request)
+        .expect("Unable to list scans")
+        .expect("Scanner not found")
+        .scans
+        .expect("No scans returned");
+    assert_eq!(scans[0].status, Some(ScanStatus::Running));
+    let req = tenable.scanner_aws_targets(SCANNER_ID);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to list AWS targets")
+        .is_some());
+    let req = tenable.scanner_delete(SCANNER_ID);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to delete scanner")
+        .is_some());
+
+    let requests = server.transport().requests();
+    assert_eq!(
+        requests[2].uri.path(),
+        format!("/scanners/{}/link", SCANNER_ID)
+    );
+    assert_eq!(requests[2].body, br#"{"link":1}"#);
+    assert_eq!(requests[3].body, br#"{"force_plugin_update":true}"#);
+
+    // not found
+    let req = tenable.scanner_by_id(404);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to fetch scanner")
+        .is_none());
+    let req = tenable.scanner_link(404, false);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to link scanner")
+        .is_none());
+    let req = tenable.scanner_scans(404);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to list scans")
+        .is_none());
+    let req = tenable.scanner_delete(404);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to delete scanner")
+        .is_none());
+}
+
+#[test]
+fn scanner_groups() {
+    use std::borrow::Cow;
+    use tenable::{requests::ScannerReq, types::ScannerGroupDef, Tenable};
+
+    let payload = ScannerGroupDef {
+        name: "Data center".into(),
+        group_type: Some("load_balancing".into()),
+    };
+
+    let server = common::server();
+    let tenable = common::get_tenable(&server);
+    let groups = Tenable::request(tenable.scanner_groups(), common::request)
+        .expect("Unable to list scanner groups")
+        .scanner_pools
+        .expect("No scanner groups returned");
+    assert_eq!(groups[0].id, Some(GROUP_ID));
+
+    // test ref
+    let _req = tenable.scanner_group_create(&payload);
+    // test cow
+    let _req = tenable.scanner_group_create(Cow::Borrowed(&payload));
+    // test owned
+    let req = tenable.scanner_group_create(payload.clone());
+    let group = Tenable::request(req, common::request).expect("Unable to create scanner group");
+    assert_eq!(group.group_type, payload.group_type);
+
+    let req = tenable.scanner_group_by_id(GROUP_ID);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to fetch scanner group")
+        .is_some());
+    let req = tenable.scanner_group_update(GROUP_ID, &payload);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to update scanner group")
+        .is_some());
+    let req = tenable.scanner_group_add_scanner(GROUP_ID, SCANNER_ID);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to add scanner")
+        .is_some());
+    let req = tenable.scanner_group_scanners(GROUP_ID);
+    let scanners = Tenable::request(req, common::request)
+        .expect("Unable to list scanners")
+        .expect("Scanner group not found")
+        .scanners
+        .expect("No scanners returned");
+    assert_eq!(scanners.len(), 2);
+    let req = tenable.scanner_group_remove_scanner(GROUP_ID, SCANNER_ID);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to remove scanner")
+        .is_some());
+    let req = tenable.scanner_group_delete(GROUP_ID);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to delete scanner group")
+        .is_some());
+
+    let requests = server.transport().requests();
+    let body: ScannerGroupDef = serde_json::from_slice(&requests[1].body).expect("Invalid body");
+    assert_eq!(body, payload);
+    assert_eq!(
+        requests[4].uri.path(),
+        format!("/scanner-groups/{}/scanners/{}", GROUP_ID, SCANNER_ID)
+    );
+
+    // not found
+    let req = tenable.scanner_group_by_id(404);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to fetch scanner group")
+        .is_none());
+    let req = tenable.scanner_group_delete(404);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to delete scanner group")
+        .is_none());
+    let req = tenable.scanner_group_add_scanner(GROUP_ID, 404);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to add scanner")
+        .is_none());
+}
@@ -0,0 +1,440 @@
+mod common;
+
+fn scan_def() -> tenable::types::ScanDef {
+    use tenable::types::{ScanDef, ScanSettings};
+
+    ScanDef {
+        uuid: "731a8e52-3ea6-a291-ec0a-d2ff0619c19d7bd788d6be818b65".into(),
+        settings: ScanSettings {
+            name: "Deployment".into(),
+            text_targets: Some("192.0.2.0/24".into()),
+            ..ScanSettings::default()
+        },
+    }
+}
+
+#[test]
+fn scans() {
+    use tenable::{requests::ScanReq, types::ScansReq, Tenable};
+
+    let server = common::server();
+    let tenable = common::get_tenable(&server);
+    let req = tenable.scans();
+    let scans = Tenable::request(req, common::request).expect("Unable to list all scans");
+    assert_eq!(scans.scans.map(|scans| scans.len()), Some(1));
+
+    let req = ScansReq {
+        folder_id: Some(3),
+        last_modification_date: scans.timestamp,
+        ..tenable.scans()
+    };
+    let _scans = Tenable::request(req, common::request).expect("Unable to list all scans");
+    let requests = server.transport().requests();
+    assert_eq!(
+        requests[1].uri.query(),
+        Some("folder_id=3&last_modification_date=1591016000")
+    );
+}
+
+#[test]
+fn scan_create() {
+    use std::borrow::Cow;
+    use tenable::{mock::Fault, requests::ScanReq, types::ScanDef, Error, Tenable};
+
+    let payload = scan_def();
+
+    let server = common::server();
+    let tenable = common::get_tenable(&server);
+    // test ref
+    let _req = tenable.scan_create(&payload);
+    // test cow
+    let _req = tenable.scan_create(Cow::Borrowed(&payload));
+    // test owned
+    let req = tenable.scan_create(payload.clone());
+    let created = Tenable::request(&req, common::request).expect("Unable to create scan");
+    assert_eq!(created.scan.id, Some(42));
+    let body: ScanDef =
+        serde_json::from_slice(&server.transport().requests()[0].body).expect("Invalid body");
+    assert_eq!(body, payload);
+
+    let _ = server.transport().inject(Fault::Forbidden, 1);
+    match Tenable::request(req, common::request) {
+        Err(Error::InsufficientPermission) => {}
+        res => panic!("Unexpected response: {:?}", res),
+    }
+}
+
+#[test]
+fn scan_by_id() {
+    use std::borrow::Cow;
+    use tenable::{requests::ScanReq, types::ScanStatus, Tenable};
+
+    let server = common::server();
+    let tenable = common::get_tenable(&server);
+    // test str
+    let _req = tenable.scan_by_id(common::SCAN_ID);
+    // test string
+    let _req = tenable.scan_by_id(String::from(common::SCAN_ID));
+    // test cow
+    let req = tenable.scan_by_id(Cow::Borrowed(common::SCAN_ID));
+    let scan = Tenable::request(req, common::request)
+        .expect("Unable to get scan")
+        .expect("Scan not found");
+    assert_eq!(
+        scan.info.and_then(|info| info.status),
+        Some(ScanStatus::Completed)
+    );
+
+    // not found
+    let req = tenable.scan_by_id("notfound");
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to get scan")
+        .is_none());
+}
+
+#[test]
+fn scan_update() {
+    use tenable::{requests::ScanReq, Tenable};
+
+    let server = common::server();
+    let tenable = common::get_tenable(&server);
+    let req = tenable.scan_update(common::SCAN_ID, scan_def());
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to update scan")
+        .is_some());
+
+    // not found
+    let req = tenable.scan_update("notfound", scan_def());
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to update scan")
+        .is_none());
+}
+
+#[test]
+fn scan_launch() {
+    use http::StatusCode;
+    use tenable::{requests::ScanReq, types::ScanLaunchReq, Error, Tenable};
+
+    let server = common::server();
+    let tenable = common::get_tenable(&server);
+    let req = tenable.scan_launch(common::SCAN_ID);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to launch scan")
+        .is_some());
+
+    let targets = vec![String::from("192.0.2.10")];
+    let req = ScanLaunchReq {
+        alt_targets: Some(targets.as_slice().into()),
+        ..tenable.scan_launch(common::SCAN_ID)
+    };
+    let _launched = Tenable::request(req, common::request).expect("Unable to launch scan");
+    let requests = server.transport().requests();
+    assert_eq!(requests[0].body, b"{}");
+    assert_eq!(requests[1].body, br#"{"alt_targets":["192.0.2.10"]}"#);
+
+    // not found
+    let req = tenable.scan_launch("notfound");
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to launch scan")
+        .is_none());
+
+    // already running
+    let _ = server.transport().route(
+        http::Method::POST,
+        "/scans/{scan_id}/launch",
+        StatusCode::CONFLICT,
+        r#"{"error":"Scan is already running"}"#,
+    );
+    match Tenable::request(tenable.scan_launch(common::SCAN_ID), common::request) {
+        Err(Error::Conflict { .. }) => {}
+        res => panic!("Unexpected response: {:?}", res),
+    }
+}
+
+#[test]
+fn scan_control() {
+    use tenable::{requests::ScanReq, Tenable};
+
+    let server = common::server();
+    let tenable = common::get_tenable(&server);
+    assert!(
+        Tenable::request(tenable.scan_pause(common::SCAN_ID), common::request)
+            .expect("Unable to pause scan")
+            .is_some()
+    );
+    assert!(
+        Tenable::request(tenable.scan_resume(common::SCAN_ID), common::request)
+            .expect("Unable to resume scan")
+            .is_some()
+    );
+    assert!(
+        Tenable::request(tenable.scan_stop(common::SCAN_ID), common::request)
+            .expect("Unable to stop scan")
+            .is_some()
+    );
+    assert!(
+        Tenable::request(tenable.scan_delete(common::SCAN_ID), common::request)
+            .expect("Unable to delete scan")
+            .is_some()
+    );
+    let paths: Vec<_> = server
+        .transport()
+        .requests()
+        .iter()
+        .map(|req| format!("{} {}", req.method, req.uri.path()))
+        .collect();
+    assert_eq!(
+        paths,
+        vec![
+            "POST /scans/42/pause",
+            "POST /scans/42/resume",
+            "POST /scans/42/stop",
+            "DELETE /scans/42"
+        ]
+    );
+
+    // not found
+    assert!(
+        Tenable::request(tenable.scan_pause("notfound"), common::request)
+            .expect("Unable to pause scan")
+            .is_none()
+    );
+    assert!(
+        Tenable::request(tenable.scan_delete("notfound"), common::request)
+            .expect("Unable to delete scan")
+            .is_none()
+    );
+}
+
+#[test]
+fn scan_latest_status() {
+    use tenable::{requests::ScanReq, types::ScanStatus, Tenable};
+
+    let server = common::server();
+    let tenable = common::get_tenable(&server);
+    let req = tenable.scan_latest_status(common::SCAN_ID);
+    let status = Tenable::request(req, common::request)
+        .expect("Unable to get scan status")
+        .expect("Scan not found");
+    assert_eq!(status.status, ScanStatus::Completed);
+
+    // not found
+    let req = tenable.scan_latest_status("notfound");
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to get scan status")
+        .is_none());
+
+    // unknown states do not break deserialization
+    let _ = server.transport().route(
+        http::Method::GET,
+        "/scans/{scan_id}/latest-status",
+        http::StatusCode::OK,
+        r#"{"status":"publishing"}"#,
+    );
+    let req = tenable.scan_latest_status(common::SCAN_ID);
+    let status = Tenable::request(req, common::request)
+        .expect("Unable to get scan status")
+        .expect("Scan not found");
+    assert_eq!(status.status, ScanStatus::Unknown);
+}
+
+#[test]
+fn scan_export() {
+    use std::borrow::Cow;
+    use tenable::{
+        requests::ScanReq,
+        types::{
+            ScanExportChapter, ScanExportDef, ScanExportFormat, ScanExportReq, ScanExportState,
+        },
+        Tenable,
+    };
+
+    let payload = ScanExportDef {
+        format: ScanExportFormat::Pdf,
+        chapters: Some(vec![
+            ScanExportChapter::VulnHostsSummary,
+            ScanExportChapter::VulnByPlugin,
+        ]),
+    };
+
+    let server = common::server();
+    let tenable = common::get_tenable(&server);
+    // test ref
+    let _req = tenable.scan_export(common::SCAN_ID, &payload);
+    // test cow
+    let _req = tenable.scan_export(common::SCAN_ID, Cow::Borrowed(&payload));
+    // test owned
+    let req = ScanExportReq {
+        history_uuid: Some("5d7e9a1b-3c2f-4e6d-8b0a-1f2e3d4c5b6a".into()),
+        ..tenable.scan_export(common::SCAN_ID, payload)
+    };
+    let file = Tenable::request(req, common::request)
+        .expect("Unable to export scan")
+        .expect("Scan not found");
+    assert_eq!(file.file, "1036548617");
+    let requests = server.transport().requests();
+    assert_eq!(
+        requests[0].uri.query(),
+        Some("history_uuid=5d7e9a1b-3c2f-4e6d-8b0a-1f2e3d4c5b6a")
+    );
+    assert_eq!(
+        requests[0].body,
+        br#"{"format":"pdf","chapters":"vuln_hosts_summary;vuln_by_plugin"}"#
+    );
+
+    let req = tenable.scan_export_status(common::SCAN_ID, file.file.as_str());
+    let status = Tenable::request(req, common::request)
+        .expect("Unable to get export status")
+        .expect("Export not found");
+    assert_eq!(status.status, ScanExportState::Ready);
+
+    let req = tenable.scan_export_download(common::SCAN_ID, file.file.as_str());
+    let report = Tenable::request(req, common::request)
+        .expect("Unable to download export")
+        .expect("Export not found");
+    assert!(report.starts_with(b"<?xml"));
+
+    // not found
+    let req = tenable.scan_export_status(common::SCAN_ID, "notfound");
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to get export status")
+        .is_none());
+    let req = tenable.scan_export_download(common::SCAN_ID, "notfound");
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to download export")
+        .is_none());
+}
+
+#[test]
+fn scan_history() {
+    use tenable::{requests::ScanReq, types::ScanHistoryReq, Tenable};
+
+    let server = common::server();
+    let tenable = common::get_tenable(&server);
+    let req = ScanHistoryReq {
+        limit: Some(2),
+        exclude_rollover: Some(true),
+        ..tenable.scan_history(common::SCAN_ID)
+    };
+    let history = Tenable::request(req, common::request)
+        .expect("Unable to list scan history")
+        .expect("Scan not found");
+    let runs = history.history.expect("No runs returned");
+    assert_eq!(runs.len(), 2);
+    assert_eq!(
+        server.transport().requests()[0].uri.query(),
+        Some("limit=2&exclude_rollover=true")
+    );
+
+    let history_uuid = runs[0].scan_uuid.as_deref().expect("Run without uuid");
+    let req = tenable.scan_history_by_id(common::SCAN_ID, history_uuid);
+    let run = Tenable::request(req, common::request)
+        .expect("Unable to get scan run")
+        .expect("Scan run not found");
+    assert_eq!(run.scan_uuid.as_deref(), Some(history_uuid));
+
+    // not found
+    let req = tenable.scan_history("notfound");
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to list scan history")
+        .is_none());
+    let req = tenable.scan_history_by_id(common::SCAN_ID, "notfound");
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to get scan run")
+        .is_none());
+}
+
+#[test]
+fn scan_host_details() {
+    use tenable::{
+        requests::ScanReq,
+        types::{ScanHostDetailsReq, ScanPluginOutputReq},
+        Tenable,
+    };
+
+    let server = common::server();
+    let tenable = common::get_tenable(&server);
+    let req = ScanHostDetailsReq {
+        history_id: Some(16),
+        ..tenable.scan_host_details(common::SCAN_ID, 2)
+    };
+    let host = Tenable::request(req, common::request)
+        .expect("Unable to get host details")
+        .expect("Host not found");
+    assert_eq!(
+        host.info.and_then(|info| info.host_fqdn).as_deref(),
+        Some("web01.example.com")
+    );
+    assert_eq!(host.vulnerabilities.map(|vulns| vulns.len()), Some(2));
+
+    let req = ScanPluginOutputReq {
+        history_uuid: Some("9b2c4d6e-8f0a-4b1c-9d3e-5f6a7b8c9d0e".into()),
+        ..tenable.scan_plugin_output(common::SCAN_ID, 2, 51192)
+    };
+    let output = Tenable::request(req, common::request)
+        .expect("Unable to get plugin output")
+        .expect("Plugin output not found");
+    let outputs = output.outputs.expect("No outputs returned");
+    assert!(outputs[0]
+        .ports
+        .as_ref()
+        .map_or(false, |ports| ports.contains_key("443 / tcp / www")));
+
+    let requests = server.transport().requests();
+    assert_eq!(requests[0].uri.path(), "/scans/42/hosts/2");
+    assert_eq!(requests[0].uri.query(), Some("history_id=16"));
+    assert_eq!(requests[1].uri.path(), "/scans/42/hosts/2/plugins/51192");
+    assert_eq!(
+        requests[1].uri.query(),
+        Some("history_uuid=9b2c4d6e-8f0a-4b1c-9d3e-5f6a7b8c9d0e")
+    );
+
+    // not found
+    let req = tenable.scan_host_details("notfound", 2);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to get host details")
+        .is_none());
+    let req = tenable.scan_plugin_output("notfound", 2, 51192);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to get plugin output")
+        .is_none());
+}
+
+#[test]
+fn history_uuid_encoded() {
+    use std::convert::Infallible;
+    use tenable::{
+        requests::ScanReq,
+        types::{
+            ScanExportDef, ScanExportFormat, ScanExportReq, ScanHostDetailsReq, ScanPluginOutputReq,
+        },
+        HttpRequest, Tenable,
+    };
+
+    let tenable = Tenable::new("", "");
+    let req = ScanExportReq {
+        history_uuid: Some("a&b=c d".into()),
+        ..tenable.scan_export(
+            common::SCAN_ID,
+            ScanExportDef {
+                format: ScanExportFormat::Nessus,
+                chapters: None,
+            },
+        )
+    };
+    let req = HttpRequest::<Infallible>::to_request(&req).expect("Unable to build request");
+    assert_eq!(req.uri().query(), Some("history_uuid=a%26b%3Dc%20d"));
+
+    let req = ScanHostDetailsReq {
+        history_uuid: Some("a&b=c d".into()),
+        ..tenable.scan_host_details(common::SCAN_ID, 2)
+    };
+    let req = HttpRequest::<Infallible>::to_request(&req).expect("Unable to build request");
+    assert_eq!(req.uri().query(), Some("history_uuid=a%26b%3Dc%20d"));
+    let req = ScanPluginOutputReq {
+        history_uuid: Some("a&b=c d".into()),
+        ..tenable.scan_plugin_output(common::SCAN_ID, 2, 51192)
+    };
+    let req = HttpRequest::<Infallible>::to_request(&req).expect("Unable to build request");
+    assert_eq!(req.uri().query(), Some("history_uuid=a%26b%3Dc%20d"));
+}
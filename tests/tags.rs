@@ -0,0 +1,170 @@
+mod common;
+
+const CATEGORY_UUID: &str = "2b4c6d8e-0f1a-4b3c-8d5e-7f9a1b3c5d7e";
+const VALUE_UUID: &str = "7e9f1a3b-5c7d-4e9f-a1b3-c5d7e9f1a3b5";
+
+#[test]
+fn tag_categories() {
+    use std::borrow::Cow;
+    use tenable::{
+        requests::TagReq,
+        types::{TagCategoriesReq, TagCategoryDef},
+        Tenable,
+    };
+
+    let payload = TagCategoryDef {
+        name: "Owner".into(),
+        description: Some("Team responsible for the asset".into()),
+    };
+
+    let server = common::server();
+    let tenable = common::get_tenable(&server);
+    let req = TagCategoriesReq {
+        limit: Some(100),
+        sort: Some("name:asc".into()),
+        ..tenable.tag_categories()
+    };
+    let categories = Tenable::request(req, common::request).expect("Unable to list categories");
+    assert_eq!(
+        categories
+            .pagination
+            .and_then(|pagination| pagination.total),
+        Some(1)
+    );
+
+    // test ref
+    let _req = tenable.tag_category_create(&payload);
+    // test cow
+    let _req = tenable.tag_category_create(Cow::Borrowed(&payload));
+    // test owned
+    let req = tenable.tag_category_create(payload.clone());
+    let category = Tenable::request(req, common::request).expect("Unable to create category");
+    assert_eq!(category.uuid.as_deref(), Some(CATEGORY_UUID));
+
+    let req = tenable.tag_category_by_uuid(CATEGORY_UUID);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to get category")
+        .is_some());
+    let req = tenable.tag_category_update(CATEGORY_UUID, &payload);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to update category")
+        .is_some());
+    let req = tenable.tag_category_delete(CATEGORY_UUID);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to delete category")
+        .is_some());
+
+    let requests = server.transport().requests();
+    assert_eq!(requests[0].uri.query(), Some("limit=100&sort=name:asc"));
+    let body: TagCategoryDef = serde_json::from_slice(&requests[1].body).expect("Invalid body");
+    assert_eq!(body, payload);
+
+    // not found
+    let req = tenable.tag_category_by_uuid("notfound");
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to get category")
+        .is_none());
+    let req = tenable.tag_category_update("notfound", &payload);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to update category")
+        .is_none());
+    let req = tenable.tag_category_delete("notfound");
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to delete category")
+        .is_none());
+}
+
+#[test]
+fn tag_values() {
+    use tenable::{
+        requests::TagReq,
+        types::{TagRule, TagRules, TagValueDef, TagValueFilters},
+        Tenable,
+    };
+
+    let filters = TagValueFilters {
+        asset: TagRules::And(vec![TagRule {
+            field: "fqdn".into(),
+            operator: "ends".into(),
+            value: ".web.example.com".into(),
+        }]),
+    };
+    let payload = TagValueDef {
+        category_name: Some("Owner".into()),
+        value: "Web".into(),
+        filters: Some(filters.clone()),
+        ..TagValueDef::default()
+    };
+
+    let server = common::server();
+    let tenable = common::get_tenable(&server);
+    let values = Tenable::request(tenable.tag_values(), common::request)
+        .expect("Unable to list values")
+        .values
+        .expect("No values returned");
+    // rules are returned as JSON encoded string
+    assert_eq!(values[0].filters, Some(filters.clone()));
+
+    let req = tenable.tag_value_create(&payload);
+    let value = Tenable::request(req, common::request).expect("Unable to create value");
+    assert_eq!(value.uuid.as_deref(), Some(VALUE_UUID));
+    assert_eq!(
+        server.transport().requests()[1].body,
+        br#"{"category_name":"Owner","value":"Web","filters":{"asset":{"and":[{"field":"fqdn","operator":"ends","value":".web.example.com"}]}}}"#
+    );
+
+    let req = tenable.tag_value_by_uuid(VALUE_UUID);
+    let value = Tenable::request(req, common::request)
+        .expect("Unable to get value")
+        .expect("Value not found");
+    assert_eq!(value.value_type.as_deref(), Some("dynamic"));
+    let req = tenable.tag_value_update(VALUE_UUID, &payload);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to update value")
+        .is_some());
+    let req = tenable.tag_value_delete(VALUE_UUID);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to delete value")
+        .is_some());
+
+    // not found
+    let req = tenable.tag_value_by_uuid("notfound");
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to get value")
+        .is_none());
+    let req = tenable.tag_value_delete("notfound");
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to delete value")
+        .is_none());
+}
+
+#[test]
+fn tag_assignments() {
+    use tenable::{
+        mock::Fault,
+        requests::TagReq,
+        types::{TagAssignmentAction, TagAssignmentsDef},
+        Error, Tenable,
+    };
+
+    let payload = TagAssignmentsDef {
+        action: TagAssignmentAction::Add,
+        assets: vec![common::ASSET_ID.into()],
+        tags: vec![VALUE_UUID.into()],
+    };
+
+    let server = common::server();
+    let tenable = common::get_tenable(&server);
+    let req = tenable.tag_assignments(&payload);
+    let job = Tenable::request(&req, common::request).expect("Unable to assign tags");
+    assert_eq!(job.job_uuid, "c1d3e5f7-a9b1-4c3d-9e5f-7a9b1c3d5e7f");
+    let body: TagAssignmentsDef =
+        serde_json::from_slice(&server.transport().requests()[0].body).expect("Invalid body");
+    assert_eq!(body, payload);
+
+    let _ = server.transport().inject(Fault::Forbidden, 1);
+    match Tenable::request(req, common::request) {
+        Err(Error::InsufficientPermission) => {}
+        res => panic!("Unexpected response: {:?}", res),
+    }
+}
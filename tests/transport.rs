@@ -0,0 +1,171 @@
+mod common;
+
+use tenable::{
+    mock::MockServer,
+    requests::AssetReq,
+    transport::{Hyper, Isahc, ReqwestAsync, ReqwestBlocking, Ureq},
+    types::AssetsMoveDef,
+    TenableClient,
+};
+use tokio::runtime::Runtime;
+
+fn payload() -> AssetsMoveDef {
+    AssetsMoveDef {
+        source: "source".into(),
+        destination: "destination".into(),
+        targets: "10.0.0.1".into(),
+    }
+}
+
+/// Checks that the server received a `GET` and a `POST` including credentials and body
+fn assert_requests(server: &MockServer) {
+    let requests = server.transport().requests();
+    assert_eq!(requests.len(), 3);
+    for req in &requests {
+        assert_eq!(
+            req.headers.get("X-ApiKeys").map(|value| value.as_bytes()),
+            Some(&b"accessKey=access;secretKey=secret"[..])
+        );
+    }
+    assert_eq!(requests[0].uri.path(), "/assets");
+    assert_eq!(
+        requests[1].uri.path(),
+        format!("/assets/{}", common::ASSET_ID)
+    );
+    let body: AssetsMoveDef = serde_json::from_slice(&requests[2].body).expect("Invalid body");
+    assert_eq!(body, payload());
+}
+
+#[test]
+fn reqwest_blocking() {
+    let server = common::server();
+    let client = TenableClient::new(common::get_tenable(&server), ReqwestBlocking::default());
+    let _assets = client
+        .send(client.tenable.assets())
+        .expect("Unable to list all assets");
+    assert!(client
+        .send(client.tenable.asset_by_uuid(common::ASSET_ID))
+        .expect("Unable to get asset")
+        .is_some());
+    assert!(client
+        .send(client.tenable.assets_move(payload()))
+        .expect("Unable to move assets")
+        .is_some());
+    assert_requests(&server);
+}
+
+#[test]
+fn reqwest_async() {
+    let mut rt = Runtime::new().expect("Unable to create runtime");
+    rt.block_on(async {
+        let server = common::server();
+        let client = TenableClient::new(common::get_tenable(&server), ReqwestAsync::default());
+        let _assets = client
+            .send_async(client.tenable.assets())
+            .await
+            .expect("Unable to list all assets");
+        assert!(client
+            .send_async(client.tenable.asset_by_uuid(common::ASSET_ID))
+            .await
+            .expect("Unable to get asset")
+            .is_some());
+        assert!(client
+            .send_async(client.tenable.assets_move(payload()))
+            .await
+            .expect("Unable to move assets")
+            .is_some());
+        assert_requests(&server);
+    });
+}
+
+#[test]
+fn ureq() {
+    let server = common::server();
+    let client = TenableClient::new(common::get_tenable(&server), Ureq::default());
+    let _assets = client
+        .send(client.tenable.assets())
+        .expect("Unable to list all assets");
+    assert!(client
+        .send(client.tenable.asset_by_uuid(common::ASSET_ID))
+        .expect("Unable to get asset")
+        .is_some());
+    assert!(client
+        .send(client.tenable.assets_move(payload()))
+        .expect("Unable to move assets")
+        .is_some());
+    assert_requests(&server);
+}
+
+#[test]
+fn hyper() {
+    let mut rt = Runtime::new().expect("Unable to create runtime");
+    rt.block_on(async {
+        let server = common::server();
+        let client = TenableClient::new(
+            common::get_tenable(&server),
+            Hyper::new(hyper::Client::new()),
+        );
+        let _assets = client
+            .send_async(client.tenable.assets())
+            .await
+            .expect("Unable to list all assets");
+        assert!(client
+            .send_async(client.tenable.asset_by_uuid(common::ASSET_ID))
+            .await
+            .expect("Unable to get asset")
+            .is_some());
+        assert!(client
+            .send_async(client.tenable.assets_move(payload()))
+            .await
+            .expect("Unable to move assets")
+            .is_some());
+        assert_requests(&server);
+    });
+}
+
+#[test]
+fn isahc() {
+    let server = common::server();
+    let client = TenableClient::new(
+        common::get_tenable(&server),
+        Isahc::new(isahc::HttpClient::new().expect("Unable to create client")),
+    );
+    let _assets = client
+        .send(client.tenable.assets())
+        .expect("Unable to list all assets");
+    assert!(client
+        .send(client.tenable.asset_by_uuid(common::ASSET_ID))
+        .expect("Unable to get asset")
+        .is_some());
+    assert!(client
+        .send(client.tenable.assets_move(payload()))
+        .expect("Unable to move assets")
+        .is_some());
+    assert_requests(&server);
+}
+
+#[test]
+fn isahc_async() {
+    async_std::task::block_on(async {
+        let server = common::server();
+        let client = TenableClient::new(
+            common::get_tenable(&server),
+            Isahc::new(isahc::HttpClient::new().expect("Unable to create client")),
+        );
+        let _assets = client
+            .send_async(client.tenable.assets())
+            .await
+            .expect("Unable to list all assets");
+        assert!(client
+            .send_async(client.tenable.asset_by_uuid(common::ASSET_ID))
+            .await
+            .expect("Unable to get asset")
+            .is_some());
+        assert!(client
+            .send_async(client.tenable.assets_move(payload()))
+            .await
+            .expect("Unable to move assets")
+            .is_some());
+        assert_requests(&server);
+    });
+}
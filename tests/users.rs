@@ -0,0 +1,219 @@
+mod common;
+
+const USER_ID: u64 = 3;
+const GROUP_ID: u64 = 5;
+const ACCESS_KEY: &str = "1111111111111111111111111111111111111111111111111111111111111111";
+const SECRET_KEY: &str = "2222222222222222222222222222222222222222222222222222222222222222";
+
+#[test]
+fn users() {
+    use std::borrow::Cow;
+    use tenable::{
+        requests::UserReq,
+        types::{UserDef, UserUpdateDef},
+        Tenable,
+    };
+
+    let payload = UserDef {
+        username: "jane.doe@example.com".into(),
+        password: "correct horse battery staple".into(),
+        permissions: 32,
+        name: Some("Jane Doe".into()),
+        email: Some("jane.doe@example.com".into()),
+        user_type: Some("local".into()),
+    };
+    assert!(!format!("{:?}", payload).contains(&payload.password.0));
+
+    let server = common::server();
+    let tenable = common::get_tenable(&server);
+    let users = Tenable::request(tenable.users(), common::request)
+        .expect("Unable to list users")
+        .users
+        .expect("No users returned");
+    assert_eq!(users[1].id, Some(USER_ID));
+
+    // test ref
+    let _req = tenable.user_create(&payload);
+    // test cow
+    let _req = tenable.user_create(Cow::Borrowed(&payload));
+    // test owned
+    let req = tenable.user_create(payload.clone());
+    let user = Tenable::request(req, common::request).expect("Unable to create user");
+    assert_eq!(user.permissions, Some(payload.permissions));
+
+    let req = tenable.user_by_id(USER_ID);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to fetch user")
+        .is_some());
+    let req = tenable.user_update(
+        USER_ID,
+        UserUpdateDef {
+            permissions: Some(64),
+            ..UserUpdateDef::default()
+        },
+    );
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to update user")
+        .is_some());
+    let req = tenable.user_enable(USER_ID, false);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to disable user")
+        .is_some());
+    let req = tenable.user_authorizations(USER_ID);
+    let authorizations = Tenable::request(req, common::request)
+        .expect("Unable to fetch authorizations")
+        .expect("User not found");
+    assert_eq!(authorizations.api_permitted, Some(true));
+    let req = tenable.user_keys(USER_ID);
+    let keys = Tenable::request(req, common::request)
+        .expect("Unable to generate keys")
+        .expect("User not found");
+    assert_eq!(keys.access_key, ACCESS_KEY);
+    assert!(!format!("{:?}", keys).contains(SECRET_KEY));
+    let req = tenable.user_delete(USER_ID);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to delete user")
+        .is_some());
+
+    let requests = server.transport().requests();
+    let body: UserDef = serde_json::from_slice(&requests[1].body).expect("Invalid body");
+    assert_eq!(body, payload);
+    assert_eq!(requests[3].body, br#"{"permissions":64}"#);
+    assert_eq!(requests[4].body, br#"{"enabled":false}"#);
+
+    // not found
+    let req = tenable.user_by_id(404);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to fetch user")
+        .is_none());
+    let req = tenable.user_update(404, UserUpdateDef::default());
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to update user")
+        .is_none());
+    let req = tenable.user_enable(404, true);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to enable user")
+        .is_none());
+    let req = tenable.user_delete(404);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to delete user")
+        .is_none());
+}
+
+#[test]
+fn rotate_keys() {
+    let server = common::server();
+    let tenable = common::get_tenable(&server);
+    let rotated = tenable
+        .rotate_keys(USER_ID, common::request)
+        .expect("Unable to rotate keys")
+        .expect("User not found");
+    assert_eq!(rotated.uri, tenable.uri);
+    assert_eq!(
+        rotated.auth,
+        format!("accessKey={};secretKey={}", ACCESS_KEY, SECRET_KEY)
+    );
+    assert!(!format!("{:?}", rotated).contains(SECRET_KEY));
+
+    let requests = server.transport().requests();
+    assert_eq!(requests[0].uri.path(), format!("/users/{}/keys", USER_ID));
+    assert_eq!(requests[0].headers["X-ApiKeys"], tenable.auth.as_str());
+
+    // not found
+    assert!(tenable
+        .rotate_keys(404, common::request)
+        .expect("Unable to rotate keys")
+        .is_none());
+}
+
+#[test]
+fn rotate_keys_async() {
+    use tokio::runtime::Runtime;
+
+    let mut rt = Runtime::new().expect("Unable to create runtime");
+    rt.block_on(async {
+        let server = common::server();
+        let tenable = common::get_tenable(&server);
+        let rotated = tenable
+            .rotate_keys_async(USER_ID, common::request_async)
+            .await
+            .expect("Unable to rotate keys")
+            .expect("User not found");
+        assert_eq!(rotated.uri, tenable.uri);
+        assert_ne!(rotated.auth, tenable.auth);
+    });
+}
+
+#[test]
+fn groups() {
+    use std::borrow::Cow;
+    use tenable::{requests::GroupReq, types::GroupDef, Tenable};
+
+    let payload = GroupDef {
+        name: "Web team".into(),
+    };
+
+    let server = common::server();
+    let tenable = common::get_tenable(&server);
+    let groups = Tenable::request(tenable.groups(), common::request)
+        .expect("Unable to list groups")
+        .groups
+        .expect("No groups returned");
+    assert_eq!(groups[0].id, Some(GROUP_ID));
+
+    // test ref
+    let _req = tenable.group_create(&payload);
+    // test cow
+    let _req = tenable.group_create(Cow::Borrowed(&payload));
+    // test owned
+    let req = tenable.group_create(payload.clone());
+    let group = Tenable::request(req, common::request).expect("Unable to create group");
+    assert_eq!(group.name.as_deref(), Some("Web team"));
+
+    let req = tenable.group_update(GROUP_ID, &payload);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to update group")
+        .is_some());
+    let req = tenable.group_add_user(GROUP_ID, USER_ID);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to add user")
+        .is_some());
+    let req = tenable.group_users(GROUP_ID);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to list users")
+        .is_some());
+    let req = tenable.group_remove_user(GROUP_ID, USER_ID);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to remove user")
+        .is_some());
+    let req = tenable.group_delete(GROUP_ID);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to delete group")
+        .is_some());
+
+    let requests = server.transport().requests();
+    let body: GroupDef = serde_json::from_slice(&requests[1].body).expect("Invalid body");
+    assert_eq!(body, payload);
+    assert_eq!(
+        requests[3].uri.path(),
+        format!("/groups/{}/users/{}", GROUP_ID, USER_ID)
+    );
+
+    // not found
+    let req = tenable.group_update(404, &payload);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to update group")
+        .is_none());
+    let req = tenable.group_users(404);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to list users")
+        .is_none());
+    let req = tenable.group_add_user(GROUP_ID, 404);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to add user")
+        .is_none());
+    let req = tenable.group_delete(404);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to delete group")
+        .is_none());
+}
@@ -0,0 +1,58 @@
+mod common;
+
+#[test]
+fn vulns_export() {
+    use std::borrow::Cow;
+    use tenable::{
+        requests::VulnReq,
+        types::{Severity, VulnState, VulnsExportDef, VulnsExportFilters},
+        Tenable,
+    };
+
+    let payload = VulnsExportDef {
+        num_assets: Some(50),
+        filters: Some(VulnsExportFilters {
+            severity: Some(vec![Severity::High, Severity::Critical]),
+            state: Some(vec![VulnState::Open, VulnState::Reopened]),
+            ..VulnsExportFilters::default()
+        }),
+        ..VulnsExportDef::default()
+    };
+
+    let server = common::server();
+    let tenable = common::get_tenable(&server);
+    // test ref
+    let _req = tenable.vulns_export(&payload);
+    // test cow
+    let _req = tenable.vulns_export(Cow::Borrowed(&payload));
+    // test owned
+    let req = tenable.vulns_export(payload);
+    let export = Tenable::request(req, common::request).expect("Unable to export vulns");
+
+    let req = tenable.vulns_export_status(export.export_uuid.as_str());
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to get export status")
+        .is_some());
+
+    let req = tenable.vulns_export_cancel(export.export_uuid.as_str());
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to cancel export")
+        .is_some());
+}
+
+#[test]
+fn vulns_export_not_found() {
+    use tenable::{requests::VulnReq, Tenable};
+
+    let server = common::server();
+    let tenable = common::get_tenable(&server);
+    let req = tenable.vulns_export_status("notfound");
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to get export status")
+        .is_none());
+
+    let req = tenable.vulns_export_chunk("notfound", 1);
+    assert!(Tenable::request(req, common::request)
+        .expect("Unable to get export chunk")
+        .is_none());
+}